@@ -402,7 +402,7 @@ mod opportunity_detection_benchmarks {
 mod queue_benchmarks {
     use super::*;
     use arbitrage2::strategy::opportunity_queue::OpportunityQueue;
-    use arbitrage2::strategy::types::{ArbitrageOpportunity, ConfluenceMetrics, HardConstraints};
+    use arbitrage2::strategy::types::{ArbitrageOpportunity, ConfluenceMetrics, HardConstraints, OpportunityKind};
 
     fn create_test_opportunity() -> ArbitrageOpportunity {
         ArbitrageOpportunity {
@@ -435,6 +435,7 @@ mod queue_benchmarks {
             order_book_depth_long: 15000.0,
             order_book_depth_short: 15000.0,
             timestamp: Some(1234567890),
+            kind: OpportunityKind::SpreadArbitrage,
         }
     }
 
@@ -743,7 +744,7 @@ mod comprehensive_benchmarks {
         println!("\n--- Task 6.4.4: Queue Operations ---");
         {
             use arbitrage2::strategy::opportunity_queue::OpportunityQueue;
-            use arbitrage2::strategy::types::{ArbitrageOpportunity, ConfluenceMetrics, HardConstraints};
+            use arbitrage2::strategy::types::{ArbitrageOpportunity, ConfluenceMetrics, HardConstraints, OpportunityKind};
             
             let queue = OpportunityQueue::with_capacity(10000);
             let producer = queue.producer();
@@ -779,6 +780,7 @@ mod comprehensive_benchmarks {
                 order_book_depth_long: 15000.0,
                 order_book_depth_short: 15000.0,
                 timestamp: Some(1234567890),
+                kind: OpportunityKind::SpreadArbitrage,
             };
             
             // Pre-fill queue
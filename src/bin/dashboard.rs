@@ -16,8 +16,9 @@ use ratatui::{
 };
 use std::io;
 
+use arbitrage2::strategy::filter_analytics::{FilterAnalyticsSnapshot, FilterStage, FILTER_ANALYTICS_KEY};
 use arbitrage2::strategy::opportunity_queue::OpportunityConsumer;
-use arbitrage2::strategy::types::ArbitrageOpportunity;
+use arbitrage2::strategy::types::{ArbitrageOpportunity, OpportunityKind};
 
 type DynError = Box<dyn Error + Send + Sync>;
 
@@ -35,6 +36,7 @@ struct AppState {
     should_quit: bool,
     scroll_offset: usize,
     redis_conn: redis::aio::MultiplexedConnection,
+    filter_analytics: Option<FilterAnalyticsSnapshot>,
 }
 
 impl AppState {
@@ -46,6 +48,19 @@ impl AppState {
             should_quit: false,
             scroll_offset: 0,
             redis_conn,
+            filter_analytics: None,
+        }
+    }
+
+    /// Fetch the detector's latest filter rejection snapshot from Redis
+    async fn update_filter_analytics(&mut self) {
+        use redis::AsyncCommands;
+        
+        let value: Option<String> = self.redis_conn.get(FILTER_ANALYTICS_KEY).await.unwrap_or(None);
+        if let Some(json) = value {
+            if let Ok(snapshot) = serde_json::from_str::<FilterAnalyticsSnapshot>(&json) {
+                self.filter_analytics = Some(snapshot);
+            }
         }
     }
 
@@ -219,6 +234,7 @@ impl AppState {
                             order_book_depth_long: 15000.0,
                             order_book_depth_short: 15000.0,
                             timestamp: Some(now),
+                            kind: OpportunityKind::SpreadArbitrage,
                         };
                         // Store with additional bid/ask info in the key for later lookup
                         let key = format!("{}:{}:{}:{}:{}:{}:{}", symbol, ex1, ex2, bid1, ask1, bid2, ask2);
@@ -256,6 +272,7 @@ impl AppState {
                             order_book_depth_long: 15000.0,
                             order_book_depth_short: 15000.0,
                             timestamp: Some(now),
+                            kind: OpportunityKind::SpreadArbitrage,
                         };
                         let key = format!("{}:{}:{}:{}:{}:{}:{}", symbol, ex2, ex1, bid2, ask2, bid1, ask1);
                         new_opportunities.insert(key, opp);
@@ -398,6 +415,7 @@ async fn main() -> Result<(), DynError> {
         // Update data from Redis periodically (100ms interval)
         if last_update.elapsed() >= update_interval {
             app_state.update_from_redis().await;
+            app_state.update_filter_analytics().await;
             last_update = std::time::Instant::now();
         }
 
@@ -424,6 +442,7 @@ fn ui(f: &mut ratatui::Frame, app: &AppState) {
         .constraints([
             Constraint::Length(4),      // Header
             Constraint::Min(8),         // Opportunities table
+            Constraint::Length(8),      // Filter rejections (top 5 venue pairs + header + borders)
             Constraint::Length(12),     // Removed opportunities (max 10 + 2 for borders)
             Constraint::Length(2)       // Footer
         ])
@@ -580,6 +599,73 @@ fn ui(f: &mut ratatui::Frame, app: &AppState) {
 
     f.render_widget(table, chunks[1]);
 
+    // Filter rejections section (venue pairs whose costs kill the most trades)
+    let filter_rows: Vec<Row> = app.filter_analytics
+        .as_ref()
+        .map(|snapshot| {
+            snapshot.top_venue_pairs_by_profit_rejections(5)
+                .into_iter()
+                .map(|(pair, hist)| {
+                    let dominant = hist.top_dominant_cost()
+                        .map(|c| c.as_str())
+                        .unwrap_or("-");
+                    Row::new(vec![
+                        Span::raw(pair.clone()),
+                        Span::raw(format!("{}", hist.rejections_for(FilterStage::Spread))),
+                        Span::raw(format!("{:.1}", hist.avg_shortfall(FilterStage::Spread))),
+                        Span::raw(format!("{}", hist.rejections_for(FilterStage::Confidence))),
+                        Span::styled(
+                            format!("{}", hist.rejections_for(FilterStage::Profit)),
+                            Style::default().fg(Color::Yellow),
+                        ),
+                        Span::raw(format!("{:.1}", hist.avg_shortfall(FilterStage::Profit))),
+                        Span::raw(dominant),
+                        Span::raw(format!("{}", hist.passed)),
+                    ])
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let filter_title = match &app.filter_analytics {
+        Some(snapshot) => format!(
+            "Filter Rejections by Venue Pair ({} rejected / {} passed)",
+            snapshot.totals.total_rejections(),
+            snapshot.totals.passed
+        ),
+        None => "Filter Rejections by Venue Pair (waiting for detector)".to_string(),
+    };
+
+    let filter_table = Table::new(
+        filter_rows,
+        [
+            Constraint::Length(22),  // Venue pair
+            Constraint::Length(10),  // Spread rejections
+            Constraint::Length(10),  // Avg spread shortfall
+            Constraint::Length(8),   // Confidence rejections
+            Constraint::Length(10),  // Profit rejections
+            Constraint::Length(10),  // Avg profit shortfall
+            Constraint::Length(14),  // Dominant cost
+            Constraint::Length(8),   // Passed
+        ],
+    )
+    .header(
+        Row::new(vec![
+            Span::styled("Pair", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled("Spread", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled("Sp.Short", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled("Conf", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled("Profit", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled("Pr.Short", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled("Top Cost", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled("Passed", Style::default().add_modifier(Modifier::BOLD)),
+        ])
+        .style(Style::default().fg(Color::Cyan)),
+    )
+    .block(Block::default().borders(Borders::ALL).title(filter_title));
+
+    f.render_widget(filter_table, chunks[2]);
+
     // Removed opportunities section
     let removed_rows: Vec<Row> = app.removed_opportunities
         .iter()
@@ -617,12 +703,12 @@ fn ui(f: &mut ratatui::Frame, app: &AppState) {
             .style(Style::default().fg(Color::DarkGray))
     );
 
-    f.render_widget(removed_table, chunks[2]);
+    f.render_widget(removed_table, chunks[3]);
 
     // Footer with controls
     let footer_text = "↑↓: Scroll | PgUp/PgDn: Page | Home/End: Jump | q: Quit | Streaming Mode: 100ms updates";
     let footer = ratatui::widgets::Paragraph::new(footer_text)
         .style(Style::default().fg(Color::Gray))
         .block(Block::default().borders(Borders::TOP));
-    f.render_widget(footer, chunks[3]);
+    f.render_widget(footer, chunks[4]);
 }
@@ -3,6 +3,8 @@ use std::sync::Arc;
 use arbitrage2::strategy::runner::StrategyRunner;
 use arbitrage2::strategy::testnet_backend::TestnetBackend;
 use arbitrage2::strategy::testnet_config::TestnetConfig;
+use arbitrage2::strategy::order_manager::{ManagedBackend, OrderManager};
+use arbitrage2::strategy::testnet::bybit_private_ws::BybitPrivateStream;
 use arbitrage2::strategy::pipeline::MarketPipeline;
 use arbitrage2::strategy::symbol_map::SymbolMap;
 
@@ -17,22 +19,37 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
 
     eprintln!("[DEMO] Starting demo trading runner...");
     eprintln!("[DEMO] This will execute REAL trades on demo accounts!");
-    eprintln!("[DEMO] Bybit + Binance only");
 
     // Load demo credentials from environment
     let config = TestnetConfig::from_env()?;
     
     if !config.has_any_configured() {
         eprintln!("[ERROR] No demo credentials configured in .env");
-        eprintln!("[ERROR] Please set BYBIT_DEMO_* and BINANCE_DEMO_* environment variables");
+        eprintln!("[ERROR] Please set BYBIT_DEMO_*, BINANCE_DEMO_*, BITGET_DEMO_*, OKX_DEMO_*, KUCOIN_DEMO_*, GATEIO_DEMO_*, HYPERLIQUID_DEMO_* or PARADEX_DEMO_* environment variables");
         return Err("Missing demo credentials".into());
     }
 
+    let bybit_credentials = config.bybit.clone();
+
     // Initialize demo backend
-    let demo_backend = Arc::new(TestnetBackend::new(config));
+    let testnet_backend = Arc::new(TestnetBackend::new(config));
+    let demo_exchanges: Vec<String> = testnet_backend.configured_exchanges()
+        .into_iter()
+        .map(String::from)
+        .collect();
+    eprintln!("[DEMO] Trading on: {}", demo_exchanges.join(", "));
 
     // Synchronize server time with exchanges
-    demo_backend.sync_server_time().await?;
+    testnet_backend.sync_server_time().await?;
+
+    // Route orders through the OMS; private streams push fills into it
+    let order_manager = Arc::new(OrderManager::new());
+    let demo_backend = Arc::new(ManagedBackend::new(testnet_backend, order_manager.clone()));
+
+    if let Some(credentials) = bybit_credentials {
+        tokio::spawn(BybitPrivateStream::new(credentials, order_manager.clone()).run());
+        eprintln!("[DEMO] Bybit private stream started (order/execution/position)");
+    }
 
     // Connect to Redis
     let redis_client = redis::Client::open(REDIS_URL)?;
@@ -49,14 +66,14 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
     eprintln!("[DEMO] ✅ Streaming pipeline initialized (low-latency mode)");
 
     // Initialize strategy runner with demo backend and "demo" Redis prefix
-    // Only trade on Bybit and Binance demo accounts
+    // Only trade on venues with configured demo accounts
     // Note: starting_capital will be auto-fetched from exchanges by StrategyRunner::new()
     let mut runner = StrategyRunner::new(
         redis_conn,
         0.0, // Placeholder - will be replaced with actual fetched balance
         demo_backend,
         Some("demo".to_string()),
-        Some(vec!["bybit".to_string(), "binance".to_string()]),
+        Some(demo_exchanges.clone()),
         symbol_map,  // Pass the dynamic symbol map
     ).await?;
     
@@ -66,7 +83,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
 
     eprintln!("[DEMO] Strategy runner initialized");
     eprintln!("[DEMO] Redis prefix: demo");
-    eprintln!("[DEMO] Monitoring Bybit + Binance arbitrage opportunities...");
+    eprintln!("[DEMO] Monitoring {} arbitrage opportunities...", demo_exchanges.join(" + "));
 
     // Run the strategy
     runner.run_scanning_loop().await?;
@@ -7,6 +7,8 @@
 //! - Queue depth metrics (market data and order execution)
 //! - Allocation rate metrics
 //! - CPU utilization metrics
+//! - Detector filter rejections per venue pair (read from Redis)
+//! - Detector shard load and latency (read from Redis)
 //!
 //! ## Usage
 //!
@@ -23,6 +25,10 @@
 //! - Requirement 15.3: Expose latency percentiles
 //! - Requirement 15.4: Track allocations per second in hot paths
 
+use arbitrage2::strategy::filter_analytics::{
+    DominantCost, FilterAnalyticsSnapshot, FilterStage, FILTER_ANALYTICS_KEY,
+};
+use arbitrage2::strategy::sharded_detector::{ShardedDetectorSnapshot, SHARD_METRICS_KEY};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
@@ -44,6 +50,12 @@ struct MetricsState {
     /// CPU utilization statistics
     cpu: Arc<RwLock<CpuMetrics>>,
     
+    /// Detector filter rejection statistics
+    filters: Arc<RwLock<FilterMetrics>>,
+    
+    /// Detector shard load statistics
+    shards: Arc<RwLock<ShardMetrics>>,
+    
     /// Server start time
     start_time: Instant,
 }
@@ -55,6 +67,8 @@ impl MetricsState {
             queue: Arc::new(RwLock::new(QueueMetrics::default())),
             allocation: Arc::new(RwLock::new(AllocationMetrics::default())),
             cpu: Arc::new(RwLock::new(CpuMetrics::default())),
+            filters: Arc::new(RwLock::new(FilterMetrics::default())),
+            shards: Arc::new(RwLock::new(ShardMetrics::default())),
             start_time: Instant::now(),
         }
     }
@@ -299,6 +313,141 @@ impl CpuMetrics {
     }
 }
 
+/// Detector filter rejection metrics
+#[derive(Default, Clone)]
+struct FilterMetrics {
+    /// Latest snapshot published by the opportunity detector
+    snapshot: Option<FilterAnalyticsSnapshot>,
+}
+
+impl FilterMetrics {
+    fn to_prometheus(&self) -> String {
+        let snapshot = match &self.snapshot {
+            Some(s) => s,
+            None => return String::new(),
+        };
+        
+        let mut out = String::from(
+            "# HELP detector_filter_rejections_total Detector rejections by stage and venue pair\n\
+             # TYPE detector_filter_rejections_total counter\n",
+        );
+        let mut pairs: Vec<_> = snapshot.by_venue_pair.iter().collect();
+        pairs.sort_by(|a, b| a.0.cmp(b.0));
+        
+        for (pair, hist) in &pairs {
+            for stage in FilterStage::ALL {
+                out.push_str(&format!(
+                    "detector_filter_rejections_total{{pair=\"{}\",stage=\"{}\"}} {}\n",
+                    pair, stage.as_str(), hist.rejections_for(stage)
+                ));
+            }
+        }
+        
+        out.push_str(
+            "# HELP detector_filter_shortfall_avg Average distance below threshold by stage and venue pair\n\
+             # TYPE detector_filter_shortfall_avg gauge\n",
+        );
+        for (pair, hist) in &pairs {
+            for stage in FilterStage::ALL {
+                out.push_str(&format!(
+                    "detector_filter_shortfall_avg{{pair=\"{}\",stage=\"{}\"}} {:.4}\n",
+                    pair, stage.as_str(), hist.avg_shortfall(stage)
+                ));
+            }
+        }
+        
+        out.push_str(
+            "# HELP detector_profit_rejections_by_cost_total Profit rejections by dominant cost and venue pair\n\
+             # TYPE detector_profit_rejections_by_cost_total counter\n",
+        );
+        for (pair, hist) in &pairs {
+            for (i, cost) in DominantCost::ALL.iter().enumerate() {
+                out.push_str(&format!(
+                    "detector_profit_rejections_by_cost_total{{pair=\"{}\",cost=\"{}\"}} {}\n",
+                    pair, cost.as_str(), hist.dominant_costs[i]
+                ));
+            }
+        }
+        
+        out.push_str(&format!(
+            "# HELP detector_filter_passed_total Candidates that passed every filter\n\
+             # TYPE detector_filter_passed_total counter\n\
+             detector_filter_passed_total {}\n",
+            snapshot.totals.passed
+        ));
+        
+        out
+    }
+}
+
+/// Detector shard load metrics
+#[derive(Default, Clone)]
+struct ShardMetrics {
+    /// Latest snapshot published by the sharded detector
+    snapshot: Option<ShardedDetectorSnapshot>,
+}
+
+impl ShardMetrics {
+    fn to_prometheus(&self) -> String {
+        let snapshot = match &self.snapshot {
+            Some(s) => s,
+            None => return String::new(),
+        };
+        
+        let mut out = String::from(
+            "# HELP detector_shard_updates_processed_total Market updates processed per detector shard\n\
+             # TYPE detector_shard_updates_processed_total counter\n",
+        );
+        for s in &snapshot.shards {
+            out.push_str(&format!(
+                "detector_shard_updates_processed_total{{shard=\"{}\",core=\"{}\"}} {}\n",
+                s.shard, s.core, s.updates_processed
+            ));
+        }
+        
+        out.push_str(
+            "# HELP detector_shard_symbols Instruments assigned per detector shard\n\
+             # TYPE detector_shard_symbols gauge\n",
+        );
+        for s in &snapshot.shards {
+            out.push_str(&format!("detector_shard_symbols{{shard=\"{}\"}} {}\n", s.shard, s.symbols_assigned));
+        }
+        
+        out.push_str(
+            "# HELP detector_shard_queue_depth Pending updates per detector shard\n\
+             # TYPE detector_shard_queue_depth gauge\n",
+        );
+        for s in &snapshot.shards {
+            out.push_str(&format!("detector_shard_queue_depth{{shard=\"{}\"}} {}\n", s.shard, s.queue_depth));
+        }
+        
+        out.push_str(
+            "# HELP detector_shard_queue_drops_total Updates dropped per detector shard (backpressure)\n\
+             # TYPE detector_shard_queue_drops_total counter\n",
+        );
+        for s in &snapshot.shards {
+            out.push_str(&format!("detector_shard_queue_drops_total{{shard=\"{}\"}} {}\n", s.shard, s.queue_drops));
+        }
+        
+        out.push_str(
+            "# HELP detector_shard_latency_microseconds Per-update detection latency per shard\n\
+             # TYPE detector_shard_latency_microseconds gauge\n",
+        );
+        for s in &snapshot.shards {
+            out.push_str(&format!(
+                "detector_shard_latency_microseconds{{shard=\"{}\",quantile=\"0.5\"}} {:.2}\n\
+                 detector_shard_latency_microseconds{{shard=\"{}\",quantile=\"0.99\"}} {:.2}\n\
+                 detector_shard_latency_microseconds{{shard=\"{}\",quantile=\"1\"}} {:.2}\n",
+                s.shard, s.latency_p50_ns as f64 / 1000.0,
+                s.shard, s.latency_p99_ns as f64 / 1000.0,
+                s.shard, s.latency_max_ns as f64 / 1000.0
+            ));
+        }
+        
+        out
+    }
+}
+
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn std::error::Error>> {
     println!("[MONITOR] Starting monitoring HTTP server on 0.0.0.0:9090");
@@ -364,6 +513,8 @@ async fn handle_metrics(state: &MetricsState) -> String {
     let queue = state.queue.read().await;
     let allocation = state.allocation.read().await;
     let cpu = state.cpu.read().await;
+    let filters = state.filters.read().await;
+    let shards = state.shards.read().await;
     
     let body = format!(
         "# Low-Latency Trading System Metrics\n\
@@ -372,12 +523,16 @@ async fn handle_metrics(state: &MetricsState) -> String {
          {}\
          {}\
          {}\
+         {}\
+         {}\
          {}",
         state.uptime_seconds(),
         latency.to_prometheus(),
         queue.to_prometheus(),
         allocation.to_prometheus(),
-        cpu.to_prometheus()
+        cpu.to_prometheus(),
+        filters.to_prometheus(),
+        shards.to_prometheus()
     );
     
     format!(
@@ -457,9 +612,32 @@ fn handle_not_found() -> String {
 async fn collect_metrics_loop(state: MetricsState) {
     let mut interval = tokio::time::interval(Duration::from_secs(1));
     
+    // Redis is optional: filter analytics are only exported when it is reachable
+    let mut redis_conn = match redis::Client::open("redis://127.0.0.1:6379") {
+        Ok(client) => client.get_multiplexed_tokio_connection().await.ok(),
+        Err(_) => None,
+    };
+    if redis_conn.is_none() {
+        eprintln!("[MONITOR] Redis unavailable, detector filter metrics disabled");
+    }
+    
     loop {
         interval.tick().await;
         
+        // Update detector filter metrics (published by OpportunityDetector)
+        if let Some(conn) = redis_conn.as_mut() {
+            use redis::AsyncCommands;
+            let value: Option<String> = conn.get(FILTER_ANALYTICS_KEY).await.unwrap_or(None);
+            if let Some(snapshot) = value.and_then(|json| serde_json::from_str(&json).ok()) {
+                state.filters.write().await.snapshot = Some(snapshot);
+            }
+            
+            let value: Option<String> = conn.get(SHARD_METRICS_KEY).await.unwrap_or(None);
+            if let Some(snapshot) = value.and_then(|json| serde_json::from_str(&json).ok()) {
+                state.shards.write().await.snapshot = Some(snapshot);
+            }
+        }
+        
         // Simulate metrics collection
         // In a real implementation, this would read from shared atomic counters
         // or query the actual pipeline/latency tracker instances
@@ -21,6 +21,31 @@ const SUBSCRIBE_BATCH_DELAY_MS: u64 = 100;
 
 pub struct BitgetUsdtFuturesConnector;
 
+/// USDT spot pairs, published as `bitget:spot:tickers:SYMBOL` (venue `bitget_spot`).
+pub struct BitgetSpotConnector;
+
+/// Instrument type served by a websocket worker.
+struct BitgetMarket {
+    /// `instType` in subscription args
+    inst_type: &'static str,
+    /// Redis key segment after `bitget:`
+    key_market: &'static str,
+    /// Futures tickers double as the funding feed
+    publishes_funding: bool,
+}
+
+const USDT_FUTURES_MARKET: BitgetMarket = BitgetMarket {
+    inst_type: "USDT-FUTURES",
+    key_market: "usdt",
+    publishes_funding: true,
+};
+
+const SPOT_MARKET: BitgetMarket = BitgetMarket {
+    inst_type: "SPOT",
+    key_market: "spot",
+    publishes_funding: false,
+};
+
 #[derive(Debug, Deserialize)]
 struct BitgetResponse<T> {
     code: String,
@@ -38,6 +63,14 @@ struct BitgetContract {
     symbol_status: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct BitgetSpotSymbol {
+    symbol: String,
+    #[serde(rename = "quoteCoin")]
+    quote_coin: String,
+    status: String,
+}
+
 impl BitgetUsdtFuturesConnector {
     async fn connection_check(client: &reqwest::Client) -> Result<(), DynError> {
         let url = format!("{}/api/v2/public/time", BITGET_BASE_URL);
@@ -64,7 +97,29 @@ impl BitgetUsdtFuturesConnector {
         println!("Starting Bitget websocket workers: {}", batches.len());
 
         for (worker_id, batch) in batches.into_iter().enumerate() {
-            tokio::spawn(run_bitget_ws_worker(worker_id, Arc::new(batch), tx.clone()));
+            tokio::spawn(run_bitget_ws_worker(worker_id, &USDT_FUTURES_MARKET, Arc::new(batch), tx.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+impl BitgetSpotConnector {
+    pub async fn run(
+        client: &reqwest::Client, 
+        tx: mpsc::Sender<(String, String)>,
+        _market_producer: Option<crate::strategy::pipeline::MarketProducer>,
+    ) -> Result<(), DynError> {
+        BitgetUsdtFuturesConnector::connection_check(client).await?;
+
+        let symbols = fetch_valid_usdt_spot_symbols(client).await?;
+        println!("Valid BITGET USDT spot symbols (online): {}", symbols.len());
+
+        let batches = utils::chunk_vec(&symbols, SYMBOLS_PER_CONNECTION);
+        println!("Starting Bitget spot websocket workers: {}", batches.len());
+
+        for (worker_id, batch) in batches.into_iter().enumerate() {
+            tokio::spawn(run_bitget_ws_worker(worker_id, &SPOT_MARKET, Arc::new(batch), tx.clone()));
         }
 
         Ok(())
@@ -93,15 +148,37 @@ async fn fetch_valid_usdt_perp_symbols(client: &reqwest::Client) -> Result<Vec<S
     Ok(symbols)
 }
 
+async fn fetch_valid_usdt_spot_symbols(client: &reqwest::Client) -> Result<Vec<String>, DynError> {
+    let url = format!("{}/api/v2/spot/public/symbols", BITGET_BASE_URL);
+    let resp = client.get(url).send().await?.json::<BitgetResponse<BitgetSpotSymbol>>().await?;
+
+    if resp.code != "00000" {
+        return Err(format!("Bitget spot symbols returned code={}", resp.code).into());
+    }
+
+    let mut symbols: Vec<String> = resp
+        .data
+        .into_iter()
+        .filter(|s| s.quote_coin == "USDT")
+        .filter(|s| s.status == "online")
+        .map(|s| s.symbol)
+        .collect();
+
+    symbols.sort();
+    symbols.dedup();
+    Ok(symbols)
+}
+
 async fn subscribe_bitget_channel(
     write: &mut BitgetWrite,
+    inst_type: &str,
     channel: &str,
     inst_ids: &[String],
 ) -> Result<(), DynError> {
     let mut args = Vec::with_capacity(inst_ids.len());
     for inst_id in inst_ids {
         args.push(serde_json::json!({
-            "instType": "USDT-FUTURES",
+            "instType": inst_type,
             "channel": channel,
             "instId": inst_id,
         }));
@@ -119,7 +196,12 @@ async fn subscribe_bitget_channel(
     Ok(())
 }
 
-async fn run_bitget_ws_batch(worker_id: usize, symbols: &[String], tx: mpsc::Sender<(String, String)>) -> Result<(), DynError> {
+async fn run_bitget_ws_batch(
+    worker_id: usize,
+    market: &'static BitgetMarket,
+    symbols: &[String],
+    tx: mpsc::Sender<(String, String)>,
+) -> Result<(), DynError> {
     let (ws, _) = tokio_tungstenite::connect_async(BITGET_WS_PUBLIC_URL).await?;
     let (mut write, mut read) = ws.split();
 
@@ -128,10 +210,10 @@ async fn run_bitget_ws_batch(worker_id: usize, symbols: &[String], tx: mpsc::Sen
     let mut first_data_logged = false;
 
     for chunk in symbols.chunks(SUBSCRIBE_SYMBOLS_PER_MSG) {
-        subscribe_bitget_channel(&mut write, "ticker", chunk).await?;
+        subscribe_bitget_channel(&mut write, market.inst_type, "ticker", chunk).await?;
         time::sleep(std::time::Duration::from_millis(SUBSCRIBE_BATCH_DELAY_MS)).await;
 
-        subscribe_bitget_channel(&mut write, "books5", chunk).await?;
+        subscribe_bitget_channel(&mut write, market.inst_type, "books5", chunk).await?;
         time::sleep(std::time::Duration::from_millis(SUBSCRIBE_BATCH_DELAY_MS)).await;
     }
 
@@ -204,18 +286,20 @@ async fn run_bitget_ws_batch(worker_id: usize, symbols: &[String], tx: mpsc::Sen
 
                 match channel {
                     "ticker" => {
-                        let key = format!("bitget:usdt:tickers:{}", inst_id);
+                        let key = format!("bitget:{}:tickers:{}", market.key_market, inst_id);
                         if tx.send((key, payload.clone())).await.is_err() {
                             break;
                         }
 
-                        let funding_key = format!("bitget:usdt:funding:{}", inst_id);
-                        if tx.send((funding_key, payload)).await.is_err() {
-                            break;
+                        if market.publishes_funding {
+                            let funding_key = format!("bitget:{}:funding:{}", market.key_market, inst_id);
+                            if tx.send((funding_key, payload)).await.is_err() {
+                                break;
+                            }
                         }
                     }
                     "books5" => {
-                        let key = format!("bitget:usdt:book:{}", inst_id);
+                        let key = format!("bitget:{}:book:{}", market.key_market, inst_id);
                         if tx.send((key, payload)).await.is_err() {
                             break;
                         }
@@ -229,7 +313,12 @@ async fn run_bitget_ws_batch(worker_id: usize, symbols: &[String], tx: mpsc::Sen
     Ok(())
 }
 
-async fn run_bitget_ws_worker(worker_id: usize, symbols: Arc<Vec<String>>, tx: mpsc::Sender<(String, String)>) {
+async fn run_bitget_ws_worker(
+    worker_id: usize,
+    market: &'static BitgetMarket,
+    symbols: Arc<Vec<String>>,
+    tx: mpsc::Sender<(String, String)>,
+) {
     // Pin WebSocket thread to cores 2-7 for optimal cache performance
     // Requirement: 4.2 (Pin WebSocket threads to cores 2-7)
     if let Err(e) = crate::strategy::thread_pinning::pin_websocket_thread(worker_id) {
@@ -239,7 +328,7 @@ async fn run_bitget_ws_worker(worker_id: usize, symbols: Arc<Vec<String>>, tx: m
     
     let mut backoff_ms: u64 = 0;
     loop {
-        let res = run_bitget_ws_batch(worker_id, &symbols[..], tx.clone()).await;
+        let res = run_bitget_ws_batch(worker_id, market, &symbols[..], tx.clone()).await;
         match &res {
             Ok(()) => println!("[{}] Bitget ws[{}] disconnected -> reconnecting", utils::ts_hm(), worker_id),
             Err(e) => println!("[{}] Bitget ws[{}] error: {} -> reconnecting", utils::ts_hm(), worker_id, e),
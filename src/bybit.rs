@@ -12,6 +12,7 @@ use crate::strategy::types::{MarketUpdate, symbol_to_id};
 
 const BYBIT_BASE_URL: &str = "https://api.bybit.com";
 const BYBIT_LINEAR_WS_PUBLIC_URL: &str = "wss://stream.bybit.com/v5/public/linear";
+const BYBIT_SPOT_WS_PUBLIC_URL: &str = "wss://stream.bybit.com/v5/public/spot";
 
 const TOPICS_PER_CONNECTION: usize = 100;
 const SUBSCRIBE_BATCH_SIZE: usize = 10;
@@ -19,6 +20,13 @@ const SUBSCRIBE_BATCH_DELAY_MS: u64 = 100;
 
 pub struct BybitLinearConnector;
 
+/// USDT spot books, published as `bybit:spot:tickers:SYMBOL` (venue `bybit_spot`).
+///
+/// Spot tickers carry no bid/ask, so the connector subscribes to the level-1
+/// order book and republishes it in the linear ticker shape
+/// (`data.bid1Price` / `data.ask1Price`) so the Bybit parser reads both.
+pub struct BybitSpotConnector;
+
 #[derive(Debug, Deserialize)]
 struct InstrumentsInfoResponse {
     #[serde(rename = "retCode")]
@@ -60,7 +68,7 @@ impl BybitLinearConnector {
     ) -> Result<(), DynError> {
         Self::connection_check(client).await?;
 
-        let symbols = fetch_valid_linear_symbols(client).await?;
+        let symbols = fetch_valid_symbols(client, "linear").await?;
         println!("Valid BYBIT linear symbols (TRADING): {}", symbols.len());
 
         let mut topics: Vec<String> = symbols.iter().map(|s| format!("tickers.{}", s)).collect();
@@ -82,13 +90,36 @@ impl BybitLinearConnector {
     }
 }
 
-async fn fetch_valid_linear_symbols(client: &reqwest::Client) -> Result<Vec<String>, DynError> {
+impl BybitSpotConnector {
+    pub async fn run(
+        client: &reqwest::Client, 
+        tx: mpsc::Sender<(String, String)>,
+        _market_producer: Option<MarketProducer>,
+    ) -> Result<(), DynError> {
+        BybitLinearConnector::connection_check(client).await?;
+
+        let symbols = fetch_valid_symbols(client, "spot").await?;
+        println!("Valid BYBIT spot symbols (TRADING): {}", symbols.len());
+
+        let topics: Vec<String> = symbols.iter().map(|s| format!("orderbook.1.{}", s)).collect();
+        let batches = utils::chunk_vec(&topics, TOPICS_PER_CONNECTION);
+        println!("Starting Bybit spot websocket workers: {}", batches.len());
+
+        for (worker_id, batch) in batches.into_iter().enumerate() {
+            tokio::spawn(run_bybit_spot_ws_worker(worker_id, Arc::new(batch), tx.clone()));
+        }
+
+        Ok(())
+    }
+}
+
+async fn fetch_valid_symbols(client: &reqwest::Client, category: &str) -> Result<Vec<String>, DynError> {
     let mut cursor: Option<String> = None;
     let mut symbols: Vec<String> = Vec::new();
 
     loop {
         let url = format!("{}/v5/market/instruments-info", BYBIT_BASE_URL);
-        let mut req = client.get(url).query(&[("category", "linear"), ("limit", "1000")]);
+        let mut req = client.get(url).query(&[("category", category), ("limit", "1000")]);
         if let Some(c) = cursor.as_ref() {
             req = req.query(&[("cursor", c.as_str())]);
         }
@@ -305,3 +336,141 @@ async fn run_bybit_linear_ws_worker(
         }
     }
 }
+
+/// Best bid/ask of a level-1 side (`[["price", "size"]]`), if present.
+fn top_level(side: Option<&serde_json::Value>) -> Option<(String, String)> {
+    let level = side?.as_array()?.first()?.as_array()?;
+    let price = level.first()?.as_str()?;
+    let size = level.get(1)?.as_str()?;
+    Some((price.to_string(), size.to_string()))
+}
+
+async fn run_bybit_spot_ws_batch(
+    worker_id: usize, 
+    topics: &[String], 
+    tx: mpsc::Sender<(String, String)>,
+) -> Result<(), DynError> {
+    let (ws, _) = tokio_tungstenite::connect_async(BYBIT_SPOT_WS_PUBLIC_URL).await?;
+    let (mut write, mut read) = ws.split();
+
+    println!("Bybit spot ws[{}] connected", worker_id);
+
+    let mut first_data_logged = false;
+    // Last known top of book per symbol: (bid, bid size, ask, ask size)
+    let mut book_state: std::collections::HashMap<String, (String, String, String, String)> = std::collections::HashMap::new();
+
+    utils::subscribe_in_batches(&mut write, topics, SUBSCRIBE_BATCH_SIZE, SUBSCRIBE_BATCH_DELAY_MS, |w, chunk| {
+        Box::pin(async move {
+            let subscribe = json!({
+                "op": "subscribe",
+                "args": chunk
+            });
+            w
+                .send(tokio_tungstenite::tungstenite::Message::Text(subscribe.to_string()))
+                .await?;
+            Ok(())
+        })
+    }).await?;
+
+    let mut ping_tick = utils::interval_secs(20);
+
+    loop {
+        tokio::select! {
+            _ = ping_tick.tick() => {
+                let ping = json!({"op": "ping"});
+                if write.send(tokio_tungstenite::tungstenite::Message::Text(ping.to_string())).await.is_err() {
+                    break;
+                }
+            }
+            msg = read.next() => {
+                let msg = match msg {
+                    Some(m) => m?,
+                    None => break,
+                };
+
+                let bytes = match msg {
+                    tokio_tungstenite::tungstenite::Message::Text(text) => text.into_bytes(),
+                    tokio_tungstenite::tungstenite::Message::Binary(bytes) => bytes,
+                    _ => continue,
+                };
+                
+                let mut bytes_mut = bytes;
+                let v: serde_json::Value = match simd_json::serde::from_slice(&mut bytes_mut) {
+                    Ok(v) => v,
+                    Err(_) => continue,
+                };
+
+                let symbol = match v.get("topic").and_then(|t| t.as_str()).and_then(|t| t.strip_prefix("orderbook.1.")) {
+                    Some(s) => s,
+                    None => continue,
+                };
+                let data = match v.get("data") {
+                    Some(d) => d,
+                    None => continue,
+                };
+
+                if !first_data_logged {
+                    first_data_logged = true;
+                    println!("Bybit spot ws[{}] first data message received", worker_id);
+                }
+
+                // Keep the previous side when a message leaves one empty
+                let state = book_state.entry(symbol.to_string()).or_default();
+                if let Some((price, size)) = top_level(data.get("b")) {
+                    state.0 = price;
+                    state.1 = size;
+                }
+                if let Some((price, size)) = top_level(data.get("a")) {
+                    state.2 = price;
+                    state.3 = size;
+                }
+                if state.0.is_empty() || state.2.is_empty() {
+                    continue;
+                }
+
+                let payload = json!({
+                    "topic": format!("tickers.{}", symbol),
+                    "ts": v.get("ts").cloned().unwrap_or_default(),
+                    "data": {
+                        "symbol": symbol,
+                        "bid1Price": state.0,
+                        "bid1Size": state.1,
+                        "ask1Price": state.2,
+                        "ask1Size": state.3,
+                    }
+                });
+
+                let key = format!("bybit:spot:tickers:{}", symbol);
+                if tx.send((key, payload.to_string())).await.is_err() {
+                    break;
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+async fn run_bybit_spot_ws_worker(
+    worker_id: usize, 
+    topics: Arc<Vec<String>>, 
+    tx: mpsc::Sender<(String, String)>,
+) {
+    if let Err(e) = crate::strategy::thread_pinning::pin_websocket_thread(worker_id) {
+        eprintln!("[THREAD-PIN] Warning: Failed to pin Bybit spot worker {}: {}", worker_id, e);
+        eprintln!("[THREAD-PIN] Continuing without thread pinning (performance may be degraded)");
+    }
+    
+    let mut backoff_ms: u64 = 0;
+    loop {
+        let res = run_bybit_spot_ws_batch(worker_id, &topics[..], tx.clone()).await;
+        match &res {
+            Ok(()) => println!("[{}] Bybit spot ws[{}] disconnected -> reconnecting", utils::ts_hm(), worker_id),
+            Err(e) => println!("[{}] Bybit spot ws[{}] error: {} -> reconnecting", utils::ts_hm(), worker_id, e),
+        }
+        match res {
+            Ok(()) => utils::reset_backoff(&mut backoff_ms),
+            Err(_) => utils::apply_backoff(&mut backoff_ms).await,
+        }
+    }
+}
@@ -563,8 +563,10 @@ impl ExchangeParser for ParadexParser {
     }
 }
 
+/// Get the parser for an exchange; spot venues (`bybit_spot`, ...) share
+/// their exchange's parser.
 pub fn get_parser(exchange: &str) -> Box<dyn ExchangeParser> {
-    match exchange {
+    match crate::strategy::instruments::base_exchange(exchange) {
         "binance" => Box::new(BinanceParser),
         "bybit" => Box::new(BybitParser),
         "okx" => Box::new(OKXParser),
@@ -583,6 +585,13 @@ pub fn get_parser(exchange: &str) -> Box<dyn ExchangeParser> {
 pub fn get_redis_key_patterns(exchange: &str, symbol: &str) -> Vec<String> {
     match exchange.to_lowercase().as_str() {
         "bybit" => vec![format!("{}:linear:tickers:{}", exchange, symbol)],
+        "bybit_spot" => vec![format!("bybit:spot:tickers:{}", symbol)],
+        "bitget_spot" => vec![format!("bitget:spot:tickers:{}", symbol)],
+        "okx_spot" => {
+            // OKX spot uses format: BASE-USDT
+            let base = symbol.trim_end_matches("USDT");
+            vec![format!("okx:spot:tickers:{}-USDT", base)]
+        },
         "bitget" => vec![format!("{}:usdt:tickers:{}", exchange, symbol)],
         "binance" => vec![
             format!("{}:linear:tickers:{}", exchange, symbol),
@@ -2,7 +2,6 @@ use std::error::Error;
 use std::time::Duration;
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
 
 mod binance;
 mod bitget;
@@ -18,8 +17,9 @@ mod strategy;
 
 use tokio::sync::mpsc;
 use tokio::time;
-use strategy::runner::StrategyRunner;
+use strategy::runner::{is_shutdown_requested, request_shutdown, StrategyRunner};
 use strategy::paper_trading_backend::PaperTradingBackend;
+use strategy::order_manager::{ManagedBackend, OrderManager};
 use crossbeam_queue::ArrayQueue;
 
 const REDIS_URL: &str = "redis://127.0.0.1:6379";
@@ -33,19 +33,6 @@ const SHUTDOWN_TIMEOUT_SECS: u64 = 30;
 
 pub type DynError = Box<dyn Error + Send + Sync>;
 
-/// Global shutdown flag for coordinating graceful shutdown across threads
-static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false);
-
-/// Check if shutdown has been requested
-pub fn is_shutdown_requested() -> bool {
-    SHUTDOWN_REQUESTED.load(Ordering::Relaxed)
-}
-
-/// Request graceful shutdown
-pub fn request_shutdown() {
-    SHUTDOWN_REQUESTED.store(true, Ordering::Relaxed);
-}
-
 /// Background thread for Redis persistence (non-blocking writes)
 /// Uses SPSC queue to decouple Redis writes from hot path
 fn redis_writer_thread(queue: Arc<ArrayQueue<(String, String)>>) {
@@ -151,7 +138,7 @@ async fn flush_redis_buffer(
 /// - Zero-copy where possible
 fn parse_to_market_update(
     key: &str,
-    value: &str,
+    json: &serde_json::Value,
     symbol_map: &strategy::symbol_map::SymbolMap,
 ) -> Option<strategy::types::MarketUpdate> {
     // Parse key format: "exchange:type:subtype:symbol"
@@ -159,6 +146,7 @@ fn parse_to_market_update(
     // - "bybit:linear:tickers:BTCUSDT"
     // - "okx:usdt:tickers:BTC-USDT-SWAP"
     // - "hyperliquid:usdc:ctx:BTC"
+    // - "bybit:spot:tickers:BTCUSDT" (venue "bybit_spot")
     let parts: Vec<&str> = key.split(':').collect();
     if parts.len() < 3 {
         return None;
@@ -170,18 +158,12 @@ fn parse_to_market_update(
     // Normalize symbol to standard format (BTCUSDT)
     let symbol = exchange_parser::normalize_symbol(symbol_raw);
     
-    // Parse JSON value
-    let json: serde_json::Value = match serde_json::from_str(value) {
-        Ok(j) => j,
-        Err(_) => return None,
-    };
-    
     // Get exchange-specific parser
     let parser = exchange_parser::get_parser(exchange);
     
     // Extract bid and ask prices
-    let bid_str = parser.parse_bid(&json)?;
-    let ask_str = parser.parse_ask(&json)?;
+    let bid_str = parser.parse_bid(json)?;
+    let ask_str = parser.parse_ask(json)?;
     
     // Parse prices using SIMD-accelerated parser
     let bid = exchange_parser::parse_price_simd(&bid_str)?;
@@ -192,8 +174,9 @@ fn parse_to_market_update(
         return None;
     }
     
-    // Map (exchange, symbol) to symbol_id
-    let symbol_id = symbol_map.get_or_insert(exchange, &symbol);
+    // Map (venue, symbol) to symbol_id; spot books get their own venue
+    let venue = strategy::instruments::venue_from_key(exchange, parts[1]);
+    let symbol_id = symbol_map.get_or_insert(&venue, &symbol);
     
     // Get current timestamp
     let timestamp_us = std::time::SystemTime::now()
@@ -210,6 +193,29 @@ fn parse_to_market_update(
     ))
 }
 
+/// Extract a funding rate from a connector message.
+///
+/// Returns `(exchange, canonical symbol, rate per settlement interval)`.
+/// KuCoin publishes funding under `kucoin:futures:instrument:<symbol>:funding.rate`,
+/// so the symbol is the last key segment that is not a topic name.
+fn parse_funding_update(key: &str, json: &serde_json::Value) -> Option<(String, String, f64)> {
+    let parts: Vec<&str> = key.split(':').collect();
+    if parts.len() < 3 {
+        return None;
+    }
+    
+    // Spot markets have no funding
+    if parts[1] == "spot" {
+        return None;
+    }
+    
+    let exchange = parts[0];
+    let symbol_raw = parts.iter().rev().find(|p| !p.contains('.'))?;
+    let rate = exchange_parser::get_parser(exchange).parse_funding_rate(json)?;
+    
+    Some((exchange.to_string(), exchange_parser::normalize_symbol(symbol_raw), rate))
+}
+
 /// Bridge task: forwards from mpsc channel to SPSC queue (non-blocking)
 /// This allows connectors to keep using mpsc::Sender while Redis writes use SPSC queue
 /// 
@@ -219,13 +225,26 @@ async fn redis_bridge(
     queue: Arc<ArrayQueue<(String, String)>>,
     pipeline: Arc<strategy::pipeline::MarketPipeline>,
     symbol_map: Arc<strategy::symbol_map::SymbolMap>,
+    funding_store: Arc<strategy::funding_rates::FundingRateStore>,
+    market_data: Arc<std::sync::RwLock<strategy::market_data::MarketDataStore>>,
 ) {
     let producer = pipeline.producer();
     
     while let Some((key, value)) = rx.recv().await {
         // Hot path: Parse and push to pipeline (streaming)
-        if let Some(update) = parse_to_market_update(&key, &value, &symbol_map) {
-            producer.push(update);
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&value) {
+            if let Some(update) = parse_to_market_update(&key, &json, &symbol_map) {
+                // Mids for the risk engine's price band and the strategy runner
+                if let Ok(mut store) = market_data.write() {
+                    store.update_from_market_update(&update);
+                }
+                producer.push(update);
+            }
+            
+            // Funding streams feed the funding-carry detector and holding policy
+            if let Some((exchange, symbol, rate)) = parse_funding_update(&key, &json) {
+                funding_store.update(&exchange, &symbol, rate);
+            }
         }
         
         // Cold path: Push to Redis queue (persistence)
@@ -457,6 +476,19 @@ async fn main() -> Result<(), DynError> {
     let opportunity_queue = Arc::new(strategy::opportunity_queue::OpportunityQueue::new());
     println!("Opportunity queue created (capacity: 1024)");
     
+    // Live funding rates shared by the detector shards and the strategy runner
+    let funding_store = Arc::new(strategy::funding_rates::FundingRateStore::new());
+    
+    // Account fee schedule shared by the detector shards and the strategy runner
+    let fee_schedule = Arc::new(strategy::fee_schedule::FeeSchedule::from_env());
+    
+    // Every order is checked against the risk limits before it is sent; the
+    // bridge below keeps its market data store current
+    let risk_engine = Arc::new(strategy::risk_engine::RiskEngine::new(
+        strategy::risk_engine::RiskLimits::from_env(),
+        symbol_map.clone(),
+    ));
+    
     // Task 5.2.4: Get consumers and producers
     let market_consumer = market_pipeline.consumer();
     let opportunity_producer = opportunity_queue.producer();
@@ -481,8 +513,10 @@ async fn main() -> Result<(), DynError> {
     let redis_queue_bridge = redis_queue.clone();
     let market_pipeline_bridge = market_pipeline.clone();
     let symbol_map_bridge = symbol_map.clone();
+    let funding_store_bridge = funding_store.clone();
+    let market_data_bridge = risk_engine.market_data();
     let bridge_handle = tokio::spawn(async move {
-        redis_bridge(rx, redis_queue_bridge, market_pipeline_bridge, symbol_map_bridge).await;
+        redis_bridge(rx, redis_queue_bridge, market_pipeline_bridge, symbol_map_bridge, funding_store_bridge, market_data_bridge).await;
     });
 
     // DISABLED: Binance websocket connection causes IP bans due to aggressive rate limiting
@@ -502,6 +536,11 @@ async fn main() -> Result<(), DynError> {
 
     spawn_connector!(client, tx, paradex::ParadexPerpsConnector);
 
+    // Spot books for spot-perp basis trades (venues "<exchange>_spot")
+    spawn_connector!(client, tx, bybit::BybitSpotConnector);
+    spawn_connector!(client, tx, okx::OkxSpotConnector);
+    spawn_connector!(client, tx, bitget::BitgetSpotConnector);
+
     // Spawn OI poller (uses same mpsc channel)
     let client_oi = client.clone();
     let tx_oi = tx.clone();
@@ -514,19 +553,20 @@ async fn main() -> Result<(), DynError> {
     drop(tx);
 
     // Task 5.2.6: Create OpportunityDetector with consumers/producers
-    // Task 5.2.7: Spawn detector task
+    // Task 5.2.7: Spawn detector shards (partitioned by canonical symbol, pinned threads)
     println!("Initializing opportunity detection service...");
-    let mut detector = strategy::opportunity_detector::OpportunityDetector::new(
+    let detector_config = strategy::sharded_detector::ShardedDetectorConfig::from_env();
+    let detector = strategy::sharded_detector::ShardedDetector::start(
+        detector_config,
         market_consumer,
         symbol_map.clone(),
         opportunity_producer,
+        Some(redis_queue.clone()),
+        Some(funding_store.clone()),
+        fee_schedule.clone(),
     );
     
-    let detector_handle = tokio::spawn(async move {
-        detector.run().await;
-    });
-    
-    println!("Opportunity detector service started");
+    println!("Opportunity detector service started ({} shard(s))", detector.shard_count());
 
     // Start strategy runner with real data from Redis
     println!("Starting spread arbitrage strategy runner...");
@@ -542,8 +582,33 @@ async fn main() -> Result<(), DynError> {
     initial_balances.insert("okx".to_string(), 20000.0);
     initial_balances.insert("hyperliquid".to_string(), 20000.0);
     initial_balances.insert("paradex".to_string(), 20000.0);
+    for venue in strategy::instruments::SPOT_VENUES {
+        initial_balances.insert(venue.to_string(), 20000.0);
+    }
     
-    let backend = Arc::new(PaperTradingBackend::new(initial_balances));
+    // Every trade and order transition is journaled (fsynced) before it takes effect
+    let trade_journal = Arc::new(strategy::trade_journal::TradeJournal::open(
+        strategy::trade_journal::TradeJournal::path_from_env(),
+    )?);
+    println!("Trade journal: {}", trade_journal.path().display());
+    
+    // All order traffic goes through the order management system
+    let order_manager = Arc::new(OrderManager::new()
+        .with_journal(trade_journal.clone())
+        .with_risk_engine(risk_engine.clone()));
+    let backend = Arc::new(ManagedBackend::new(
+        Arc::new(PaperTradingBackend::new(initial_balances).with_fee_schedule(fee_schedule.clone())),
+        order_manager.clone(),
+    ));
+    
+    // Orders that don't need an inline answer are placed off the strategy task
+    let execution_worker = strategy::execution_worker::ExecutionWorker::start(
+        strategy::execution_worker::ExecutionWorkerConfig::from_env(),
+        backend.clone(),
+        symbol_map.clone(),
+        fee_schedule.clone(),
+        tokio::runtime::Handle::current(),
+    );
     
     let mut strategy_runner = StrategyRunner::new(
         redis_conn, 
@@ -556,6 +621,11 @@ async fn main() -> Result<(), DynError> {
     
     // Task 5.2.8: Pass OpportunityConsumer to StrategyRunner
     strategy_runner.set_opportunity_consumer(opportunity_consumer_strategy);
+    strategy_runner.set_funding_store(funding_store.clone());
+    strategy_runner.set_fee_schedule(fee_schedule.clone());
+    strategy_runner.set_execution_handle(execution_worker.handle());
+    strategy_runner.set_trade_journal(trade_journal);
+    strategy_runner.set_risk_engine(risk_engine);
     
     println!("Strategy runner initialized with $20,000 capital");
     println!("OpportunityConsumer connected to streaming queue");
@@ -604,7 +674,7 @@ async fn main() -> Result<(), DynError> {
     
     let shutdown_result = tokio::time::timeout(
         Duration::from_secs(SHUTDOWN_TIMEOUT_SECS),
-        perform_graceful_shutdown(strategy_handle, bridge_handle, oi_handle, redis_writer_handle, redis_queue, detector_handle)
+        perform_graceful_shutdown(strategy_handle, execution_worker, bridge_handle, oi_handle, redis_writer_handle, redis_queue, detector)
     ).await;
     
     match shutdown_result {
@@ -627,22 +697,24 @@ async fn main() -> Result<(), DynError> {
 ///
 /// This function coordinates the shutdown of:
 /// 1. Strategy runner (stops processing opportunities)
-/// 2. Opportunity detector (stops detecting opportunities)
-/// 3. Redis bridge (stops forwarding messages)
-/// 4. OI poller (stops polling)
-/// 5. Redis writer thread (drains queue and flushes)
-/// 6. WebSocket connections (implicitly closed when tasks are dropped)
+/// 2. Execution worker (dispatches queued orders, then stops)
+/// 3. Opportunity detector (stops detecting opportunities)
+/// 4. Redis bridge (stops forwarding messages)
+/// 5. OI poller (stops polling)
+/// 6. Redis writer thread (drains queue and flushes)
+/// 7. WebSocket connections (implicitly closed when tasks are dropped)
 ///
 /// Requirements: Task 33 (Graceful shutdown), Task 5.2.9 (Stop detector)
 async fn perform_graceful_shutdown(
     strategy_handle: tokio::task::JoinHandle<()>,
+    execution_worker: strategy::execution_worker::ExecutionWorker,
     bridge_handle: tokio::task::JoinHandle<()>,
     oi_handle: tokio::task::JoinHandle<()>,
     redis_writer_handle: std::thread::JoinHandle<()>,
     redis_queue: Arc<ArrayQueue<(String, String)>>,
-    detector_handle: tokio::task::JoinHandle<()>,
+    detector: strategy::sharded_detector::ShardedDetector,
 ) -> Result<(), DynError> {
-    println!("[SHUTDOWN] Step 1/6: Stopping strategy runner...");
+    println!("[SHUTDOWN] Step 1/7: Stopping strategy runner...");
     // Strategy runner will check is_shutdown_requested() and exit gracefully
     // Wait for it to finish processing current opportunities
     if let Err(e) = strategy_handle.await {
@@ -651,17 +723,28 @@ async fn perform_graceful_shutdown(
         println!("[SHUTDOWN] Strategy runner stopped");
     }
     
-    println!("[SHUTDOWN] Step 2/6: Stopping opportunity detector...");
-    // Detector will be aborted (it's a background task)
-    detector_handle.abort();
-    println!("[SHUTDOWN] Opportunity detector stopped");
+    println!("[SHUTDOWN] Step 2/7: Stopping execution worker...");
+    // Queued orders are dispatched before the worker thread exits
+    if let Err(e) = tokio::task::spawn_blocking(move || execution_worker.shutdown()).await {
+        eprintln!("[SHUTDOWN] Execution worker join error: {}", e);
+    } else {
+        println!("[SHUTDOWN] Execution worker stopped");
+    }
+    
+    println!("[SHUTDOWN] Step 3/7: Stopping opportunity detector...");
+    // Detector shards are OS threads: signal them and wait for them to exit
+    if let Err(e) = tokio::task::spawn_blocking(move || detector.shutdown()).await {
+        eprintln!("[SHUTDOWN] Opportunity detector join error: {}", e);
+    } else {
+        println!("[SHUTDOWN] Opportunity detector stopped");
+    }
     
-    println!("[SHUTDOWN] Step 3/6: Stopping OI poller...");
+    println!("[SHUTDOWN] Step 4/7: Stopping OI poller...");
     // OI poller will be aborted (it's a background task)
     oi_handle.abort();
     println!("[SHUTDOWN] OI poller stopped");
     
-    println!("[SHUTDOWN] Step 4/6: Stopping Redis bridge...");
+    println!("[SHUTDOWN] Step 5/7: Stopping Redis bridge...");
     // Redis bridge will exit when the mpsc channel is closed (already dropped tx)
     if let Err(e) = bridge_handle.await {
         if !e.is_cancelled() {
@@ -671,7 +754,7 @@ async fn perform_graceful_shutdown(
         println!("[SHUTDOWN] Redis bridge stopped");
     }
     
-    println!("[SHUTDOWN] Step 5/6: Flushing Redis writes...");
+    println!("[SHUTDOWN] Step 6/7: Flushing Redis writes...");
     // Wait for Redis writer thread to drain queue and flush
     // The thread checks is_shutdown_requested() and will exit after draining
     let queue_depth = redis_queue.len();
@@ -685,7 +768,7 @@ async fn perform_graceful_shutdown(
         println!("[SHUTDOWN] Redis writes flushed");
     }
     
-    println!("[SHUTDOWN] Step 6/6: Saving state to disk...");
+    println!("[SHUTDOWN] Step 7/7: Saving state to disk...");
     // Save final state snapshot
     if let Err(e) = save_shutdown_state().await {
         eprintln!("[SHUTDOWN] Failed to save state: {}", e);
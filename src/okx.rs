@@ -23,6 +23,27 @@ const SUBSCRIBE_BATCH_DELAY_MS: u64 = 50;
 
 pub struct OkxUsdtSwapConnector;
 
+/// USDT spot pairs, published as `okx:spot:tickers:BASE-USDT` (venue `okx_spot`).
+pub struct OkxSpotConnector;
+
+/// Instrument type served by a websocket worker.
+struct OkxMarket {
+    /// Redis key segment after `okx:`
+    key_market: &'static str,
+    /// Public channels to subscribe per instrument
+    channels: &'static [&'static str],
+}
+
+const USDT_SWAP_MARKET: OkxMarket = OkxMarket {
+    key_market: "usdt",
+    channels: &["tickers", "funding-rate", "books5"],
+};
+
+const SPOT_MARKET: OkxMarket = OkxMarket {
+    key_market: "spot",
+    channels: &["tickers", "books5"],
+};
+
 #[derive(Debug, Deserialize)]
 struct OkxResponse<T> {
     code: String,
@@ -34,8 +55,10 @@ struct OkxInstrument {
     #[serde(rename = "instId")]
     inst_id: String,
     state: String,
-    #[serde(rename = "settleCcy")]
+    #[serde(rename = "settleCcy", default)]
     settle_ccy: String,
+    #[serde(rename = "quoteCcy", default)]
+    quote_ccy: String,
 }
 
 impl OkxUsdtSwapConnector {
@@ -66,6 +89,7 @@ impl OkxUsdtSwapConnector {
         for (worker_id, batch) in batches.into_iter().enumerate() {
             tokio::spawn(run_okx_ws_worker(
                 worker_id, 
+                &USDT_SWAP_MARKET,
                 Arc::new(batch), 
                 tx.clone(),
                 market_producer.clone(),
@@ -76,6 +100,30 @@ impl OkxUsdtSwapConnector {
     }
 }
 
+impl OkxSpotConnector {
+    pub async fn run(
+        client: &reqwest::Client, 
+        tx: mpsc::Sender<(String, String)>,
+        _market_producer: Option<MarketProducer>,
+    ) -> Result<(), DynError> {
+        OkxUsdtSwapConnector::connection_check(client).await?;
+
+        let inst_ids = fetch_valid_usdt_spot_instruments(client).await?;
+        println!("Valid OKX USDT SPOT instruments (live): {}", inst_ids.len());
+
+        let batches = utils::chunk_vec(&inst_ids, INSTRUMENTS_PER_CONNECTION);
+        println!("Starting OKX spot websocket workers: {}", batches.len());
+
+        // Spot books never feed the hot path: the global symbol ids are per
+        // canonical symbol and would overwrite the perp book
+        for (worker_id, batch) in batches.into_iter().enumerate() {
+            tokio::spawn(run_okx_ws_worker(worker_id, &SPOT_MARKET, Arc::new(batch), tx.clone(), None));
+        }
+
+        Ok(())
+    }
+}
+
 async fn fetch_valid_usdt_swap_instruments(client: &reqwest::Client) -> Result<Vec<String>, DynError> {
     let url = format!("{}/api/v5/public/instruments?instType=SWAP", OKX_BASE_URL);
     let resp = client.get(url).send().await?.json::<OkxResponse<OkxInstrument>>().await?;
@@ -97,6 +145,27 @@ async fn fetch_valid_usdt_swap_instruments(client: &reqwest::Client) -> Result<V
     Ok(inst_ids)
 }
 
+async fn fetch_valid_usdt_spot_instruments(client: &reqwest::Client) -> Result<Vec<String>, DynError> {
+    let url = format!("{}/api/v5/public/instruments?instType=SPOT", OKX_BASE_URL);
+    let resp = client.get(url).send().await?.json::<OkxResponse<OkxInstrument>>().await?;
+
+    if resp.code != "0" {
+        return Err(format!("OKX instruments returned code={}", resp.code).into());
+    }
+
+    let mut inst_ids: Vec<String> = resp
+        .data
+        .into_iter()
+        .filter(|i| i.state == "live")
+        .filter(|i| i.quote_ccy == "USDT")
+        .map(|i| i.inst_id)
+        .collect();
+
+    inst_ids.sort();
+    inst_ids.dedup();
+    Ok(inst_ids)
+}
+
 async fn subscribe_channel(
     write: &mut OkxWrite,
     channel: &str,
@@ -121,6 +190,7 @@ async fn subscribe_channel(
 
 async fn run_okx_ws_batch(
     worker_id: usize, 
+    market: &'static OkxMarket,
     inst_ids: &[String], 
     tx: mpsc::Sender<(String, String)>,
     market_producer: Option<MarketProducer>,
@@ -134,14 +204,10 @@ async fn run_okx_ws_batch(
 
     utils::subscribe_in_batches(&mut write, inst_ids, SUBSCRIBE_INSTRUMENTS_PER_MSG, 0, |w, chunk| {
         Box::pin(async move {
-            subscribe_channel(w, "tickers", chunk).await?;
-            time::sleep(std::time::Duration::from_millis(SUBSCRIBE_BATCH_DELAY_MS)).await;
-
-            subscribe_channel(w, "funding-rate", chunk).await?;
-            time::sleep(std::time::Duration::from_millis(SUBSCRIBE_BATCH_DELAY_MS)).await;
-
-            subscribe_channel(w, "books5", chunk).await?;
-            time::sleep(std::time::Duration::from_millis(SUBSCRIBE_BATCH_DELAY_MS)).await;
+            for channel in market.channels {
+                subscribe_channel(w, channel, chunk).await?;
+                time::sleep(std::time::Duration::from_millis(SUBSCRIBE_BATCH_DELAY_MS)).await;
+            }
             Ok(())
         })
     }).await?;
@@ -257,9 +323,9 @@ async fn run_okx_ws_batch(
                 }
 
                 let key = match channel {
-                    "tickers" => format!("okx:usdt:tickers:{}", inst_id),
-                    "funding-rate" => format!("okx:usdt:funding:{}", inst_id),
-                    "books5" => format!("okx:usdt:book:{}", inst_id),
+                    "tickers" => format!("okx:{}:tickers:{}", market.key_market, inst_id),
+                    "funding-rate" => format!("okx:{}:funding:{}", market.key_market, inst_id),
+                    "books5" => format!("okx:{}:book:{}", market.key_market, inst_id),
                     _ => continue,
                 };
 
@@ -277,6 +343,7 @@ async fn run_okx_ws_batch(
 
 async fn run_okx_ws_worker(
     worker_id: usize, 
+    market: &'static OkxMarket,
     inst_ids: Arc<Vec<String>>, 
     tx: mpsc::Sender<(String, String)>,
     market_producer: Option<MarketProducer>,
@@ -290,7 +357,7 @@ async fn run_okx_ws_worker(
     
     let mut backoff_ms: u64 = 0;
     loop {
-        let res = run_okx_ws_batch(worker_id, &inst_ids[..], tx.clone(), market_producer.clone()).await;
+        let res = run_okx_ws_batch(worker_id, market, &inst_ids[..], tx.clone(), market_producer.clone()).await;
         match &res {
             Ok(()) => println!("[{}] OKX ws[{}] disconnected -> reconnecting", utils::ts_hm(), worker_id),
             Err(e) => println!("[{}] OKX ws[{}] error: {} -> reconnecting", utils::ts_hm(), worker_id, e),
@@ -1,6 +1,8 @@
 use crate::strategy::types::{SimulatedOrder, OrderSide, OrderStatus};
 use crate::strategy::entry::EntryExecutor;
 use crate::strategy::execution_backend::ExecutionBackend;
+use crate::strategy::execution_error::ExecutionError;
+use serde::{Deserialize, Serialize};
 use std::error::Error;
 use std::time::{Instant, Duration};
 use std::sync::Arc;
@@ -26,6 +28,9 @@ pub struct HedgeTimingMetrics {
     pub first_reprice_at: Option<Instant>,
     pub last_reprice_at: Option<Instant>,
     pub api_response_times: Vec<(String, Duration)>,
+    /// Set when the hedge belongs to one slice of a sliced execution
+    pub slice_index: Option<u32>,
+    pub slice_quantity: Option<f64>,
 }
 
 impl HedgeTimingMetrics {
@@ -81,6 +86,11 @@ impl HedgeTimingMetrics {
         self.api_response_times.push((endpoint, duration));
     }
 
+    pub fn record_slice(&mut self, index: u32, quantity: f64) {
+        self.slice_index = Some(index);
+        self.slice_quantity = Some(quantity);
+    }
+
     pub fn fill_to_other_leg_check(&self) -> Option<Duration> {
         match (self.fill_detected_at, self.other_leg_check_at) {
             (Some(fill), Some(check)) => Some(check.duration_since(fill)),
@@ -145,6 +155,9 @@ impl HedgeTimingMetrics {
 
     pub fn log_summary(&self) {
         println!("=== Hedge Timing Summary ===");
+        if let (Some(index), Some(quantity)) = (self.slice_index, self.slice_quantity) {
+            println!("Slice {}: {} contracts", index, quantity);
+        }
         if let Some(d) = self.fill_to_other_leg_check() {
             println!("Fill to other leg check: {}ms", d.as_millis());
         }
@@ -438,58 +451,84 @@ impl MarketOrderPlacer {
         Self { backend }
     }
 
+    /// Place a market order, retrying only while the submission itself fails.
+    ///
+    /// The order keeps one ID across attempts so a backend wrapped in the OMS
+    /// (`ManagedBackend`) recognises retries of an order it already accepted.
+    /// Once accepted, the order is re-polled instead of submitted again.
+    /// Rejections that a resubmission can't fix (balance, quantity, auth) are
+    /// returned immediately; throttled submissions wait out `retry_after`.
     pub async fn place_with_retry(
         &self,
         order: SimulatedOrder,
-        target_quantity: f64,
+        _target_quantity: f64,
         max_retries: u32,
         metrics: &mut HedgeTimingMetrics,
-    ) -> Result<SimulatedOrder, String> {
+    ) -> Result<SimulatedOrder, ExecutionError> {
+        let mut order = order;
+        if order.id.is_empty() {
+            order.id = format!("mkt_{}", uuid::Uuid::new_v4().simple());
+        }
+
         let mut attempts = 0;
-        let mut current_order = order.clone();
+        let mut placed: Option<SimulatedOrder> = None;
 
         while attempts < max_retries {
             attempts += 1;
 
-            // Record API call
-            let start = Instant::now();
-            let result = self.backend.place_market_order(current_order.clone()).await;
-            let duration = start.elapsed();
-            metrics.record_api_response(format!("place_market_order_attempt_{}", attempts), duration);
-
-            match result {
-                Ok(placed_order) => {
-                    // Check if filled
-                    let status_start = Instant::now();
-                    let status_result = self.backend.get_order_status_detailed(&placed_order.exchange, &placed_order.id, &order.symbol).await;
-                    let status_duration = status_start.elapsed();
-                    metrics.record_api_response(format!("get_order_status_attempt_{}", attempts), status_duration);
-
-                    if let Ok(status_info) = status_result {
-                        if status_info.status == OrderStatus::Filled {
-                            return Ok(placed_order);
+            let placed_order = match placed.clone() {
+                Some(p) => p,
+                None => {
+                    // Record API call
+                    let start = Instant::now();
+                    let result = self.backend.place_market_order(order.clone()).await;
+                    let duration = start.elapsed();
+                    metrics.record_api_response(format!("place_market_order_attempt_{}", attempts), duration);
+
+                    match result {
+                        Ok(p) => {
+                            placed = Some(p.clone());
+                            p
+                        }
+                        Err(e) => {
+                            let permanent = matches!(e,
+                                ExecutionError::InsufficientBalance(_)
+                                | ExecutionError::InvalidQuantity(_)
+                                | ExecutionError::Auth(_));
+                            if permanent || attempts >= max_retries {
+                                eprintln!("[MARKET ORDER] Giving up after {} attempt(s): {}", attempts, e);
+                                return Err(e);
+                            }
+                            let wait = match e {
+                                ExecutionError::RateLimited { retry_after: Some(wait) } => wait,
+                                _ => Duration::from_millis(100),
+                            };
+                            tokio::time::sleep(wait).await;
+                            continue;
                         }
                     }
+                }
+            };
 
-                    // If not filled and we have retries left, try again
-                    if attempts < max_retries {
-                        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                        current_order = order.clone();
-                        continue;
-                    }
+            // Check if filled
+            let status_start = Instant::now();
+            let status_result = self.backend.get_order_status_detailed(&placed_order.exchange, &placed_order.id, &order.symbol).await;
+            let status_duration = status_start.elapsed();
+            metrics.record_api_response(format!("get_order_status_attempt_{}", attempts), status_duration);
 
+            if let Ok(status_info) = status_result {
+                if status_info.status == OrderStatus::Filled {
                     return Ok(placed_order);
                 }
-                Err(e) => {
-                    if attempts >= max_retries {
-                        return Err(format!("Failed after {} attempts: {}", attempts, e));
-                    }
-                    tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                }
+            }
+
+            // Not filled yet: poll again if we have attempts left
+            if attempts < max_retries {
+                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
             }
         }
 
-        Err(format!("Failed to place order after {} attempts", max_retries))
+        placed.ok_or_else(|| ExecutionError::Other(format!("Failed to place order after {} attempts", max_retries)))
     }
 }
 
@@ -500,14 +539,21 @@ impl MarketOrderPlacer {
 use std::sync::atomic::{AtomicBool, Ordering};
 
 static TRADING_HALTED: AtomicBool = AtomicBool::new(false);
+static HALT_REASON: Mutex<Option<String>> = Mutex::new(None);
 
 pub fn halt_trading(reason: &str) {
     TRADING_HALTED.store(true, Ordering::SeqCst);
+    if let Ok(mut current) = HALT_REASON.lock() {
+        *current = Some(reason.to_string());
+    }
     println!("[TRADING HALT] Trading has been halted: {}", reason);
 }
 
 pub fn resume_trading() {
     TRADING_HALTED.store(false, Ordering::SeqCst);
+    if let Ok(mut current) = HALT_REASON.lock() {
+        *current = None;
+    }
     println!("[TRADING RESUME] Trading has been resumed");
 }
 
@@ -515,6 +561,14 @@ pub fn is_trading_halted() -> bool {
     TRADING_HALTED.load(Ordering::SeqCst)
 }
 
+/// Reason passed to the last `halt_trading`, while halted
+pub fn trading_halt_reason() -> Option<String> {
+    if !is_trading_halted() {
+        return None;
+    }
+    HALT_REASON.lock().ok().and_then(|reason| reason.clone())
+}
+
 // ============================================================================
 // Existing Types
 // ============================================================================
@@ -528,7 +582,7 @@ pub struct AtomicExecutionResult {
     pub error: Option<String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 #[allow(dead_code)]
 pub struct NegativeFundingTracker {
     pub symbol: String,
@@ -0,0 +1,389 @@
+//! Cross-venue collateral rebalancing
+//!
+//! Each trade makes on one venue what it loses on the other, so free
+//! collateral drifts towards the venues that happened to be on the winning
+//! side until the others can no longer fund an entry. The planner reads free
+//! balances (`get_all_balances`) and the margin held by open positions, and
+//! recommends transfers so each venue's free collateral matches its share of
+//! recent opportunity flow:
+//!
+//! ```text
+//! reserve_v = reserve_fraction × free_total / n_venues
+//! target_v  = reserve_v + demand_v / demand_total × (free_total − Σ reserve)
+//! ```
+//!
+//! `demand_v` is the collateral the opportunities of the last
+//! `flow_window_secs` would have needed on venue `v` (both legs, at the
+//! configured leverage). Without recent flow every venue gets an equal share.
+//! Margin top-ups requested by the margin monitor are planned first.
+//!
+//! Transfers only run when `auto_execute` is set and a `TransferApi` is
+//! installed; otherwise the plan is published for an operator.
+
+use crate::strategy::execution_error::ExecutionError;
+use crate::strategy::instruments::{base_exchange, instrument_kind};
+use crate::strategy::margin::LeverageConfig;
+use crate::strategy::types::InstrumentKind;
+use serde::Serialize;
+use std::collections::{HashMap, VecDeque};
+
+/// Configuration of the rebalancing planner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollateralConfig {
+    pub check_interval_secs: u64,
+    /// Opportunity flow older than this no longer shapes the targets
+    pub flow_window_secs: u64,
+    /// Fraction of free collateral spread evenly over all venues regardless of flow
+    pub reserve_fraction: f64,
+    /// Smaller imbalances are left alone (withdrawal fees, minimums)
+    pub min_transfer_usd: f64,
+    /// Execute planned transfers through the `TransferApi`
+    pub auto_execute: bool,
+    /// Minimum time between executed transfers
+    pub transfer_cooldown_secs: u64,
+    /// Asset moved between venues
+    pub transfer_asset: String,
+}
+
+impl Default for CollateralConfig {
+    fn default() -> Self {
+        Self {
+            check_interval_secs: 60,
+            flow_window_secs: 3600,
+            reserve_fraction: 0.2,
+            min_transfer_usd: 100.0,
+            auto_execute: false,
+            transfer_cooldown_secs: 900,
+            transfer_asset: "USDT".to_string(),
+        }
+    }
+}
+
+impl CollateralConfig {
+    /// Override defaults from `COLLATERAL_CHECK_INTERVAL_SECS`, `COLLATERAL_FLOW_WINDOW_SECS`,
+    /// `COLLATERAL_RESERVE_FRACTION`, `COLLATERAL_MIN_TRANSFER_USD`, `COLLATERAL_AUTO_EXECUTE`,
+    /// `COLLATERAL_TRANSFER_COOLDOWN_SECS` and `COLLATERAL_TRANSFER_ASSET`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let parse = |var: &str, default: f64| -> f64 {
+            std::env::var(var)
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .unwrap_or(default)
+        };
+
+        Self {
+            check_interval_secs: parse("COLLATERAL_CHECK_INTERVAL_SECS", defaults.check_interval_secs as f64).max(1.0) as u64,
+            flow_window_secs: parse("COLLATERAL_FLOW_WINDOW_SECS", defaults.flow_window_secs as f64).max(1.0) as u64,
+            reserve_fraction: parse("COLLATERAL_RESERVE_FRACTION", defaults.reserve_fraction).min(1.0),
+            min_transfer_usd: parse("COLLATERAL_MIN_TRANSFER_USD", defaults.min_transfer_usd),
+            auto_execute: std::env::var("COLLATERAL_AUTO_EXECUTE")
+                .map(|v| v == "1" || v.eq_ignore_ascii_case("true"))
+                .unwrap_or(defaults.auto_execute),
+            transfer_cooldown_secs: parse("COLLATERAL_TRANSFER_COOLDOWN_SECS", defaults.transfer_cooldown_secs as f64) as u64,
+            transfer_asset: std::env::var("COLLATERAL_TRANSFER_ASSET").unwrap_or(defaults.transfer_asset),
+        }
+    }
+}
+
+/// Collateral demand of recent opportunities, per exchange account.
+#[derive(Debug, Clone, Default)]
+pub struct OpportunityFlow {
+    /// (unix secs, exchange, collateral usd), oldest first
+    events: VecDeque<(u64, String, f64)>,
+}
+
+impl OpportunityFlow {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the collateral a `notional_usd` leg on `venue` needs: initial
+    /// margin on perps, the full notional on spot. Spot venues draw on their
+    /// exchange's account.
+    pub fn record(&mut self, now: u64, venue: &str, symbol: &str, notional_usd: f64, leverage: &LeverageConfig) {
+        let collateral = match instrument_kind(venue) {
+            InstrumentKind::Perp => leverage.required_margin(venue, symbol, notional_usd),
+            InstrumentKind::Spot => notional_usd,
+        };
+        self.events.push_back((now, base_exchange(venue).to_string(), collateral));
+    }
+
+    /// Collateral demanded per exchange within the last `window_secs`;
+    /// older events are dropped. Events are expected in time order.
+    pub fn demand(&mut self, now: u64, window_secs: u64) -> HashMap<String, f64> {
+        while self.events.front().is_some_and(|(ts, _, _)| now.saturating_sub(*ts) > window_secs) {
+            self.events.pop_front();
+        }
+        let mut demand = HashMap::new();
+        for (_, exchange, collateral) in &self.events {
+            *demand.entry(exchange.clone()).or_insert(0.0) += collateral;
+        }
+        demand
+    }
+}
+
+/// Extra margin the margin monitor wants on `to_exchange` for a thin leg
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MarginTopUp {
+    pub trade_id: String,
+    pub from_exchange: String,
+    pub to_exchange: String,
+    pub amount_usd: f64,
+}
+
+/// Why a transfer was planned
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TransferReason {
+    /// A leg of `trade_id` is close to liquidation
+    MarginTopUp { trade_id: String },
+    /// Free collateral does not match opportunity flow
+    Allocation,
+}
+
+/// Recommended move of collateral between two exchange accounts
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CollateralTransfer {
+    pub from_exchange: String,
+    pub to_exchange: String,
+    pub amount_usd: f64,
+    pub reason: TransferReason,
+}
+
+/// Collateral of one exchange account
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct VenueCollateral {
+    pub exchange: String,
+    pub free_usd: f64,
+    /// Margin held by open positions
+    pub margin_used_usd: f64,
+    /// Share of recent opportunity flow (0..1)
+    pub demand_share: f64,
+    pub target_free_usd: f64,
+    /// Notional of new perp legs the free collateral funds today
+    pub capacity_usd: f64,
+    /// Same, once the planned transfers have landed
+    pub projected_capacity_usd: f64,
+}
+
+/// Output of one planning pass
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CollateralPlan {
+    pub venues: Vec<VenueCollateral>,
+    pub transfers: Vec<CollateralTransfer>,
+}
+
+/// Plan transfers between the exchanges in `free`.
+///
+/// `margin_used` is informational; only free collateral is moved.
+pub fn plan_rebalance(
+    free: &HashMap<String, f64>,
+    margin_used: &HashMap<String, f64>,
+    demand: &HashMap<String, f64>,
+    top_ups: &[MarginTopUp],
+    leverage: &LeverageConfig,
+    config: &CollateralConfig,
+) -> CollateralPlan {
+    let mut exchanges: Vec<&String> = free.keys().collect();
+    exchanges.sort();
+    let n = exchanges.len();
+    let mut balances: HashMap<&str, f64> = exchanges.iter().map(|e| (e.as_str(), free[*e].max(0.0))).collect();
+    let mut transfers = Vec::new();
+
+    // Margin top-ups come first, funded from the trade's other leg
+    for top_up in top_ups {
+        let (Some(&source), true) = (balances.get(top_up.from_exchange.as_str()), balances.contains_key(top_up.to_exchange.as_str())) else {
+            continue;
+        };
+        let amount = top_up.amount_usd.min(source);
+        if amount < config.min_transfer_usd {
+            continue;
+        }
+        *balances.get_mut(top_up.from_exchange.as_str()).unwrap() -= amount;
+        *balances.get_mut(top_up.to_exchange.as_str()).unwrap() += amount;
+        transfers.push(CollateralTransfer {
+            from_exchange: top_up.from_exchange.clone(),
+            to_exchange: top_up.to_exchange.clone(),
+            amount_usd: amount,
+            reason: TransferReason::MarginTopUp { trade_id: top_up.trade_id.clone() },
+        });
+    }
+
+    let total_free: f64 = balances.values().sum();
+    let total_demand: f64 = exchanges.iter().map(|e| demand.get(*e).copied().unwrap_or(0.0)).sum();
+    let reserve = if n > 0 { config.reserve_fraction * total_free / n as f64 } else { 0.0 };
+    let allocatable = total_free - reserve * n as f64;
+    let share = |exchange: &str| -> f64 {
+        if total_demand > 0.0 {
+            demand.get(exchange).copied().unwrap_or(0.0) / total_demand
+        } else {
+            1.0 / n as f64
+        }
+    };
+    let targets: HashMap<&str, f64> = exchanges
+        .iter()
+        .map(|e| (e.as_str(), reserve + share(e) * allocatable))
+        .collect();
+
+    // Largest surplus funds largest deficit until one side runs out
+    let mut surpluses: Vec<(&str, f64)> = Vec::new();
+    let mut deficits: Vec<(&str, f64)> = Vec::new();
+    for exchange in &exchanges {
+        let gap = balances[exchange.as_str()] - targets[exchange.as_str()];
+        if gap > 0.0 {
+            surpluses.push((exchange.as_str(), gap));
+        } else if gap < 0.0 {
+            deficits.push((exchange.as_str(), -gap));
+        }
+    }
+    surpluses.sort_by(|a, b| b.1.total_cmp(&a.1));
+    deficits.sort_by(|a, b| b.1.total_cmp(&a.1));
+    for (to, mut needed) in deficits {
+        for (from, available) in surpluses.iter_mut() {
+            let amount = needed.min(*available);
+            if amount < config.min_transfer_usd {
+                continue;
+            }
+            *available -= amount;
+            needed -= amount;
+            *balances.get_mut(*from).unwrap() -= amount;
+            *balances.get_mut(to).unwrap() += amount;
+            transfers.push(CollateralTransfer {
+                from_exchange: from.to_string(),
+                to_exchange: to.to_string(),
+                amount_usd: amount,
+                reason: TransferReason::Allocation,
+            });
+        }
+    }
+
+    let capacity = |exchange: &str, collateral: f64| collateral * leverage.leverage_for(exchange, "") as f64;
+    let venues = exchanges
+        .iter()
+        .map(|exchange| {
+            let free_usd = free[*exchange].max(0.0);
+            VenueCollateral {
+                exchange: exchange.to_string(),
+                free_usd,
+                margin_used_usd: margin_used.get(*exchange).copied().unwrap_or(0.0),
+                demand_share: share(exchange),
+                target_free_usd: targets[exchange.as_str()],
+                capacity_usd: capacity(exchange, free_usd),
+                projected_capacity_usd: capacity(exchange, balances[exchange.as_str()]),
+            }
+        })
+        .collect();
+
+    CollateralPlan { venues, transfers }
+}
+
+/// Withdrawal and deposit endpoints used to move collateral between venues.
+#[async_trait::async_trait]
+pub trait TransferApi: Send + Sync {
+    /// Address on `exchange` that accepts deposits of `asset`
+    async fn deposit_address(&self, exchange: &str, asset: &str) -> Result<String, ExecutionError>;
+
+    /// Withdraw `amount` of `asset` from `exchange` to `address`; returns the withdrawal id
+    async fn withdraw(&self, exchange: &str, asset: &str, amount: f64, address: &str) -> Result<String, ExecutionError>;
+}
+
+/// Execute one planned transfer: withdraw from the source to the target's deposit address.
+pub async fn execute_transfer(api: &dyn TransferApi, transfer: &CollateralTransfer, asset: &str) -> Result<String, ExecutionError> {
+    let address = api.deposit_address(&transfer.to_exchange, asset).await?;
+    api.withdraw(&transfer.from_exchange, asset, transfer.amount_usd, &address).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    fn balances(entries: &[(&str, f64)]) -> HashMap<String, f64> {
+        entries.iter().map(|(e, v)| (e.to_string(), *v)).collect()
+    }
+
+    #[test]
+    fn test_flow_drives_targets() {
+        let leverage = LeverageConfig::default();
+        let mut flow = OpportunityFlow::new();
+        flow.record(0, "binance", "BTCUSDT", 1_000.0, &leverage); // outside the window
+        // Three recent opportunities long bybit / short okx, none touching binance
+        for ts in 0..3 {
+            flow.record(1_000 + ts, "bybit", "BTCUSDT", 1_000.0, &leverage);
+            flow.record(1_000 + ts, "okx", "BTCUSDT", 1_000.0, &leverage);
+        }
+        let demand = flow.demand(1_500, 600);
+        assert_eq!(demand.get("binance"), None);
+
+        let free = balances(&[("binance", 6_000.0), ("bybit", 3_000.0), ("okx", 0.0)]);
+        let config = CollateralConfig { reserve_fraction: 0.3, ..CollateralConfig::default() };
+        let plan = plan_rebalance(&free, &HashMap::new(), &demand, &[], &leverage, &config);
+
+        // 9000 free: 900 reserved per venue, the other 6300 split between bybit and okx
+        let target = |e: &str| plan.venues.iter().find(|v| v.exchange == e).unwrap().target_free_usd;
+        assert!((target("binance") - 900.0).abs() < 1e-6);
+        assert!((target("okx") - 4_050.0).abs() < 1e-6);
+        let moved_to_okx: f64 = plan.transfers.iter().filter(|t| t.to_exchange == "okx").map(|t| t.amount_usd).sum();
+        assert!((moved_to_okx - 4_050.0).abs() < 1e-6);
+        assert!(plan.transfers.iter().all(|t| t.from_exchange == "binance"));
+        let okx = plan.venues.iter().find(|v| v.exchange == "okx").unwrap();
+        assert_eq!(okx.capacity_usd, 0.0);
+        assert!((okx.projected_capacity_usd - 4_050.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_top_up_first_and_small_gaps_ignored() {
+        let free = balances(&[("bybit", 1_050.0), ("okx", 950.0)]);
+        let top_up = MarginTopUp {
+            trade_id: "t1".to_string(),
+            from_exchange: "bybit".to_string(),
+            to_exchange: "okx".to_string(),
+            amount_usd: 300.0,
+        };
+        let plan = plan_rebalance(&free, &HashMap::new(), &HashMap::new(), &[top_up], &LeverageConfig::default(), &CollateralConfig::default());
+        assert_eq!(plan.transfers[0].reason, TransferReason::MarginTopUp { trade_id: "t1".to_string() });
+        assert_eq!(plan.transfers[0].amount_usd, 300.0);
+        // 750 / 1250 after the top-up: the 250 gap is above the minimum and flows back
+        assert_eq!(plan.transfers.len(), 2);
+        assert_eq!(plan.transfers[1].from_exchange, "okx");
+
+        let plan = plan_rebalance(&balances(&[("bybit", 1_050.0), ("okx", 950.0)]), &HashMap::new(), &HashMap::new(), &[],
+            &LeverageConfig::default(), &CollateralConfig::default());
+        assert!(plan.transfers.is_empty());
+    }
+
+    struct MockTransferApi {
+        calls: Mutex<Vec<String>>,
+    }
+
+    #[async_trait::async_trait]
+    impl TransferApi for MockTransferApi {
+        async fn deposit_address(&self, exchange: &str, asset: &str) -> Result<String, ExecutionError> {
+            self.calls.lock().unwrap().push(format!("address {} {}", exchange, asset));
+            Ok(format!("{}-addr", exchange))
+        }
+
+        async fn withdraw(&self, exchange: &str, asset: &str, amount: f64, address: &str) -> Result<String, ExecutionError> {
+            self.calls.lock().unwrap().push(format!("withdraw {} {} {} {}", exchange, asset, amount, address));
+            Ok("w1".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_execute_transfer_withdraws_to_deposit_address() {
+        let api = MockTransferApi { calls: Mutex::new(Vec::new()) };
+        let transfer = CollateralTransfer {
+            from_exchange: "binance".to_string(),
+            to_exchange: "okx".to_string(),
+            amount_usd: 500.0,
+            reason: TransferReason::Allocation,
+        };
+        assert_eq!(execute_transfer(&api, &transfer, "USDT").await.unwrap(), "w1");
+        assert_eq!(*api.calls.lock().unwrap(), vec![
+            "address okx USDT".to_string(),
+            "withdraw binance USDT 500 okx-addr".to_string(),
+        ]);
+    }
+}
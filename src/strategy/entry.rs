@@ -1,10 +1,13 @@
 use crate::strategy::types::{
     SimulatedOrder, OrderSide, OrderType, OrderStatus, ArbitrageOpportunity, 
-    PaperTrade, QueuePosition, TradeStatus
+    PaperTrade, QueuePosition, TradeStatus, OpportunityKind, InstrumentKind
 };
-use crate::strategy::execution_backend::ExecutionBackend;
+use crate::strategy::execution_backend::{fit_close_order, ExecutionBackend};
+use crate::strategy::execution_error::ExecutionError;
 use crate::strategy::atomic_execution::{HedgeTimingMetrics, HedgeLogger, CancellationResult, RaceConditionGuard, BothLegsStatus};
 use crate::strategy::depth_checker::DepthChecker;
+use crate::strategy::margin::LeverageConfig;
+use crate::strategy::sliced_execution::{LegFill, SliceConfig, SlicedExecutor};
 use crate::strategy::price_chaser::{PriceChaser, RepricingConfig, RepricingMetrics, ExecutionMode};
 use uuid::Uuid;
 use std::time::{SystemTime, UNIX_EPOCH, Duration, Instant};
@@ -77,6 +80,28 @@ fn get_exchange_tier(exchange: &str) -> u8 {
 }
 
 impl EntryExecutor {
+    /// Wait up to `timeout` for an order to fill completely, waking on pushed order updates.
+    /// Without a private stream this is a plain sleep and the caller's status check goes to REST.
+    async fn wait_for_full_fill(
+        backend: &Arc<dyn ExecutionBackend>,
+        exchange: &str,
+        order_id: &str,
+        symbol: &str,
+        timeout: Duration,
+    ) {
+        let deadline = Instant::now() + timeout;
+        while let Some(remaining) = deadline.checked_duration_since(Instant::now()) {
+            if !backend.wait_for_order_update(&[order_id], remaining).await {
+                return;
+            }
+            if let Ok(info) = backend.get_order_status_detailed(exchange, order_id, symbol).await {
+                if info.is_fully_filled() || info.status == OrderStatus::Cancelled {
+                    return;
+                }
+            }
+        }
+    }
+
     /// Place market order with retry logic and verification (Scenarios 1 & 2 fix)
     /// 
     /// This function:
@@ -228,12 +253,16 @@ impl EntryExecutor {
                 order_type: OrderType::Market,
                 price: filled_order.price,
                 size: filled_order.size,
-                queue_position: None,
                 created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                filled_at: None,
-                fill_price: None,
                 status: OrderStatus::Pending,
+                reduce_only: true,
+                ..Default::default()
             };
+            let close_order = fit_close_order(backend.as_ref(), close_order).await;
+            if close_order.size <= 0.0 {
+                eprintln!("[EMERGENCY] ✅ {} shows no position left to close", filled_order.exchange);
+                return Ok(());
+            }
 
             // Use MarketOrderPlacer with retry logic (up to 10 attempts)
             let placer = MarketOrderPlacer::new(backend.clone());
@@ -253,15 +282,17 @@ impl EntryExecutor {
                     Ok(())
                 }
                 Err(e) => {
-                    // Check if error is due to quantity limits
-                    if e.contains("Quantity greater than max quantity") || e.contains("exceeds minimum limit") {
+                    // Quantity limits: retry in smaller chunks
+                    if matches!(e, ExecutionError::InvalidQuantity(_)) {
                         eprintln!("[EMERGENCY] ⚠️  Quantity limit hit, attempting to split order into smaller chunks");
                         
                         // Try splitting into 2 orders of half size
                         let half_size = close_order.size / 2.0;
                         eprintln!("[EMERGENCY] Attempting to close in 2 orders of {} contracts each", half_size);
                         
+                        // Each half is a new order with its own ID
                         let mut close_order_half = close_order.clone();
+                        close_order_half.id = format!("emergency_close_{}", uuid::Uuid::new_v4());
                         close_order_half.size = half_size;
                         
                         // Try first half
@@ -370,11 +401,9 @@ impl EntryExecutor {
             order_type: OrderType::Market,
             price,
             size,
-            queue_position: None,
             created_at: now,
-            filled_at: None,
-            fill_price: None,
             status: OrderStatus::Pending,
+            ..Default::default()
         }
     }
 
@@ -410,9 +439,8 @@ impl EntryExecutor {
             size,
             queue_position: Some(queue_position),
             created_at: now,
-            filled_at: None,
-            fill_price: None,
             status: OrderStatus::Pending,
+            ..Default::default()
         }
     }
 
@@ -567,6 +595,10 @@ impl EntryExecutor {
             stop_loss_long_price: 0.0,  // Not used in paper trading
             stop_loss_short_price: 0.0, // Not used in paper trading
             leg_out_event: None,
+            kind: opportunity.kind,
+            funding_payments: Vec::new(),
+            fills: Vec::new(),
+            carry: None,
         };
 
         Ok(paper_trade)
@@ -586,6 +618,8 @@ impl EntryExecutor {
         available_capital: f64,
         position_size: f64,
         backend: Arc<dyn ExecutionBackend>,
+        leverage: &LeverageConfig,
+        slicing: &SliceConfig,
     ) -> Result<PaperTrade, String> {
         // Check if trading is halted due to a critical error
         use crate::strategy::atomic_execution::is_trading_halted;
@@ -639,7 +673,8 @@ impl EntryExecutor {
             return Err("Rounded quantity is zero or negative - position size too small for exchange minimums".to_string());
         }
 
-        // Create limit orders for both legs
+        // Create limit orders for both legs; they rest on the book, so post-only where supported
+        let capabilities = backend.capabilities();
         let long_order_template = SimulatedOrder {
             id: String::new(),
             exchange: opportunity.long_exchange.clone(),
@@ -648,11 +683,10 @@ impl EntryExecutor {
             order_type: OrderType::Limit,
             price: opportunity.long_price,
             size: contract_quantity,
-            queue_position: None,
             created_at: now,
-            filled_at: None,
-            fill_price: None,
             status: OrderStatus::Pending,
+            time_in_force: capabilities.resting_time_in_force(),
+            ..Default::default()
         };
 
         let short_order_template = SimulatedOrder {
@@ -663,11 +697,10 @@ impl EntryExecutor {
             order_type: OrderType::Limit,
             price: opportunity.short_price,
             size: contract_quantity,
-            queue_position: None,
             created_at: now,
-            filled_at: None,
-            fill_price: None,
             status: OrderStatus::Pending,
+            time_in_force: capabilities.resting_time_in_force(),
+            ..Default::default()
         };
 
         // STEP 0: Pre-check balances on BOTH exchanges (Scenario 7 fix)
@@ -677,20 +710,26 @@ impl EntryExecutor {
         let short_balance = backend.get_available_balance(&opportunity.short_exchange).await
             .map_err(|e| format!("Failed to get {} balance: {}", opportunity.short_exchange, e))?;
         
-        // Reserve 20% extra margin for slippage on market orders
-        let required_margin = position_size * 1.2;
+        // Perp legs need initial margin at their leverage, spot legs the full notional;
+        // reserve 20% extra for slippage on market orders
+        let leg_margin = |exchange: &str, instrument: InstrumentKind| match instrument {
+            InstrumentKind::Perp => leverage.required_margin(exchange, &opportunity.symbol, position_size) * 1.2,
+            InstrumentKind::Spot => position_size * 1.2,
+        };
+        let long_required = leg_margin(&opportunity.long_exchange, opportunity.long_instrument());
+        let short_required = leg_margin(&opportunity.short_exchange, opportunity.short_instrument());
         
-        if long_balance < required_margin {
+        if long_balance < long_required {
             return Err(format!(
                 "Insufficient balance on {}: ${:.2} available, ${:.2} required (including 20% buffer)",
-                opportunity.long_exchange, long_balance, required_margin
+                opportunity.long_exchange, long_balance, long_required
             ));
         }
         
-        if short_balance < required_margin {
+        if short_balance < short_required {
             return Err(format!(
                 "Insufficient balance on {}: ${:.2} available, ${:.2} required (including 20% buffer)",
-                opportunity.short_exchange, short_balance, required_margin
+                opportunity.short_exchange, short_balance, short_required
             ));
         }
         
@@ -709,15 +748,77 @@ impl EntryExecutor {
         eprintln!("[EXECUTION] Mode: {:?}, Confidence: {:.1}%", 
             config.execution_mode, opportunity.confidence_score);
 
-        // STEP 1: Set leverage to 1x on BOTH exchanges
-        eprintln!("[LEVERAGE] Setting 1x leverage on both exchanges");
-        let _ = backend.set_leverage(&opportunity.long_exchange, &opportunity.symbol, 1).await;
-        let _ = backend.set_leverage(&opportunity.short_exchange, &opportunity.symbol, 1).await;
+        // STEP 1: Set the configured leverage and STEP 1.5: ISOLATED margin on every perp leg
+        // (spot legs are fully paid and have neither)
+        for (exchange, instrument) in [
+            (&opportunity.long_exchange, opportunity.long_instrument()),
+            (&opportunity.short_exchange, opportunity.short_instrument()),
+        ] {
+            if instrument == InstrumentKind::Perp {
+                let lev = leverage.leverage_for(exchange, &opportunity.symbol);
+                tracing::debug!(%exchange, symbol = %opportunity.symbol, leverage = lev, "setting leverage and isolated margin");
+                // Margin was sized for this leverage; entering at whatever the venue has set is not safe
+                backend.set_leverage(exchange, &opportunity.symbol, lev).await
+                    .map_err(|e| format!("Failed to set {}x leverage on {} {}: {}", lev, exchange, opportunity.symbol, e))?;
+                if let Err(e) = backend.set_margin_type_isolated(exchange, &opportunity.symbol).await {
+                    tracing::warn!(%exchange, symbol = %opportunity.symbol, error = %e, "could not set isolated margin");
+                }
+            }
+        }
+
+        // STEP 1.2: Sizes beyond what the books absorb at once go out in hedged slices,
+        // harder leg first
+        if position_size >= slicing.min_notional_usd {
+            eprintln!("[ATOMIC] ${:.2} position: executing in slices", position_size);
+            let long_first = identify_harder_leg(&opportunity.long_exchange, &opportunity.short_exchange) == "long";
+            let (first, hedge) = if long_first {
+                (&long_order_template, &short_order_template)
+            } else {
+                (&short_order_template, &long_order_template)
+            };
+            let fill = SlicedExecutor::new(backend.clone(), slicing.clone())
+                .execute(first, hedge, contract_quantity, long_step.max(short_step), true)
+                .await;
+
+            if fill.naked_quantity() > 0.0 {
+                let naked = SimulatedOrder {
+                    size: fill.naked_quantity(),
+                    fill_price: fill.first.average_price(),
+                    ..first.clone()
+                };
+                if let Err(e) = Self::emergency_close_position(&backend, &naked).await {
+                    eprintln!("[ATOMIC] ❌ Unhedged slice remainder not closed: {}", e);
+                }
+            }
 
-        // STEP 1.5: Set margin type to ISOLATED on BOTH exchanges
-        eprintln!("[MARGIN TYPE] Setting ISOLATED margin on both exchanges");
-        let _ = backend.set_margin_type_isolated(&opportunity.long_exchange, &opportunity.symbol).await;
-        let _ = backend.set_margin_type_isolated(&opportunity.short_exchange, &opportunity.symbol).await;
+            let hedged = fill.hedge.quantity;
+            let abort = fill.abort.as_ref().map(|a| a.to_string()).unwrap_or_default();
+            if hedged <= 0.0 {
+                return Err(format!("Sliced entry filled nothing: {}", abort));
+            }
+            if fill.abort.is_some() {
+                eprintln!("[ATOMIC] ⚠️  Sliced entry stopped at {:.4}/{:.4} contracts: {}", hedged, contract_quantity, abort);
+            }
+
+            let (long_fill, short_fill) = if long_first { (fill.first, fill.hedge) } else { (fill.hedge, fill.first) };
+            let filled_leg = |template: &SimulatedOrder, leg: LegFill| SimulatedOrder {
+                id: format!("sliced_{}", Uuid::new_v4()),
+                price: leg.average_price().unwrap_or(template.price),
+                size: hedged,
+                filled_at: Some(SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs()),
+                fill_price: leg.average_price(),
+                status: OrderStatus::Filled,
+                ..template.clone()
+            };
+            let long_order = filled_leg(&long_order_template, long_fill);
+            let short_order = filled_leg(&short_order_template, short_fill);
+            let entry_spread_bps = ((short_order.price - long_order.price) / long_order.price) * 10000.0;
+            eprintln!("[ATOMIC] Sliced entry: {} slice(s), {:.4} contracts, spread {:.2}bps",
+                fill.slices.len(), hedged, entry_spread_bps);
+
+            let filled_size = position_size * hedged / contract_quantity;
+            return Self::finish_entry(opportunity, entry_spread_bps, now, filled_size, long_order, short_order, &backend).await;
+        }
 
         // STEP 1.6: Pre-flight depth checks (mode-dependent)
         match config.execution_mode {
@@ -798,8 +899,8 @@ impl EntryExecutor {
         // STEP 2: Place limit orders on BOTH exchanges SIMULTANEOUSLY
         eprintln!("[ATOMIC] Placing limit orders on BOTH exchanges simultaneously");
         
-        let long_result = backend.place_order(long_order_template.clone()).await;
-        let short_result = backend.place_order(short_order_template.clone()).await;
+        let long_result = price_chaser.place_resting(long_order_template.clone()).await;
+        let short_result = price_chaser.place_resting(short_order_template.clone()).await;
 
         let mut long_order = match long_result {
             Ok(order) => {
@@ -838,7 +939,8 @@ impl EntryExecutor {
         let initial_spread_bps = ((opportunity.short_price - opportunity.long_price) / opportunity.long_price) * 10000.0;
 
         while start.elapsed() < timeout && (!long_filled || !short_filled) {
-            tokio::time::sleep(Duration::from_millis(config.reprice_interval_ms)).await;
+            // Wakes early when a private stream pushes an update for either leg
+            backend.wait_for_order_update(&[&long_order.id, &short_order.id], Duration::from_millis(config.reprice_interval_ms)).await;
             
             // Check long order status (if not already filled)
             if !long_filled {
@@ -922,11 +1024,10 @@ impl EntryExecutor {
                                                     order_type: OrderType::Market,
                                                     price: opportunity.long_price,
                                                     size: diff,
-                                                    queue_position: None,
                                                     created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                                    filled_at: None,
-                                                    fill_price: None,
                                                     status: OrderStatus::Pending,
+                                                    reduce_only: true,
+                                                    ..Default::default()
                                                 };
                                                 
                                                 match backend.place_market_order(balance_order).await {
@@ -953,11 +1054,10 @@ impl EntryExecutor {
                                                     order_type: OrderType::Market,
                                                     price: opportunity.short_price,
                                                     size: diff,
-                                                    queue_position: None,
                                                     created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                                    filled_at: None,
-                                                    fill_price: None,
                                                     status: OrderStatus::Pending,
+                                                    reduce_only: true,
+                                                    ..Default::default()
                                                 };
                                                 
                                                 match backend.place_market_order(balance_order).await {
@@ -1066,11 +1166,9 @@ impl EntryExecutor {
                                     order_type: OrderType::Market,
                                     price: opportunity.short_price, // Estimate
                                     size: hedge_quantity,  // Use actual filled quantity from long order
-                                    queue_position: None,
                                     created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                    filled_at: None,
-                                    fill_price: None,
                                     status: OrderStatus::Pending,
+                                    ..Default::default()
                                 };
                                 
                                 // CRITICAL PATH OPTIMIZATION: Initiate cancellation immediately after both-legs check
@@ -1173,6 +1271,18 @@ impl EntryExecutor {
                             break;
                         }
                     }
+                    Err(ExecutionError::PostOnlyRejected(reason)) => {
+                        // Accepted, then cancelled for crossing the book: re-post at the passive touch
+                        eprintln!("[ATOMIC] Long post-only order {} cancelled ({}), re-posting", long_order.id, reason);
+                        let touch = price_chaser.get_best_price_for_order(&long_order).await.unwrap_or(long_order.price);
+                        match price_chaser.place_resting(SimulatedOrder { id: String::new(), price: touch, ..long_order.clone() }).await {
+                            Ok(order) => long_order = order,
+                            Err(e) => {
+                                eprintln!("[ATOMIC] ⚠️  Failed to re-post long order: {}", e);
+                                break;
+                            }
+                        }
+                    }
                     Err(e) => {
                         eprintln!("[ATOMIC] ⚠️  Failed to get long order status: {}", e);
                     }
@@ -1278,11 +1388,10 @@ impl EntryExecutor {
                                                     order_type: OrderType::Market,
                                                     price: opportunity.long_price,
                                                     size: diff,
-                                                    queue_position: None,
                                                     created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                                    filled_at: None,
-                                                    fill_price: None,
                                                     status: OrderStatus::Pending,
+                                                    reduce_only: true,
+                                                    ..Default::default()
                                                 };
                                                 
                                                 match backend.place_market_order(balance_order).await {
@@ -1309,11 +1418,10 @@ impl EntryExecutor {
                                                     order_type: OrderType::Market,
                                                     price: opportunity.short_price,
                                                     size: diff,
-                                                    queue_position: None,
                                                     created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                                    filled_at: None,
-                                                    fill_price: None,
                                                     status: OrderStatus::Pending,
+                                                    reduce_only: true,
+                                                    ..Default::default()
                                                 };
                                                 
                                                 match backend.place_market_order(balance_order).await {
@@ -1422,11 +1530,9 @@ impl EntryExecutor {
                                     order_type: OrderType::Market,
                                     price: opportunity.long_price, // Estimate
                                     size: hedge_quantity,  // Use actual filled quantity from short order
-                                    queue_position: None,
                                     created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                    filled_at: None,
-                                    fill_price: None,
                                     status: OrderStatus::Pending,
+                                    ..Default::default()
                                 };
                                 
                                 // CRITICAL PATH OPTIMIZATION: Initiate cancellation immediately after both-legs check
@@ -1529,6 +1635,18 @@ impl EntryExecutor {
                             break;
                         }
                     }
+                    Err(ExecutionError::PostOnlyRejected(reason)) => {
+                        // Accepted, then cancelled for crossing the book: re-post at the passive touch
+                        eprintln!("[ATOMIC] Short post-only order {} cancelled ({}), re-posting", short_order.id, reason);
+                        let touch = price_chaser.get_best_price_for_order(&short_order).await.unwrap_or(short_order.price);
+                        match price_chaser.place_resting(SimulatedOrder { id: String::new(), price: touch, ..short_order.clone() }).await {
+                            Ok(order) => short_order = order,
+                            Err(e) => {
+                                eprintln!("[ATOMIC] ⚠️  Failed to re-post short order: {}", e);
+                                break;
+                            }
+                        }
+                    }
                     Err(e) => {
                         eprintln!("[ATOMIC] ⚠️  Failed to get short order status: {}", e);
                     }
@@ -1750,19 +1868,17 @@ impl EntryExecutor {
                             order_type: OrderType::Limit,
                             price: best_bid,
                             size: remaining_after_cancel,
-                            queue_position: None,
                             created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                            filled_at: None,
-                            fill_price: None,
                             status: OrderStatus::Pending,
+                            ..Default::default()
                         };
                         
                         match backend.place_order(limit_order.clone()).await {
                             Ok(placed_order) => {
                                 eprintln!("[ATOMIC] Aggressive limit placed: {} at ${:.4}", placed_order.id, best_bid);
                                 
-                                // Wait 2 seconds for fill
-                                tokio::time::sleep(Duration::from_secs(2)).await;
+                                // Wait up to 2 seconds for fill (returns early on pushed fills)
+                                Self::wait_for_full_fill(&backend, &opportunity.short_exchange, &placed_order.id, &opportunity.symbol, Duration::from_secs(2)).await;
                                 
                                 // Check how much the limit order filled (may be partial)
                                 match backend.get_order_status_detailed(&opportunity.short_exchange, &placed_order.id, &opportunity.symbol).await {
@@ -1819,11 +1935,9 @@ impl EntryExecutor {
                         order_type: OrderType::Market,
                         price: opportunity.short_price,
                         size: remaining_quantity,  // FIX: Use remaining quantity, not full quantity
-                        queue_position: None,
                         created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                        filled_at: None,
-                        fill_price: None,
                         status: OrderStatus::Pending,
+                        ..Default::default()
                     };
                     
                     metrics.record_market_order_initiated();
@@ -2008,19 +2122,17 @@ impl EntryExecutor {
                             order_type: OrderType::Limit,
                             price: best_ask,
                             size: remaining_after_cancel,
-                            queue_position: None,
                             created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                            filled_at: None,
-                            fill_price: None,
                             status: OrderStatus::Pending,
+                            ..Default::default()
                         };
                         
                         match backend.place_order(limit_order.clone()).await {
                             Ok(placed_order) => {
                                 eprintln!("[ATOMIC] Aggressive limit placed: {} at ${:.4}", placed_order.id, best_ask);
                                 
-                                // Wait 2 seconds for fill
-                                tokio::time::sleep(Duration::from_secs(2)).await;
+                                // Wait up to 2 seconds for fill (returns early on pushed fills)
+                                Self::wait_for_full_fill(&backend, &opportunity.long_exchange, &placed_order.id, &opportunity.symbol, Duration::from_secs(2)).await;
                                 
                                 // Check how much the limit order filled (may be partial)
                                 match backend.get_order_status_detailed(&opportunity.long_exchange, &placed_order.id, &opportunity.symbol).await {
@@ -2077,11 +2189,9 @@ impl EntryExecutor {
                         order_type: OrderType::Market,
                         price: opportunity.long_price,
                         size: remaining_quantity,  // FIX: Use remaining quantity, not full quantity
-                        queue_position: None,
                         created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                        filled_at: None,
-                        fill_price: None,
                         status: OrderStatus::Pending,
+                        ..Default::default()
                     };
                     
                     metrics.record_market_order_initiated();
@@ -2161,10 +2271,7 @@ impl EntryExecutor {
         }
 
         // STEP 5: Both legs filled - create trade
-        let trade_id = Uuid::new_v4().to_string();
         let entry_spread_bps = ((opportunity.short_price - opportunity.long_price) / opportunity.long_price) * 10000.0;
-
-        eprintln!("[ATOMIC] ✅ Both legs filled successfully! Trade ID: {}", trade_id);
         
         // Log repricing statistics
         if long_reprice_count > 0 || short_reprice_count > 0 {
@@ -2182,6 +2289,22 @@ impl EntryExecutor {
                 short_metrics.reprice_total_time_ms);
         }
 
+        Self::finish_entry(opportunity, entry_spread_bps, now, position_size, long_order, short_order, &backend).await
+    }
+
+    /// Place the passive exit orders of a filled entry and build its trade
+    async fn finish_entry(
+        opportunity: &ArbitrageOpportunity,
+        entry_spread_bps: f64,
+        now: u64,
+        position_size: f64,
+        long_order: SimulatedOrder,
+        short_order: SimulatedOrder,
+        backend: &Arc<dyn ExecutionBackend>,
+    ) -> Result<PaperTrade, String> {
+        let trade_id = Uuid::new_v4().to_string();
+        eprintln!("[ATOMIC] ✅ Both legs filled successfully! Trade ID: {}", trade_id);
+
         // STEP 6: Place exit orders (passive strategy)
         let spread_amount = opportunity.short_price - opportunity.long_price;
         let target_capture = spread_amount * 0.9;
@@ -2198,6 +2321,9 @@ impl EntryExecutor {
         let long_quantity = long_order.size;
         let short_quantity = short_order.size;
 
+        // Resting take-profits are maker-only, and reduce-only so a fill after the
+        // position is already closed can't open a new one
+        let capabilities = backend.capabilities();
         let long_exit_order_template = SimulatedOrder {
             id: String::new(),
             exchange: opportunity.long_exchange.clone(),
@@ -2206,11 +2332,11 @@ impl EntryExecutor {
             order_type: OrderType::Limit,
             price: long_exit_price,
             size: long_quantity,
-            queue_position: None,
             created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-            filled_at: None,
-            fill_price: None,
             status: OrderStatus::Pending,
+            time_in_force: capabilities.resting_time_in_force(),
+            reduce_only: true,
+            ..Default::default()
         };
 
         let short_exit_order_template = SimulatedOrder {
@@ -2221,33 +2347,45 @@ impl EntryExecutor {
             order_type: OrderType::Limit,
             price: short_exit_price,
             size: short_quantity,
-            queue_position: None,
             created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-            filled_at: None,
-            fill_price: None,
             status: OrderStatus::Pending,
+            time_in_force: capabilities.resting_time_in_force(),
+            reduce_only: true,
+            ..Default::default()
         };
 
-        let long_exit_order = match backend.place_order(long_exit_order_template).await {
-            Ok(order) => {
-                eprintln!("[EXIT ORDERS] Long exit order placed: {} at ${:.4}", order.id, long_exit_price);
-                Some(order)
-            }
-            Err(e) => {
-                eprintln!("[EXIT ORDERS] WARNING: Failed to place long exit order: {}", e);
-                None
-            }
-        };
+        // Funding-carry trades are held across funding timestamps, so no passive
+        // take-profit orders are resting on the book
+        let (long_exit_order, short_exit_order) = if opportunity.kind == OpportunityKind::FundingCarry {
+            eprintln!("[EXIT ORDERS] Funding carry trade - skipping passive exit orders");
+            (None, None)
+        } else if !capabilities.supports_reduce_only {
+            // Without reduce-only a take-profit filling after the close would open a new position
+            eprintln!("[EXIT ORDERS] {} can't flag reduce-only - skipping passive exit orders", backend.backend_name());
+            (None, None)
+        } else {
+            let long_exit_order = match backend.place_order(long_exit_order_template).await {
+                Ok(order) => {
+                    eprintln!("[EXIT ORDERS] Long exit order placed: {} at ${:.4}", order.id, long_exit_price);
+                    Some(order)
+                }
+                Err(e) => {
+                    eprintln!("[EXIT ORDERS] WARNING: Failed to place long exit order: {}", e);
+                    None
+                }
+            };
 
-        let short_exit_order = match backend.place_order(short_exit_order_template).await {
-            Ok(order) => {
-                eprintln!("[EXIT ORDERS] Short exit order placed: {} at ${:.4}", order.id, short_exit_price);
-                Some(order)
-            }
-            Err(e) => {
-                eprintln!("[EXIT ORDERS] WARNING: Failed to place short exit order: {}", e);
-                None
-            }
+            let short_exit_order = match backend.place_order(short_exit_order_template).await {
+                Ok(order) => {
+                    eprintln!("[EXIT ORDERS] Short exit order placed: {} at ${:.4}", order.id, short_exit_price);
+                    Some(order)
+                }
+                Err(e) => {
+                    eprintln!("[EXIT ORDERS] WARNING: Failed to place short exit order: {}", e);
+                    None
+                }
+            };
+            (long_exit_order, short_exit_order)
         };
 
         let paper_trade = PaperTrade {
@@ -2275,6 +2413,10 @@ impl EntryExecutor {
             stop_loss_long_price: long_stop_price,
             stop_loss_short_price: short_stop_price,
             leg_out_event: None,
+            kind: opportunity.kind,
+            funding_payments: Vec::new(),
+            fills: Vec::new(),
+            carry: None,
         };
 
         eprintln!("[ATOMIC] ✅ Trade setup complete! Trade ID: {}", paper_trade.id);
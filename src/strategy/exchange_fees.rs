@@ -51,6 +51,9 @@ pub const EXCHANGE_ID_KUCOIN: u8 = 5;
 pub const EXCHANGE_ID_HYPERLIQUID: u8 = 6;
 pub const EXCHANGE_ID_PARADEX: u8 = 7;
 pub const EXCHANGE_ID_GATEIO: u8 = 8;
+pub const EXCHANGE_ID_BYBIT_SPOT: u8 = 9;
+pub const EXCHANGE_ID_OKX_SPOT: u8 = 10;
+pub const EXCHANGE_ID_BITGET_SPOT: u8 = 11;
 
 /// Pre-computed exchange fee array (in basis points).
 ///
@@ -73,6 +76,24 @@ static EXCHANGE_FEES: [f64; 256] = {
     fees[EXCHANGE_ID_HYPERLIQUID as usize] = 4.5;  // 0.045%
     fees[EXCHANGE_ID_PARADEX as usize] = 5.0;      // 0.05%
     fees[EXCHANGE_ID_GATEIO as usize] = 6.0;       // 0.06%
+    fees[EXCHANGE_ID_BYBIT_SPOT as usize] = 10.0;  // 0.10%
+    fees[EXCHANGE_ID_OKX_SPOT as usize] = 10.0;    // 0.10%
+    fees[EXCHANGE_ID_BITGET_SPOT as usize] = 10.0; // 0.10%
+    
+    fees
+};
+
+/// Maker fees in basis points, indexed by exchange ID (regular account tier).
+///
+/// Account tiers, per-symbol rates and rebates are layered on top by
+/// `fee_schedule::FeeSchedule`.
+static EXCHANGE_MAKER_FEES: [f64; 256] = {
+    let mut fees = [2.0; 256]; // Default: 2.0 bps (0.02%)
+    
+    fees[EXCHANGE_ID_HYPERLIQUID as usize] = 1.5;  // 0.015%
+    fees[EXCHANGE_ID_BYBIT_SPOT as usize] = 10.0;  // 0.10%
+    fees[EXCHANGE_ID_OKX_SPOT as usize] = 8.0;     // 0.08%
+    fees[EXCHANGE_ID_BITGET_SPOT as usize] = 10.0; // 0.10%
     
     fees
 };
@@ -92,6 +113,9 @@ static EXCHANGE_TO_ID: Lazy<HashMap<String, u8>> = Lazy::new(|| {
     map.insert("hyperliquid".to_string(), EXCHANGE_ID_HYPERLIQUID);
     map.insert("paradex".to_string(), EXCHANGE_ID_PARADEX);
     map.insert("gateio".to_string(), EXCHANGE_ID_GATEIO);
+    map.insert("bybit_spot".to_string(), EXCHANGE_ID_BYBIT_SPOT);
+    map.insert("okx_spot".to_string(), EXCHANGE_ID_OKX_SPOT);
+    map.insert("bitget_spot".to_string(), EXCHANGE_ID_BITGET_SPOT);
     
     map
 });
@@ -108,6 +132,9 @@ static ID_TO_EXCHANGE: Lazy<Vec<&'static str>> = Lazy::new(|| {
         "hyperliquid",  // 6
         "paradex",      // 7
         "gateio",       // 8
+        "bybit_spot",   // 9
+        "okx_spot",     // 10
+        "bitget_spot",  // 11
     ]
 });
 
@@ -174,6 +201,20 @@ pub fn get_exchange_fee_by_name(exchange: &str) -> f64 {
     get_exchange_fee(exchange_id)
 }
 
+/// Get exchange maker fee in basis points (hot path - array lookup).
+#[inline(always)]
+pub fn get_exchange_maker_fee(exchange_id: u8) -> f64 {
+    EXCHANGE_MAKER_FEES[exchange_id as usize]
+}
+
+/// Get exchange maker fee from exchange name (cold path - string lookup).
+///
+/// Returns 2.0 bps (default) if the exchange is not found.
+#[inline(always)]
+pub fn get_exchange_maker_fee_by_name(exchange: &str) -> f64 {
+    get_exchange_maker_fee(exchange_to_id(exchange))
+}
+
 /// Convert exchange name to ID (cold path).
 ///
 /// This is used during initialization to pre-map exchange names to IDs.
@@ -213,8 +254,9 @@ pub fn id_to_exchange(exchange_id: u8) -> &'static str {
         .unwrap_or("")
 }
 
-/// Get all supported exchange IDs.
+/// Get all supported perpetual exchange IDs.
 ///
+/// Spot venues (see `instruments::SPOT_VENUES`) are not included.
 /// This is useful for iteration in cold paths.
 pub fn get_all_exchange_ids() -> Vec<u8> {
     vec![
@@ -229,8 +271,9 @@ pub fn get_all_exchange_ids() -> Vec<u8> {
     ]
 }
 
-/// Get all supported exchange names.
+/// Get all supported perpetual exchange names.
 ///
+/// Spot venues (see `instruments::SPOT_VENUES`) are not included.
 /// This is useful for iteration in cold paths.
 pub fn get_all_exchange_names() -> Vec<&'static str> {
     vec![
@@ -283,6 +326,14 @@ mod tests {
         assert_eq!(get_exchange_fee_by_name("unknown"), 6.0);
     }
     
+    #[test]
+    fn test_get_exchange_maker_fee() {
+        assert_eq!(get_exchange_maker_fee(EXCHANGE_ID_BYBIT), 2.0);
+        assert_eq!(get_exchange_maker_fee(EXCHANGE_ID_HYPERLIQUID), 1.5);
+        assert_eq!(get_exchange_maker_fee_by_name("OKX_SPOT"), 8.0);
+        assert_eq!(get_exchange_maker_fee_by_name("unknown"), 2.0);
+    }
+    
     #[test]
     fn test_exchange_to_id() {
         assert_eq!(exchange_to_id("binance"), EXCHANGE_ID_BINANCE);
@@ -337,6 +388,13 @@ mod tests {
         }
     }
     
+    #[test]
+    fn test_spot_venue_fees() {
+        assert_eq!(exchange_to_id("bybit_spot"), EXCHANGE_ID_BYBIT_SPOT);
+        assert_eq!(id_to_exchange(EXCHANGE_ID_OKX_SPOT), "okx_spot");
+        assert_eq!(get_exchange_fee_by_name("bitget_spot"), 10.0);
+    }
+    
     #[test]
     fn test_fee_consistency() {
         // Verify that array lookup and name lookup give same results
@@ -88,6 +88,7 @@
             stop_loss_long_price: 0.0,
             stop_loss_short_price: 0.0,
             leg_out_event: None,
+            kind: OpportunityKind::SpreadArbitrage,
         };
         
         // Insert placeholder to reserve the symbol
@@ -1,39 +1,170 @@
-use std::error::Error;
 use std::collections::HashMap;
-use crate::strategy::types::{SimulatedOrder, OrderStatus, OrderBookDepth};
+use crate::strategy::execution_error::ExecutionError;
+use crate::strategy::types::{SimulatedOrder, OrderStatus, OrderBookDepth, OrderType, FeeRates, Fill, FundingPayment, TimeInForce, VenuePosition};
+
+/// What a backend can do. Callers branch on this instead of on `backend_name`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackendCapabilities {
+    /// Orders never reach an exchange; fills are simulated locally
+    pub is_simulated: bool,
+    /// Limit orders can be sent as post-only (maker-only)
+    pub supports_post_only: bool,
+    /// Venues where resting orders are amended in place; elsewhere `amend_order`
+    /// falls back to cancel/replace
+    pub amend_exchanges: Vec<String>,
+    /// Orders can be flagged reduce-only
+    pub supports_reduce_only: bool,
+    /// Order and fill updates are pushed over a private user-data stream
+    pub supports_private_stream: bool,
+    /// Venues orders can be routed to; `None` means any venue (simulated backends)
+    pub supported_exchanges: Option<Vec<String>>,
+    pub order_types: Vec<OrderType>,
+}
+
+impl BackendCapabilities {
+    /// Locally simulated backend that accepts any venue
+    pub fn simulated() -> Self {
+        Self {
+            is_simulated: true,
+            supports_post_only: false,
+            amend_exchanges: Vec::new(),
+            supports_reduce_only: false,
+            supports_private_stream: false,
+            supported_exchanges: None,
+            order_types: vec![OrderType::Limit, OrderType::Market],
+        }
+    }
+
+    pub fn supports_exchange(&self, exchange: &str) -> bool {
+        match &self.supported_exchanges {
+            Some(venues) => venues.iter().any(|e| e == exchange),
+            None => true,
+        }
+    }
+
+    pub fn supports_amend(&self, exchange: &str) -> bool {
+        self.amend_exchanges.iter().any(|e| e == exchange)
+    }
+
+    /// Time in force for an order meant to rest on the book: post-only where the
+    /// backend can send it, plain GTC elsewhere
+    pub fn resting_time_in_force(&self) -> TimeInForce {
+        if self.supports_post_only {
+            TimeInForce::PostOnly
+        } else {
+            TimeInForce::Gtc
+        }
+    }
+}
+
+/// How `amend_order` moved a resting order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmendMethod {
+    /// Modified on the book under the same order ID
+    InPlace,
+    /// Cancelled and placed again under a new order ID
+    CancelReplace,
+}
+
+/// Result of `amend_order`: the order now working, and how it got there
+#[derive(Debug, Clone)]
+pub struct AmendedOrder {
+    pub order: SimulatedOrder,
+    pub method: AmendMethod,
+}
+
+/// Cancel `order` and place it again at `new_price` (and `new_size` if given).
+///
+/// Fallback for venues without native amend. There is a short window with no
+/// order on the book, and the replacement joins the back of the queue. If the
+/// cancel fails (e.g. the order already filled) nothing is placed.
+pub async fn cancel_replace<B: ExecutionBackend + ?Sized>(
+    backend: &B,
+    order: &SimulatedOrder,
+    new_price: f64,
+    new_size: Option<f64>,
+) -> Result<AmendedOrder, ExecutionError> {
+    backend.cancel_order(&order.exchange, &order.id).await?;
+
+    let mut replacement = order.clone();
+    replacement.price = new_price;
+    if let Some(size) = new_size {
+        replacement.size = size;
+    }
+    replacement.id = String::new();  // Will be assigned by exchange
+    replacement.status = OrderStatus::Pending;
+    replacement.created_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let placed = backend.place_order(replacement).await?;
+    Ok(AmendedOrder { order: placed, method: AmendMethod::CancelReplace })
+}
+
+/// Fit a reduce-only closing order to `backend`.
+///
+/// Where orders can't be flagged reduce-only, the flag is dropped and the size
+/// capped at the position the venue reports, so a stale size can't flip the
+/// leg. If the venue can't be queried the size is left as is.
+pub async fn fit_close_order<B: ExecutionBackend + ?Sized>(backend: &B, mut order: SimulatedOrder) -> SimulatedOrder {
+    if backend.capabilities().supports_reduce_only {
+        return order;
+    }
+    order.reduce_only = false;
+    order.close_position = false;
+    if let Ok(positions) = backend.get_open_positions(&order.exchange).await {
+        let held: f64 = positions.iter()
+            .filter(|p| p.symbol == order.symbol && p.side != order.side)
+            .map(|p| p.size)
+            .sum();
+        order.size = order.size.min(held);
+    }
+    order
+}
 
 /// Trait for different execution backends (paper trading, testnet, live)
+///
+/// Failures are typed (`ExecutionError`) so callers can tell an order that is
+/// already gone from a throttled or rejected request.
 #[async_trait::async_trait]
 pub trait ExecutionBackend: Send + Sync {
     /// Set leverage for a symbol on an exchange (must be called before placing orders)
-    async fn set_leverage(&self, exchange: &str, symbol: &str, leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn set_leverage(&self, exchange: &str, symbol: &str, leverage: u8) -> Result<(), ExecutionError>;
     
     /// Set margin type to ISOLATED for a symbol on an exchange (must be called before placing orders)
-    async fn set_margin_type_isolated(&self, exchange: &str, symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn set_margin_type_isolated(&self, exchange: &str, symbol: &str) -> Result<(), ExecutionError>;
     
     /// Place a limit order on the exchange
-    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>>;
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError>;
     
     /// Place a market order on the exchange (for immediate hedging)
-    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>>;
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError>;
     
     /// Cancel an existing order
-    async fn cancel_order(&self, exchange: &str, order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>>;
+    async fn cancel_order(&self, exchange: &str, order_id: &str) -> Result<(), ExecutionError>;
+    
+    /// Move a resting limit order to `new_price` (and `new_size` if given).
+    /// Amends in place on venues in `capabilities().amend_exchanges`; the default
+    /// cancels and re-places.
+    async fn amend_order(&self, order: &SimulatedOrder, new_price: f64, new_size: Option<f64>) -> Result<AmendedOrder, ExecutionError> {
+        cancel_replace(self, order, new_price, new_size).await
+    }
     
     /// Get the status of an order
-    async fn get_order_status(&self, exchange: &str, order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>>;
+    async fn get_order_status(&self, exchange: &str, order_id: &str) -> Result<OrderStatus, ExecutionError>;
     
     /// Get detailed order status including filled quantity (for partial fill handling)
-    async fn get_order_status_detailed(&self, exchange: &str, order_id: &str, symbol: &str) -> Result<crate::strategy::types::OrderStatusInfo, Box<dyn Error + Send + Sync>>;
+    async fn get_order_status_detailed(&self, exchange: &str, order_id: &str, symbol: &str) -> Result<crate::strategy::types::OrderStatusInfo, ExecutionError>;
     
     /// Get current available balance for an exchange
-    async fn get_available_balance(&self, exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>>;
+    async fn get_available_balance(&self, exchange: &str) -> Result<f64, ExecutionError>;
     
     /// Get balances from all configured exchanges
-    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>>;
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError>;
     
     /// Check if a symbol is tradeable on an exchange
-    async fn is_symbol_tradeable(&self, exchange: &str, symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>>;
+    async fn is_symbol_tradeable(&self, exchange: &str, symbol: &str) -> Result<bool, ExecutionError>;
     
     /// Get order book depth (top N levels) for a symbol on an exchange
     async fn get_order_book_depth(
@@ -41,26 +172,68 @@ pub trait ExecutionBackend: Send + Sync {
         exchange: &str,
         symbol: &str,
         levels: usize,
-    ) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>>;
+    ) -> Result<OrderBookDepth, ExecutionError>;
     
     /// Get the best bid price for a symbol on an exchange
     async fn get_best_bid(
         &self,
         exchange: &str,
         symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>>;
+    ) -> Result<f64, ExecutionError>;
     
     /// Get the best ask price for a symbol on an exchange
     async fn get_best_ask(
         &self,
         exchange: &str,
         symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>>;
+    ) -> Result<f64, ExecutionError>;
     
-    /// Get the name of this backend (for logging)
+    /// Get the name of this backend (for logging only; branch on `capabilities`)
     fn backend_name(&self) -> &str;
+
+    /// Features and venues this backend supports
+    fn capabilities(&self) -> BackendCapabilities;
     
     /// Get the quantity rounding step for a symbol on an exchange
     /// Returns the minimum quantity increment (e.g., 0.1, 0.01, 1.0)
-    async fn get_quantity_step(&self, exchange: &str, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>>;
+    async fn get_quantity_step(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError>;
+    
+    /// Get the base-asset quantity held on a spot venue (e.g. BTC held on "bybit_spot")
+    async fn get_spot_position(&self, exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
+        Err(ExecutionError::Other(format!("Spot positions not supported on {} by {}", exchange, self.backend_name())))
+    }
+
+    /// Perpetual positions currently held on a venue, sizes in coins
+    async fn get_open_positions(&self, exchange: &str) -> Result<Vec<VenuePosition>, ExecutionError> {
+        Err(ExecutionError::Other(format!("Position queries not supported on {} by {}", exchange, self.backend_name())))
+    }
+
+    /// Orders resting on a venue, sizes in coins (remaining, not original)
+    async fn get_open_orders(&self, exchange: &str) -> Result<Vec<SimulatedOrder>, ExecutionError> {
+        Err(ExecutionError::Other(format!("Open order queries not supported on {} by {}", exchange, self.backend_name())))
+    }
+
+    /// Funding settled on a venue's `symbol` position since `since` (unix
+    /// seconds), oldest first. `side` is the position side at settlement.
+    async fn get_funding_history(&self, exchange: &str, _symbol: &str, _since: u64) -> Result<Vec<FundingPayment>, ExecutionError> {
+        Err(ExecutionError::Other(format!("Funding history not supported on {} by {}", exchange, self.backend_name())))
+    }
+
+    /// Executions on a venue's `symbol` since `since` (unix seconds), oldest
+    /// first, with the fee the venue actually charged on each.
+    async fn get_fills(&self, exchange: &str, _symbol: &str, _since: u64) -> Result<Vec<Fill>, ExecutionError> {
+        Err(ExecutionError::Other(format!("Fill history not supported on {} by {}", exchange, self.backend_name())))
+    }
+
+    /// Maker and taker rate the account currently pays on a venue's `symbol`
+    async fn get_fee_rates(&self, exchange: &str, _symbol: &str) -> Result<FeeRates, ExecutionError> {
+        Err(ExecutionError::Other(format!("Fee rate queries not supported on {} by {}", exchange, self.backend_name())))
+    }
+
+    /// Wait until one of `order_ids` changes or `timeout` elapses; true if a change was seen.
+    /// Backends without pushed order updates just sleep, so callers keep polling at the same pace.
+    async fn wait_for_order_update(&self, _order_ids: &[&str], timeout: std::time::Duration) -> bool {
+        tokio::time::sleep(timeout).await;
+        false
+    }
 }
@@ -0,0 +1,151 @@
+//! Typed execution errors
+//!
+//! Every `ExecutionBackend` call fails with an `ExecutionError`. Venue clients
+//! map their native error codes onto it where they parse API responses, so
+//! retry and cleanup logic matches on variants instead of error text. Errors
+//! the clients don't classify (local validation, unexpected payloads) arrive
+//! as `Other` with the original message.
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExecutionError {
+    /// The venue does not know the order (never placed, or already closed and purged)
+    OrderNotFound(String),
+    /// The order is already filled, so it can't be cancelled or amended
+    AlreadyFilled(String),
+    /// Not enough margin or balance for the order
+    InsufficientBalance(String),
+    /// Request throttled; `retry_after` is the venue's hint when it sends one
+    RateLimited { retry_after: Option<Duration> },
+    /// A post-only order would have taken liquidity
+    PostOnlyRejected(String),
+    /// Size below the minimum, above the maximum or off the lot step
+    InvalidQuantity(String),
+    /// The venue already holds an order with this client order ID, i.e. an
+    /// earlier submission of the same order was accepted
+    DuplicateOrder(String),
+    /// Transport failure: connect, timeout, truncated response
+    Network(String),
+    /// Invalid key, signature, passphrase or permissions
+    Auth(String),
+    /// Any other rejection, with the venue's native code
+    ExchangeRejected { code: String, msg: String },
+    /// Refused by the pre-trade risk engine before reaching the venue
+    RiskRejected(String),
+    /// Errors raised locally (venue not configured, unsupported call, bad payload)
+    Other(String),
+}
+
+impl ExecutionError {
+    pub fn rejected(code: impl ToString, msg: impl Into<String>) -> Self {
+        ExecutionError::ExchangeRejected { code: code.to_string(), msg: msg.into() }
+    }
+
+    /// The order is no longer working on the venue, so a cancel has nothing left to do
+    pub fn is_order_gone(&self) -> bool {
+        matches!(self, ExecutionError::OrderNotFound(_) | ExecutionError::AlreadyFilled(_))
+    }
+
+    /// Transient failure worth retrying after a short wait
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, ExecutionError::RateLimited { .. } | ExecutionError::Network(_))
+    }
+}
+
+impl fmt::Display for ExecutionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutionError::OrderNotFound(msg) => write!(f, "order not found: {}", msg),
+            ExecutionError::AlreadyFilled(msg) => write!(f, "order already filled: {}", msg),
+            ExecutionError::InsufficientBalance(msg) => write!(f, "insufficient balance: {}", msg),
+            ExecutionError::RateLimited { retry_after: Some(wait) } => write!(f, "rate limited, retry after {}ms", wait.as_millis()),
+            ExecutionError::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            ExecutionError::PostOnlyRejected(msg) => write!(f, "post-only order rejected: {}", msg),
+            ExecutionError::InvalidQuantity(msg) => write!(f, "invalid quantity: {}", msg),
+            ExecutionError::DuplicateOrder(msg) => write!(f, "duplicate client order ID: {}", msg),
+            ExecutionError::Network(msg) => write!(f, "network error: {}", msg),
+            ExecutionError::Auth(msg) => write!(f, "authentication failed: {}", msg),
+            ExecutionError::ExchangeRejected { code, msg } => write!(f, "exchange rejected ({}): {}", code, msg),
+            ExecutionError::RiskRejected(msg) => write!(f, "risk check failed: {}", msg),
+            ExecutionError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl Error for ExecutionError {}
+
+impl From<reqwest::Error> for ExecutionError {
+    fn from(e: reqwest::Error) -> Self {
+        ExecutionError::Network(e.to_string())
+    }
+}
+
+/// Venue clients return boxed errors; recover the typed error they boxed, if any
+impl From<Box<dyn Error + Send + Sync>> for ExecutionError {
+    fn from(e: Box<dyn Error + Send + Sync>) -> Self {
+        let e = match e.downcast::<ExecutionError>() {
+            Ok(typed) => return *typed,
+            Err(e) => e,
+        };
+        match e.downcast::<reqwest::Error>() {
+            Ok(network) => ExecutionError::from(*network),
+            Err(e) => ExecutionError::Other(e.to_string()),
+        }
+    }
+}
+
+impl From<String> for ExecutionError {
+    fn from(msg: String) -> Self {
+        ExecutionError::Other(msg)
+    }
+}
+
+impl From<&str> for ExecutionError {
+    fn from(msg: &str) -> Self {
+        ExecutionError::Other(msg.to_string())
+    }
+}
+
+/// Error for a non-2xx HTTP response whose body carries no venue error code
+pub fn http_error(status: u16, retry_after: Option<&str>, body: &str) -> ExecutionError {
+    match status {
+        429 => ExecutionError::RateLimited {
+            retry_after: retry_after.and_then(|s| s.trim().parse::<u64>().ok()).map(Duration::from_secs),
+        },
+        401 | 403 => ExecutionError::Auth(body.to_string()),
+        // Gateway and overload errors are transient
+        500..=599 => ExecutionError::Network(format!("HTTP {}: {}", status, body)),
+        _ => ExecutionError::rejected(status, body),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_boxed_errors_convert_back_to_variants() {
+        let boxed: Box<dyn Error + Send + Sync> = ExecutionError::OrderNotFound("Unknown order sent.".to_string()).into();
+        let typed = ExecutionError::from(boxed);
+        assert!(typed.is_order_gone());
+        assert_eq!(typed.to_string(), "order not found: Unknown order sent.");
+
+        let boxed: Box<dyn Error + Send + Sync> = "Binance demo not configured".into();
+        assert_eq!(ExecutionError::from(boxed), ExecutionError::Other("Binance demo not configured".to_string()));
+    }
+
+    #[test]
+    fn test_http_status_classification() {
+        assert_eq!(
+            http_error(429, Some("2"), ""),
+            ExecutionError::RateLimited { retry_after: Some(Duration::from_secs(2)) },
+        );
+        assert!(matches!(http_error(401, None, "bad key"), ExecutionError::Auth(_)));
+        assert!(http_error(503, None, "").is_retryable());
+        assert_eq!(http_error(400, None, "bad"), ExecutionError::rejected(400, "bad"));
+        assert!(!ExecutionError::rejected(-1013, "Filter failure").is_retryable());
+    }
+}
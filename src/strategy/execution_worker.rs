@@ -0,0 +1,682 @@
+//! Dedicated Execution Worker
+//!
+//! Moves order placement off the strategy thread. The strategy submits
+//! `OrderRequest`s to an `ExecutionPipeline`; a pinned worker thread drains
+//! the pipeline and dispatches each request onto the tokio runtime, so venue
+//! calls run concurrently. Acks, fills and rejections come back to the
+//! strategy over a lock-free report queue.
+//!
+//! # Architecture
+//!
+//! ```text
+//! Strategy Thread                 Execution Worker (pinned)          Tokio runtime
+//!        │                                 │                               │
+//!        ├─ submit() ──── ExecutionPipeline ──▶ pop_batch() ── spawn ──▶ place_order()
+//!        │                                                                 │
+//!        └─ poll_report() ◀──────────── report queue ◀──── Ack / Fill / Rejected
+//! ```
+//!
+//! Resting orders are polled for fills until they reach a terminal state or
+//! `fill_timeout` passes; a final `Handover` report then gives them back to
+//! the strategy.
+//!
+//! `WorkerBackend` puts the strategy's entry and exit flows on this path: it
+//! is an `ExecutionBackend` whose placements go through `ExecutionHandle::submit`
+//! with the full order attached, and whose callers await the venue's answer.
+
+use crate::strategy::exchange_fees::exchange_to_id;
+use crate::strategy::execution_backend::{cancel_replace, AmendedOrder, BackendCapabilities, ExecutionBackend};
+use crate::strategy::execution_error::ExecutionError;
+use crate::strategy::fee_schedule::FeeSchedule;
+use crate::strategy::fill_accounting::{aggregate, simulated_fill};
+use crate::strategy::pipeline::{ExecutionPipeline, OrderProducer};
+use crate::strategy::symbol_map::SymbolMap;
+use crate::strategy::thread_pinning::{get_core_count, spawn_pinned_thread};
+use crate::strategy::trade_journal::{current_scope, in_scope, JournalPhase};
+use crate::strategy::types::{
+    FeeRates, Fill, FundingPayment, Liquidity, OrderBookDepth, OrderRequest, OrderSide, OrderStatus, OrderStatusInfo,
+    OrderType, SimulatedOrder, VenuePosition,
+};
+use core_affinity::CoreId;
+use crossbeam_queue::SegQueue;
+use dashmap::DashMap;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::runtime::Handle;
+use tokio::sync::oneshot;
+
+/// Maximum requests dispatched per drain of the pipeline
+const DISPATCH_BATCH: usize = 64;
+
+/// Idle back-off when the pipeline is empty
+const IDLE_SLEEP: Duration = Duration::from_micros(50);
+
+/// Configuration for the execution worker.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionWorkerConfig {
+    /// Core the worker thread is pinned to
+    pub core: usize,
+
+    /// Interval between status polls of a resting order
+    pub fill_poll_interval: Duration,
+
+    /// How long to keep polling a resting order before handing it back
+    pub fill_timeout: Duration,
+}
+
+impl Default for ExecutionWorkerConfig {
+    /// Pinned after the first detector shard (core 9), polling every 200ms for 30s.
+    fn default() -> Self {
+        Self {
+            core: 9,
+            fill_poll_interval: Duration::from_millis(200),
+            fill_timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+impl ExecutionWorkerConfig {
+    /// Load configuration from environment variables.
+    ///
+    /// - `EXECUTION_WORKER_CORE`: core for the worker thread (default 9)
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let core = std::env::var("EXECUTION_WORKER_CORE")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(defaults.core);
+
+        Self { core, ..defaults }
+    }
+
+    /// Worker core, wrapped to the available cores.
+    pub fn core_id(&self) -> CoreId {
+        let core_count = get_core_count().max(1);
+        CoreId { id: self.core % core_count }
+    }
+}
+
+/// Outcome of a submitted order, published back to the strategy.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExecutionReport {
+    /// The venue accepted the order
+    Ack { order_id: u64, exchange_order_id: String, latency_us: u64 },
+    /// Some or all of the order filled; `complete` is set on the last fill.
+    /// `fee` is charged in `fee_asset` (negative for rebates); venues that
+    /// cannot list executions report the fee-table estimate.
+    Fill { order_id: u64, price: f64, size: f64, fee: f64, fee_asset: String, liquidity: Liquidity, complete: bool },
+    /// The venue cancelled the order with `filled_size` executed
+    Cancelled { order_id: u64, filled_size: f64 },
+    /// The order never reached the book
+    Rejected { order_id: u64, error: ExecutionError },
+    /// Still resting after `fill_timeout` with `filled_size` executed; the
+    /// strategy polls it from here on
+    Handover { order_id: u64, filled_size: f64 },
+}
+
+impl ExecutionReport {
+    /// Strategy-assigned id of the order this report is about.
+    pub fn order_id(&self) -> u64 {
+        match self {
+            ExecutionReport::Ack { order_id, .. }
+            | ExecutionReport::Fill { order_id, .. }
+            | ExecutionReport::Cancelled { order_id, .. }
+            | ExecutionReport::Rejected { order_id, .. }
+            | ExecutionReport::Handover { order_id, .. } => *order_id,
+        }
+    }
+
+    /// No further reports will follow for this order.
+    pub fn is_final(&self) -> bool {
+        match self {
+            ExecutionReport::Ack { .. } => false,
+            ExecutionReport::Fill { complete, .. } => *complete,
+            ExecutionReport::Cancelled { .. } | ExecutionReport::Rejected { .. } | ExecutionReport::Handover { .. } => true,
+        }
+    }
+}
+
+/// Snapshot of worker counters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionWorkerMetrics {
+    pub dispatched: u64,
+    pub acked: u64,
+    pub rejected: u64,
+    pub in_flight: u64,
+    pub queue_depth: usize,
+}
+
+/// Order submitted by `WorkerBackend`, dispatched in place of the bare request
+struct AttachedOrder {
+    order: SimulatedOrder,
+    market: bool,
+    /// Journal scope of the submitting task, re-entered around the placement
+    scope: Option<(String, JournalPhase)>,
+    reply: oneshot::Sender<Result<SimulatedOrder, ExecutionError>>,
+}
+
+/// State shared between the worker thread, dispatched tasks and handles.
+struct Shared {
+    config: ExecutionWorkerConfig,
+    pipeline: ExecutionPipeline,
+    reports: SegQueue<ExecutionReport>,
+    attached: DashMap<u64, AttachedOrder>,
+    next_order_id: AtomicU64,
+    dispatched: AtomicU64,
+    acked: AtomicU64,
+    rejected: AtomicU64,
+    in_flight: AtomicU64,
+    shutdown: AtomicBool,
+    /// Prices fills the venue does not itemise
+    fees: Arc<FeeSchedule>,
+}
+
+impl Shared {
+    fn publish(&self, report: ExecutionReport) {
+        match &report {
+            ExecutionReport::Ack { .. } => { self.acked.fetch_add(1, Ordering::Relaxed); }
+            ExecutionReport::Rejected { .. } => { self.rejected.fetch_add(1, Ordering::Relaxed); }
+            _ => {}
+        }
+        self.reports.push(report);
+    }
+
+    fn metrics(&self) -> ExecutionWorkerMetrics {
+        ExecutionWorkerMetrics {
+            dispatched: self.dispatched.load(Ordering::Relaxed),
+            acked: self.acked.load(Ordering::Relaxed),
+            rejected: self.rejected.load(Ordering::Relaxed),
+            in_flight: self.in_flight.load(Ordering::Relaxed),
+            queue_depth: self.pipeline.depth(),
+        }
+    }
+}
+
+/// Strategy-side handle: submit orders and read reports.
+///
+/// Cheap to clone. Keeps the worker's queues alive, so it stays valid after
+/// the worker is shut down (submissions are then simply never dispatched).
+#[derive(Clone)]
+pub struct ExecutionHandle {
+    shared: Arc<Shared>,
+    producer: OrderProducer,
+}
+
+impl ExecutionHandle {
+    /// Allocate a unique order id for a new request.
+    pub fn next_order_id(&self) -> u64 {
+        self.shared.next_order_id.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Queue an order for execution (non-blocking).
+    ///
+    /// Unlike market data, orders are never dropped for backpressure: a full
+    /// queue hands the request back to the caller.
+    pub fn submit(&self, order: OrderRequest) -> Result<(), OrderRequest> {
+        self.producer.try_submit(order)
+    }
+
+    /// Pop the next execution report, if any.
+    pub fn poll_report(&self) -> Option<ExecutionReport> {
+        self.shared.reports.pop()
+    }
+
+    pub fn metrics(&self) -> ExecutionWorkerMetrics {
+        self.shared.metrics()
+    }
+}
+
+/// Pinned execution worker thread.
+///
+/// Dropping the worker does not stop the thread; call `shutdown()` for a
+/// clean stop.
+pub struct ExecutionWorker {
+    shared: Arc<Shared>,
+    handle: Option<thread::JoinHandle<()>>,
+}
+
+impl ExecutionWorker {
+    /// Start the worker thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Core placement and fill polling
+    /// * `backend` - Backend the requests are placed through
+    /// * `symbol_map` - Resolves `OrderRequest::symbol_id` to (exchange, symbol)
+    /// * `fees` - Account fee schedule, for fills reported without venue executions
+    /// * `runtime` - Runtime the venue calls are spawned onto
+    pub fn start(
+        config: ExecutionWorkerConfig,
+        backend: Arc<dyn ExecutionBackend>,
+        symbol_map: Arc<SymbolMap>,
+        fees: Arc<FeeSchedule>,
+        runtime: Handle,
+    ) -> Self {
+        let core = config.core_id();
+        let shared = Arc::new(Shared {
+            config,
+            pipeline: ExecutionPipeline::new(),
+            reports: SegQueue::new(),
+            attached: DashMap::new(),
+            next_order_id: AtomicU64::new(1),
+            dispatched: AtomicU64::new(0),
+            acked: AtomicU64::new(0),
+            rejected: AtomicU64::new(0),
+            in_flight: AtomicU64::new(0),
+            shutdown: AtomicBool::new(false),
+            fees,
+        });
+
+        eprintln!("[EXECUTION-WORKER] Starting on core {}", core.id);
+
+        let worker_shared = shared.clone();
+        let handle = spawn_pinned_thread(core, "execution-worker", move || {
+            run_worker(&worker_shared, backend, symbol_map, runtime);
+        });
+
+        Self { shared, handle: Some(handle) }
+    }
+
+    /// Handle for the strategy to submit orders and read reports.
+    pub fn handle(&self) -> ExecutionHandle {
+        ExecutionHandle {
+            shared: self.shared.clone(),
+            producer: self.shared.pipeline.producer(),
+        }
+    }
+
+    pub fn metrics(&self) -> ExecutionWorkerMetrics {
+        self.shared.metrics()
+    }
+
+    /// Dispatch anything still queued, then stop the worker thread.
+    ///
+    /// Orders already dispatched finish on the runtime.
+    pub fn shutdown(mut self) {
+        self.shared.shutdown.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.handle.take() {
+            if let Err(e) = handle.join() {
+                eprintln!("[EXECUTION-WORKER] Join error: {:?}", e);
+            }
+        }
+    }
+}
+
+/// Worker loop: drain the pipeline and spawn one task per request.
+fn run_worker(shared: &Arc<Shared>, backend: Arc<dyn ExecutionBackend>, symbol_map: Arc<SymbolMap>, runtime: Handle) {
+    let consumer = shared.pipeline.consumer();
+
+    loop {
+        // Read the flag before draining so nothing queued ahead of shutdown is lost
+        let stopping = shared.shutdown.load(Ordering::Relaxed);
+        let batch = consumer.pop_batch(DISPATCH_BATCH);
+
+        for request in &batch {
+            shared.dispatched.fetch_add(1, Ordering::Relaxed);
+            shared.in_flight.fetch_add(1, Ordering::Relaxed);
+            runtime.spawn(dispatch(*request, shared.clone(), backend.clone(), symbol_map.clone()));
+        }
+
+        if batch.is_empty() {
+            if stopping {
+                break;
+            }
+            thread::sleep(IDLE_SLEEP);
+        }
+    }
+}
+
+/// Place one request and report its outcome.
+async fn dispatch(request: OrderRequest, shared: Arc<Shared>, backend: Arc<dyn ExecutionBackend>, symbol_map: Arc<SymbolMap>) {
+    let order_id = request.order_id;
+    let (order, market, scope, reply) = match shared.attached.remove(&order_id) {
+        Some((_, attached)) => (Some(attached.order), attached.market, attached.scope, Some(attached.reply)),
+        None => (
+            symbol_map.get(request.symbol_id).map(|(exchange, symbol)| to_simulated_order(&request, exchange, symbol)),
+            request.is_market(),
+            None,
+            None,
+        ),
+    };
+    match order {
+        Some(order) => {
+            let started = Instant::now();
+            let placement = async {
+                if market {
+                    backend.place_market_order(order).await
+                } else {
+                    backend.place_order(order).await
+                }
+            };
+            let result = match scope {
+                Some((trade_id, phase)) => in_scope(&trade_id, phase, placement).await,
+                None => placement.await,
+            };
+            if let Some(reply) = reply {
+                let _ = reply.send(result.clone());
+            }
+            match result {
+                Ok(placed) => {
+                    shared.publish(ExecutionReport::Ack {
+                        order_id,
+                        exchange_order_id: placed.id.clone(),
+                        latency_us: started.elapsed().as_micros() as u64,
+                    });
+                    if placed.status == OrderStatus::Filled {
+                        let executions = venue_fills(&placed, backend.as_ref(), 0).await;
+                        shared.publish(fill_report(order_id, &placed, &executions, placed.fill_price.unwrap_or(placed.price), placed.size, true, &shared.fees));
+                    } else {
+                        track_fills(order_id, &placed, &shared, backend.as_ref()).await;
+                    }
+                }
+                Err(error) => shared.publish(ExecutionReport::Rejected { order_id, error }),
+            }
+        }
+        None => shared.publish(ExecutionReport::Rejected {
+            order_id,
+            error: ExecutionError::Other(format!("Unknown symbol id {}", request.symbol_id)),
+        }),
+    }
+    shared.in_flight.fetch_sub(1, Ordering::Relaxed);
+}
+
+/// Poll a resting order and publish fills until it's done or the timeout passes.
+async fn track_fills(order_id: u64, placed: &SimulatedOrder, shared: &Shared, backend: &dyn ExecutionBackend) {
+    let deadline = Instant::now() + shared.config.fill_timeout;
+    let mut reported = 0.0;
+    let mut seen_executions = 0;
+
+    while Instant::now() < deadline {
+        tokio::time::sleep(shared.config.fill_poll_interval).await;
+        let info = match backend.get_order_status_detailed(&placed.exchange, &placed.id, &placed.symbol).await {
+            Ok(info) => info,
+            // Accepted, then cancelled for crossing the book
+            Err(ExecutionError::PostOnlyRejected(_)) => {
+                shared.publish(ExecutionReport::Cancelled { order_id, filled_size: reported });
+                return;
+            }
+            Err(e) => {
+                eprintln!("[EXECUTION-WORKER] Status check failed for order {}: {}", order_id, e);
+                continue;
+            }
+        };
+
+        let complete = info.status == OrderStatus::Filled;
+        if info.filled_quantity > reported || complete {
+            let executions = venue_fills(placed, backend, seen_executions).await;
+            seen_executions += executions.len();
+            shared.publish(fill_report(order_id, placed, &executions, placed.price, (info.filled_quantity - reported).max(0.0), complete, &shared.fees));
+            reported = info.filled_quantity;
+        }
+        if complete {
+            return;
+        }
+        if info.status == OrderStatus::Cancelled {
+            shared.publish(ExecutionReport::Cancelled { order_id, filled_size: info.filled_quantity });
+            return;
+        }
+    }
+    shared.publish(ExecutionReport::Handover { order_id, filled_size: reported });
+}
+
+/// Executions of `placed` the venue lists beyond the first `seen`; empty when
+/// the backend cannot list them.
+async fn venue_fills(placed: &SimulatedOrder, backend: &dyn ExecutionBackend, seen: usize) -> Vec<Fill> {
+    match backend.get_fills(&placed.exchange, &placed.symbol, placed.created_at).await {
+        Ok(fills) => fills.into_iter().filter(|f| f.order_id == placed.id).skip(seen).collect(),
+        Err(_) => Vec::new(),
+    }
+}
+
+/// Fill report from the venue's executions, or from the order at the fee
+/// schedule when there are none.
+fn fill_report(order_id: u64, placed: &SimulatedOrder, executions: &[Fill], price: f64, size: f64, complete: bool, fees: &FeeSchedule) -> ExecutionReport {
+    let fill = aggregate(executions, fees).unwrap_or_else(|| {
+        simulated_fill(&SimulatedOrder { fill_price: Some(price), size, ..placed.clone() }, 0, fees)
+    });
+    ExecutionReport::Fill {
+        order_id,
+        price: fill.price,
+        size: fill.quantity,
+        fee: fill.fee,
+        fee_asset: fill.fee_asset,
+        liquidity: fill.liquidity,
+        complete,
+    }
+}
+
+/// Queue entry for `order` under the worker id `order_id`.
+pub fn order_request(order_id: u64, order: &SimulatedOrder, symbol_map: &SymbolMap) -> OrderRequest {
+    let symbol_id = symbol_map.get_or_insert(&order.exchange, &order.symbol);
+    let side = if order.side == OrderSide::Long { 0 } else { 1 };
+    let timestamp_us = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_micros() as u64;
+    let mut request = match order.order_type {
+        OrderType::Market => OrderRequest::market(order_id, symbol_id, exchange_to_id(&order.exchange), side, order.size, timestamp_us),
+        OrderType::Limit => OrderRequest::limit(order_id, symbol_id, exchange_to_id(&order.exchange), side, order.price, order.size, timestamp_us),
+    }.with_time_in_force(order.time_in_force);
+    if order.reduce_only {
+        request = request.with_reduce_only();
+    }
+    if order.close_position {
+        request = request.with_close_position();
+    }
+    request
+}
+
+/// Backend whose order placement runs on the execution worker.
+///
+/// Each order goes through `ExecutionHandle::submit` with the full order
+/// attached, and the caller waits for the venue's answer, so journal
+/// attribution and client order IDs survive the hop. Everything else is
+/// passed straight to `inner`. Orders are placed inline when the queue is full
+/// or the worker has shut down.
+pub struct WorkerBackend {
+    inner: Arc<dyn ExecutionBackend>,
+    handle: ExecutionHandle,
+    symbol_map: Arc<SymbolMap>,
+}
+
+impl WorkerBackend {
+    pub fn new(inner: Arc<dyn ExecutionBackend>, handle: ExecutionHandle, symbol_map: Arc<SymbolMap>) -> Self {
+        Self { inner, handle, symbol_map }
+    }
+
+    async fn place(&self, order: SimulatedOrder, market: bool) -> Result<SimulatedOrder, ExecutionError> {
+        let shared = &self.handle.shared;
+        if !shared.shutdown.load(Ordering::Relaxed) {
+            let order_id = self.handle.next_order_id();
+            let request = order_request(order_id, &order, &self.symbol_map);
+            let (reply, answer) = oneshot::channel();
+            // Attach before submitting so the worker always finds it
+            shared.attached.insert(order_id, AttachedOrder { order: order.clone(), market, scope: current_scope(), reply });
+            if self.handle.submit(request).is_ok() {
+                return answer.await.unwrap_or_else(|_| {
+                    Err(ExecutionError::Other(format!("Execution worker dropped order {}", order_id)))
+                });
+            }
+            shared.attached.remove(&order_id);
+            eprintln!("[EXECUTION] ⚠️  Execution queue full, placing inline");
+        }
+        if market {
+            self.inner.place_market_order(order).await
+        } else {
+            self.inner.place_order(order).await
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ExecutionBackend for WorkerBackend {
+    async fn set_leverage(&self, exchange: &str, symbol: &str, leverage: u8) -> Result<(), ExecutionError> {
+        self.inner.set_leverage(exchange, symbol, leverage).await
+    }
+
+    async fn set_margin_type_isolated(&self, exchange: &str, symbol: &str) -> Result<(), ExecutionError> {
+        self.inner.set_margin_type_isolated(exchange, symbol).await
+    }
+
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        self.place(order, false).await
+    }
+
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        self.place(order, true).await
+    }
+
+    async fn cancel_order(&self, exchange: &str, order_id: &str) -> Result<(), ExecutionError> {
+        self.inner.cancel_order(exchange, order_id).await
+    }
+
+    async fn amend_order(&self, order: &SimulatedOrder, new_price: f64, new_size: Option<f64>) -> Result<AmendedOrder, ExecutionError> {
+        if !self.inner.capabilities().supports_amend(&order.exchange) {
+            // The replacement is a new order, so it goes through the worker too
+            return cancel_replace(self, order, new_price, new_size).await;
+        }
+        self.inner.amend_order(order, new_price, new_size).await
+    }
+
+    async fn get_order_status(&self, exchange: &str, order_id: &str) -> Result<OrderStatus, ExecutionError> {
+        self.inner.get_order_status(exchange, order_id).await
+    }
+
+    async fn get_order_status_detailed(&self, exchange: &str, order_id: &str, symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
+        self.inner.get_order_status_detailed(exchange, order_id, symbol).await
+    }
+
+    async fn get_available_balance(&self, exchange: &str) -> Result<f64, ExecutionError> {
+        self.inner.get_available_balance(exchange).await
+    }
+
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
+        self.inner.get_all_balances().await
+    }
+
+    async fn is_symbol_tradeable(&self, exchange: &str, symbol: &str) -> Result<bool, ExecutionError> {
+        self.inner.is_symbol_tradeable(exchange, symbol).await
+    }
+
+    async fn get_order_book_depth(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        levels: usize,
+    ) -> Result<OrderBookDepth, ExecutionError> {
+        self.inner.get_order_book_depth(exchange, symbol, levels).await
+    }
+
+    async fn get_best_bid(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError> {
+        self.inner.get_best_bid(exchange, symbol).await
+    }
+
+    async fn get_best_ask(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError> {
+        self.inner.get_best_ask(exchange, symbol).await
+    }
+
+    fn backend_name(&self) -> &str {
+        self.inner.backend_name()
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        self.inner.capabilities()
+    }
+
+    async fn get_quantity_step(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError> {
+        self.inner.get_quantity_step(exchange, symbol).await
+    }
+
+    async fn get_spot_position(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError> {
+        self.inner.get_spot_position(exchange, symbol).await
+    }
+
+    async fn get_open_positions(&self, exchange: &str) -> Result<Vec<VenuePosition>, ExecutionError> {
+        self.inner.get_open_positions(exchange).await
+    }
+
+    async fn get_open_orders(&self, exchange: &str) -> Result<Vec<SimulatedOrder>, ExecutionError> {
+        self.inner.get_open_orders(exchange).await
+    }
+
+    async fn get_funding_history(&self, exchange: &str, symbol: &str, since: u64) -> Result<Vec<FundingPayment>, ExecutionError> {
+        self.inner.get_funding_history(exchange, symbol, since).await
+    }
+
+    async fn get_fills(&self, exchange: &str, symbol: &str, since: u64) -> Result<Vec<Fill>, ExecutionError> {
+        self.inner.get_fills(exchange, symbol, since).await
+    }
+
+    async fn get_fee_rates(&self, exchange: &str, symbol: &str) -> Result<FeeRates, ExecutionError> {
+        self.inner.get_fee_rates(exchange, symbol).await
+    }
+
+    async fn wait_for_order_update(&self, order_ids: &[&str], timeout: Duration) -> bool {
+        self.inner.wait_for_order_update(order_ids, timeout).await
+    }
+}
+
+/// Expand a queued request into the order the backends take.
+fn to_simulated_order(request: &OrderRequest, exchange: String, symbol: String) -> SimulatedOrder {
+    SimulatedOrder {
+        id: format!("exec_{}", request.order_id),
+        exchange,
+        symbol,
+        side: if request.is_buy() { OrderSide::Long } else { OrderSide::Short },
+        order_type: if request.is_market() { OrderType::Market } else { OrderType::Limit },
+        price: request.price,
+        size: request.size,
+        queue_position: None,
+        created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+        filled_at: None,
+        fill_price: None,
+        status: OrderStatus::Pending,
+        time_in_force: request.tif(),
+        reduce_only: request.flags & OrderRequest::FLAG_REDUCE_ONLY != 0,
+        close_position: request.is_close_position(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::exchange_fees::EXCHANGE_ID_BYBIT;
+    use crate::strategy::paper_trading_backend::PaperTradingBackend;
+    use std::collections::HashMap;
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_worker_dispatches_and_reports_fills() {
+        let backend = Arc::new(PaperTradingBackend::new(HashMap::from([("bybit".to_string(), 10_000.0)])));
+        let symbol_map = Arc::new(SymbolMap::new());
+        let symbol_id = symbol_map.get_or_insert("bybit", "BTCUSDT");
+        let worker = ExecutionWorker::start(ExecutionWorkerConfig::default(), backend, symbol_map, Arc::new(FeeSchedule::default()), Handle::current());
+        let handle = worker.handle();
+
+        let filled_id = handle.next_order_id();
+        handle.submit(OrderRequest::limit(filled_id, symbol_id, EXCHANGE_ID_BYBIT, 1, 100.0, 0.5, 0).with_reduce_only()).unwrap();
+        let unknown_id = handle.next_order_id();
+        handle.submit(OrderRequest::market(unknown_id, u32::MAX, EXCHANGE_ID_BYBIT, 0, 1.0, 0)).unwrap();
+
+        let mut reports = Vec::new();
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while reports.iter().filter(|r: &&ExecutionReport| r.is_final()).count() < 2 && Instant::now() < deadline {
+            match handle.poll_report() {
+                Some(report) => reports.push(report),
+                None => tokio::time::sleep(Duration::from_millis(5)).await,
+            }
+        }
+        worker.shutdown();
+
+        let filled: Vec<_> = reports.iter().filter(|r| r.order_id() == filled_id).collect();
+        assert!(matches!(filled[0], ExecutionReport::Ack { exchange_order_id, .. } if exchange_order_id == &format!("exec_{}", filled_id)));
+        // The paper backend lists its fills, charged at the 5.5bps bybit fee table
+        assert!(matches!(filled[1], ExecutionReport::Fill { order_id, price, size, fee, fee_asset, liquidity: Liquidity::Taker, complete: true }
+            if *order_id == filled_id && *price == 100.0 && *size == 0.5 && (fee - 0.0275).abs() < 1e-12 && fee_asset == "USDT"));
+
+        let rejected = reports.iter().find(|r| r.order_id() == unknown_id).unwrap();
+        assert!(matches!(rejected, ExecutionReport::Rejected { .. }));
+
+        let metrics = handle.metrics();
+        assert_eq!((metrics.dispatched, metrics.acked, metrics.rejected), (2, 1, 1));
+    }
+}
@@ -0,0 +1,398 @@
+//! Exit order mechanics for real trading
+//!
+//! Trades opened by a real backend rest a reduce-only exit order on each leg.
+//! `ExitExecutor` handles everything order-related once a trade is open:
+//!
+//! - `poll_exit_orders` reports which resting exit orders filled;
+//! - `hedge_open_leg` closes the other leg when only one exit order filled:
+//!   cancel its exit order, try a limit where the fill-probability estimator
+//!   expects a fill (amending to the touch if supported), then market;
+//! - `flatten` closes both legs when an exit policy fires or the exit orders
+//!   time out: cancel the exit orders, rest a limit at the touch for 2s, then
+//!   market whatever is left.
+//!
+//! Deciding *when* to exit is the job of the `exit_policy` module.
+
+use crate::strategy::execution_backend::{fit_close_order, ExecutionBackend};
+use crate::strategy::fill_probability::FillProbabilityEstimator;
+use crate::strategy::types::{OrderSide, OrderStatus, OrderStatusInfo, OrderType, PaperTrade, SimulatedOrder, TimeInForce};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Resting exit orders older than this with neither leg filled are replaced
+/// by a forced close
+pub const EXIT_ORDER_TIMEOUT_SECS: u64 = 3;
+
+/// How long the touch limit of `flatten` rests before the market fallback
+const FLATTEN_LIMIT_WAIT: Duration = Duration::from_secs(2);
+
+/// Remainders below this are considered closed
+const MIN_CLOSE_QUANTITY: f64 = 0.001;
+
+/// Leg of a two-legged trade
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeLeg {
+    Long,
+    Short,
+}
+
+impl TradeLeg {
+    /// Side of the order that closes this leg
+    pub fn close_side(self) -> OrderSide {
+        match self {
+            TradeLeg::Long => OrderSide::Short,
+            TradeLeg::Short => OrderSide::Long,
+        }
+    }
+}
+
+/// Which resting exit orders have filled
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ExitOrderFills {
+    pub long_filled: bool,
+    pub short_filled: bool,
+}
+
+/// True when both resting exit orders are older than `EXIT_ORDER_TIMEOUT_SECS`.
+pub fn exit_orders_timed_out(trade: &PaperTrade, now: u64) -> bool {
+    let age = |order: &Option<SimulatedOrder>| order.as_ref().map(|o| now.saturating_sub(o.created_at)).unwrap_or(0);
+    age(&trade.long_exit_order) > EXIT_ORDER_TIMEOUT_SECS && age(&trade.short_exit_order) > EXIT_ORDER_TIMEOUT_SECS
+}
+
+/// Reduce-only order closing part of one leg
+fn close_order(prefix: &str, exchange: &str, symbol: &str, leg: TradeLeg, order_type: OrderType, price: f64, size: f64) -> SimulatedOrder {
+    SimulatedOrder {
+        id: format!("{}_{}", prefix, uuid::Uuid::new_v4()),
+        exchange: exchange.to_string(),
+        symbol: symbol.to_string(),
+        side: leg.close_side(),
+        order_type,
+        price,
+        size,
+        created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+        status: OrderStatus::Pending,
+        time_in_force: TimeInForce::Gtc,
+        reduce_only: true,
+        ..Default::default()
+    }
+}
+
+/// One leg of a trade as seen by the executor
+struct LegView<'a> {
+    leg: TradeLeg,
+    exchange: &'a str,
+    symbol: &'a str,
+    quantity: f64,
+    mark: f64,
+    exit_order: Option<&'a SimulatedOrder>,
+}
+
+impl<'a> LegView<'a> {
+    fn of(trade: &'a PaperTrade, leg: TradeLeg, mark: f64) -> Self {
+        match leg {
+            TradeLeg::Long => Self {
+                leg,
+                exchange: &trade.long_exchange,
+                symbol: &trade.symbol,
+                quantity: trade.long_order.size,
+                mark,
+                exit_order: trade.long_exit_order.as_ref(),
+            },
+            TradeLeg::Short => Self {
+                leg,
+                exchange: &trade.short_exchange,
+                symbol: &trade.symbol,
+                quantity: trade.short_order.size,
+                mark,
+                exit_order: trade.short_exit_order.as_ref(),
+            },
+        }
+    }
+}
+
+/// Places and supervises the orders that close trades
+pub struct ExitExecutor {
+    backend: Arc<dyn ExecutionBackend>,
+    fill_probability: FillProbabilityEstimator,
+}
+
+impl ExitExecutor {
+    pub fn new(backend: Arc<dyn ExecutionBackend>) -> Self {
+        Self { backend, fill_probability: FillProbabilityEstimator::new() }
+    }
+
+    /// Check the status of both resting exit orders.
+    pub async fn poll_exit_orders(&self, trade: &PaperTrade) -> ExitOrderFills {
+        let mut fills = ExitOrderFills::default();
+        for leg in [TradeLeg::Long, TradeLeg::Short] {
+            let view = LegView::of(trade, leg, 0.0);
+            let Some(order) = view.exit_order else {
+                continue;
+            };
+            // An execution report may already have settled it
+            let status = match order.status {
+                OrderStatus::Pending => self.backend.get_order_status(view.exchange, &order.id).await,
+                settled => Ok(settled),
+            };
+            let filled = match status {
+                Ok(OrderStatus::Filled) => {
+                    eprintln!("[EXIT FILLED] {} | {:?} exit order filled!", trade.id, leg);
+                    true
+                }
+                Ok(OrderStatus::Cancelled) => {
+                    eprintln!("[EXIT CANCELLED] {} | {:?} exit order was cancelled", trade.id, leg);
+                    false
+                }
+                Ok(OrderStatus::Pending) => false,
+                Err(e) => {
+                    eprintln!("[EXIT ERROR] {} | Error checking {:?} exit: {}", trade.id, leg, e);
+                    false
+                }
+            };
+            match leg {
+                TradeLeg::Long => fills.long_filled = filled,
+                TradeLeg::Short => fills.short_filled = filled,
+            }
+        }
+        fills
+    }
+
+    /// Close both legs: cancel resting exit orders, try a limit at the touch,
+    /// then market the remainder. `tag` prefixes the log lines.
+    pub async fn flatten(&self, trade: &PaperTrade, long_price: f64, short_price: f64, tag: &str) {
+        let long = LegView::of(trade, TradeLeg::Long, long_price);
+        let short = LegView::of(trade, TradeLeg::Short, short_price);
+        let (long_result, short_result) = tokio::join!(self.close_leg(&long, tag), self.close_leg(&short, tag));
+
+        for (view, result) in [(&long, long_result), (&short, short_result)] {
+            match result {
+                Ok(()) => eprintln!("{} ✅ {:?} leg closed on {}", tag, view.leg, view.exchange),
+                Err(e) => {
+                    eprintln!("{} ❌ CRITICAL: {:?} leg failed to close: {}", tag, view.leg, e);
+                    eprintln!("{} 🚨 UNHEDGED {:?} POSITION ON {}", tag, view.leg, view.exchange);
+                }
+            }
+        }
+    }
+
+    /// Close `open_leg` after the other leg's exit order filled.
+    ///
+    /// Returns the exit reason, or `None` if the leg is still open.
+    pub async fn hedge_open_leg(&self, trade: &PaperTrade, open_leg: TradeLeg, mark: f64) -> Option<&'static str> {
+        let view = LegView::of(trade, open_leg, mark);
+        let mut filled = self.cancel_exit_order(&view, "[PARTIAL EXIT]").await;
+        if filled >= view.quantity {
+            eprintln!("[PARTIAL EXIT] ✅ Already fully hedged from cancelled exit order ({:.4} contracts)", filled);
+            return Some("Partial exit hedged (cancelled order filled)");
+        }
+
+        let mut reason = "Partial exit hedged with market order";
+        match self.backend.get_order_book_depth(view.exchange, view.symbol, 5).await {
+            Ok(order_book) => {
+                let remaining = view.quantity - filled;
+                let decision = self.fill_probability.should_try_limit(&order_book, open_leg.close_side(), remaining * mark);
+                eprintln!("[PROBABILITY] {} | Use limit: {} | Probability: {:.0}% | Reason: {}",
+                    view.symbol, decision.use_limit, decision.probability * 100.0, decision.reason);
+
+                let book_side = match open_leg.close_side() {
+                    OrderSide::Short => &order_book.bids,
+                    OrderSide::Long => &order_book.asks,
+                };
+                let limit_price = book_side.get(decision.price_level).or(book_side.first()).map(|level| level.price);
+                if let (true, Some(limit_price)) = (decision.use_limit, limit_price) {
+                    let limit = close_order("smart_exit", view.exchange, view.symbol, open_leg, OrderType::Limit, limit_price, remaining);
+                    let limit = fit_close_order(self.backend.as_ref(), limit).await;
+                    match self.backend.place_order(limit).await {
+                        Ok(placed) => {
+                            eprintln!("[PARTIAL EXIT] Smart limit placed: {} at ${:.4} (level {})", placed.id, limit_price, decision.price_level);
+                            tokio::time::sleep(Duration::from_millis(decision.wait_time_ms)).await;
+
+                            let mut placed = placed;
+                            let mut status = self.backend.get_order_status_detailed(view.exchange, &placed.id, view.symbol).await;
+                            if matches!(&status, Ok(info) if !info.is_fully_filled()) {
+                                if let Some((amended, amended_status)) = self.amend_to_touch(&placed, decision.wait_time_ms).await {
+                                    placed = amended;
+                                    status = Ok(amended_status);
+                                }
+                            }
+                            match status {
+                                Ok(info) if info.is_fully_filled() => {
+                                    eprintln!("[PARTIAL EXIT] ✅ Smart limit fully filled! Hedge complete (maker fee)");
+                                    return Some("Partial exit hedged with smart limit");
+                                }
+                                Ok(info) => {
+                                    filled += info.filled_quantity;
+                                    if info.filled_quantity > 0.0 {
+                                        eprintln!("[PARTIAL EXIT] ⚠️  Limit partially filled {:.4}/{:.4} contracts", info.filled_quantity, remaining);
+                                        reason = "Partial exit hedged (limit partial + market)";
+                                    } else {
+                                        eprintln!("[PARTIAL EXIT] ⚠️  Limit didn't fill - using market order");
+                                    }
+                                    let _ = self.backend.cancel_order(view.exchange, &placed.id).await;
+                                }
+                                Err(e) => {
+                                    eprintln!("[PARTIAL EXIT] ⚠️  Failed to check limit order status: {} (assuming 0 fill)", e);
+                                    let _ = self.backend.cancel_order(view.exchange, &placed.id).await;
+                                }
+                            }
+                        }
+                        Err(e) => eprintln!("[PARTIAL EXIT] ⚠️  Failed to place smart limit: {}", e),
+                    }
+                }
+            }
+            Err(e) => {
+                eprintln!("[PARTIAL EXIT] ⚠️  Failed to get order book: {} - using market order", e);
+                reason = "Partial exit hedged with market (no order book)";
+            }
+        }
+
+        let remaining = view.quantity - filled;
+        if remaining <= MIN_CLOSE_QUANTITY {
+            return Some(reason);
+        }
+        eprintln!("[PARTIAL EXIT] Placing market order for REMAINING {:.4} contracts (filled so far: {:.4})", remaining, filled);
+        let market = close_order("market_hedge", view.exchange, view.symbol, open_leg, OrderType::Market, mark, remaining);
+        let market = fit_close_order(self.backend.as_ref(), market).await;
+        if market.size <= MIN_CLOSE_QUANTITY {
+            eprintln!("[PARTIAL EXIT] ✅ Venue shows no {:?} position left on {}", open_leg, view.exchange);
+            return Some(reason);
+        }
+        match self.backend.place_market_order(market).await {
+            Ok(_) => {
+                eprintln!("[PARTIAL EXIT] ✅ Market hedge complete (taker fee)");
+                Some(reason)
+            }
+            Err(e) => {
+                eprintln!("[PARTIAL EXIT] ❌ CRITICAL: Market order failed: {}", e);
+                eprintln!("[PARTIAL EXIT] 🚨 UNHEDGED {:?} POSITION ON {}", open_leg, view.exchange);
+                None
+            }
+        }
+    }
+
+    /// Cancel a leg's resting exit order and return how much of it filled
+    /// before the cancel took effect.
+    async fn cancel_exit_order(&self, view: &LegView<'_>, tag: &str) -> f64 {
+        let Some(order) = view.exit_order else {
+            return 0.0;
+        };
+        eprintln!("{} Cancelling {:?} exit order: {}", tag, view.leg, order.id);
+        let _ = self.backend.cancel_order(view.exchange, &order.id).await;
+        match self.backend.get_order_status_detailed(view.exchange, &order.id, view.symbol).await {
+            Ok(info) => {
+                if info.filled_quantity > 0.0 {
+                    eprintln!("{} ⚠️  Cancelled {:?} exit order filled {:.4} contracts before cancellation", tag, view.leg, info.filled_quantity);
+                }
+                info.filled_quantity
+            }
+            Err(e) => {
+                eprintln!("{} ⚠️  Failed to check cancelled {:?} exit order: {} (assuming 0 fill)", tag, view.leg, e);
+                0.0
+            }
+        }
+    }
+
+    async fn close_leg(&self, view: &LegView<'_>, tag: &str) -> Result<(), String> {
+        let mut filled = self.cancel_exit_order(view, tag).await;
+
+        if view.quantity - filled > MIN_CLOSE_QUANTITY {
+            let remaining = view.quantity - filled;
+            let touch = match view.leg.close_side() {
+                OrderSide::Short => self.backend.get_best_bid(view.exchange, view.symbol).await,
+                OrderSide::Long => self.backend.get_best_ask(view.exchange, view.symbol).await,
+            };
+            match touch {
+                Ok(touch) => {
+                    let limit = close_order("exit_limit", view.exchange, view.symbol, view.leg, OrderType::Limit, touch, remaining);
+                    let limit = fit_close_order(self.backend.as_ref(), limit).await;
+                    match self.backend.place_order(limit).await {
+                        Ok(placed) => {
+                            tokio::time::sleep(FLATTEN_LIMIT_WAIT).await;
+                            if let Ok(info) = self.backend.get_order_status_detailed(view.exchange, &placed.id, view.symbol).await {
+                                if info.filled_quantity > 0.0 {
+                                    eprintln!("{} {:?} limit filled {:.4} contracts (maker fee)", tag, view.leg, info.filled_quantity);
+                                }
+                                filled += info.filled_quantity;
+                            }
+                            let _ = self.backend.cancel_order(view.exchange, &placed.id).await;
+                        }
+                        Err(e) => eprintln!("{} ⚠️  Failed to place {:?} limit: {}", tag, view.leg, e),
+                    }
+                }
+                Err(e) => eprintln!("{} ⚠️  Failed to get touch for {:?} leg: {}", tag, view.leg, e),
+            }
+        }
+
+        let remaining = view.quantity - filled;
+        if remaining <= MIN_CLOSE_QUANTITY {
+            return Ok(());
+        }
+        eprintln!("{} Market closing {:?} leg: {:.4} contracts", tag, view.leg, remaining);
+        let market = close_order("exit_market", view.exchange, view.symbol, view.leg, OrderType::Market, view.mark, remaining);
+        let market = fit_close_order(self.backend.as_ref(), market).await;
+        if market.size <= MIN_CLOSE_QUANTITY {
+            eprintln!("{} Venue shows no {:?} position left on {}", tag, view.leg, view.exchange);
+            return Ok(());
+        }
+        self.backend.place_market_order(market).await.map(|_| ()).map_err(|e| e.to_string())
+    }
+
+    /// Move a resting exit limit to the touch and give it one more wait.
+    ///
+    /// Only used where the venue amends in place, so the order keeps working
+    /// on the book instead of being pulled. Returns the working order and its
+    /// status after the wait, or None if the amend wasn't possible.
+    async fn amend_to_touch(&self, order: &SimulatedOrder, wait_ms: u64) -> Option<(SimulatedOrder, OrderStatusInfo)> {
+        if !self.backend.capabilities().supports_amend(&order.exchange) {
+            return None;
+        }
+        let touch = match order.side {
+            OrderSide::Long => self.backend.get_best_ask(&order.exchange, &order.symbol).await,
+            OrderSide::Short => self.backend.get_best_bid(&order.exchange, &order.symbol).await,
+        }.ok()?;
+
+        let amended = match self.backend.amend_order(order, touch, None).await {
+            Ok(amended) => amended,
+            Err(e) => {
+                eprintln!("[PARTIAL EXIT] ⚠️  Failed to amend limit to ${:.4}: {}", touch, e);
+                return None;
+            }
+        };
+        eprintln!("[PARTIAL EXIT] Limit amended {:?} from ${:.4} to ${:.4}", amended.method, order.price, touch);
+
+        tokio::time::sleep(Duration::from_millis(wait_ms)).await;
+        let status = self.backend
+            .get_order_status_detailed(&order.exchange, &amended.order.id, &order.symbol)
+            .await
+            .ok()?;
+        Some((amended.order, status))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exit_orders_time_out_only_when_both_are_stale() {
+        let resting = |created_at| Some(SimulatedOrder { created_at, ..Default::default() });
+        let mut trade = PaperTrade {
+            entry_time: 100,
+            long_exit_order: resting(100),
+            short_exit_order: resting(102),
+            ..PaperTrade::test_trade("t1", "binance", "bybit")
+        };
+        assert!(!exit_orders_timed_out(&trade, 104));
+        assert!(exit_orders_timed_out(&trade, 106));
+        trade.short_exit_order = None;
+        assert!(!exit_orders_timed_out(&trade, 1_000));
+    }
+
+    #[test]
+    fn test_close_order_is_reduce_only_on_the_opposite_side() {
+        let order = close_order("exit_market", "okx", "ETHUSDT", TradeLeg::Long, OrderType::Market, 2_000.0, 1.5);
+        assert_eq!(order.side, OrderSide::Short);
+        assert!(order.reduce_only && order.id.starts_with("exit_market_"));
+        assert_eq!(TradeLeg::Short.close_side(), OrderSide::Long);
+    }
+}
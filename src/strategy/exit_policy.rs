@@ -0,0 +1,508 @@
+//! Exit policies
+//!
+//! An exit policy looks at one open trade and the current market and either
+//! asks for the trade to be closed or lets it run. Policies never touch the
+//! execution backend: the runner builds an `ExitContext` for each trade, asks
+//! the trade's `ExitPolicySet` and hands any `ExitSignal` to the exit executor
+//! (`exit_executor`), which owns the order mechanics.
+//!
+//! Policies are composed per strategy (`OpportunityKind`). The first policy of
+//! a set that fires wins, so sets are ordered by priority: stops first,
+//! targets last. Funding-carry trades additionally keep the carry holding
+//! policy of `funding_carry`, which tracks funding timestamps per position.
+
+use crate::strategy::types::{OpportunityKind, PaperTrade};
+use std::fmt::Debug;
+
+/// Market view of one open trade
+#[derive(Debug, Clone)]
+pub struct ExitContext<'a> {
+    pub trade: &'a PaperTrade,
+    /// Unix seconds
+    pub now: u64,
+    pub long_price: f64,
+    pub short_price: f64,
+    pub spread_bps: f64,
+    /// Live 8h funding differential (short - long); `None` without fresh rates
+    pub funding_delta_8h: Option<f64>,
+}
+
+impl ExitContext<'_> {
+    /// Spread narrowed since entry (bps); negative when it widened
+    pub fn spread_reduction_bps(&self) -> f64 {
+        self.trade.entry_spread_bps - self.spread_bps
+    }
+
+    /// Fraction of the entry spread captured so far
+    pub fn captured_fraction(&self) -> f64 {
+        if self.trade.entry_spread_bps > 0.0 {
+            self.spread_reduction_bps() / self.trade.entry_spread_bps
+        } else {
+            0.0
+        }
+    }
+
+    /// Spread P&L of the position plus the funding settled so far (fees are
+    /// in the projected profit)
+    pub fn pnl_usd(&self) -> f64 {
+        self.spread_reduction_bps() / 10000.0 * self.trade.position_size_usd + self.trade.funding_pnl_usd()
+    }
+
+    /// Seconds since entry
+    pub fn age_secs(&self) -> u64 {
+        self.now.saturating_sub(self.trade.entry_time)
+    }
+}
+
+/// Per-trade state carried between evaluations
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExitState {
+    /// Best spread reduction seen since the trailing stop armed (bps)
+    pub peak_reduction_bps: Option<f64>,
+}
+
+/// Request to close a trade
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExitSignal {
+    /// `ExitPolicy::name` of the policy that fired
+    pub policy: &'static str,
+    /// Stored as the trade's exit reason
+    pub reason: String,
+}
+
+/// A single exit rule
+pub trait ExitPolicy: Send + Sync + Debug {
+    fn name(&self) -> &'static str;
+
+    /// Exit reason if the trade should be closed now.
+    fn evaluate(&self, ctx: &ExitContext<'_>, state: &mut ExitState) -> Option<String>;
+}
+
+/// Take profit once `target_fraction` of the entry spread has converged.
+#[derive(Debug, Clone)]
+pub struct SpreadConvergence {
+    pub target_fraction: f64,
+}
+
+impl ExitPolicy for SpreadConvergence {
+    fn name(&self) -> &'static str {
+        "profit_target"
+    }
+
+    fn evaluate(&self, ctx: &ExitContext<'_>, _state: &mut ExitState) -> Option<String> {
+        // Only exit while the spread is still positive (valid arbitrage)
+        if ctx.trade.entry_spread_bps <= 0.0 || ctx.spread_bps <= 0.0 || ctx.captured_fraction() < self.target_fraction {
+            return None;
+        }
+        Some(format!("Profit hit | Entry: {:.2}bps | Exit: {:.2}bps | Captured: {:.2}bps",
+            ctx.trade.entry_spread_bps, ctx.spread_bps, ctx.spread_reduction_bps()))
+    }
+}
+
+/// Cut losses: price stops set at entry, a loss relative to the projected
+/// profit, an absolute loss, or the spread widening past the entry spread.
+#[derive(Debug, Clone)]
+pub struct StopLoss {
+    /// Exit when the loss reaches this fraction of the projected profit
+    pub projected_fraction: f64,
+    /// Hard loss limit: `max(projected * hard_fraction, hard_floor_usd)`
+    pub hard_fraction: f64,
+    pub hard_floor_usd: f64,
+    /// Exit when the spread exceeds `entry * widen_factor`
+    pub widen_factor: f64,
+}
+
+impl StopLoss {
+    /// `ExitPolicy::name` of the stop loss, for matching `ExitSignal::policy`
+    pub const NAME: &'static str = "stop_loss";
+}
+
+impl ExitPolicy for StopLoss {
+    fn name(&self) -> &'static str {
+        Self::NAME
+    }
+
+    fn evaluate(&self, ctx: &ExitContext<'_>, _state: &mut ExitState) -> Option<String> {
+        let trade = ctx.trade;
+        let long_stop = trade.stop_loss_long_price > 0.0 && ctx.long_price <= trade.stop_loss_long_price;
+        let short_stop = trade.stop_loss_short_price > 0.0 && ctx.short_price >= trade.stop_loss_short_price;
+        if long_stop || short_stop {
+            return Some(format!("Stop-loss triggered (price stop) | Long: ${:.2} | Short: ${:.2}",
+                ctx.long_price, ctx.short_price));
+        }
+
+        let pnl = ctx.pnl_usd();
+        let projected = trade.projected_profit_usd;
+        let hard_limit = (projected * self.hard_fraction).max(self.hard_floor_usd);
+        let loss_hit = (projected > 0.0 && pnl <= -projected * self.projected_fraction) || pnl <= -hard_limit;
+        let widened = ctx.spread_bps > trade.entry_spread_bps * self.widen_factor;
+        if loss_hit || widened {
+            return Some(format!("Stop loss | Entry: {:.2}bps | Exit: {:.2}bps", trade.entry_spread_bps, ctx.spread_bps));
+        }
+        None
+    }
+}
+
+/// Lock in gains: once the spread has narrowed by `activation_bps`, exit when
+/// it gives back `giveback_bps` from its best level.
+#[derive(Debug, Clone)]
+pub struct TrailingSpread {
+    pub activation_bps: f64,
+    pub giveback_bps: f64,
+}
+
+impl ExitPolicy for TrailingSpread {
+    fn name(&self) -> &'static str {
+        "trailing_spread"
+    }
+
+    fn evaluate(&self, ctx: &ExitContext<'_>, state: &mut ExitState) -> Option<String> {
+        let reduction = ctx.spread_reduction_bps();
+        let peak = match state.peak_reduction_bps {
+            Some(peak) => peak.max(reduction),
+            None if reduction >= self.activation_bps => reduction,
+            None => return None,
+        };
+        state.peak_reduction_bps = Some(peak);
+        if peak - reduction < self.giveback_bps {
+            return None;
+        }
+        Some(format!("Trailing stop | Peak capture: {:.2}bps | Now: {:.2}bps | Exit: {:.2}bps",
+            peak, reduction, ctx.spread_bps))
+    }
+}
+
+/// Give up on trades that have not converged by `min_capture_fraction`
+/// within `after_secs`.
+#[derive(Debug, Clone)]
+pub struct TimeStop {
+    pub after_secs: u64,
+    pub min_capture_fraction: f64,
+}
+
+impl ExitPolicy for TimeStop {
+    fn name(&self) -> &'static str {
+        "time_stop"
+    }
+
+    fn evaluate(&self, ctx: &ExitContext<'_>, _state: &mut ExitState) -> Option<String> {
+        if ctx.age_secs() < self.after_secs || ctx.captured_fraction() >= self.min_capture_fraction {
+            return None;
+        }
+        Some(format!("Time stop | {}s without {:.0}% convergence | Captured: {:.0}%",
+            ctx.age_secs(), self.min_capture_fraction * 100.0, ctx.captured_fraction() * 100.0))
+    }
+}
+
+/// Close anything held longer than `max_secs`.
+#[derive(Debug, Clone)]
+pub struct MaxHoldingPeriod {
+    pub max_secs: u64,
+}
+
+impl ExitPolicy for MaxHoldingPeriod {
+    fn name(&self) -> &'static str {
+        "max_holding_period"
+    }
+
+    fn evaluate(&self, ctx: &ExitContext<'_>, _state: &mut ExitState) -> Option<String> {
+        (ctx.age_secs() >= self.max_secs)
+            .then(|| format!("Max holding period | Held {}s (limit {}s)", ctx.age_secs(), self.max_secs))
+    }
+}
+
+/// Exit when live funding turns against the position by more than
+/// `min_adverse_8h` per 8h.
+#[derive(Debug, Clone)]
+pub struct FundingFlip {
+    pub min_adverse_8h: f64,
+}
+
+impl ExitPolicy for FundingFlip {
+    fn name(&self) -> &'static str {
+        "funding_flip"
+    }
+
+    fn evaluate(&self, ctx: &ExitContext<'_>, _state: &mut ExitState) -> Option<String> {
+        let delta = ctx.funding_delta_8h?;
+        (delta <= -self.min_adverse_8h)
+            .then(|| format!("Funding flipped | Entry delta: {:.6} | Now: {:.6}", ctx.trade.funding_delta_entry, delta))
+    }
+}
+
+/// Ordered list of policies; the first one that fires closes the trade
+#[derive(Debug, Default)]
+pub struct ExitPolicySet {
+    policies: Vec<Box<dyn ExitPolicy>>,
+}
+
+impl ExitPolicySet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a policy (evaluated after those already in the set).
+    pub fn with(mut self, policy: impl ExitPolicy + 'static) -> Self {
+        self.policies.push(Box::new(policy));
+        self
+    }
+
+    /// Names of the policies in evaluation order
+    pub fn names(&self) -> Vec<&'static str> {
+        self.policies.iter().map(|p| p.name()).collect()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.policies.is_empty()
+    }
+
+    /// First policy asking to exit, if any.
+    ///
+    /// Every policy before the one that fires is evaluated, so stateful
+    /// policies keep tracking even while a higher-priority policy is quiet.
+    pub fn evaluate(&self, ctx: &ExitContext<'_>, state: &mut ExitState) -> Option<ExitSignal> {
+        self.policies.iter().find_map(|policy| {
+            policy.evaluate(ctx, state).map(|reason| ExitSignal { policy: policy.name(), reason })
+        })
+    }
+}
+
+/// Parameters of the default policy sets. Zero disables an optional policy.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExitPolicyConfig {
+    pub target_capture_fraction: f64,
+    pub stop_projected_fraction: f64,
+    pub stop_hard_fraction: f64,
+    pub stop_hard_floor_usd: f64,
+    pub stop_widen_factor: f64,
+    pub trailing_activation_bps: f64,
+    pub trailing_giveback_bps: f64,
+    pub time_stop_secs: u64,
+    pub time_stop_min_capture: f64,
+    pub max_holding_secs: u64,
+    pub funding_flip_8h: f64,
+    pub carry_max_holding_secs: u64,
+}
+
+impl Default for ExitPolicyConfig {
+    fn default() -> Self {
+        Self {
+            target_capture_fraction: 0.9,
+            stop_projected_fraction: 0.2,
+            stop_hard_fraction: 0.5,
+            stop_hard_floor_usd: 5.0,
+            stop_widen_factor: 1.3,
+            trailing_activation_bps: 0.0,
+            trailing_giveback_bps: 0.0,
+            time_stop_secs: 0,
+            time_stop_min_capture: 0.25,
+            max_holding_secs: 0,
+            funding_flip_8h: 0.0001,
+            carry_max_holding_secs: 0,
+        }
+    }
+}
+
+impl ExitPolicyConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let parse = |var: &str, default: f64| -> f64 {
+            std::env::var(var)
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .unwrap_or(default)
+        };
+
+        Self {
+            target_capture_fraction: parse("EXIT_TARGET_CAPTURE", defaults.target_capture_fraction),
+            stop_projected_fraction: parse("EXIT_STOP_PROJECTED_FRACTION", defaults.stop_projected_fraction),
+            stop_hard_fraction: parse("EXIT_STOP_HARD_FRACTION", defaults.stop_hard_fraction),
+            stop_hard_floor_usd: parse("EXIT_STOP_HARD_FLOOR_USD", defaults.stop_hard_floor_usd),
+            stop_widen_factor: parse("EXIT_STOP_WIDEN_FACTOR", defaults.stop_widen_factor),
+            trailing_activation_bps: parse("EXIT_TRAILING_ACTIVATION_BPS", defaults.trailing_activation_bps),
+            trailing_giveback_bps: parse("EXIT_TRAILING_GIVEBACK_BPS", defaults.trailing_giveback_bps),
+            time_stop_secs: parse("EXIT_TIME_STOP_SECS", defaults.time_stop_secs as f64) as u64,
+            time_stop_min_capture: parse("EXIT_TIME_STOP_MIN_CAPTURE", defaults.time_stop_min_capture),
+            max_holding_secs: parse("EXIT_MAX_HOLDING_SECS", defaults.max_holding_secs as f64) as u64,
+            funding_flip_8h: parse("EXIT_FUNDING_FLIP_8H", defaults.funding_flip_8h),
+            carry_max_holding_secs: parse("EXIT_CARRY_MAX_HOLDING_SECS", defaults.carry_max_holding_secs as f64) as u64,
+        }
+    }
+
+    /// Default policy set of a strategy.
+    ///
+    /// Spread trades: stop-loss, funding flip, max holding period, time stop,
+    /// trailing spread, convergence target. Carry trades only get the max
+    /// holding period; their spread is not expected to converge.
+    pub fn policies_for(&self, kind: OpportunityKind) -> ExitPolicySet {
+        let mut set = ExitPolicySet::new();
+        match kind {
+            OpportunityKind::SpreadArbitrage => {
+                set = set.with(StopLoss {
+                    projected_fraction: self.stop_projected_fraction,
+                    hard_fraction: self.stop_hard_fraction,
+                    hard_floor_usd: self.stop_hard_floor_usd,
+                    widen_factor: self.stop_widen_factor,
+                });
+                if self.funding_flip_8h > 0.0 {
+                    set = set.with(FundingFlip { min_adverse_8h: self.funding_flip_8h });
+                }
+                if self.max_holding_secs > 0 {
+                    set = set.with(MaxHoldingPeriod { max_secs: self.max_holding_secs });
+                }
+                if self.time_stop_secs > 0 {
+                    set = set.with(TimeStop { after_secs: self.time_stop_secs, min_capture_fraction: self.time_stop_min_capture });
+                }
+                if self.trailing_activation_bps > 0.0 && self.trailing_giveback_bps > 0.0 {
+                    set = set.with(TrailingSpread {
+                        activation_bps: self.trailing_activation_bps,
+                        giveback_bps: self.trailing_giveback_bps,
+                    });
+                }
+                set.with(SpreadConvergence { target_fraction: self.target_capture_fraction })
+            }
+            OpportunityKind::FundingCarry => {
+                if self.carry_max_holding_secs > 0 {
+                    set = set.with(MaxHoldingPeriod { max_secs: self.carry_max_holding_secs });
+                }
+                set
+            }
+        }
+    }
+}
+
+/// Policy sets of every strategy
+#[derive(Debug, Default)]
+pub struct StrategyExitPolicies {
+    pub spread_arbitrage: ExitPolicySet,
+    pub funding_carry: ExitPolicySet,
+}
+
+impl StrategyExitPolicies {
+    pub fn from_config(config: &ExitPolicyConfig) -> Self {
+        Self {
+            spread_arbitrage: config.policies_for(OpportunityKind::SpreadArbitrage),
+            funding_carry: config.policies_for(OpportunityKind::FundingCarry),
+        }
+    }
+
+    pub fn for_kind(&self, kind: OpportunityKind) -> &ExitPolicySet {
+        match kind {
+            OpportunityKind::SpreadArbitrage => &self.spread_arbitrage,
+            OpportunityKind::FundingCarry => &self.funding_carry,
+        }
+    }
+
+    /// Replace the policy set of one strategy.
+    pub fn set(&mut self, kind: OpportunityKind, policies: ExitPolicySet) {
+        match kind {
+            OpportunityKind::SpreadArbitrage => self.spread_arbitrage = policies,
+            OpportunityKind::FundingCarry => self.funding_carry = policies,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade(entry_spread_bps: f64, projected_profit_usd: f64) -> PaperTrade {
+        PaperTrade {
+            entry_time: 1_000,
+            entry_spread_bps,
+            position_size_usd: 10_000.0,
+            funding_delta_entry: 0.0002,
+            projected_profit_usd,
+            ..PaperTrade::test_trade("t1", "binance", "bybit")
+        }
+    }
+
+    fn ctx(trade: &PaperTrade, now: u64, spread_bps: f64) -> ExitContext<'_> {
+        ExitContext { trade, now, long_price: 100.0, short_price: 100.0, spread_bps, funding_delta_8h: None }
+    }
+
+    #[test]
+    fn test_default_spread_set_orders_stops_before_target() {
+        let policies = ExitPolicyConfig::default().policies_for(OpportunityKind::SpreadArbitrage);
+        assert_eq!(policies.names(), vec!["stop_loss", "funding_flip", "profit_target"]);
+        assert!(ExitPolicyConfig::default().policies_for(OpportunityKind::FundingCarry).is_empty());
+
+        let trade = trade(50.0, 40.0);
+        let mut state = ExitState::default();
+        assert_eq!(policies.evaluate(&ctx(&trade, 1_010, 40.0), &mut state), None);
+        let target = policies.evaluate(&ctx(&trade, 1_010, 4.0), &mut state).unwrap();
+        assert_eq!(target.policy, "profit_target");
+        // Converged past zero is not a valid exit spread; widening is a stop
+        assert_eq!(policies.evaluate(&ctx(&trade, 1_010, -1.0), &mut state), None);
+        assert_eq!(policies.evaluate(&ctx(&trade, 1_010, 66.0), &mut state).unwrap().policy, "stop_loss");
+
+        let mut flipped = ctx(&trade, 1_010, 40.0);
+        flipped.funding_delta_8h = Some(-0.0002);
+        assert_eq!(policies.evaluate(&flipped, &mut state).unwrap().policy, "funding_flip");
+    }
+
+    #[test]
+    fn test_stop_loss_price_levels_and_loss_limits() {
+        let stop = StopLoss { projected_fraction: 0.2, hard_fraction: 0.5, hard_floor_usd: 5.0, widen_factor: 1.3 };
+        let mut state = ExitState::default();
+
+        let mut priced = trade(50.0, 40.0);
+        priced.stop_loss_long_price = 99.0;
+        priced.stop_loss_short_price = 101.0;
+        let mut c = ctx(&priced, 1_010, 50.0);
+        assert_eq!(stop.evaluate(&c, &mut state), None);
+        c.short_price = 101.5;
+        assert!(stop.evaluate(&c, &mut state).unwrap().contains("price stop"));
+
+        // -10bps on $10k = -$10 <= -20% of $40 projected
+        let projected = trade(50.0, 40.0);
+        assert!(stop.evaluate(&ctx(&projected, 1_010, 60.0), &mut state).is_some());
+        // No projected profit: only the $5 floor applies
+        let unprojected = trade(50.0, 0.0);
+        assert_eq!(stop.evaluate(&ctx(&unprojected, 1_010, 54.0), &mut state), None);
+        assert!(stop.evaluate(&ctx(&unprojected, 1_010, 56.0), &mut state).is_some());
+        // Funding received while open counts toward the loss limit
+        let mut funded = trade(50.0, 0.0);
+        funded.funding_payments.push(crate::strategy::types::FundingPayment {
+            exchange: "bybit".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side: crate::strategy::types::OrderSide::Short,
+            rate: 0.0002,
+            amount_usd: 2.0,
+            funding_time: 1_005,
+        });
+        assert_eq!(stop.evaluate(&ctx(&funded, 1_010, 56.0), &mut state), None);
+    }
+
+    #[test]
+    fn test_trailing_spread_arms_then_fires_on_giveback() {
+        let trailing = TrailingSpread { activation_bps: 20.0, giveback_bps: 10.0 };
+        let trade = trade(50.0, 40.0);
+        let mut state = ExitState::default();
+
+        assert_eq!(trailing.evaluate(&ctx(&trade, 1_010, 40.0), &mut state), None);
+        assert_eq!(state.peak_reduction_bps, None);
+        assert_eq!(trailing.evaluate(&ctx(&trade, 1_010, 25.0), &mut state), None);
+        assert_eq!(state.peak_reduction_bps, Some(25.0));
+        assert_eq!(trailing.evaluate(&ctx(&trade, 1_010, 20.0), &mut state), None);
+        assert_eq!(trailing.evaluate(&ctx(&trade, 1_010, 28.0), &mut state), None);
+        assert!(trailing.evaluate(&ctx(&trade, 1_010, 30.0), &mut state).is_some());
+    }
+
+    #[test]
+    fn test_time_stop_and_max_holding_period() {
+        let time_stop = TimeStop { after_secs: 60, min_capture_fraction: 0.25 };
+        let max_holding = MaxHoldingPeriod { max_secs: 3_600 };
+        let trade = trade(40.0, 30.0);
+        let mut state = ExitState::default();
+
+        assert_eq!(time_stop.evaluate(&ctx(&trade, 1_030, 40.0), &mut state), None);
+        assert!(time_stop.evaluate(&ctx(&trade, 1_060, 40.0), &mut state).is_some());
+        // Converging trades are left alone
+        assert_eq!(time_stop.evaluate(&ctx(&trade, 1_060, 25.0), &mut state), None);
+
+        assert_eq!(max_holding.evaluate(&ctx(&trade, 4_599, 25.0), &mut state), None);
+        assert!(max_holding.evaluate(&ctx(&trade, 4_600, 25.0), &mut state).is_some());
+    }
+}
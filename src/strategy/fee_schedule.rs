@@ -0,0 +1,257 @@
+//! Per-account fee schedule
+//!
+//! `exchange_fees` holds the regular-tier maker and taker rate of every venue.
+//! The schedule layers the account's actual terms on top; the most specific
+//! rate wins:
+//!
+//! 1. rates reported by the venue's fee-rate endpoint for a symbol
+//!    (`ExecutionBackend::get_fee_rates`, refreshed by the runner);
+//! 2. per-symbol overrides (`venue:SYMBOL`);
+//! 3. the account tier selected for the venue (`venue@tier`);
+//! 4. venue-wide overrides (`venue`);
+//! 5. the regular-tier table.
+//!
+//! A negative maker rate is a rebate. Costs are estimated for the way legs
+//! execute: a spread entry rests on the harder venue and hedges at market on
+//! the other, so only the resting leg can earn maker, and only when the
+//! backend sends it post-only (the runner reports that with
+//! `set_post_only`). Exits are assumed to cross the spread (taker) unless
+//! configured otherwise.
+//!
+//! # Configuration
+//!
+//! ```text
+//! FEE_SCHEDULE=bybit@vip1=1.6/4.0,bybit:BTCUSDT=-0.5/4.0,okx=1.5/4.5   (maker/taker bps)
+//! FEE_TIERS=bybit=vip1
+//! FEE_ENTRY_LIQUIDITY=maker
+//! FEE_EXIT_LIQUIDITY=taker
+//! FEE_REFRESH_SECS=3600                                                 (0 disables)
+//! ```
+
+use crate::strategy::entry::identify_harder_leg;
+use crate::strategy::exchange_fees::{exchange_to_id, get_exchange_fee, get_exchange_maker_fee};
+use crate::strategy::types::{FeeRates, Liquidity};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::RwLock;
+
+/// Maker, taker and rebate rates of the trading account, shared by the
+/// detector shards and the strategy runner
+#[derive(Debug)]
+pub struct FeeSchedule {
+    /// Configured rates keyed `venue`, `venue@tier` or `venue:SYMBOL`
+    overrides: HashMap<String, FeeRates>,
+    /// Account tier per venue
+    tiers: HashMap<String, String>,
+    /// Rates reported by the venues, keyed `venue:SYMBOL`
+    reported: RwLock<HashMap<String, FeeRates>>,
+    /// How the resting entry leg is expected to fill when it goes out post-only
+    pub entry_liquidity: Liquidity,
+    /// Whether resting entry orders go out post-only
+    post_only: AtomicBool,
+    /// How exit legs are expected to fill
+    pub exit_liquidity: Liquidity,
+    /// How often the runner refreshes rates of traded symbols from the venues
+    pub refresh_interval_secs: u64,
+}
+
+impl Default for FeeSchedule {
+    fn default() -> Self {
+        Self {
+            overrides: HashMap::new(),
+            tiers: HashMap::new(),
+            reported: RwLock::new(HashMap::new()),
+            entry_liquidity: Liquidity::Maker,
+            post_only: AtomicBool::new(false),
+            exit_liquidity: Liquidity::Taker,
+            refresh_interval_secs: 3600,
+        }
+    }
+}
+
+impl FeeSchedule {
+    pub fn from_env() -> Self {
+        let mut schedule = Self::default();
+        if let Ok(spec) = std::env::var("FEE_SCHEDULE") {
+            schedule = schedule.with_entries(&spec);
+        }
+        if let Ok(spec) = std::env::var("FEE_TIERS") {
+            for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+                match entry.split_once('=') {
+                    Some((venue, tier)) => schedule = schedule.with_tier(venue.trim(), tier.trim()),
+                    None => eprintln!("[FEES] Ignoring malformed tier '{}'", entry),
+                }
+            }
+        }
+        for (venue, tier) in &schedule.tiers {
+            if !schedule.overrides.contains_key(&format!("{}@{}", venue, tier)) {
+                eprintln!("[FEES] ⚠️  No rates configured for {} tier '{}', using the regular tier", venue, tier);
+            }
+        }
+        let liquidity = |var: &str, default: Liquidity| match std::env::var(var).map(|v| v.to_lowercase()).as_deref() {
+            Ok("maker") => Liquidity::Maker,
+            Ok("taker") => Liquidity::Taker,
+            _ => default,
+        };
+        schedule.entry_liquidity = liquidity("FEE_ENTRY_LIQUIDITY", schedule.entry_liquidity);
+        schedule.exit_liquidity = liquidity("FEE_EXIT_LIQUIDITY", schedule.exit_liquidity);
+        if let Some(secs) = std::env::var("FEE_REFRESH_SECS").ok().and_then(|v| v.parse::<u64>().ok()) {
+            schedule.refresh_interval_secs = secs;
+        }
+        schedule
+    }
+
+    /// Add `key=maker/taker` entries (comma separated); `key` is `venue`,
+    /// `venue@tier` or `venue:SYMBOL`.
+    pub fn with_entries(mut self, spec: &str) -> Self {
+        for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let parsed = entry.split_once('=').and_then(|(key, rates)| {
+                let (maker, taker) = rates.split_once('/')?;
+                Some((key.trim(), maker.trim().parse::<f64>().ok()?, taker.trim().parse::<f64>().ok()?))
+            });
+            match parsed {
+                Some((key, maker_bps, taker_bps)) => self = self.with_rates(key, FeeRates { maker_bps, taker_bps }),
+                None => eprintln!("[FEES] Ignoring malformed fee entry '{}'", entry),
+            }
+        }
+        self
+    }
+
+    /// Set the rates of a venue (`key` = `venue`), one of its account tiers
+    /// (`venue@tier`) or one symbol on it (`venue:SYMBOL`).
+    pub fn with_rates(mut self, key: &str, rates: FeeRates) -> Self {
+        self.overrides.insert(normalize_key(key), rates);
+        self
+    }
+
+    /// Select the account tier of a venue
+    pub fn with_tier(mut self, venue: &str, tier: &str) -> Self {
+        self.tiers.insert(venue.to_lowercase(), tier.to_lowercase());
+        self
+    }
+
+    /// Record the rates a venue reports for `symbol`; they take precedence
+    /// over anything configured.
+    pub fn record_reported(&self, exchange: &str, symbol: &str, rates: FeeRates) {
+        self.reported.write().unwrap().insert(format!("{}:{}", exchange.to_lowercase(), symbol), rates);
+    }
+
+    /// Rates for `symbol` on `exchange`; the most specific setting wins.
+    pub fn rates(&self, exchange: &str, symbol: &str) -> FeeRates {
+        let venue = exchange.to_lowercase();
+        let symbol_key = format!("{}:{}", venue, symbol);
+        if let Some(rates) = self.reported.read().unwrap().get(&symbol_key) {
+            return *rates;
+        }
+        self.overrides
+            .get(&symbol_key)
+            .or_else(|| self.tiers.get(&venue).and_then(|tier| self.overrides.get(&format!("{}@{}", venue, tier))))
+            .or_else(|| self.overrides.get(&venue))
+            .copied()
+            .unwrap_or_else(|| {
+                let id = exchange_to_id(&venue);
+                FeeRates { maker_bps: get_exchange_maker_fee(id), taker_bps: get_exchange_fee(id) }
+            })
+    }
+
+    /// Fee in bps of a `liquidity` fill of `symbol` on `exchange`
+    pub fn fee_bps(&self, exchange: &str, symbol: &str, liquidity: Liquidity) -> f64 {
+        self.rates(exchange, symbol).bps(liquidity)
+    }
+
+    /// Record whether the execution backend sends resting orders post-only.
+    pub fn set_post_only(&self, post_only: bool) {
+        self.post_only.store(post_only, Ordering::Relaxed);
+    }
+
+    /// How the resting entry leg fills: `entry_liquidity` when post-only, otherwise
+    /// it may cross and is charged as taker.
+    pub fn resting_liquidity(&self) -> Liquidity {
+        if self.post_only.load(Ordering::Relaxed) {
+            self.entry_liquidity
+        } else {
+            Liquidity::Taker
+        }
+    }
+
+    /// Entry plus exit fee of one leg in bps, entering at `entry` and exiting at
+    /// the expected exit liquidity
+    pub fn round_trip_bps(&self, exchange: &str, symbol: &str, entry: Liquidity) -> f64 {
+        let rates = self.rates(exchange, symbol);
+        rates.bps(entry) + rates.bps(self.exit_liquidity)
+    }
+
+    /// Round-trip fees of the long and short leg of a spread entry in bps: the
+    /// harder venue rests, the other is the taker hedge.
+    pub fn spread_round_trip_bps(&self, long_exchange: &str, short_exchange: &str, symbol: &str) -> (f64, f64) {
+        let (long_entry, short_entry) = if identify_harder_leg(long_exchange, short_exchange) == "long" {
+            (self.resting_liquidity(), Liquidity::Taker)
+        } else {
+            (Liquidity::Taker, self.resting_liquidity())
+        };
+        (self.round_trip_bps(long_exchange, symbol, long_entry), self.round_trip_bps(short_exchange, symbol, short_entry))
+    }
+}
+
+/// Venues are matched case-insensitively, symbols and tiers as given
+fn normalize_key(key: &str) -> String {
+    match key.find([':', '@']) {
+        Some(split) => {
+            let (venue, rest) = key.split_at(split);
+            if rest.starts_with('@') {
+                format!("{}{}", venue.to_lowercase(), rest.to_lowercase())
+            } else {
+                format!("{}{}", venue.to_lowercase(), rest)
+            }
+        }
+        None => key.to_lowercase(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_most_specific_rate_wins() {
+        let schedule = FeeSchedule::default()
+            .with_entries("Bybit=1.8/5.0, bybit@VIP1=1.6/4.0, bybit:BTCUSDT=-0.5/4.0, okx=oops")
+            .with_tier("bybit", "vip1");
+
+        assert_eq!(schedule.rates("bybit", "BTCUSDT"), FeeRates { maker_bps: -0.5, taker_bps: 4.0 });
+        assert_eq!(schedule.rates("bybit", "ETHUSDT"), FeeRates { maker_bps: 1.6, taker_bps: 4.0 });
+        // Malformed entries are skipped; the regular tier applies
+        assert_eq!(schedule.rates("okx", "ETHUSDT"), FeeRates { maker_bps: 2.0, taker_bps: 5.0 });
+
+        schedule.record_reported("bybit", "ETHUSDT", FeeRates { maker_bps: 1.0, taker_bps: 3.0 });
+        assert_eq!(schedule.fee_bps("bybit", "ETHUSDT", Liquidity::Taker), 3.0);
+    }
+
+    #[test]
+    fn test_venue_override_applies_without_tier() {
+        let schedule = FeeSchedule::default().with_entries("bybit=1.8/5.0,bybit@vip1=1.6/4.0");
+        assert_eq!(schedule.rates("bybit", "ETHUSDT"), FeeRates { maker_bps: 1.8, taker_bps: 5.0 });
+        assert_eq!(schedule.rates("binance", "ETHUSDT"), FeeRates { maker_bps: 2.0, taker_bps: 4.0 });
+    }
+
+    #[test]
+    fn test_round_trip_uses_expected_liquidity_and_rebates() {
+        let mut schedule = FeeSchedule::default().with_entries("bybit:BTCUSDT=-0.5/4.0");
+        // Maker entry earns the rebate, the exit crosses
+        assert_eq!(schedule.round_trip_bps("bybit", "BTCUSDT", Liquidity::Maker), 3.5);
+        schedule.exit_liquidity = Liquidity::Maker;
+        assert_eq!(schedule.round_trip_bps("bybit", "BTCUSDT", Liquidity::Maker), -1.0);
+    }
+
+    #[test]
+    fn test_spread_entry_charges_the_hedge_leg_as_taker() {
+        let schedule = FeeSchedule::default().with_entries("hyperliquid=1.0/3.5,bybit=2.0/5.5");
+        // Without post-only the resting leg may cross too
+        assert_eq!(schedule.spread_round_trip_bps("hyperliquid", "bybit", "BTCUSDT"), (7.0, 11.0));
+
+        // Hyperliquid is the harder venue: it rests as maker, bybit hedges
+        schedule.set_post_only(true);
+        assert_eq!(schedule.spread_round_trip_bps("hyperliquid", "bybit", "BTCUSDT"), (4.5, 11.0));
+        assert_eq!(schedule.spread_round_trip_bps("bybit", "hyperliquid", "BTCUSDT"), (11.0, 4.5));
+    }
+}
@@ -0,0 +1,386 @@
+//! Fill-based trade accounting
+//!
+//! Venues report every execution with its price, size, fee, fee asset and
+//! maker/taker flag (`ExecutionBackend::get_fills`). A trade's PnL is built
+//! from those fills leg by leg: volume-weighted entry and exit prices,
+//! realised PnL on the closed quantity, the open remainder marked to the
+//! venue mark price, minus the fees actually charged, plus settled funding.
+//!
+//! `FillReconciliation` sets that against the runner's estimate (opportunity
+//! prices, spread capture and the account's `FeeSchedule`), so slippage and
+//! fee drift show up per trade.
+
+use crate::strategy::exit_executor::TradeLeg;
+use crate::strategy::fee_schedule::FeeSchedule;
+use crate::strategy::types::{Fill, Liquidity, OrderSide, PaperTrade, SimulatedOrder, TradeStatus};
+use serde::{Deserialize, Serialize};
+
+/// How often fills of open trades are pulled from the venues
+pub const RECONCILE_INTERVAL_SECS: u64 = 30;
+
+/// Closed-trade reconciliations kept in the published report
+pub const RECONCILIATION_HISTORY: usize = 200;
+
+/// Open quantity below this fraction of the entry counts as flat (venue rounding)
+const FLAT_TOLERANCE: f64 = 0.001;
+
+/// Fee assets valued one-to-one in USD
+const USD_ASSETS: [&str; 5] = ["USDT", "USDC", "USD", "BUSD", "FDUSD"];
+
+/// Fee-schedule estimate for `notional` of `symbol` traded on `exchange`, in USD.
+pub fn estimated_fee_usd(exchange: &str, symbol: &str, notional: f64, liquidity: Liquidity, fees: &FeeSchedule) -> f64 {
+    notional * fees.fee_bps(exchange, symbol, liquidity) / 10_000.0
+}
+
+/// Fee of a fill in USD. Fees in the base asset are valued at the fill price;
+/// other assets (e.g. BNB discounts) fall back to the fee-schedule estimate.
+pub fn fee_usd(fill: &Fill, fees: &FeeSchedule) -> f64 {
+    if USD_ASSETS.contains(&fill.fee_asset.as_str()) {
+        return fill.fee;
+    }
+    let base = USD_ASSETS.iter().find_map(|quote| fill.symbol.strip_suffix(quote));
+    if base == Some(fill.fee_asset.as_str()) {
+        fill.fee * fill.price
+    } else {
+        estimated_fee_usd(&fill.exchange, &fill.symbol, fill.notional(), fill.liquidity, fees)
+    }
+}
+
+/// Fill for a locally simulated execution of `order`, charged at the fee schedule.
+pub fn simulated_fill(order: &SimulatedOrder, time: u64, fees: &FeeSchedule) -> Fill {
+    let price = order.fill_price.unwrap_or(order.price);
+    let liquidity = if order.is_post_only() { Liquidity::Maker } else { Liquidity::Taker };
+    Fill {
+        id: format!("sim_{}", order.id),
+        order_id: order.id.clone(),
+        exchange: order.exchange.clone(),
+        symbol: order.symbol.clone(),
+        side: order.side,
+        price,
+        quantity: order.size,
+        fee: estimated_fee_usd(&order.exchange, &order.symbol, price * order.size, liquidity, fees),
+        fee_asset: "USDT".to_string(),
+        liquidity,
+        time,
+    }
+}
+
+/// Combine executions of one order into a single fill at the volume-weighted
+/// price. Taker if any part took liquidity; fees in mixed assets are summed
+/// in USD.
+pub fn aggregate(fills: &[Fill], fees: &FeeSchedule) -> Option<Fill> {
+    let first = fills.first()?;
+    let quantity: f64 = fills.iter().map(|f| f.quantity).sum();
+    if quantity <= 0.0 {
+        return None;
+    }
+    let uniform_asset = fills.iter().all(|f| f.fee_asset == first.fee_asset);
+    Some(Fill {
+        price: fills.iter().map(Fill::notional).sum::<f64>() / quantity,
+        quantity,
+        fee: if uniform_asset { fills.iter().map(|f| f.fee).sum() } else { fills.iter().map(|f| fee_usd(f, fees)).sum() },
+        fee_asset: if uniform_asset { first.fee_asset.clone() } else { "USDT".to_string() },
+        liquidity: if fills.iter().any(|f| f.liquidity == Liquidity::Taker) { Liquidity::Taker } else { Liquidity::Maker },
+        time: fills.iter().map(|f| f.time).max().unwrap_or(first.time),
+        ..first.clone()
+    })
+}
+
+/// Add venue fills to a trade, keeping only executions of its legs at or after
+/// entry and skipping ones already recorded. Returns how many were added.
+pub fn merge_fills(trade: &mut PaperTrade, fills: Vec<Fill>) -> usize {
+    let mut added = 0;
+    for fill in fills {
+        let on_leg = fill.exchange == trade.long_exchange || fill.exchange == trade.short_exchange;
+        if !on_leg
+            || fill.symbol != trade.symbol
+            || fill.time < trade.entry_time
+            || trade.fills.iter().any(|f| f.exchange == fill.exchange && f.id == fill.id)
+        {
+            continue;
+        }
+        trade.fills.push(fill);
+        added += 1;
+    }
+    if added > 0 {
+        trade.fills.sort_by_key(|f| f.time);
+    }
+    added
+}
+
+/// Executions of one leg, split into the entry and exit side
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct LegAccount {
+    pub entry_quantity: f64,
+    pub entry_notional: f64,
+    pub exit_quantity: f64,
+    pub exit_notional: f64,
+    pub fees_usd: f64,
+    pub maker_quantity: f64,
+}
+
+impl LegAccount {
+    /// Tally the fills of `leg` recorded on a trade
+    pub fn of(trade: &PaperTrade, leg: TradeLeg, fees: &FeeSchedule) -> Self {
+        let exchange = match leg {
+            TradeLeg::Long => &trade.long_exchange,
+            TradeLeg::Short => &trade.short_exchange,
+        };
+        let mut account = Self::default();
+        for fill in trade.fills.iter().filter(|f| &f.exchange == exchange) {
+            if fill.side == leg.close_side() {
+                account.exit_quantity += fill.quantity;
+                account.exit_notional += fill.notional();
+            } else {
+                account.entry_quantity += fill.quantity;
+                account.entry_notional += fill.notional();
+            }
+            account.fees_usd += fee_usd(fill, fees);
+            if fill.liquidity == Liquidity::Maker {
+                account.maker_quantity += fill.quantity;
+            }
+        }
+        account
+    }
+
+    /// Volume-weighted entry price
+    pub fn entry_price(&self) -> Option<f64> {
+        (self.entry_quantity > 0.0).then(|| self.entry_notional / self.entry_quantity)
+    }
+
+    /// Volume-weighted exit price
+    pub fn exit_price(&self) -> Option<f64> {
+        (self.exit_quantity > 0.0).then(|| self.exit_notional / self.exit_quantity)
+    }
+
+    /// Quantity still held
+    pub fn open_quantity(&self) -> f64 {
+        (self.entry_quantity - self.exit_quantity).max(0.0)
+    }
+
+    pub fn is_flat(&self) -> bool {
+        self.entry_quantity > 0.0 && self.open_quantity() <= self.entry_quantity * FLAT_TOLERANCE
+    }
+
+    /// PnL locked in on the closed quantity, before fees
+    pub fn realised_pnl(&self, side: OrderSide) -> f64 {
+        match (self.entry_price(), self.exit_price()) {
+            (Some(entry), Some(exit)) => direction(side) * (exit - entry) * self.exit_quantity.min(self.entry_quantity),
+            _ => 0.0,
+        }
+    }
+
+    /// PnL of the open quantity at `mark`, before fees
+    pub fn unrealised_pnl(&self, side: OrderSide, mark: f64) -> f64 {
+        self.entry_price()
+            .map(|entry| direction(side) * (mark - entry) * self.open_quantity())
+            .unwrap_or(0.0)
+    }
+}
+
+fn direction(side: OrderSide) -> f64 {
+    match side {
+        OrderSide::Long => 1.0,
+        OrderSide::Short => -1.0,
+    }
+}
+
+/// Trade PnL from recorded fills, open quantity marked to market
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FillPnl {
+    pub long: LegAccount,
+    pub short: LegAccount,
+    pub realised_usd: f64,
+    pub unrealised_usd: f64,
+    pub fees_usd: f64,
+    pub funding_usd: f64,
+    pub net_usd: f64,
+    /// Both legs are fully closed, so `unrealised_usd` is zero
+    pub flat: bool,
+}
+
+impl FillPnl {
+    /// None until both legs have entry fills
+    pub fn of(trade: &PaperTrade, long_mark: f64, short_mark: f64, fees: &FeeSchedule) -> Option<Self> {
+        let long = LegAccount::of(trade, TradeLeg::Long, fees);
+        let short = LegAccount::of(trade, TradeLeg::Short, fees);
+        if long.entry_quantity <= 0.0 || short.entry_quantity <= 0.0 {
+            return None;
+        }
+        let realised_usd = long.realised_pnl(OrderSide::Long) + short.realised_pnl(OrderSide::Short);
+        let unrealised_usd = long.unrealised_pnl(OrderSide::Long, long_mark) + short.unrealised_pnl(OrderSide::Short, short_mark);
+        let fees_usd = long.fees_usd + short.fees_usd;
+        let funding_usd = trade.funding_pnl_usd();
+        Some(Self {
+            long,
+            short,
+            realised_usd,
+            unrealised_usd,
+            fees_usd,
+            funding_usd,
+            net_usd: realised_usd + unrealised_usd - fees_usd + funding_usd,
+            flat: long.is_flat() && short.is_flat(),
+        })
+    }
+}
+
+/// Estimated against fill-based figures for one trade
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FillReconciliation {
+    pub trade_id: String,
+    pub symbol: String,
+    pub status: TradeStatus,
+    pub estimated_long_entry: f64,
+    pub actual_long_entry: Option<f64>,
+    pub estimated_short_entry: f64,
+    pub actual_short_entry: Option<f64>,
+    pub estimated_entry_spread_bps: f64,
+    pub actual_entry_spread_bps: Option<f64>,
+    pub estimated_fees_usd: f64,
+    pub actual_fees_usd: f64,
+    /// Share of filled quantity that rested as maker
+    pub maker_ratio: f64,
+    pub estimated_pnl_usd: f64,
+    /// Fill-based PnL; open quantity marked to market
+    pub actual_pnl_usd: Option<f64>,
+    pub flat: bool,
+    pub fill_count: usize,
+}
+
+impl FillReconciliation {
+    /// `estimated_fee_bps` and `estimated_pnl_usd` are what the runner books
+    /// without fills; marks value whatever is still open.
+    pub fn new(trade: &PaperTrade, estimated_fee_bps: f64, estimated_pnl_usd: f64, long_mark: f64, short_mark: f64, fees: &FeeSchedule) -> Self {
+        let pnl = FillPnl::of(trade, long_mark, short_mark, fees);
+        let long = LegAccount::of(trade, TradeLeg::Long, fees);
+        let short = LegAccount::of(trade, TradeLeg::Short, fees);
+        let filled: f64 = trade.fills.iter().map(|f| f.quantity).sum();
+        let (actual_long_entry, actual_short_entry) = (long.entry_price(), short.entry_price());
+        Self {
+            trade_id: trade.id.clone(),
+            symbol: trade.symbol.clone(),
+            status: trade.status,
+            estimated_long_entry: trade.entry_long_price,
+            actual_long_entry,
+            estimated_short_entry: trade.entry_short_price,
+            actual_short_entry,
+            estimated_entry_spread_bps: trade.entry_spread_bps,
+            actual_entry_spread_bps: actual_long_entry
+                .zip(actual_short_entry)
+                .map(|(long, short)| (short - long) / long * 10_000.0),
+            estimated_fees_usd: estimated_fee_bps / 10_000.0 * trade.position_size_usd,
+            actual_fees_usd: long.fees_usd + short.fees_usd,
+            maker_ratio: if filled > 0.0 { (long.maker_quantity + short.maker_quantity) / filled } else { 0.0 },
+            estimated_pnl_usd,
+            actual_pnl_usd: pnl.map(|p| p.net_usd),
+            flat: pnl.is_some_and(|p| p.flat),
+            fill_count: trade.fills.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade() -> PaperTrade {
+        PaperTrade {
+            entry_time: 1_000,
+            projected_profit_usd: 4.0,
+            ..PaperTrade::test_trade("t1", "binance", "bybit")
+        }
+    }
+
+    /// Taker fill of 1 BTCUSDT at 100 with no fee; tests override fields with struct update
+    fn fill(id: &str, exchange: &str, side: OrderSide) -> Fill {
+        Fill {
+            id: id.to_string(),
+            order_id: format!("o_{}", id),
+            exchange: exchange.to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side,
+            price: 100.0,
+            quantity: 1.0,
+            fee: 0.0,
+            fee_asset: "USDT".to_string(),
+            liquidity: Liquidity::Taker,
+            time: 0,
+        }
+    }
+
+    #[test]
+    fn test_fee_usd_converts_base_asset_and_estimates_others() {
+        let fees = FeeSchedule::default().with_entries("binance=1.8/3.0");
+        assert_eq!(fee_usd(&Fill { fee: 0.05, ..fill("1", "bybit", OrderSide::Long) }, &fees), 0.05);
+        assert!((fee_usd(&Fill { fee: 0.001, fee_asset: "BTC".to_string(), ..fill("2", "bybit_spot", OrderSide::Long) }, &fees) - 0.1).abs() < 1e-12);
+        // BNB-paid commission falls back to the account's 3bps binance taker rate
+        assert!((fee_usd(&Fill { fee: 0.0002, fee_asset: "BNB".to_string(), ..fill("3", "binance", OrderSide::Long) }, &fees) - 0.03).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_fill_pnl_uses_executed_prices_fees_and_marks() {
+        let fees = FeeSchedule::default();
+        let mut trade = trade();
+        let added = merge_fills(&mut trade, vec![
+            Fill { price: 100.1, quantity: 5.0, fee: 0.2, time: 1_000, ..fill("l1", "binance", OrderSide::Long) },
+            Fill { price: 100.3, quantity: 5.0, fee: 0.2, time: 1_001, ..fill("l2", "binance", OrderSide::Long) },
+            Fill { price: 100.4, quantity: 10.0, fee: -0.1, liquidity: Liquidity::Maker, time: 1_000, ..fill("s1", "bybit", OrderSide::Short) },
+            // Before entry, and another symbol's venue: not part of the trade
+            Fill { price: 99.0, quantity: 10.0, fee: 0.2, time: 999, ..fill("old", "binance", OrderSide::Short) },
+            Fill { quantity: 10.0, fee: 0.2, time: 1_002, ..fill("x", "okx", OrderSide::Long) },
+        ]);
+        assert_eq!(added, 3);
+        assert_eq!(merge_fills(&mut trade, vec![Fill { price: 100.1, quantity: 5.0, fee: 0.2, time: 1_000, ..fill("l1", "binance", OrderSide::Long) }]), 0);
+
+        // Open: both legs marked at 101
+        let open = FillPnl::of(&trade, 101.0, 101.0, &fees).unwrap();
+        assert!(!open.flat);
+        assert!((open.long.entry_price().unwrap() - 100.2).abs() < 1e-9);
+        assert!((open.unrealised_usd - (8.0 - 6.0)).abs() < 1e-9);
+        assert!((open.net_usd - (2.0 - 0.3)).abs() < 1e-9);
+
+        // Closed: realised from exit fills, the marks no longer matter
+        merge_fills(&mut trade, vec![
+            Fill { price: 100.5, quantity: 10.0, fee: 0.4, time: 1_100, ..fill("l3", "binance", OrderSide::Short) },
+            Fill { price: 100.2, quantity: 10.0, fee: 0.4, time: 1_100, ..fill("s2", "bybit", OrderSide::Long) },
+        ]);
+        let closed = FillPnl::of(&trade, 0.0, 0.0, &fees).unwrap();
+        assert!(closed.flat);
+        assert!((closed.realised_usd - (3.0 + 2.0)).abs() < 1e-9);
+        assert!((closed.net_usd - (5.0 - 1.1)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_reconciliation_reports_estimate_against_fills() {
+        let fees = FeeSchedule::default();
+        let mut trade = trade();
+        let unfilled = FillReconciliation::new(&trade, 9.5, 3.0, 100.0, 100.0, &fees);
+        assert_eq!((unfilled.actual_pnl_usd, unfilled.fill_count), (None, 0));
+
+        merge_fills(&mut trade, vec![
+            Fill { price: 100.2, quantity: 10.0, fee: 0.4, time: 1_000, ..fill("l1", "binance", OrderSide::Long) },
+            Fill { price: 100.4, quantity: 10.0, fee: -0.1, liquidity: Liquidity::Maker, time: 1_000, ..fill("s1", "bybit", OrderSide::Short) },
+        ]);
+        let rec = FillReconciliation::new(&trade, 9.5, 3.0, 100.2, 100.4, &fees);
+        assert!((rec.actual_long_entry.unwrap() - 100.2).abs() < 1e-9);
+        assert!((rec.actual_entry_spread_bps.unwrap() - 0.2 / 100.2 * 10_000.0).abs() < 1e-9);
+        assert!((rec.estimated_fees_usd - 0.95).abs() < 1e-12);
+        assert!((rec.actual_fees_usd - 0.3).abs() < 1e-12);
+        assert!((rec.maker_ratio - 0.5).abs() < 1e-12);
+        assert!((rec.actual_pnl_usd.unwrap() + 0.3).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_aggregate_is_volume_weighted_and_taker_if_any_part_took() {
+        let parts = [
+            Fill { fee: -0.01, liquidity: Liquidity::Maker, time: 5, ..fill("1", "bybit", OrderSide::Long) },
+            Fill { price: 103.0, quantity: 2.0, fee: 0.1, time: 7, ..fill("2", "bybit", OrderSide::Long) },
+        ];
+        let fees = FeeSchedule::default();
+        let total = aggregate(&parts, &fees).unwrap();
+        assert!((total.price - 102.0).abs() < 1e-12);
+        assert_eq!((total.quantity, total.liquidity, total.time), (3.0, Liquidity::Taker, 7));
+        assert!((total.fee - 0.09).abs() < 1e-12);
+        assert!(aggregate(&[], &fees).is_none());
+    }
+}
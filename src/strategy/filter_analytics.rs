@@ -0,0 +1,516 @@
+//! Filter Rejection Analytics
+//!
+//! Structured statistics about why the `OpportunityDetector` rejected candidate
+//! opportunities. Rejections are bucketed per symbol and per venue pair so that
+//! thresholds can be tuned from evidence and venues whose fees kill every trade
+//! can be spotted.
+//!
+//! # Architecture
+//!
+//! ```text
+//! OpportunityDetector::check_opportunity
+//!         ↓ record_*()
+//!   FilterAnalytics (owned by detector, single-threaded)
+//!         ↓ snapshot() every 10s
+//!   Redis "detector:filter_analytics" → Dashboard / Monitor
+//! ```
+//!
+//! # Histograms
+//!
+//! For every rejection we record the *shortfall*: how far the candidate was
+//! from passing the filter (in bps for spread/profit, in rate units ×10⁴ for
+//! funding, in points for confidence). Shortfalls are bucketed into fixed
+//! ranges so the histogram has a constant size and no allocation per update.
+//!
+//! For profit rejections we also record which cost dominated (long fee,
+//! short fee, slippage or funding cost).
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Redis key the detector publishes its analytics snapshot under
+pub const FILTER_ANALYTICS_KEY: &str = "detector:filter_analytics";
+
+/// Upper bounds of the shortfall buckets (last bucket is open-ended)
+pub const SHORTFALL_BUCKET_BOUNDS: [f64; 6] = [1.0, 2.0, 5.0, 10.0, 25.0, 50.0];
+
+/// Number of shortfall buckets (bounds + overflow bucket)
+pub const SHORTFALL_BUCKETS: usize = SHORTFALL_BUCKET_BOUNDS.len() + 1;
+
+/// Detector filter stage at which a candidate was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum FilterStage {
+    Spread,
+    Funding,
+    Confidence,
+    Profit,
+}
+
+impl FilterStage {
+    pub const ALL: [FilterStage; 4] = [
+        FilterStage::Spread,
+        FilterStage::Funding,
+        FilterStage::Confidence,
+        FilterStage::Profit,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilterStage::Spread => "spread",
+            FilterStage::Funding => "funding",
+            FilterStage::Confidence => "confidence",
+            FilterStage::Profit => "profit",
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            FilterStage::Spread => 0,
+            FilterStage::Funding => 1,
+            FilterStage::Confidence => 2,
+            FilterStage::Profit => 3,
+        }
+    }
+}
+
+/// Cost component that consumed the largest share of the spread
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum DominantCost {
+    LongFee,
+    ShortFee,
+    Slippage,
+    FundingCost,
+}
+
+impl DominantCost {
+    pub const ALL: [DominantCost; 4] = [
+        DominantCost::LongFee,
+        DominantCost::ShortFee,
+        DominantCost::Slippage,
+        DominantCost::FundingCost,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DominantCost::LongFee => "long_fee",
+            DominantCost::ShortFee => "short_fee",
+            DominantCost::Slippage => "slippage",
+            DominantCost::FundingCost => "funding_cost",
+        }
+    }
+
+    fn index(&self) -> usize {
+        match self {
+            DominantCost::LongFee => 0,
+            DominantCost::ShortFee => 1,
+            DominantCost::Slippage => 2,
+            DominantCost::FundingCost => 3,
+        }
+    }
+
+    /// Pick the largest cost component (ties resolve in declaration order)
+    pub fn from_costs(long_fee_bps: f64, short_fee_bps: f64, slippage_bps: f64, funding_cost_bps: f64) -> Self {
+        let costs = [long_fee_bps, short_fee_bps, slippage_bps, funding_cost_bps];
+        let mut best = 0;
+        for (i, cost) in costs.iter().enumerate() {
+            if *cost > costs[best] {
+                best = i;
+            }
+        }
+        Self::ALL[best]
+    }
+}
+
+/// Cost breakdown of a profit-filter rejection
+#[derive(Debug, Clone, Copy)]
+pub struct CostBreakdown {
+    pub long_fee_bps: f64,
+    pub short_fee_bps: f64,
+    pub slippage_bps: f64,
+    pub funding_cost_bps: f64,
+}
+
+impl CostBreakdown {
+    pub fn total_bps(&self) -> f64 {
+        self.long_fee_bps + self.short_fee_bps + self.slippage_bps + self.funding_cost_bps
+    }
+
+    pub fn dominant(&self) -> DominantCost {
+        DominantCost::from_costs(
+            self.long_fee_bps,
+            self.short_fee_bps,
+            self.slippage_bps,
+            self.funding_cost_bps,
+        )
+    }
+}
+
+/// Rejection histogram for one key (symbol or venue pair)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RejectionHistogram {
+    /// Total rejections per stage (indexed by `FilterStage`)
+    pub rejections: [u64; 4],
+
+    /// Shortfall buckets per stage (see `SHORTFALL_BUCKET_BOUNDS`)
+    pub shortfall_buckets: [[u64; SHORTFALL_BUCKETS]; 4],
+
+    /// Sum of shortfalls per stage (for averages)
+    pub shortfall_sum: [f64; 4],
+
+    /// Profit rejections by dominant cost (indexed by `DominantCost`)
+    pub dominant_costs: [u64; 4],
+
+    /// Sum of total costs over profit rejections (bps)
+    pub profit_cost_sum_bps: f64,
+
+    /// Candidates that passed every filter
+    pub passed: u64,
+}
+
+impl RejectionHistogram {
+    fn record(&mut self, stage: FilterStage, shortfall: f64) {
+        let i = stage.index();
+        let shortfall = shortfall.max(0.0);
+        self.rejections[i] += 1;
+        self.shortfall_buckets[i][bucket_for(shortfall)] += 1;
+        self.shortfall_sum[i] += shortfall;
+    }
+
+    fn record_profit_costs(&mut self, costs: &CostBreakdown) {
+        self.dominant_costs[costs.dominant().index()] += 1;
+        self.profit_cost_sum_bps += costs.total_bps();
+    }
+
+    /// Add another histogram's counts into this one
+    pub fn merge(&mut self, other: &RejectionHistogram) {
+        for i in 0..4 {
+            self.rejections[i] += other.rejections[i];
+            self.shortfall_sum[i] += other.shortfall_sum[i];
+            self.dominant_costs[i] += other.dominant_costs[i];
+            for b in 0..SHORTFALL_BUCKETS {
+                self.shortfall_buckets[i][b] += other.shortfall_buckets[i][b];
+            }
+        }
+        self.profit_cost_sum_bps += other.profit_cost_sum_bps;
+        self.passed += other.passed;
+    }
+
+    /// Total rejections across all stages
+    pub fn total_rejections(&self) -> u64 {
+        self.rejections.iter().sum()
+    }
+
+    /// Rejections for a single stage
+    pub fn rejections_for(&self, stage: FilterStage) -> u64 {
+        self.rejections[stage.index()]
+    }
+
+    /// Average shortfall for a stage (0.0 if never rejected there)
+    pub fn avg_shortfall(&self, stage: FilterStage) -> f64 {
+        let count = self.rejections[stage.index()];
+        if count == 0 {
+            0.0
+        } else {
+            self.shortfall_sum[stage.index()] / count as f64
+        }
+    }
+
+    /// Cost that most often dominated profit rejections
+    pub fn top_dominant_cost(&self) -> Option<DominantCost> {
+        let (idx, count) = self
+            .dominant_costs
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, c)| **c)?;
+        if *count == 0 {
+            None
+        } else {
+            Some(DominantCost::ALL[idx])
+        }
+    }
+
+    /// Share of candidates rejected (0.0 - 1.0)
+    pub fn rejection_rate(&self) -> f64 {
+        let rejected = self.total_rejections();
+        let total = rejected + self.passed;
+        if total == 0 {
+            0.0
+        } else {
+            rejected as f64 / total as f64
+        }
+    }
+}
+
+/// Map a shortfall onto its bucket index
+fn bucket_for(shortfall: f64) -> usize {
+    SHORTFALL_BUCKET_BOUNDS
+        .iter()
+        .position(|bound| shortfall <= *bound)
+        .unwrap_or(SHORTFALL_BUCKET_BOUNDS.len())
+}
+
+/// Build the venue-pair key used in snapshots ("long->short")
+pub fn venue_pair_key(long_exchange: &str, short_exchange: &str) -> String {
+    format!("{}->{}", long_exchange, short_exchange)
+}
+
+/// Point-in-time copy of the analytics, suitable for publishing
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FilterAnalyticsSnapshot {
+    /// Unix timestamp (seconds) the snapshot was taken
+    pub timestamp: u64,
+
+    /// Aggregate histogram across all symbols and venues
+    pub totals: RejectionHistogram,
+
+    /// Histograms keyed by normalized symbol
+    pub by_symbol: HashMap<String, RejectionHistogram>,
+
+    /// Histograms keyed by venue pair ("long->short")
+    pub by_venue_pair: HashMap<String, RejectionHistogram>,
+}
+
+impl FilterAnalyticsSnapshot {
+    /// Fold another snapshot (e.g. from a different detector shard) into this one
+    pub fn merge(&mut self, other: &FilterAnalyticsSnapshot) {
+        self.timestamp = self.timestamp.max(other.timestamp);
+        self.totals.merge(&other.totals);
+        for (symbol, hist) in &other.by_symbol {
+            self.by_symbol.entry(symbol.clone()).or_default().merge(hist);
+        }
+        for (pair, hist) in &other.by_venue_pair {
+            self.by_venue_pair.entry(pair.clone()).or_default().merge(hist);
+        }
+    }
+
+    /// Venue pairs sorted by profit-filter rejections (descending)
+    ///
+    /// Venue pairs at the top are where costs most often kill trades.
+    pub fn top_venue_pairs_by_profit_rejections(&self, limit: usize) -> Vec<(&String, &RejectionHistogram)> {
+        let mut pairs: Vec<_> = self.by_venue_pair.iter().collect();
+        pairs.sort_by(|a, b| {
+            b.1.rejections_for(FilterStage::Profit)
+                .cmp(&a.1.rejections_for(FilterStage::Profit))
+                .then_with(|| a.0.cmp(b.0))
+        });
+        pairs.truncate(limit);
+        pairs
+    }
+
+    /// Symbols sorted by total rejections (descending)
+    pub fn top_symbols_by_rejections(&self, limit: usize) -> Vec<(&String, &RejectionHistogram)> {
+        let mut symbols: Vec<_> = self.by_symbol.iter().collect();
+        symbols.sort_by(|a, b| {
+            b.1.total_rejections()
+                .cmp(&a.1.total_rejections())
+                .then_with(|| a.0.cmp(b.0))
+        });
+        symbols.truncate(limit);
+        symbols
+    }
+}
+
+/// Per-symbol and per-venue-pair rejection analytics.
+///
+/// Not thread-safe: owned by the detector and mutated on its thread only.
+/// Use `snapshot()` to hand data to other components.
+#[derive(Debug, Default)]
+pub struct FilterAnalytics {
+    totals: RejectionHistogram,
+    by_symbol: HashMap<String, RejectionHistogram>,
+    by_venue_pair: HashMap<String, RejectionHistogram>,
+    /// Scratch buffer the venue-pair key is built in before each lookup
+    pair_key: String,
+}
+
+impl FilterAnalytics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a rejection with its shortfall below the stage threshold
+    pub fn record_rejection(
+        &mut self,
+        symbol: &str,
+        long_exchange: &str,
+        short_exchange: &str,
+        stage: FilterStage,
+        shortfall: f64,
+    ) {
+        self.totals.record(stage, shortfall);
+        self.symbol_entry(symbol).record(stage, shortfall);
+        self.pair_entry(long_exchange, short_exchange).record(stage, shortfall);
+    }
+
+    /// Record a profit-filter rejection along with its cost breakdown
+    pub fn record_profit_rejection(
+        &mut self,
+        symbol: &str,
+        long_exchange: &str,
+        short_exchange: &str,
+        shortfall_bps: f64,
+        costs: &CostBreakdown,
+    ) {
+        let stage = FilterStage::Profit;
+        self.totals.record(stage, shortfall_bps);
+        self.totals.record_profit_costs(costs);
+
+        let by_symbol = self.symbol_entry(symbol);
+        by_symbol.record(stage, shortfall_bps);
+        by_symbol.record_profit_costs(costs);
+
+        let by_pair = self.pair_entry(long_exchange, short_exchange);
+        by_pair.record(stage, shortfall_bps);
+        by_pair.record_profit_costs(costs);
+    }
+
+    /// Record a candidate that passed every filter
+    pub fn record_pass(&mut self, symbol: &str, long_exchange: &str, short_exchange: &str) {
+        self.totals.passed += 1;
+        self.symbol_entry(symbol).passed += 1;
+        self.pair_entry(long_exchange, short_exchange).passed += 1;
+    }
+
+    /// Aggregate histogram across all keys
+    pub fn totals(&self) -> &RejectionHistogram {
+        &self.totals
+    }
+
+    /// Copy the current state into a publishable snapshot
+    pub fn snapshot(&self) -> FilterAnalyticsSnapshot {
+        FilterAnalyticsSnapshot {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            totals: self.totals.clone(),
+            by_symbol: self.by_symbol.clone(),
+            by_venue_pair: self.by_venue_pair.clone(),
+        }
+    }
+
+    /// Clear all counters
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    fn symbol_entry(&mut self, symbol: &str) -> &mut RejectionHistogram {
+        // Avoid allocating the key on the hot path when it already exists
+        if !self.by_symbol.contains_key(symbol) {
+            self.by_symbol.insert(symbol.to_string(), RejectionHistogram::default());
+        }
+        self.by_symbol.get_mut(symbol).unwrap()
+    }
+
+    fn pair_entry(&mut self, long_exchange: &str, short_exchange: &str) -> &mut RejectionHistogram {
+        // Same as symbol_entry(); the key is assembled in a reused buffer so only
+        // the first sighting of a pair allocates
+        self.pair_key.clear();
+        self.pair_key.push_str(long_exchange);
+        self.pair_key.push_str("->");
+        self.pair_key.push_str(short_exchange);
+        if !self.by_venue_pair.contains_key(self.pair_key.as_str()) {
+            self.by_venue_pair.insert(self.pair_key.clone(), RejectionHistogram::default());
+        }
+        self.by_venue_pair.get_mut(self.pair_key.as_str()).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_for_boundaries() {
+        assert_eq!(bucket_for(0.0), 0);
+        assert_eq!(bucket_for(1.0), 0);
+        assert_eq!(bucket_for(1.5), 1);
+        assert_eq!(bucket_for(7.0), 3);
+        assert_eq!(bucket_for(50.0), 5);
+        assert_eq!(bucket_for(500.0), 6);
+    }
+
+    #[test]
+    fn test_records_per_symbol_and_venue_pair() {
+        let mut analytics = FilterAnalytics::new();
+        analytics.record_rejection("BTCUSDT", "bybit", "okx", FilterStage::Spread, 3.0);
+        analytics.record_rejection("BTCUSDT", "okx", "bybit", FilterStage::Spread, 8.0);
+        analytics.record_rejection("ETHUSDT", "bybit", "okx", FilterStage::Confidence, 12.0);
+        analytics.record_pass("ETHUSDT", "bybit", "okx");
+
+        let snapshot = analytics.snapshot();
+        assert_eq!(snapshot.totals.total_rejections(), 3);
+        assert_eq!(snapshot.totals.passed, 1);
+
+        let btc = &snapshot.by_symbol["BTCUSDT"];
+        assert_eq!(btc.rejections_for(FilterStage::Spread), 2);
+        assert_eq!(btc.avg_shortfall(FilterStage::Spread), 5.5);
+
+        let pair = &snapshot.by_venue_pair["bybit->okx"];
+        assert_eq!(pair.rejections_for(FilterStage::Spread), 1);
+        assert_eq!(pair.rejections_for(FilterStage::Confidence), 1);
+        assert_eq!(pair.passed, 1);
+        assert_eq!(pair.rejection_rate(), 2.0 / 3.0);
+    }
+
+    #[test]
+    fn test_profit_rejection_tracks_dominant_cost() {
+        let mut analytics = FilterAnalytics::new();
+        let costs = CostBreakdown {
+            long_fee_bps: 5.5,
+            short_fee_bps: 20.0,
+            slippage_bps: 3.0,
+            funding_cost_bps: 10.0,
+        };
+        analytics.record_profit_rejection("SOLUSDT", "bybit", "paradex", 4.0, &costs);
+
+        let snapshot = analytics.snapshot();
+        let pair = &snapshot.by_venue_pair["bybit->paradex"];
+        assert_eq!(pair.rejections_for(FilterStage::Profit), 1);
+        assert_eq!(pair.top_dominant_cost(), Some(DominantCost::ShortFee));
+        assert_eq!(pair.profit_cost_sum_bps, 38.5);
+    }
+
+    #[test]
+    fn test_venue_pairs_are_keyed_by_venue_pair_key() {
+        let mut analytics = FilterAnalytics::new();
+        analytics.record_rejection("BTCUSDT", "bybit", "okx", FilterStage::Spread, 1.0);
+        analytics.record_rejection("BTCUSDT", "bybit", "okxspot", FilterStage::Spread, 1.0);
+        analytics.record_pass("BTCUSDT", "bybit", "okx");
+
+        let snapshot = analytics.snapshot();
+        assert_eq!(snapshot.by_venue_pair.len(), 2);
+        assert_eq!(snapshot.by_venue_pair[&venue_pair_key("bybit", "okx")].passed, 1);
+        assert_eq!(
+            snapshot.by_venue_pair[&venue_pair_key("bybit", "okxspot")].rejections_for(FilterStage::Spread),
+            1
+        );
+    }
+
+    #[test]
+    fn test_snapshot_merge_sums_counts() {
+        let mut a = FilterAnalytics::new();
+        a.record_rejection("BTCUSDT", "bybit", "okx", FilterStage::Spread, 2.0);
+        let mut b = FilterAnalytics::new();
+        b.record_rejection("BTCUSDT", "bybit", "okx", FilterStage::Spread, 4.0);
+        b.record_pass("ETHUSDT", "okx", "bitget");
+
+        let mut merged = a.snapshot();
+        merged.merge(&b.snapshot());
+        assert_eq!(merged.totals.total_rejections(), 2);
+        assert_eq!(merged.by_symbol["BTCUSDT"].avg_shortfall(FilterStage::Spread), 3.0);
+        assert_eq!(merged.by_venue_pair["okx->bitget"].passed, 1);
+    }
+
+    #[test]
+    fn test_snapshot_round_trips_through_json() {
+        let mut analytics = FilterAnalytics::new();
+        analytics.record_rejection("BTCUSDT", "bybit", "okx", FilterStage::Funding, 0.5);
+
+        let snapshot = analytics.snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let decoded: FilterAnalyticsSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, snapshot);
+    }
+}
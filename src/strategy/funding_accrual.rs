@@ -0,0 +1,153 @@
+//! Funding payment accrual
+//!
+//! Perpetual legs pay or receive funding at every funding timestamp of their
+//! venue while a trade is open. Live backends report the settlements
+//! (`ExecutionBackend::get_funding_history`); paper and backtest runs, and
+//! venues without a history API, simulate them from the funding stream in
+//! `FundingRateStore`.
+//!
+//! A simulated settlement applies the latest streamed rate to the leg notional
+//! at each venue funding timestamp (multiples of the funding interval since
+//! the epoch), provided the rate is no older than `max_rate_age_secs` there.
+//! A positive rate is paid by longs and received by shorts; spot legs never
+//! pay funding.
+
+use crate::strategy::funding_rates::{funding_interval_hours, FundingRateStore};
+use crate::strategy::instruments::instrument_kind;
+use crate::strategy::types::{FundingPayment, InstrumentKind, OrderSide, PaperTrade};
+
+/// How often open trades are checked for new settlements
+pub const ACCRUAL_INTERVAL_SECS: u64 = 60;
+
+/// Funding timestamps of a venue with `interval_hours` in `(after, until]`.
+pub fn funding_times(interval_hours: f64, after: u64, until: u64) -> Vec<u64> {
+    let interval = (interval_hours * 3600.0) as u64;
+    if interval == 0 {
+        return Vec::new();
+    }
+    let first = (after / interval + 1) * interval;
+    (first..=until).step_by(interval as usize).collect()
+}
+
+/// Simulated settlements on the `side` leg of a trade after its last
+/// recorded settlement, up to `until`.
+pub fn simulate_leg(store: &FundingRateStore, trade: &PaperTrade, side: OrderSide, until: u64, max_rate_age_secs: u64) -> Vec<FundingPayment> {
+    let (exchange, direction) = match side {
+        OrderSide::Long => (&trade.long_exchange, -1.0),
+        OrderSide::Short => (&trade.short_exchange, 1.0),
+    };
+    if instrument_kind(exchange) == InstrumentKind::Spot {
+        return Vec::new();
+    }
+    let Some(rate) = store.get(exchange, &trade.symbol) else {
+        return Vec::new();
+    };
+    funding_times(funding_interval_hours(exchange), last_settlement(trade, exchange), until)
+        .into_iter()
+        .filter(|&t| rate.age_secs(t) <= max_rate_age_secs)
+        .map(|funding_time| FundingPayment {
+            exchange: exchange.clone(),
+            symbol: trade.symbol.clone(),
+            side,
+            rate: rate.rate,
+            amount_usd: direction * rate.rate * trade.position_size_usd,
+            funding_time,
+        })
+        .collect()
+}
+
+/// Latest settlement recorded on `exchange` for a trade, or its entry time.
+pub fn last_settlement(trade: &PaperTrade, exchange: &str) -> u64 {
+    trade.funding_payments
+        .iter()
+        .filter(|p| p.exchange == exchange)
+        .map(|p| p.funding_time)
+        .max()
+        .unwrap_or(trade.entry_time)
+}
+
+/// Add settlements to a trade, skipping ones already recorded (same venue and
+/// timestamp) or settled before the trade was opened. Returns how many were added.
+pub fn merge_payments(trade: &mut PaperTrade, payments: Vec<FundingPayment>) -> usize {
+    let mut added = 0;
+    for payment in payments {
+        if payment.funding_time <= trade.entry_time
+            || trade.funding_payments.iter().any(|p| p.exchange == payment.exchange && p.funding_time == payment.funding_time)
+        {
+            continue;
+        }
+        trade.funding_payments.push(payment);
+        added += 1;
+    }
+    if added > 0 {
+        trade.funding_payments.sort_by_key(|p| p.funding_time);
+    }
+    added
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: u64 = 3600;
+
+    fn trade(long_exchange: &str, short_exchange: &str, entry_time: u64) -> PaperTrade {
+        PaperTrade {
+            entry_time,
+            position_size_usd: 10_000.0,
+            projected_profit_usd: 4.0,
+            ..PaperTrade::test_trade("t1", long_exchange, short_exchange)
+        }
+    }
+
+    #[test]
+    fn test_funding_times_follow_venue_interval() {
+        assert_eq!(funding_times(8.0, 7 * HOUR, 17 * HOUR), vec![8 * HOUR, 16 * HOUR]);
+        // A timestamp equal to `after` was already settled
+        assert_eq!(funding_times(8.0, 8 * HOUR, 16 * HOUR), vec![16 * HOUR]);
+        assert_eq!(funding_times(1.0, 90 * 60, 3 * HOUR + 1), vec![2 * HOUR, 3 * HOUR]);
+        assert!(funding_times(8.0, HOUR, 2 * HOUR).is_empty());
+    }
+
+    #[test]
+    fn test_simulated_payments_signed_by_side_and_skip_spot() {
+        let store = FundingRateStore::new();
+        store.update_at("binance", "BTCUSDT", 0.0001, 15 * HOUR);
+        store.update_at("bybit", "BTCUSDT", -0.0002, 15 * HOUR);
+        let mut perp = trade("binance", "bybit", 10 * HOUR);
+
+        let long = simulate_leg(&store, &perp, OrderSide::Long, 17 * HOUR, 3600);
+        assert_eq!(long.len(), 1);
+        assert_eq!(long[0].funding_time, 16 * HOUR);
+        assert!((long[0].amount_usd + 1.0).abs() < 1e-9);
+        let short = simulate_leg(&store, &perp, OrderSide::Short, 17 * HOUR, 3600);
+        assert!((short[0].amount_usd + 2.0).abs() < 1e-9);
+
+        // Settled timestamps are not simulated again; the rate is too old at the next one
+        merge_payments(&mut perp, long);
+        assert!(simulate_leg(&store, &perp, OrderSide::Long, 25 * HOUR, 3600).is_empty());
+
+        let spot = trade("bybit_spot", "bybit", 10 * HOUR);
+        assert!(simulate_leg(&store, &spot, OrderSide::Long, 17 * HOUR, 3600).is_empty());
+    }
+
+    #[test]
+    fn test_merge_skips_duplicates_and_pre_entry_settlements() {
+        let payment = |exchange: &str, funding_time, amount_usd| FundingPayment {
+            exchange: exchange.to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Short,
+            rate: 0.0,
+            amount_usd,
+            funding_time,
+        };
+        let mut trade = trade("binance", "bybit", 1_000);
+
+        assert_eq!(last_settlement(&trade, "bybit"), 1_000);
+        let added = merge_payments(&mut trade, vec![payment("bybit", 900, 5.0), payment("bybit", 2_000, 1.5), payment("binance", 2_000, -0.5)]);
+        assert_eq!(added, 2);
+        assert_eq!(merge_payments(&mut trade, vec![payment("bybit", 2_000, 1.5)]), 0);
+        assert_eq!(last_settlement(&trade, "bybit"), 2_000);
+        assert!((trade.funding_pnl_usd() - 1.0).abs() < 1e-9);
+    }
+}
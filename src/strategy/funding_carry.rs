@@ -0,0 +1,544 @@
+//! Funding-Carry Mode
+//!
+//! The spread path only looks at funding once a price spread above
+//! `min_spread_bps` exists. Funding carry is the opposite trade: a persistent
+//! funding differential between two venues while the price spread sits near
+//! zero. We go long where funding is cheapest and short where it is richest,
+//! and collect the differential at every funding timestamp.
+//!
+//! # Scoring
+//!
+//! ```text
+//! gross_apr = (short_rate/h − long_rate/h) × 8760
+//! cost_bps  = entry + exit fees of both legs (account fee schedule)
+//!           + slippage (4 fills) + expected basis drift + adverse entry basis
+//! net_apr   = gross_apr − cost_bps / 10_000 × 8760 / holding_horizon_hours
+//! ```
+//!
+//! Venue pairs for a symbol are ranked by `net_apr`; only the best pair is
+//! emitted, and at most once per `min_emit_interval_secs`.
+//!
+//! # Holding policy
+//!
+//! `FundingCarryPosition` keeps the trade open across funding timestamps. It
+//! feeds the carry differential into a `NegativeFundingTracker` once per
+//! 8-hour settlement and exits after 2 consecutive negative cycles, or when
+//! the carry stays below `exit_carry_apr` for longer than `decay_grace_secs`.
+//!
+//! # Spot legs
+//!
+//! Spot venues (`bybit_spot`, ...) take part as zero-funding legs, which turns
+//! a positive perp funding rate into a cash-and-carry quote: long spot, short
+//! perp. Spot can only be the long leg, so quotes shorting spot are never
+//! scored.
+
+use crate::strategy::atomic_execution::NegativeFundingTracker;
+use crate::strategy::funding_rates::{FundingRate, HOURS_PER_YEAR};
+use crate::strategy::instruments::instrument_kind;
+use crate::strategy::types::InstrumentKind;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+
+/// Length of a funding settlement cycle used by the holding policy (seconds)
+const SETTLEMENT_CYCLE_SECS: u64 = 8 * 3600;
+
+/// Configuration for funding-carry entry and exit.
+#[derive(Debug, Clone)]
+pub struct FundingCarryConfig {
+    /// Minimum annualised carry net of costs to enter (0.15 = 15% APR)
+    pub min_entry_net_apr: f64,
+
+    /// Exit once the annualised carry falls below this (0.03 = 3% APR)
+    pub exit_carry_apr: f64,
+
+    /// Maximum |price spread| at entry (bps)
+    pub max_entry_basis_bps: f64,
+
+    /// Expected adverse basis move over the holding period (bps)
+    pub expected_basis_drift_bps: f64,
+
+    /// Slippage per fill (bps)
+    pub slippage_bps: f64,
+
+    /// Horizon over which entry/exit costs are amortised (hours)
+    pub holding_horizon_hours: f64,
+
+    /// Funding observations older than this are ignored (seconds)
+    pub max_rate_age_secs: u64,
+
+    /// Minimum time between two carry opportunities for the same symbol (seconds)
+    pub min_emit_interval_secs: u64,
+
+    /// How long carry must stay below `exit_carry_apr` before exiting (seconds)
+    pub decay_grace_secs: u64,
+}
+
+impl Default for FundingCarryConfig {
+    /// Defaults:
+    /// - min_entry_net_apr: 0.15 (15%)
+    /// - exit_carry_apr: 0.03 (3%)
+    /// - max_entry_basis_bps: 15.0
+    /// - expected_basis_drift_bps: 5.0
+    /// - slippage_bps: 3.0
+    /// - holding_horizon_hours: 72.0
+    /// - max_rate_age_secs: 300
+    /// - min_emit_interval_secs: 60
+    /// - decay_grace_secs: 3600
+    fn default() -> Self {
+        Self {
+            min_entry_net_apr: 0.15,
+            exit_carry_apr: 0.03,
+            max_entry_basis_bps: 15.0,
+            expected_basis_drift_bps: 5.0,
+            slippage_bps: 3.0,
+            holding_horizon_hours: 72.0,
+            max_rate_age_secs: 300,
+            min_emit_interval_secs: 60,
+            decay_grace_secs: 3600,
+        }
+    }
+}
+
+impl FundingCarryConfig {
+    /// Override thresholds from `FUNDING_CARRY_ENTRY_APR`, `FUNDING_CARRY_EXIT_APR`
+    /// and `FUNDING_CARRY_HORIZON_HOURS`, keeping defaults for the rest.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let parse = |var: &str, default: f64| -> f64 {
+            std::env::var(var)
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|v| v.is_finite())
+                .unwrap_or(default)
+        };
+
+        Self {
+            min_entry_net_apr: parse("FUNDING_CARRY_ENTRY_APR", defaults.min_entry_net_apr),
+            exit_carry_apr: parse("FUNDING_CARRY_EXIT_APR", defaults.exit_carry_apr),
+            holding_horizon_hours: parse("FUNDING_CARRY_HORIZON_HOURS", defaults.holding_horizon_hours)
+                .max(1.0),
+            ..defaults
+        }
+    }
+}
+
+/// One venue's inputs for carry scoring.
+#[derive(Debug, Clone, Copy)]
+pub struct CarryLeg<'a> {
+    pub exchange: &'a str,
+    pub funding: FundingRate,
+    pub bid: f64,
+    pub ask: f64,
+    /// Entry plus exit fee of this leg (bps)
+    pub fee_bps: f64,
+}
+
+/// Scored carry candidate: long `long_exchange`, short `short_exchange`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CarryQuote {
+    pub long_exchange: String,
+    pub short_exchange: String,
+    /// Long entry price (ask)
+    pub long_price: f64,
+    /// Short entry price (bid)
+    pub short_price: f64,
+    /// Entry price spread (bps); negative means we buy above where we sell
+    pub basis_bps: f64,
+    pub long_rate_8h: f64,
+    pub short_rate_8h: f64,
+    /// Annualised funding differential before costs
+    pub gross_apr: f64,
+    /// Round-trip costs including basis drift (bps)
+    pub cost_bps: f64,
+    /// Annualised carry net of costs amortised over the holding horizon
+    pub net_apr: f64,
+}
+
+impl CarryQuote {
+    /// Funding differential collected per 8 hours (short rate − long rate).
+    #[inline]
+    pub fn carry_8h(&self) -> f64 {
+        self.short_rate_8h - self.long_rate_8h
+    }
+
+    /// Expected profit over `horizon_hours` at the net rate.
+    #[inline]
+    pub fn expected_profit_usd(&self, position_size_usd: f64, horizon_hours: f64) -> f64 {
+        self.net_apr * (horizon_hours / HOURS_PER_YEAR) * position_size_usd
+    }
+}
+
+/// Score a long/short venue combination.
+pub fn score_pair(config: &FundingCarryConfig, long: &CarryLeg, short: &CarryLeg) -> CarryQuote {
+    let basis_bps = if long.ask > 0.0 {
+        ((short.bid - long.ask) / long.ask) * 10000.0
+    } else {
+        0.0
+    };
+
+    let gross_apr = (short.funding.hourly() - long.funding.hourly()) * HOURS_PER_YEAR;
+
+    let cost_bps = long.fee_bps + short.fee_bps
+        + 4.0 * config.slippage_bps
+        + config.expected_basis_drift_bps
+        + (-basis_bps).max(0.0);
+    let cost_apr = (cost_bps / 10000.0) * (HOURS_PER_YEAR / config.holding_horizon_hours);
+
+    CarryQuote {
+        long_exchange: long.exchange.to_string(),
+        short_exchange: short.exchange.to_string(),
+        long_price: long.ask,
+        short_price: short.bid,
+        basis_bps,
+        long_rate_8h: long.funding.rate_8h(),
+        short_rate_8h: short.funding.rate_8h(),
+        gross_apr,
+        cost_bps,
+        net_apr: gross_apr - cost_apr,
+    }
+}
+
+/// Score both directions of every venue pair and rank by net APR (best first).
+///
+/// Pairs whose entry basis exceeds `max_entry_basis_bps` are dropped, and
+/// spot legs are only ever scored on the long side.
+pub fn rank_pairs(config: &FundingCarryConfig, legs: &[CarryLeg]) -> Vec<CarryQuote> {
+    let shortable = |leg: &CarryLeg| instrument_kind(leg.exchange) == InstrumentKind::Perp;
+    let mut quotes = Vec::with_capacity(legs.len() * legs.len().saturating_sub(1));
+    for (i, a) in legs.iter().enumerate() {
+        for b in legs.iter().skip(i + 1) {
+            if shortable(b) {
+                quotes.push(score_pair(config, a, b));
+            }
+            if shortable(a) {
+                quotes.push(score_pair(config, b, a));
+            }
+        }
+    }
+    quotes.retain(|q| q.basis_bps.abs() <= config.max_entry_basis_bps);
+    quotes.sort_by(|a, b| b.net_apr.total_cmp(&a.net_apr));
+    quotes
+}
+
+/// Selects the best carry pair per symbol and throttles re-emission.
+#[derive(Debug)]
+pub struct FundingCarryScanner {
+    config: FundingCarryConfig,
+    last_emitted: HashMap<String, u64>,
+}
+
+impl FundingCarryScanner {
+    pub fn new(config: FundingCarryConfig) -> Self {
+        Self {
+            config,
+            last_emitted: HashMap::new(),
+        }
+    }
+
+    pub fn config(&self) -> &FundingCarryConfig {
+        &self.config
+    }
+
+    /// Best tradeable quote for `symbol` if it clears the entry threshold and
+    /// the symbol was not emitted within `min_emit_interval_secs`.
+    ///
+    /// `is_tradeable(long, short)` lets the caller exclude quarantined venue pairs.
+    pub fn select<F>(&mut self, symbol: &str, legs: &[CarryLeg], now: u64, is_tradeable: F) -> Option<CarryQuote>
+    where
+        F: Fn(&str, &str) -> bool,
+    {
+        if let Some(&last) = self.last_emitted.get(symbol) {
+            if now.saturating_sub(last) < self.config.min_emit_interval_secs {
+                return None;
+            }
+        }
+
+        let best = rank_pairs(&self.config, legs)
+            .into_iter()
+            .find(|q| is_tradeable(&q.long_exchange, &q.short_exchange))?;
+        if best.net_apr < self.config.min_entry_net_apr {
+            return None;
+        }
+
+        self.last_emitted.insert(symbol.to_string(), now);
+        Some(best)
+    }
+}
+
+/// Why a carry position should be closed.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CarryExitReason {
+    /// Carry stayed below `exit_carry_apr` for longer than the grace period
+    CarryDecayed { carry_apr: f64 },
+    /// Carry was negative at consecutive funding timestamps
+    NegativeCarry { cycles: u32 },
+}
+
+impl fmt::Display for CarryExitReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CarryExitReason::CarryDecayed { carry_apr } => {
+                write!(f, "funding_carry_decayed | Carry: {:.2}% APR", carry_apr * 100.0)
+            }
+            CarryExitReason::NegativeCarry { cycles } => {
+                write!(f, "negative_funding_exit | {} consecutive negative cycles", cycles)
+            }
+        }
+    }
+}
+
+/// Outcome of evaluating an open carry position.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CarryHoldDecision {
+    Hold,
+    Exit(CarryExitReason),
+}
+
+/// Holding state for one open carry trade, persisted with the trade so a
+/// restart resumes its negative-cycle count and decay timer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FundingCarryPosition {
+    pub long_exchange: String,
+    pub short_exchange: String,
+    tracker: NegativeFundingTracker,
+    last_cycle: u64,
+    below_exit_since: Option<u64>,
+}
+
+impl FundingCarryPosition {
+    pub fn new(symbol: &str, long_exchange: &str, short_exchange: &str, entry_time: u64) -> Self {
+        Self {
+            long_exchange: long_exchange.to_string(),
+            short_exchange: short_exchange.to_string(),
+            tracker: NegativeFundingTracker::new(symbol.to_string()),
+            last_cycle: entry_time / SETTLEMENT_CYCLE_SECS,
+            below_exit_since: None,
+        }
+    }
+
+    /// Consecutive settlement cycles with negative carry so far.
+    pub fn negative_cycles(&self) -> u32 {
+        self.tracker.consecutive_negative_cycles
+    }
+
+    /// Since when the carry has been below the exit threshold, if it is.
+    pub fn below_exit_since(&self) -> Option<u64> {
+        self.below_exit_since
+    }
+
+    /// Evaluate the position against the latest funding rates.
+    pub fn evaluate(
+        &mut self,
+        config: &FundingCarryConfig,
+        long_rate: &FundingRate,
+        short_rate: &FundingRate,
+        now: u64,
+    ) -> CarryHoldDecision {
+        // Feed the tracker once per funding timestamp
+        let cycle = now / SETTLEMENT_CYCLE_SECS;
+        if cycle > self.last_cycle {
+            self.last_cycle = cycle;
+            let carry_8h = short_rate.rate_8h() - long_rate.rate_8h();
+            if self.tracker.update_funding(carry_8h) {
+                return CarryHoldDecision::Exit(CarryExitReason::NegativeCarry {
+                    cycles: self.tracker.consecutive_negative_cycles,
+                });
+            }
+        }
+
+        let carry_apr = (short_rate.hourly() - long_rate.hourly()) * HOURS_PER_YEAR;
+        if carry_apr >= config.exit_carry_apr {
+            self.below_exit_since = None;
+            return CarryHoldDecision::Hold;
+        }
+
+        let since = *self.below_exit_since.get_or_insert(now);
+        if now.saturating_sub(since) >= config.decay_grace_secs {
+            CarryHoldDecision::Exit(CarryExitReason::CarryDecayed { carry_apr })
+        } else {
+            CarryHoldDecision::Hold
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::exchange_fees::get_exchange_fee_by_name;
+
+    fn rate(rate: f64, interval_hours: f64) -> FundingRate {
+        FundingRate { rate, interval_hours, updated_at: 0 }
+    }
+
+    fn leg(exchange: &str, funding_8h: f64, mid: f64) -> CarryLeg<'_> {
+        CarryLeg {
+            exchange,
+            funding: rate(funding_8h, 8.0),
+            bid: mid - 0.01,
+            ask: mid + 0.01,
+            fee_bps: 2.0 * get_exchange_fee_by_name(exchange),
+        }
+    }
+
+    #[test]
+    fn test_carry_scored_net_of_fees_and_drift() {
+        let config = FundingCarryConfig::default();
+        // Long bybit at -1bp/8h, short okx at +5bp/8h: 6bp/8h ≈ 65.7% APR gross
+        let long = leg("bybit", -0.0001, 100.0);
+        let short = leg("okx", 0.0005, 100.0);
+
+        let quote = score_pair(&config, &long, &short);
+        assert!((quote.carry_8h() - 0.0006).abs() < 1e-12);
+        assert!((quote.gross_apr - 0.657).abs() < 1e-9);
+        assert!(quote.net_apr < quote.gross_apr);
+        assert!(quote.net_apr > config.min_entry_net_apr);
+    }
+
+    #[test]
+    fn test_hourly_venue_compared_on_same_basis() {
+        let config = FundingCarryConfig::default();
+        // Hyperliquid 0.5bp/h == 4bp/8h, bybit 4bp/8h: no carry
+        let hl = CarryLeg { exchange: "hyperliquid", funding: rate(0.00005, 1.0), bid: 99.99, ask: 100.01, fee_bps: 2.0 * get_exchange_fee_by_name("hyperliquid") };
+        let bybit = leg("bybit", 0.0004, 100.0);
+
+        let quote = score_pair(&config, &hl, &bybit);
+        assert!(quote.gross_apr.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_rank_picks_best_direction_and_drops_wide_basis() {
+        let config = FundingCarryConfig::default();
+        let legs = [
+            leg("bybit", 0.0001, 100.0),
+            leg("okx", 0.0006, 100.0),
+            // Wide basis vs the others: excluded entirely
+            leg("bitget", -0.0010, 101.0),
+        ];
+
+        let ranked = rank_pairs(&config, &legs);
+        assert!(!ranked.is_empty());
+        assert_eq!(ranked[0].long_exchange, "bybit");
+        assert_eq!(ranked[0].short_exchange, "okx");
+        assert!(ranked.iter().all(|q| q.long_exchange != "bitget" && q.short_exchange != "bitget"));
+    }
+
+    #[test]
+    fn test_position_state_survives_serialization() {
+        let config = FundingCarryConfig::default();
+        let mut position = FundingCarryPosition::new("BTCUSDT", "bybit", "okx", 0);
+        let negative = (rate(0.0003, 8.0), rate(0.0001, 8.0));
+        position.evaluate(&config, &negative.0, &negative.1, SETTLEMENT_CYCLE_SECS);
+        assert_eq!(position.negative_cycles(), 1);
+
+        let mut restored: FundingCarryPosition = serde_json::from_str(&serde_json::to_string(&position).unwrap()).unwrap();
+        assert_eq!(restored.negative_cycles(), 1);
+        assert_eq!(restored.below_exit_since(), position.below_exit_since());
+        // The second negative cycle after a restart still triggers the exit
+        assert!(matches!(
+            restored.evaluate(&config, &negative.0, &negative.1, 2 * SETTLEMENT_CYCLE_SECS),
+            CarryHoldDecision::Exit(CarryExitReason::NegativeCarry { cycles: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_spot_leg_only_held_long() {
+        let config = FundingCarryConfig::default();
+        let legs = [
+            leg("bybit_spot", 0.0, 100.0),
+            leg("okx_spot", 0.0, 100.0),
+            leg("bybit", 0.0010, 100.0),
+        ];
+
+        let ranked = rank_pairs(&config, &legs);
+        // Spot-spot is never scored; both spot venues pair only as long vs the perp
+        assert_eq!(ranked.len(), 2);
+        assert!(ranked.iter().all(|q| q.short_exchange == "bybit"));
+        assert!(ranked[0].net_apr > config.min_entry_net_apr);
+    }
+
+    #[test]
+    fn test_scanner_throttles_per_symbol() {
+        let mut scanner = FundingCarryScanner::new(FundingCarryConfig::default());
+        let legs = [leg("bybit", -0.0001, 100.0), leg("okx", 0.0005, 100.0)];
+
+        assert!(scanner.select("BTCUSDT", &legs, 1_000, |_, _| true).is_some());
+        assert!(scanner.select("BTCUSDT", &legs, 1_030, |_, _| true).is_none());
+        assert!(scanner.select("ETHUSDT", &legs, 1_030, |_, _| true).is_some());
+        assert!(scanner.select("BTCUSDT", &legs, 1_060, |_, _| true).is_some());
+    }
+
+    #[test]
+    fn test_scanner_skips_untradeable_pairs() {
+        let mut scanner = FundingCarryScanner::new(FundingCarryConfig::default());
+        let legs = [leg("bybit", -0.0001, 100.0), leg("okx", 0.0005, 100.0)];
+
+        assert!(scanner.select("BTCUSDT", &legs, 1_000, |_, _| false).is_none());
+        // A rejected attempt does not start the throttle window
+        assert!(scanner.select("BTCUSDT", &legs, 1_001, |_, _| true).is_some());
+    }
+
+    #[test]
+    fn test_scanner_rejects_carry_below_entry_threshold() {
+        let mut scanner = FundingCarryScanner::new(FundingCarryConfig::default());
+        // 0.5bp/8h differential does not cover round-trip fees
+        let legs = [leg("bybit", 0.0001, 100.0), leg("okx", 0.00015, 100.0)];
+        assert!(scanner.select("BTCUSDT", &legs, 1_000, |_, _| true).is_none());
+    }
+
+    #[test]
+    fn test_position_held_across_funding_timestamps() {
+        let config = FundingCarryConfig::default();
+        let mut position = FundingCarryPosition::new("BTCUSDT", "bybit", "okx", 0);
+        let long = rate(-0.0001, 8.0);
+        let short = rate(0.0005, 8.0);
+
+        for cycle in 1..=5 {
+            let now = cycle * SETTLEMENT_CYCLE_SECS + 60;
+            assert_eq!(position.evaluate(&config, &long, &short, now), CarryHoldDecision::Hold);
+        }
+        assert_eq!(position.negative_cycles(), 0);
+    }
+
+    #[test]
+    fn test_position_exits_after_two_negative_cycles() {
+        let config = FundingCarryConfig { decay_grace_secs: u64::MAX, ..Default::default() };
+        let mut position = FundingCarryPosition::new("BTCUSDT", "bybit", "okx", 0);
+        let long = rate(0.0005, 8.0);
+        let short = rate(-0.0001, 8.0);
+
+        let first = SETTLEMENT_CYCLE_SECS + 60;
+        assert_eq!(position.evaluate(&config, &long, &short, first), CarryHoldDecision::Hold);
+        // Same cycle again does not count twice
+        assert_eq!(position.evaluate(&config, &long, &short, first + 600), CarryHoldDecision::Hold);
+
+        let second = 2 * SETTLEMENT_CYCLE_SECS + 60;
+        assert_eq!(
+            position.evaluate(&config, &long, &short, second),
+            CarryHoldDecision::Exit(CarryExitReason::NegativeCarry { cycles: 2 })
+        );
+    }
+
+    #[test]
+    fn test_position_exits_when_carry_decays_past_grace() {
+        let config = FundingCarryConfig::default();
+        let mut position = FundingCarryPosition::new("BTCUSDT", "bybit", "okx", 0);
+        // 0.1bp/8h ≈ 1.1% APR, below the 3% exit threshold but still positive
+        let long = rate(0.0001, 8.0);
+        let short = rate(0.00011, 8.0);
+
+        assert_eq!(position.evaluate(&config, &long, &short, 100), CarryHoldDecision::Hold);
+        assert_eq!(position.evaluate(&config, &long, &short, 100 + 1800), CarryHoldDecision::Hold);
+        assert!(matches!(
+            position.evaluate(&config, &long, &short, 100 + 3600),
+            CarryHoldDecision::Exit(CarryExitReason::CarryDecayed { .. })
+        ));
+
+        // Recovery resets the grace window
+        let mut position = FundingCarryPosition::new("BTCUSDT", "bybit", "okx", 0);
+        let rich = rate(0.0005, 8.0);
+        assert_eq!(position.evaluate(&config, &long, &short, 100), CarryHoldDecision::Hold);
+        assert_eq!(position.evaluate(&config, &long, &rich, 2000), CarryHoldDecision::Hold);
+        assert_eq!(position.evaluate(&config, &long, &short, 3800), CarryHoldDecision::Hold);
+    }
+}
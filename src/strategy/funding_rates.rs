@@ -0,0 +1,173 @@
+//! Live Funding Rate Store
+//!
+//! Latest funding rate per (exchange, canonical symbol), fed from the connector
+//! funding streams by the Redis bridge. Shared between the detector shards and
+//! the strategy runner so neither has to poll Redis in the hot path.
+//!
+//! Venues settle funding on different schedules (Hyperliquid hourly, most CEX
+//! perps every 8 hours), so rates are stored raw together with their interval
+//! and exposed normalized to 8 hours or annualized.
+
+use crate::strategy::instruments::instrument_kind;
+use crate::strategy::types::InstrumentKind;
+use dashmap::DashMap;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Hours in a (non-leap) year, used to annualize funding
+pub const HOURS_PER_YEAR: f64 = 24.0 * 365.0;
+
+/// Funding settlement interval for an exchange, in hours.
+pub fn funding_interval_hours(exchange: &str) -> f64 {
+    match exchange {
+        "hyperliquid" => 1.0,
+        _ => 8.0,
+    }
+}
+
+/// Latest funding observation for one venue.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FundingRate {
+    /// Rate per settlement interval (0.0001 = 1 bp); positive means longs pay shorts
+    pub rate: f64,
+
+    /// Settlement interval in hours
+    pub interval_hours: f64,
+
+    /// Unix timestamp (seconds) of the update
+    pub updated_at: u64,
+}
+
+impl FundingRate {
+    /// Rate per hour.
+    #[inline]
+    pub fn hourly(&self) -> f64 {
+        if self.interval_hours > 0.0 {
+            self.rate / self.interval_hours
+        } else {
+            0.0
+        }
+    }
+
+    /// Rate normalized to an 8-hour interval.
+    #[inline]
+    pub fn rate_8h(&self) -> f64 {
+        self.hourly() * 8.0
+    }
+
+    /// Rate annualized (simple, not compounded).
+    #[inline]
+    pub fn annualized(&self) -> f64 {
+        self.hourly() * HOURS_PER_YEAR
+    }
+
+    /// Age of the observation relative to `now` (seconds).
+    #[inline]
+    pub fn age_secs(&self, now: u64) -> u64 {
+        now.saturating_sub(self.updated_at)
+    }
+}
+
+/// Thread-safe store of the latest funding rate per (exchange, symbol).
+#[derive(Debug, Default)]
+pub struct FundingRateStore {
+    rates: DashMap<(String, String), FundingRate>,
+}
+
+impl FundingRateStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a funding rate observed now.
+    pub fn update(&self, exchange: &str, symbol: &str, rate: f64) {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        self.update_at(exchange, symbol, rate, now);
+    }
+
+    /// Record a funding rate with an explicit timestamp.
+    pub fn update_at(&self, exchange: &str, symbol: &str, rate: f64, updated_at: u64) {
+        if !rate.is_finite() {
+            return;
+        }
+        self.rates.insert(
+            (exchange.to_string(), symbol.to_string()),
+            FundingRate {
+                rate,
+                interval_hours: funding_interval_hours(exchange),
+                updated_at,
+            },
+        );
+    }
+
+    /// Latest funding rate for a venue, if any.
+    pub fn get(&self, exchange: &str, symbol: &str) -> Option<FundingRate> {
+        self.rates
+            .get(&(exchange.to_string(), symbol.to_string()))
+            .map(|r| *r)
+    }
+
+    /// Latest funding rate for a venue if it is no older than `max_age_secs`.
+    pub fn get_fresh(&self, exchange: &str, symbol: &str, now: u64, max_age_secs: u64) -> Option<FundingRate> {
+        self.get(exchange, symbol)
+            .filter(|r| r.age_secs(now) <= max_age_secs)
+    }
+
+    /// Funding paid by a trade leg on `venue`: fresh store rate for perps,
+    /// always zero for spot venues.
+    pub fn get_fresh_leg(&self, venue: &str, symbol: &str, now: u64, max_age_secs: u64) -> Option<FundingRate> {
+        match instrument_kind(venue) {
+            InstrumentKind::Spot => Some(FundingRate { rate: 0.0, interval_hours: 8.0, updated_at: now }),
+            InstrumentKind::Perp => self.get_fresh(venue, symbol, now, max_age_secs),
+        }
+    }
+
+    /// Number of (exchange, symbol) entries.
+    pub fn len(&self) -> usize {
+        self.rates.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rates.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyperliquid_rate_normalized_to_8h() {
+        let store = FundingRateStore::new();
+        store.update_at("hyperliquid", "BTCUSDT", 0.0000125, 100);
+        store.update_at("bybit", "BTCUSDT", 0.0001, 100);
+
+        let hl = store.get("hyperliquid", "BTCUSDT").unwrap();
+        let bybit = store.get("bybit", "BTCUSDT").unwrap();
+
+        assert!((hl.rate_8h() - 0.0001).abs() < 1e-12);
+        assert!((bybit.rate_8h() - 0.0001).abs() < 1e-12);
+        // 1 bp per 8h ≈ 10.95% per year
+        assert!((bybit.annualized() - 0.1095).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_stale_rates_are_filtered() {
+        let store = FundingRateStore::new();
+        store.update_at("okx", "ETHUSDT", 0.0002, 1_000);
+
+        assert!(store.get_fresh("okx", "ETHUSDT", 1_060, 120).is_some());
+        assert!(store.get_fresh("okx", "ETHUSDT", 1_200, 120).is_none());
+        assert!(store.get_fresh("bybit", "ETHUSDT", 1_060, 120).is_none());
+    }
+
+    #[test]
+    fn test_spot_leg_pays_no_funding() {
+        let store = FundingRateStore::new();
+        let spot = store.get_fresh_leg("okx_spot", "ETHUSDT", 1_000, 120).unwrap();
+        assert_eq!(spot.rate_8h(), 0.0);
+        assert!(store.get_fresh_leg("okx", "ETHUSDT", 1_000, 120).is_none());
+    }
+}
@@ -0,0 +1,78 @@
+//! Instrument Registry
+//!
+//! Spot markets are modelled as their own venues so they flow through the
+//! symbol map, detector, fee tables and execution backends unchanged: the
+//! Bybit spot book is venue `bybit_spot`, the linear perp book stays `bybit`.
+//! Everything that needs to know whether a leg is spot or perpetual derives it
+//! from the venue id with the helpers below.
+
+use crate::strategy::types::InstrumentKind;
+
+/// Suffix appended to an exchange name to form its spot venue id
+pub const SPOT_SUFFIX: &str = "_spot";
+
+/// Spot venues with a market data connector
+pub const SPOT_VENUES: [&str; 3] = ["bybit_spot", "okx_spot", "bitget_spot"];
+
+/// Spot venue id for an exchange (`"bybit"` -> `"bybit_spot"`).
+pub fn spot_venue(exchange: &str) -> String {
+    format!("{}{}", exchange, SPOT_SUFFIX)
+}
+
+/// Split a venue id into its exchange and instrument kind.
+///
+/// `"okx_spot"` -> `("okx", Spot)`, `"okx"` -> `("okx", Perp)`.
+#[inline]
+pub fn split_venue(venue: &str) -> (&str, InstrumentKind) {
+    match venue.strip_suffix(SPOT_SUFFIX) {
+        Some(exchange) => (exchange, InstrumentKind::Spot),
+        None => (venue, InstrumentKind::Perp),
+    }
+}
+
+/// Instrument kind traded on a venue.
+#[inline]
+pub fn instrument_kind(venue: &str) -> InstrumentKind {
+    split_venue(venue).1
+}
+
+/// Exchange that hosts a venue (strips the spot suffix).
+#[inline]
+pub fn base_exchange(venue: &str) -> &str {
+    split_venue(venue).0
+}
+
+/// Venue id for a Redis key's `exchange:market` prefix.
+///
+/// Connectors publish spot books under the `spot` market segment
+/// (e.g. `bybit:spot:tickers:BTCUSDT`); every other segment is a perp market.
+pub fn venue_from_key(exchange: &str, market: &str) -> String {
+    if market == "spot" {
+        spot_venue(exchange)
+    } else {
+        exchange.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_venue() {
+        assert_eq!(split_venue("bybit_spot"), ("bybit", InstrumentKind::Spot));
+        assert_eq!(split_venue("bybit"), ("bybit", InstrumentKind::Perp));
+        assert_eq!(base_exchange("bitget_spot"), "bitget");
+        for venue in SPOT_VENUES {
+            assert_eq!(instrument_kind(venue), InstrumentKind::Spot);
+        }
+    }
+
+    #[test]
+    fn test_venue_from_key() {
+        assert_eq!(venue_from_key("okx", "spot"), "okx_spot");
+        assert_eq!(venue_from_key("okx", "usdt"), "okx");
+        assert_eq!(venue_from_key("bybit", "linear"), "bybit");
+        assert_eq!(split_venue(&spot_venue("okx")), ("okx", InstrumentKind::Spot));
+    }
+}
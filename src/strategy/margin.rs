@@ -0,0 +1,278 @@
+//! Leverage configuration and margin monitoring
+//!
+//! `LeverageConfig` picks the leverage set on each perp leg at entry: a
+//! default, per-venue overrides and per-(venue, symbol) overrides.
+//!
+//! Once a trade is open, the margin monitor reads each perp leg's mark and
+//! liquidation price from its venue (`VenuePosition::margin`) and measures the
+//! adverse move the leg can take before it is liquidated. After a big move one
+//! leg of a hedged trade gains what the other loses, so the trade stays flat
+//! while the losing leg's margin thins:
+//!
+//! - below `rebalance_distance` collateral should move from the other leg's
+//!   venue to the thin leg's venue (`MarginAction::Rebalance`);
+//! - below `derisk_distance` the trade is closed (`MarginAction::DeRisk`).
+
+use crate::strategy::types::{InstrumentKind, OrderSide, PaperTrade, PositionMargin, VenuePosition};
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Exit reason of trades closed because a leg got too close to liquidation
+pub const MARGIN_DERISK_EXIT_REASON: &str = "margin_derisk";
+
+/// Leverage per venue and symbol
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeverageConfig {
+    pub default_leverage: u8,
+    /// `venue` or `venue:SYMBOL` -> leverage
+    overrides: HashMap<String, u8>,
+}
+
+impl Default for LeverageConfig {
+    /// 1x everywhere
+    fn default() -> Self {
+        Self { default_leverage: 1, overrides: HashMap::new() }
+    }
+}
+
+impl LeverageConfig {
+    /// Load `LEVERAGE_DEFAULT` and `LEVERAGE_OVERRIDES`, a comma-separated list
+    /// of `venue=N` and `venue:SYMBOL=N` entries (e.g. `okx=2,bybit:BTCUSDT=3`).
+    pub fn from_env() -> Self {
+        let mut config = Self::default();
+        if let Some(leverage) = std::env::var("LEVERAGE_DEFAULT").ok().and_then(|v| v.parse::<u8>().ok()) {
+            config.default_leverage = leverage.max(1);
+        }
+        if let Ok(spec) = std::env::var("LEVERAGE_OVERRIDES") {
+            for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+                match entry.split_once('=').and_then(|(key, value)| Some((key.trim(), value.trim().parse::<u8>().ok()?))) {
+                    Some((key, leverage)) => config = config.with_override(key, leverage),
+                    None => eprintln!("[LEVERAGE] Ignoring malformed override '{}'", entry),
+                }
+            }
+        }
+        config
+    }
+
+    /// Override the leverage of a venue (`key` = `venue`) or of one symbol on
+    /// it (`key` = `venue:SYMBOL`).
+    pub fn with_override(mut self, key: &str, leverage: u8) -> Self {
+        self.overrides.insert(key.to_string(), leverage.max(1));
+        self
+    }
+
+    /// Leverage for `symbol` on `exchange`; the most specific setting wins.
+    pub fn leverage_for(&self, exchange: &str, symbol: &str) -> u8 {
+        self.overrides
+            .get(&format!("{}:{}", exchange, symbol))
+            .or_else(|| self.overrides.get(exchange))
+            .copied()
+            .unwrap_or(self.default_leverage)
+            .max(1)
+    }
+
+    /// Initial margin of a `notional_usd` position on `exchange`.
+    pub fn required_margin(&self, exchange: &str, symbol: &str, notional_usd: f64) -> f64 {
+        notional_usd / self.leverage_for(exchange, symbol) as f64
+    }
+}
+
+/// Thresholds of the margin monitor; distances are fractions of the mark price.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MarginMonitorConfig {
+    pub check_interval_secs: u64,
+    /// Below this distance to liquidation, collateral is moved to the leg's venue
+    pub rebalance_distance: f64,
+    /// Below this distance to liquidation, the trade is closed
+    pub derisk_distance: f64,
+}
+
+impl Default for MarginMonitorConfig {
+    fn default() -> Self {
+        Self {
+            check_interval_secs: 15,
+            rebalance_distance: 0.30,
+            derisk_distance: 0.15,
+        }
+    }
+}
+
+impl MarginMonitorConfig {
+    /// Override thresholds from `MARGIN_CHECK_INTERVAL_SECS`, `MARGIN_REBALANCE_DISTANCE`
+    /// and `MARGIN_DERISK_DISTANCE`, keeping defaults for the rest.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let parse = |var: &str, default: f64| -> f64 {
+            std::env::var(var)
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .unwrap_or(default)
+        };
+
+        Self {
+            check_interval_secs: parse("MARGIN_CHECK_INTERVAL_SECS", defaults.check_interval_secs as f64).max(1.0) as u64,
+            rebalance_distance: parse("MARGIN_REBALANCE_DISTANCE", defaults.rebalance_distance),
+            derisk_distance: parse("MARGIN_DERISK_DISTANCE", defaults.derisk_distance),
+        }
+    }
+}
+
+/// Margin state of one perp leg
+#[derive(Debug, Clone, Serialize)]
+pub struct LegMargin {
+    pub exchange: String,
+    pub side: OrderSide,
+    /// Held size in coins; zero if the venue reports no position
+    pub size: f64,
+    pub margin: PositionMargin,
+    /// Adverse move to liquidation as a fraction of mark, if the venue reports enough to tell
+    pub distance: Option<f64>,
+}
+
+/// What to do about a trade's margin
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum MarginAction {
+    Hold,
+    /// Move collateral from `from_exchange` to the thin leg on `to_exchange`
+    Rebalance { from_exchange: String, to_exchange: String, distance: f64 },
+    /// Close the trade before the leg on `exchange` is liquidated
+    DeRisk { exchange: String, distance: f64 },
+}
+
+/// Margin assessment of one trade
+#[derive(Debug, Clone, Serialize)]
+pub struct TradeMargin {
+    pub trade_id: String,
+    pub symbol: String,
+    pub legs: Vec<LegMargin>,
+    pub action: MarginAction,
+}
+
+impl TradeMargin {
+    /// Distance to liquidation of the thinnest leg
+    pub fn min_distance(&self) -> Option<f64> {
+        self.legs.iter().filter_map(|leg| leg.distance).min_by(f64::total_cmp)
+    }
+
+    /// `min_distance` in percent, the unit of `ConfluenceMetrics::liquidation_cluster_distance`
+    pub fn liquidation_distance_pct(&self) -> Option<f64> {
+        self.min_distance().map(|distance| distance * 100.0)
+    }
+}
+
+/// Assess `trade`'s perp legs against the venue `positions`.
+///
+/// Legs without a matching position or without a liquidation price are
+/// reported but never trigger an action; missing legs are reconciliation's
+/// concern.
+pub fn assess_trade(trade: &PaperTrade, positions: &[VenuePosition], config: &MarginMonitorConfig) -> TradeMargin {
+    let legs: Vec<LegMargin> = [
+        (&trade.long_exchange, OrderSide::Long, trade.long_instrument()),
+        (&trade.short_exchange, OrderSide::Short, trade.short_instrument()),
+    ]
+    .into_iter()
+    .filter(|(_, _, instrument)| *instrument == InstrumentKind::Perp)
+    .map(|(exchange, side, _)| {
+        let position = positions
+            .iter()
+            .find(|p| &p.exchange == exchange && p.symbol == trade.symbol && p.side == side);
+        let margin = position.map(|p| p.margin).unwrap_or_default();
+        LegMargin {
+            exchange: exchange.clone(),
+            side,
+            size: position.map(|p| p.size).unwrap_or(0.0),
+            margin,
+            distance: margin.liquidation_distance(side),
+        }
+    })
+    .collect();
+
+    let thinnest = legs
+        .iter()
+        .filter_map(|leg| leg.distance.map(|distance| (leg, distance)))
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+    let action = match thinnest {
+        Some((leg, distance)) if distance < config.derisk_distance => {
+            MarginAction::DeRisk { exchange: leg.exchange.clone(), distance }
+        }
+        Some((leg, distance)) if distance < config.rebalance_distance => {
+            let from_exchange = if leg.side == OrderSide::Long { &trade.short_exchange } else { &trade.long_exchange };
+            MarginAction::Rebalance { from_exchange: from_exchange.clone(), to_exchange: leg.exchange.clone(), distance }
+        }
+        _ => MarginAction::Hold,
+    };
+
+    TradeMargin { trade_id: trade.id.clone(), symbol: trade.symbol.clone(), legs, action }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn trade() -> PaperTrade {
+        PaperTrade {
+            entry_long_price: 50_000.0,
+            entry_short_price: 50_010.0,
+            entry_spread_bps: 2.0,
+            position_size_usd: 5_000.0,
+            ..PaperTrade::test_trade("t1", "bybit", "okx")
+        }
+    }
+
+    fn position(exchange: &str, side: OrderSide, mark: f64, liquidation: f64) -> VenuePosition {
+        VenuePosition {
+            exchange: exchange.to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side,
+            size: 0.1,
+            entry_price: 50_000.0,
+            margin: PositionMargin::new(Some(mark), Some(liquidation), Some(1_000.0), Some(5.0)),
+        }
+    }
+
+    #[test]
+    fn test_leverage_overrides_most_specific_first() {
+        let config = LeverageConfig::default()
+            .with_override("okx", 3)
+            .with_override("okx:BTCUSDT", 5);
+        assert_eq!(config.leverage_for("okx", "BTCUSDT"), 5);
+        assert_eq!(config.leverage_for("okx", "ETHUSDT"), 3);
+        assert_eq!(config.leverage_for("bybit", "BTCUSDT"), 1);
+        assert_eq!(config.required_margin("okx", "ETHUSDT", 3_000.0), 1_000.0);
+    }
+
+    #[test]
+    fn test_thin_short_leg_after_rally() {
+        let config = MarginMonitorConfig::default();
+        // Price rallied from 50k to 60k: the long is far from liquidation, the short is 20% away
+        let positions = [
+            position("bybit", OrderSide::Long, 60_000.0, 40_000.0),
+            position("okx", OrderSide::Short, 60_000.0, 72_000.0),
+        ];
+        let assessment = assess_trade(&trade(), &positions, &config);
+        assert_eq!(assessment.action, MarginAction::Rebalance {
+            from_exchange: "bybit".to_string(),
+            to_exchange: "okx".to_string(),
+            distance: 0.2,
+        });
+        assert_eq!(assessment.liquidation_distance_pct(), Some(20.0));
+
+        let positions = [
+            position("bybit", OrderSide::Long, 60_000.0, 40_000.0),
+            position("okx", OrderSide::Short, 60_000.0, 66_000.0),
+        ];
+        assert!(matches!(assess_trade(&trade(), &positions, &config).action, MarginAction::DeRisk { ref exchange, .. } if exchange == "okx"));
+    }
+
+    #[test]
+    fn test_unknown_margin_holds() {
+        let mut unknown = position("okx", OrderSide::Short, 60_000.0, 66_000.0);
+        unknown.margin.liquidation_price = None;
+        let assessment = assess_trade(&trade(), &[unknown], &MarginMonitorConfig::default());
+        assert_eq!(assessment.action, MarginAction::Hold);
+        assert_eq!(assessment.legs.len(), 2);
+        assert_eq!(assessment.legs[0].size, 0.0);
+    }
+}
@@ -5,9 +5,17 @@ pub mod pipeline;
 pub mod symbol_map;
 pub mod opportunity_queue;
 pub mod opportunity_detector;
+pub mod filter_analytics;
+pub mod sharded_detector;
+pub mod symbol_identity;
+pub mod funding_rates;
+pub mod funding_carry;
+pub mod funding_accrual;
+pub mod instruments;
 pub mod thread_pinning;
 pub mod branchless;
 pub mod exchange_fees;
+pub mod fee_schedule;
 pub mod latency;
 pub mod latency_tracker;
 pub mod confluence;
@@ -18,6 +26,19 @@ pub mod portfolio;
 pub mod runner;
 pub mod atomic_execution;
 pub mod execution_backend;
+pub mod execution_error;
+pub mod order_manager;
+pub mod execution_worker;
+pub mod reconciliation;
+pub mod trade_journal;
+pub mod risk_engine;
+pub mod margin;
+pub mod collateral;
+pub mod sliced_execution;
+pub mod exit_policy;
+pub mod exit_executor;
+pub mod fill_accounting;
+pub mod user_stream;
 pub mod paper_trading_backend;
 pub mod testnet_config;
 pub mod testnet;
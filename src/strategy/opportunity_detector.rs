@@ -26,8 +26,18 @@ use crate::strategy::pipeline::MarketConsumer;
 use crate::strategy::market_data::MarketDataStore;
 use crate::strategy::symbol_map::SymbolMap;
 use crate::strategy::opportunity_queue::OpportunityProducer;
-use crate::strategy::types::{ArbitrageOpportunity, ConfluenceMetrics, HardConstraints};
-use crate::strategy::exchange_fees::get_exchange_fee_by_name;
+use crate::strategy::types::{ArbitrageOpportunity, ConfluenceMetrics, HardConstraints, InstrumentKind, Liquidity, MarketUpdate, OpportunityKind};
+use crate::strategy::fee_schedule::FeeSchedule;
+use crate::strategy::filter_analytics::{
+    CostBreakdown, FilterAnalytics, FilterAnalyticsSnapshot, FilterStage, FILTER_ANALYTICS_KEY,
+};
+use crate::strategy::symbol_identity::{
+    SymbolIdentityChecker, SymbolIdentityConfig, SymbolIdentityReport, SYMBOL_IDENTITY_KEY,
+};
+use crate::strategy::funding_carry::{CarryLeg, CarryQuote, FundingCarryConfig, FundingCarryScanner};
+use crate::strategy::funding_rates::FundingRateStore;
+use crate::strategy::instruments::instrument_kind;
+use crossbeam_queue::ArrayQueue;
 use std::sync::Arc;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use redis::aio::MultiplexedConnection;
@@ -64,12 +74,24 @@ pub struct OpportunityDetector {
     /// Configuration: Minimum confidence score
     min_confidence: u8,
     
-    /// Debug: Track filtering reasons
-    filter_count_spread: u64,
-    filter_count_funding: u64,
-    filter_count_confidence: u64,
-    filter_count_profit: u64,
+    /// Per-symbol and per-venue-pair filter rejection analytics
+    filter_analytics: FilterAnalytics,
     last_filter_log: std::time::Instant,
+    
+    /// Cross-venue identity checks (quarantines ticker collisions / rebased contracts)
+    identity_checker: SymbolIdentityChecker,
+    
+    /// Optional Redis write queue for publishing analytics snapshots
+    analytics_publisher: Option<Arc<ArrayQueue<(String, String)>>>,
+    
+    /// Live funding rates; the funding-carry path only runs when this is set
+    funding_store: Option<Arc<FundingRateStore>>,
+    
+    /// Funding-carry pair ranking and per-symbol throttling
+    carry_scanner: FundingCarryScanner,
+    
+    /// Account maker/taker rates used to cost each leg
+    fee_schedule: Arc<FeeSchedule>,
 }
 
 impl OpportunityDetector {
@@ -100,14 +122,62 @@ impl OpportunityDetector {
             min_spread_bps: 10.0,
             min_funding_delta: 0.0001,
             min_confidence: 70,
-            filter_count_spread: 0,
-            filter_count_funding: 0,
-            filter_count_confidence: 0,
-            filter_count_profit: 0,
+            filter_analytics: FilterAnalytics::new(),
             last_filter_log: std::time::Instant::now(),
+            identity_checker: SymbolIdentityChecker::new(SymbolIdentityConfig::from_env()),
+            analytics_publisher: None,
+            funding_store: None,
+            carry_scanner: FundingCarryScanner::new(FundingCarryConfig::from_env()),
+            fee_schedule: Arc::new(FeeSchedule::from_env()),
         }
     }
     
+    /// Enable the funding-carry path using live funding rates from `store`.
+    ///
+    /// Carry opportunities are detected independently of the spread filters:
+    /// they only need a funding differential that beats round-trip costs.
+    pub fn with_funding_store(mut self, store: Arc<FundingRateStore>) -> Self {
+        self.funding_store = Some(store);
+        self
+    }
+    
+    /// Cost legs with a shared fee schedule (refreshed from the venues by the runner).
+    pub fn with_fee_schedule(mut self, schedule: Arc<FeeSchedule>) -> Self {
+        self.fee_schedule = schedule;
+        self
+    }
+    
+    /// Replace the funding-carry entry configuration.
+    pub fn with_carry_config(mut self, config: FundingCarryConfig) -> Self {
+        self.carry_scanner = FundingCarryScanner::new(config);
+        self
+    }
+    
+    /// Replace the symbol identity configuration (thresholds, allow/deny lists).
+    pub fn with_identity_config(mut self, config: SymbolIdentityConfig) -> Self {
+        self.identity_checker = SymbolIdentityChecker::new(config);
+        self
+    }
+    
+    /// Report of venue pairs currently warming, verified or quarantined.
+    pub fn symbol_identity_report(&self) -> SymbolIdentityReport {
+        self.identity_checker.report()
+    }
+    
+    /// Publish filter analytics snapshots through the Redis write queue.
+    ///
+    /// Snapshots are serialized to JSON and pushed under `FILTER_ANALYTICS_KEY`
+    /// every 10 seconds, where the dashboard and monitor pick them up.
+    pub fn with_analytics_publisher(mut self, queue: Arc<ArrayQueue<(String, String)>>) -> Self {
+        self.analytics_publisher = Some(queue);
+        self
+    }
+    
+    /// Snapshot of filter rejection analytics collected so far.
+    pub fn filter_analytics_snapshot(&self) -> FilterAnalyticsSnapshot {
+        self.filter_analytics.snapshot()
+    }
+    
     /// Main detection loop - runs continuously.
     ///
     /// This method runs in a loop, consuming market updates from the pipeline,
@@ -134,22 +204,14 @@ impl OpportunityDetector {
         let mut last_log = std::time::Instant::now();
         
         loop {
-            // Pop market update (non-blocking)
-            if let Some(update) = self.market_consumer.pop() {
+            // Pop and process market update (non-blocking)
+            if self.poll_once() {
                 update_count += 1;
                 
                 // Log every 1000 updates
                 if update_count % 1000 == 0 {
                     eprintln!("[DETECTOR-STATS] Processed {} market updates", update_count);
                 }
-                
-                // Update market data store
-                self.market_data_store.update_from_market_update(&update);
-                
-                // Detect opportunities for this symbol
-                if let Some((exchange, symbol)) = self.symbol_map.get(update.symbol_id) {
-                    self.detect_opportunities_for_symbol(&symbol, &exchange);
-                }
             }
             
             // Log stats every 10 seconds
@@ -158,11 +220,49 @@ impl OpportunityDetector {
                 last_log = std::time::Instant::now();
             }
             
+            // Log and publish filter analytics every 10 seconds
+            self.maybe_report_filter_analytics();
+            
             // Small sleep to avoid busy-waiting (10μs)
             tokio::time::sleep(Duration::from_micros(10)).await;
         }
     }
     
+    /// Pop a single market update from the pipeline and process it.
+    ///
+    /// Returns `true` if an update was processed, `false` if the pipeline was empty.
+    /// This is the building block for both the async `run` loop and the
+    /// pinned shard threads of `ShardedDetector`.
+    #[inline]
+    pub fn poll_once(&mut self) -> bool {
+        match self.market_consumer.pop() {
+            Some(update) => {
+                self.process_update(&update);
+                true
+            }
+            None => false,
+        }
+    }
+    
+    /// Apply a market update to the store and detect opportunities for its symbol.
+    pub fn process_update(&mut self, update: &MarketUpdate) {
+        // Update market data store
+        self.market_data_store.update_from_market_update(update);
+        
+        // Detect opportunities for this symbol
+        if let Some((exchange, symbol)) = self.symbol_map.get(update.symbol_id) {
+            self.detect_opportunities_for_symbol(&symbol, &exchange);
+        }
+    }
+    
+    /// Log and publish filter analytics if the 10-second reporting interval elapsed.
+    pub fn maybe_report_filter_analytics(&mut self) {
+        if self.last_filter_log.elapsed().as_secs() >= 10 {
+            self.report_filter_analytics();
+            self.last_filter_log = std::time::Instant::now();
+        }
+    }
+    
     /// Detect arbitrage opportunities for a specific symbol.
     ///
     /// This method checks all exchange pairs for the given symbol and detects
@@ -177,6 +277,9 @@ impl OpportunityDetector {
             return;
         }
         
+        // Venue pairs that passed the identity check (reused by the carry path)
+        let mut tradeable_pairs: Vec<(usize, usize)> = Vec::new();
+        
         // Check all exchange pairs
         for i in 0..exchanges.len() {
             for j in (i + 1)..exchanges.len() {
@@ -187,12 +290,118 @@ impl OpportunityDetector {
                 if let (Some((bid1, ask1)), Some((bid2, ask2))) = 
                     (self.get_prices(ex1, symbol), self.get_prices(ex2, symbol)) {
                     
-                    // Check both directions
-                    self.check_opportunity(symbol, ex1, ex2, ask1, bid2);
-                    self.check_opportunity(symbol, ex2, ex1, ask2, bid1);
+                    // Skip pairs whose mid-price ratio says they are not the same instrument
+                    let mid1 = (bid1 + ask1) / 2.0;
+                    let mid2 = (bid2 + ask2) / 2.0;
+                    if !self.identity_checker.observe(symbol, ex1, mid1, ex2, mid2) {
+                        continue;
+                    }
+                    tradeable_pairs.push((i, j));
+                    
+                    // Check both directions; spot can only be bought, so a
+                    // spot-perp pair is only checked long spot / short perp
+                    match (instrument_kind(ex1), instrument_kind(ex2)) {
+                        (InstrumentKind::Perp, InstrumentKind::Perp) => {
+                            self.check_opportunity(symbol, ex1, ex2, ask1, bid2);
+                            self.check_opportunity(symbol, ex2, ex1, ask2, bid1);
+                        }
+                        (InstrumentKind::Spot, InstrumentKind::Perp) => {
+                            self.check_opportunity(symbol, ex1, ex2, ask1, bid2);
+                        }
+                        (InstrumentKind::Perp, InstrumentKind::Spot) => {
+                            self.check_opportunity(symbol, ex2, ex1, ask2, bid1);
+                        }
+                        (InstrumentKind::Spot, InstrumentKind::Spot) => {}
+                    }
                 }
             }
         }
+        
+        if !tradeable_pairs.is_empty() {
+            self.check_funding_carry(symbol, &exchanges, &tradeable_pairs);
+        }
+    }
+    
+    /// Rank venue pairs by funding carry and publish the best one, if any.
+    ///
+    /// Runs independently of `min_spread_bps`: carry trades are entered on the
+    /// funding differential alone, with the price spread only bounded by
+    /// `max_entry_basis_bps`.
+    fn check_funding_carry(&mut self, symbol: &str, exchanges: &[String], tradeable_pairs: &[(usize, usize)]) {
+        let store = match self.funding_store {
+            Some(ref store) => store.clone(),
+            None => return,
+        };
+        
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let max_age = self.carry_scanner.config().max_rate_age_secs;
+        
+        // Legs with both fresh funding and a valid book
+        let legs: Vec<CarryLeg> = exchanges
+            .iter()
+            .filter_map(|ex| {
+                let funding = store.get_fresh_leg(ex, symbol, now, max_age)?;
+                let (bid, ask) = self.get_prices(ex, symbol)?;
+                // Which leg ends up the taker hedge is only known once paired
+                let fee_bps = self.fee_schedule.round_trip_bps(ex, symbol, Liquidity::Taker);
+                Some(CarryLeg { exchange: ex.as_str(), funding, bid, ask, fee_bps })
+            })
+            .collect();
+        
+        if legs.len() < 2 {
+            return;
+        }
+        
+        let is_tradeable = |a: &str, b: &str| {
+            tradeable_pairs.iter().any(|&(i, j)| {
+                let (x, y) = (exchanges[i].as_str(), exchanges[j].as_str());
+                (x == a && y == b) || (x == b && y == a)
+            })
+        };
+        
+        let quote = match self.carry_scanner.select(symbol, &legs, now, is_tradeable) {
+            Some(quote) => quote,
+            None => return,
+        };
+        
+        let opportunity = self.build_carry_opportunity(symbol, &quote, now);
+        eprintln!("[DETECTOR] ✅ Funding carry detected: {} | {}->{} | Carry: {:.4}%/8h | Net: {:.1}% APR | Basis: {:.2}bps",
+            opportunity.symbol, opportunity.long_exchange, opportunity.short_exchange,
+            quote.carry_8h() * 100.0, quote.net_apr * 100.0, quote.basis_bps);
+        self.opportunity_producer.push(opportunity);
+    }
+    
+    /// Convert a carry quote into an `ArbitrageOpportunity` of kind `FundingCarry`.
+    fn build_carry_opportunity(&self, symbol: &str, quote: &CarryQuote, now: u64) -> ArbitrageOpportunity {
+        let horizon_hours = self.carry_scanner.config().holding_horizon_hours;
+        let depth_long = self.get_depth(&quote.long_exchange, symbol);
+        let depth_short = self.get_depth(&quote.short_exchange, symbol);
+        let funding_delta = quote.carry_8h();
+        let metrics = self.build_metrics(quote.basis_bps, funding_delta, depth_long, depth_short);
+        
+        // Net carry over the holding horizon, in bps of notional
+        let projected_profit_bps = quote.expected_profit_usd(10000.0, horizon_hours);
+        
+        ArbitrageOpportunity {
+            symbol: symbol.to_string(),
+            long_exchange: quote.long_exchange.clone(),
+            short_exchange: quote.short_exchange.clone(),
+            long_price: quote.long_price,
+            short_price: quote.short_price,
+            spread_bps: quote.basis_bps,
+            funding_delta_8h: funding_delta,
+            confidence_score: self.calculate_confidence(quote.basis_bps.max(0.0), funding_delta),
+            projected_profit_usd: quote.expected_profit_usd(1000.0, horizon_hours), // Assume $1000 position
+            projected_profit_after_slippage: projected_profit_bps,
+            metrics,
+            order_book_depth_long: depth_long,
+            order_book_depth_short: depth_short,
+            timestamp: Some(now),
+            kind: OpportunityKind::FundingCarry,
+        }
     }
     
     /// Check if there's an arbitrage opportunity between two exchanges.
@@ -211,16 +420,25 @@ impl OpportunityDetector {
         
         // Check minimum spread threshold (10 bps)
         if spread_bps <= self.min_spread_bps {
-            self.filter_count_spread += 1;
+            self.filter_analytics.record_rejection(
+                symbol, long_exchange, short_exchange,
+                FilterStage::Spread, self.min_spread_bps - spread_bps,
+            );
             return;
         }
         
-        // Get funding rates and calculate delta
-        let funding_delta = self.get_funding_delta(symbol, long_exchange, short_exchange);
+        // Get funding rates and calculate delta; without fresh rates on both
+        // legs the funding filter cannot judge the pair and is skipped
+        let funding = self.get_funding_delta(symbol, long_exchange, short_exchange);
+        let funding_delta = funding.unwrap_or(0.0);
         
         // Check minimum funding delta (0.0001)
-        if funding_delta.abs() < self.min_funding_delta {
-            self.filter_count_funding += 1;
+        if funding.is_some() && funding_delta.abs() < self.min_funding_delta {
+            // Shortfall expressed in bps of funding rate
+            self.filter_analytics.record_rejection(
+                symbol, long_exchange, short_exchange,
+                FilterStage::Funding, (self.min_funding_delta - funding_delta.abs()) * 10000.0,
+            );
             return;
         }
         
@@ -229,56 +447,36 @@ impl OpportunityDetector {
         
         // Filter opportunities below 70 confidence
         if confidence < self.min_confidence {
-            self.filter_count_confidence += 1;
+            self.filter_analytics.record_rejection(
+                symbol, long_exchange, short_exchange,
+                FilterStage::Confidence, (self.min_confidence - confidence) as f64,
+            );
             return;
         }
         
-        // Calculate fees (taker fees for both exchanges) - already in basis points
-        let long_fee_bps = get_exchange_fee_by_name(long_exchange);
-        let short_fee_bps = get_exchange_fee_by_name(short_exchange);
-        let total_fees_bps = long_fee_bps + short_fee_bps;
-        
-        // Estimate slippage (3 bps)
-        let slippage_bps = 3.0;
-        
-        // Estimate funding cost (10 bps)
-        let funding_cost_bps = 10.0;
+        // Entry and exit fees of each leg at the liquidity it is expected to take
+        // Slippage estimated at 3 bps, funding cost at 10 bps
+        let (long_fee_bps, short_fee_bps) = self.fee_schedule.spread_round_trip_bps(long_exchange, short_exchange, symbol);
+        let costs = CostBreakdown {
+            long_fee_bps,
+            short_fee_bps,
+            slippage_bps: 3.0,
+            funding_cost_bps: 10.0,
+        };
         
         // Calculate projected profit after costs
-        let projected_profit_bps = spread_bps - total_fees_bps - slippage_bps - funding_cost_bps;
+        let projected_profit_bps = spread_bps - costs.total_bps();
         
         // Filter unprofitable opportunities (profit ≤ 0)
         if projected_profit_bps <= 0.0 {
-            self.filter_count_profit += 1;
+            self.filter_analytics.record_profit_rejection(
+                symbol, long_exchange, short_exchange,
+                -projected_profit_bps, &costs,
+            );
             return;
         }
         
-        // Log filter stats every 10 seconds
-        if self.last_filter_log.elapsed().as_secs() >= 10 {
-            eprintln!("[DETECTOR-FILTERS] Spread: {} | Funding: {} | Confidence: {} | Profit: {}", 
-                self.filter_count_spread, self.filter_count_funding, 
-                self.filter_count_confidence, self.filter_count_profit);
-            self.last_filter_log = std::time::Instant::now();
-        }
-        
-        // Calculate fees (taker fees for both exchanges) - already in basis points
-        let long_fee_bps = get_exchange_fee_by_name(long_exchange);
-        let short_fee_bps = get_exchange_fee_by_name(short_exchange);
-        let total_fees_bps = long_fee_bps + short_fee_bps;
-        
-        // Estimate slippage (3 bps)
-        let slippage_bps = 3.0;
-        
-        // Estimate funding cost (10 bps)
-        let funding_cost_bps = 10.0;
-        
-        // Calculate projected profit after costs
-        let projected_profit_bps = spread_bps - total_fees_bps - slippage_bps - funding_cost_bps;
-        
-        // Filter unprofitable opportunities (profit ≤ 0)
-        if projected_profit_bps <= 0.0 {
-            return;
-        }
+        self.filter_analytics.record_pass(symbol, long_exchange, short_exchange);
         
         // Get order book depths
         let depth_long = self.get_depth(long_exchange, symbol);
@@ -311,6 +509,7 @@ impl OpportunityDetector {
                 .duration_since(UNIX_EPOCH)
                 .unwrap()
                 .as_secs()),
+                kind: OpportunityKind::SpreadArbitrage,
         };
         
         // Push to OpportunityQueue via producer
@@ -319,6 +518,32 @@ impl OpportunityDetector {
             opportunity.spread_bps, opportunity.confidence_score);
         self.opportunity_producer.push(opportunity);
     }
+    /// Log filter totals and publish analytics and identity reports if a publisher is set.
+    fn report_filter_analytics(&self) {
+        let totals = self.filter_analytics.totals();
+        eprintln!("[DETECTOR-FILTERS] Spread: {} | Funding: {} | Confidence: {} | Profit: {} | Passed: {}", 
+            totals.rejections_for(FilterStage::Spread),
+            totals.rejections_for(FilterStage::Funding),
+            totals.rejections_for(FilterStage::Confidence),
+            totals.rejections_for(FilterStage::Profit),
+            totals.passed);
+        
+        if let Some(queue) = &self.analytics_publisher {
+            match serde_json::to_string(&self.filter_analytics.snapshot()) {
+                Ok(json) => {
+                    if queue.push((FILTER_ANALYTICS_KEY.to_string(), json)).is_err() {
+                        eprintln!("[DETECTOR-FILTERS] Redis queue full, dropping analytics snapshot");
+                    }
+                }
+                Err(e) => eprintln!("[DETECTOR-FILTERS] Failed to serialize analytics: {}", e),
+            }
+            if let Ok(json) = serde_json::to_string(&self.identity_checker.report()) {
+                let _ = queue.push((SYMBOL_IDENTITY_KEY.to_string(), json));
+            }
+        }
+    }
+    
+    /// Calculate confidence score for an opportunity.
     ///
     /// Confidence scoring:
     /// - Spread component: 50% weight
@@ -380,7 +605,8 @@ impl OpportunityDetector {
         // Get all known exchanges from symbol_map
         let exchanges = vec![
             "binance", "bybit", "okx", "kucoin", "bitget", 
-            "gateio", "hyperliquid", "paradex"
+            "gateio", "hyperliquid", "paradex",
+            "bybit_spot", "okx_spot", "bitget_spot"
         ];
         
         // Filter to only exchanges that have VALID data for this symbol
@@ -413,15 +639,17 @@ impl OpportunityDetector {
         Some((bid, ask))
     }
     
-    /// Get funding rate delta between two exchanges.
+    /// Funding collected per 8 hours by the trade (short rate − long rate),
+    /// from the live funding store.
     ///
-    /// Note: In the current implementation, funding rates are not available
-    /// in the streaming pipeline. This returns a placeholder value.
-    /// TODO: Integrate funding rates into the streaming pipeline.
-    fn get_funding_delta(&self, _symbol: &str, _long_exchange: &str, _short_exchange: &str) -> f64 {
-        // Placeholder: Return a small positive value to pass the threshold
-        // In production, this should fetch actual funding rates
-        0.0002
+    /// `None` without a store or when either leg's rate is missing or stale.
+    fn get_funding_delta(&self, symbol: &str, long_exchange: &str, short_exchange: &str) -> Option<f64> {
+        let store = self.funding_store.as_ref()?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let max_age = self.carry_scanner.config().max_rate_age_secs;
+        let long = store.get_fresh_leg(long_exchange, symbol, now, max_age)?;
+        let short = store.get_fresh_leg(short_exchange, symbol, now, max_age)?;
+        Some(short.rate_8h() - long.rate_8h())
     }
     
     /// Get order book depth for a symbol on an exchange.
@@ -441,7 +669,6 @@ mod tests {
     use super::*;
     use crate::strategy::pipeline::MarketPipeline;
     use crate::strategy::opportunity_queue::OpportunityQueue;
-    use crate::strategy::types::MarketUpdate;
     
     #[test]
     fn test_detector_initializes_correctly() {
@@ -777,6 +1004,170 @@ mod tests {
         }
     }
     
+    // Filter rejection analytics
+    
+    #[test]
+    fn test_filter_analytics_records_rejections() {
+        let pipeline = MarketPipeline::new();
+        let consumer = pipeline.consumer();
+        let symbol_map = Arc::new(SymbolMap::new());
+        let queue = OpportunityQueue::new();
+        let producer = queue.producer();
+        
+        let mut detector = OpportunityDetector::new(consumer, symbol_map, producer);
+        
+        // 1 bps spread -> spread rejection with 9 bps shortfall
+        detector.check_opportunity("BTCUSDT", "bybit", "okx", 50000.0, 50005.0);
+        // 50 bps spread -> passes
+        detector.check_opportunity("BTCUSDT", "bybit", "okx", 50000.0, 50250.0);
+        
+        let snapshot = detector.filter_analytics_snapshot();
+        let pair = &snapshot.by_venue_pair["bybit->okx"];
+        assert_eq!(pair.rejections_for(FilterStage::Spread), 1);
+        assert!((pair.avg_shortfall(FilterStage::Spread) - 9.0).abs() < 1e-6);
+        assert_eq!(pair.passed, 1);
+        assert_eq!(snapshot.by_symbol["BTCUSDT"].total_rejections(), 1);
+    }
+    
+    #[test]
+    fn test_spread_funding_filter_uses_live_rates() {
+        let pipeline = MarketPipeline::new();
+        let consumer = pipeline.consumer();
+        let symbol_map = Arc::new(SymbolMap::new());
+        let queue = OpportunityQueue::new();
+        let producer = queue.producer();
+        let funding_store = Arc::new(FundingRateStore::new());
+        
+        let mut detector = OpportunityDetector::new(consumer, symbol_map, producer)
+            .with_funding_store(funding_store.clone());
+        
+        // Equal funding on both legs: no differential, rejected at the funding stage
+        funding_store.update("bybit", "BTCUSDT", 0.0001);
+        funding_store.update("okx", "BTCUSDT", 0.0001);
+        detector.check_opportunity("BTCUSDT", "bybit", "okx", 50000.0, 50250.0);
+        let pair = &detector.filter_analytics_snapshot().by_venue_pair["bybit->okx"];
+        assert_eq!(pair.rejections_for(FilterStage::Funding), 1);
+        
+        // Shorting the venue that pays more collects the differential
+        funding_store.update("okx", "BTCUSDT", 0.0004);
+        detector.check_opportunity("BTCUSDT", "bybit", "okx", 50000.0, 50250.0);
+        let opp = queue.consumer().pop().expect("Opportunity should pass the funding filter");
+        assert!((opp.funding_delta_8h - 0.0003).abs() < 1e-12);
+    }
+    
+    #[test]
+    fn test_filter_analytics_published_to_queue() {
+        let pipeline = MarketPipeline::new();
+        let consumer = pipeline.consumer();
+        let symbol_map = Arc::new(SymbolMap::new());
+        let queue = OpportunityQueue::new();
+        let producer = queue.producer();
+        let redis_queue = Arc::new(ArrayQueue::new(16));
+        
+        let mut detector = OpportunityDetector::new(consumer, symbol_map, producer)
+            .with_analytics_publisher(redis_queue.clone());
+        
+        detector.check_opportunity("BTCUSDT", "bybit", "okx", 50000.0, 50005.0);
+        detector.report_filter_analytics();
+        
+        let (key, json) = redis_queue.pop().expect("snapshot should be published");
+        assert_eq!(key, FILTER_ANALYTICS_KEY);
+        let snapshot: FilterAnalyticsSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(snapshot.totals.rejections_for(FilterStage::Spread), 1);
+    }
+    
+    #[test]
+    fn test_rebased_contract_pair_is_skipped() {
+        let pipeline = MarketPipeline::new();
+        let consumer = pipeline.consumer();
+        let symbol_map = Arc::new(SymbolMap::new());
+        let queue = OpportunityQueue::new();
+        let producer = queue.producer();
+        
+        let mut detector = OpportunityDetector::new(consumer, symbol_map.clone(), producer);
+        
+        // 1000PEPE on bybit vs PEPE on okx: a fake ~1000x "spread"
+        let bybit_id = symbol_map.get_or_insert("bybit", "PEPEUSDT");
+        let okx_id = symbol_map.get_or_insert("okx", "PEPEUSDT");
+        detector.market_data_store.update(bybit_id, 0.01120, 0.01121, 1000000);
+        detector.market_data_store.update(okx_id, 0.0000112, 0.00001121, 1000000);
+        
+        detector.detect_opportunities_for_symbol("PEPEUSDT", "bybit");
+        
+        assert!(queue.consumer().pop().is_none(), "Quarantined pair must not produce opportunities");
+        let report = detector.symbol_identity_report();
+        assert_eq!(report.quarantined.len(), 1);
+        assert_eq!(report.quarantined[0].symbol, "PEPEUSDT");
+    }
+    
+    #[test]
+    fn test_funding_carry_detected_without_spread() {
+        let pipeline = MarketPipeline::new();
+        let consumer = pipeline.consumer();
+        let symbol_map = Arc::new(SymbolMap::new());
+        let queue = OpportunityQueue::new();
+        let producer = queue.producer();
+        let funding_store = Arc::new(FundingRateStore::new());
+        
+        let mut detector = OpportunityDetector::new(consumer, symbol_map.clone(), producer)
+            .with_carry_config(FundingCarryConfig::default())
+            .with_funding_store(funding_store.clone());
+        
+        // Same price on both venues: the spread path rejects it
+        let bybit_id = symbol_map.get_or_insert("bybit", "BTCUSDT");
+        let okx_id = symbol_map.get_or_insert("okx", "BTCUSDT");
+        detector.market_data_store.update(bybit_id, 49999.0, 50000.0, 1000000);
+        detector.market_data_store.update(okx_id, 49999.0, 50000.0, 1000000);
+        
+        // Longs on okx pay 5bp/8h, bybit longs receive 1bp/8h
+        funding_store.update("bybit", "BTCUSDT", -0.0001);
+        funding_store.update("okx", "BTCUSDT", 0.0005);
+        
+        detector.detect_opportunities_for_symbol("BTCUSDT", "bybit");
+        
+        let opp = queue.consumer().pop().expect("Funding carry should be detected");
+        assert_eq!(opp.kind, OpportunityKind::FundingCarry);
+        assert_eq!(opp.long_exchange, "bybit");
+        assert_eq!(opp.short_exchange, "okx");
+        assert!((opp.funding_delta_8h - 0.0006).abs() < 1e-12);
+        assert!(opp.projected_profit_usd > 0.0);
+        assert!(queue.consumer().pop().is_none(), "Only the best venue pair is emitted");
+        
+        // Throttled on the next update
+        detector.detect_opportunities_for_symbol("BTCUSDT", "okx");
+        assert!(queue.consumer().pop().is_none());
+    }
+    
+    #[test]
+    fn test_spot_perp_only_long_spot() {
+        let pipeline = MarketPipeline::new();
+        let consumer = pipeline.consumer();
+        let symbol_map = Arc::new(SymbolMap::new());
+        let queue = OpportunityQueue::new();
+        let producer = queue.producer();
+        
+        let mut detector = OpportunityDetector::new(consumer, symbol_map.clone(), producer);
+        
+        // Spot rich vs perp by 50 bps: would need a spot short, so nothing is emitted
+        let spot_id = symbol_map.get_or_insert("bybit_spot", "BTCUSDT");
+        let perp_id = symbol_map.get_or_insert("bybit", "BTCUSDT");
+        detector.market_data_store.update(spot_id, 50250.0, 50260.0, 1000000);
+        detector.market_data_store.update(perp_id, 49990.0, 50000.0, 1000000);
+        detector.detect_opportunities_for_symbol("BTCUSDT", "bybit");
+        assert!(queue.consumer().pop().is_none());
+        
+        // Perp rich vs spot: long spot, short perp
+        detector.market_data_store.update(spot_id, 49990.0, 50000.0, 1000000);
+        detector.market_data_store.update(perp_id, 50250.0, 50260.0, 1000000);
+        detector.detect_opportunities_for_symbol("BTCUSDT", "bybit");
+        let opp = queue.consumer().pop().expect("Cash-and-carry spread should be detected");
+        assert_eq!(opp.long_exchange, "bybit_spot");
+        assert_eq!(opp.short_exchange, "bybit");
+        assert_eq!(opp.long_instrument(), InstrumentKind::Spot);
+        assert_eq!(opp.short_instrument(), InstrumentKind::Perp);
+        assert!(opp.is_spot_perp());
+    }
+    
     #[tokio::test]
     async fn test_opportunities_reach_queue() {
         let pipeline = MarketPipeline::new();
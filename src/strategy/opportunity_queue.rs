@@ -255,7 +255,7 @@ impl Clone for OpportunityConsumer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::strategy::types::{ConfluenceMetrics, HardConstraints};
+    use crate::strategy::types::{ConfluenceMetrics, HardConstraints, OpportunityKind};
     
     fn create_test_opportunity(symbol: &str, spread_bps: f64) -> ArbitrageOpportunity {
         ArbitrageOpportunity {
@@ -288,6 +288,7 @@ mod tests {
             order_book_depth_long: 10000.0,
             order_book_depth_short: 10000.0,
             timestamp: Some(1234567890),
+            kind: OpportunityKind::SpreadArbitrage,
         }
     }
     
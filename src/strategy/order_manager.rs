@@ -0,0 +1,1077 @@
+//! Order Management System
+//!
+//! Single owner of every order the strategy sends. Each logical order gets a
+//! client order ID generated here; the OMS tracks it through an explicit state
+//! machine, records its fills and average fill price, and maps exchange order
+//! IDs back to it.
+//!
+//! ```text
+//! New ──► Acked ──► PartiallyFilled ──► Filled
+//!  │        │              │
+//!  │        └──────────────┴──► Cancelled
+//!  ├──► Filled   (immediate fills, e.g. market orders)
+//!  └──► Rejected
+//! ```
+//!
+//! `ManagedBackend` wraps any `ExecutionBackend` so all order traffic goes
+//! through the OMS without changing call sites. Submissions are idempotent per
+//! caller reference (`SimulatedOrder::id`): once the exchange has accepted an
+//! order, re-submitting the same reference returns the tracked order instead
+//! of sending a second one. Venues that support it also receive the client
+//! order ID (Bybit `orderLinkId`, Binance `newClientOrderId`, ...) so they can
+//! reject duplicates themselves; that covers retries after an ambiguous
+//! failure such as a timeout, where the order may already be live.
+//!
+//! While a venue's private user-data stream is connected (see `user_stream`),
+//! its order and execution events drive the state machine and status queries
+//! are answered from the OMS. REST polling then only runs as a periodic
+//! reconciliation (`RECONCILE_INTERVAL_SECS`) for orders the stream has not
+//! finished.
+//!
+//! With a `TradeJournal` attached (`with_journal`), every transition is also
+//! appended to the journal; submissions are journaled before they are sent.
+//!
+//! With a `RiskEngine` attached (`with_risk_engine`), every new submission is
+//! checked against it first and refused orders end up `Rejected`; fills are
+//! booked into its exposure.
+
+use crate::strategy::execution_backend::{cancel_replace, AmendMethod, AmendedOrder, BackendCapabilities, ExecutionBackend};
+use crate::strategy::execution_error::ExecutionError;
+use crate::strategy::risk_engine::{RiskEngine, RiskViolation};
+use crate::strategy::trade_journal::{current_purpose, current_scope, JournalEvent, TradeJournal};
+use crate::strategy::types::{FeeRates, OrderBookDepth, OrderStatus, OrderStatusInfo, OrderType, SimulatedOrder, FundingPayment, VenuePosition};
+use crate::strategy::user_stream::{PositionUpdate, StreamOrderStatus, UserStreamEvent};
+use dashmap::DashMap;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::watch;
+
+/// Prefix of generated client order IDs
+pub const CLIENT_ORDER_ID_PREFIX: &str = "arb";
+
+/// Quantity below which an order counts as completely filled
+const FILL_EPSILON: f64 = 1e-9;
+
+/// How long a stream-tracked open order may go without a REST status check
+pub const RECONCILE_INTERVAL_SECS: u64 = 2;
+
+/// How long a status query on a fresh streamed market order waits for its fill event
+const MARKET_FILL_WAIT: Duration = Duration::from_millis(500);
+
+/// Lifecycle state of a managed order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum OrderState {
+    /// Created locally, not yet accepted by the exchange
+    New,
+    /// Accepted by the exchange, nothing filled yet
+    Acked,
+    /// Some quantity filled, remainder still working
+    PartiallyFilled,
+    /// Completely filled (terminal)
+    Filled,
+    /// Cancelled with any remainder unfilled (terminal)
+    Cancelled,
+    /// Refused before reaching the book (terminal)
+    Rejected,
+}
+
+impl OrderState {
+    /// Terminal states never change again.
+    pub fn is_terminal(self) -> bool {
+        matches!(self, OrderState::Filled | OrderState::Cancelled | OrderState::Rejected)
+    }
+
+    /// Whether the exchange has accepted the order at some point.
+    pub fn is_accepted(self) -> bool {
+        matches!(self, OrderState::Acked | OrderState::PartiallyFilled | OrderState::Filled | OrderState::Cancelled)
+    }
+
+    /// Allowed transitions of the state machine.
+    pub fn can_transition_to(self, next: OrderState) -> bool {
+        use OrderState::*;
+        matches!(
+            (self, next),
+            (New, Acked | PartiallyFilled | Filled | Rejected | Cancelled)
+                | (Acked, PartiallyFilled | Filled | Cancelled)
+                | (PartiallyFilled, PartiallyFilled | Filled | Cancelled)
+        )
+    }
+}
+
+impl fmt::Display for OrderState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// One execution against a managed order.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Fill {
+    pub quantity: f64,
+    pub price: f64,
+    pub timestamp: u64,
+}
+
+/// An order owned by the OMS.
+#[derive(Debug, Clone, Serialize)]
+pub struct ManagedOrder {
+    /// Client order ID generated by the OMS
+    pub client_order_id: String,
+    /// Caller's reference (`SimulatedOrder::id` at submission), used for idempotency
+    pub request_ref: Option<String>,
+    /// Exchange-assigned order ID once acknowledged
+    pub exchange_order_id: Option<String>,
+    /// Trade the order belongs to (journal scope at registration)
+    pub trade_id: Option<String>,
+    /// Latest snapshot of the order as returned by the backend
+    pub order: SimulatedOrder,
+    pub state: OrderState,
+    pub fills: Vec<Fill>,
+    pub filled_quantity: f64,
+    pub avg_fill_price: Option<f64>,
+    /// Number of times the order was sent to the backend
+    pub submissions: u32,
+    pub last_error: Option<String>,
+    pub created_at: u64,
+    pub updated_at: u64,
+    /// Last time the order was confirmed by the backend over REST
+    pub reconciled_at: u64,
+}
+
+impl ManagedOrder {
+    /// Quantity still to be filled.
+    pub fn remaining_quantity(&self) -> f64 {
+        (self.order.size - self.filled_quantity).max(0.0)
+    }
+
+    /// Status in the shape returned by `ExecutionBackend::get_order_status_detailed`.
+    pub fn status_info(&self) -> OrderStatusInfo {
+        let status = match self.state {
+            OrderState::Filled => OrderStatus::Filled,
+            OrderState::Cancelled | OrderState::Rejected => OrderStatus::Cancelled,
+            OrderState::New | OrderState::Acked | OrderState::PartiallyFilled => OrderStatus::Pending,
+        };
+        OrderStatusInfo::new(status, self.filled_quantity, self.order.size)
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Central registry of orders keyed by client order ID.
+#[derive(Debug)]
+pub struct OrderManager {
+    orders: DashMap<String, ManagedOrder>,
+    /// Caller reference -> client order ID
+    by_request_ref: DashMap<String, String>,
+    /// Exchange order ID -> client order ID
+    by_exchange_id: DashMap<String, String>,
+    sequence: AtomicU64,
+    /// Venues whose private user-data stream is currently connected
+    live_streams: DashMap<String, ()>,
+    /// Execution IDs already booked (`venue:execution_id`)
+    seen_executions: DashMap<String, ()>,
+    /// Latest pushed position per (venue, symbol)
+    positions: DashMap<(String, String), PositionUpdate>,
+    /// Bumped on every order change so waiters can react to events
+    updates: watch::Sender<u64>,
+    journal: Option<Arc<TradeJournal>>,
+    risk: Option<Arc<RiskEngine>>,
+}
+
+impl Default for OrderManager {
+    fn default() -> Self {
+        let (updates, _) = watch::channel(0);
+        Self {
+            orders: DashMap::new(),
+            by_request_ref: DashMap::new(),
+            by_exchange_id: DashMap::new(),
+            sequence: AtomicU64::new(0),
+            live_streams: DashMap::new(),
+            seen_executions: DashMap::new(),
+            positions: DashMap::new(),
+            updates,
+            journal: None,
+            risk: None,
+        }
+    }
+}
+
+impl OrderManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Journal every order transition to `journal`.
+    pub fn with_journal(mut self, journal: Arc<TradeJournal>) -> Self {
+        self.journal = Some(journal);
+        self
+    }
+
+    /// Check submissions against `risk` and book fills into its exposure.
+    pub fn with_risk_engine(mut self, risk: Arc<RiskEngine>) -> Self {
+        self.risk = Some(risk);
+        self
+    }
+
+    /// Pre-trade risk check of registered order `client_order_id` against the
+    /// other orders still working.
+    pub fn check_risk(&self, client_order_id: &str, order: &SimulatedOrder) -> Result<(), RiskViolation> {
+        let Some(risk) = &self.risk else { return Ok(()) };
+        let working: Vec<SimulatedOrder> = self.orders
+            .iter()
+            .filter(|o| !o.state.is_terminal() && o.client_order_id != client_order_id)
+            .map(|o| SimulatedOrder { size: o.remaining_quantity(), ..o.order.clone() })
+            .collect();
+        risk.check_order(order, current_purpose(order.order_type), &working)
+    }
+
+    fn journal(&self, event: impl FnOnce() -> JournalEvent) {
+        if let Some(journal) = &self.journal {
+            journal.record(event());
+        }
+    }
+
+    /// Wait until journaled transitions are on disk (write-ahead for submissions).
+    pub async fn flush_journal(&self) {
+        if let Some(journal) = &self.journal {
+            if let Err(e) = journal.flush().await {
+                eprintln!("[JOURNAL] ❌ Failed to flush {}: {}", journal.path().display(), e);
+            }
+        }
+    }
+
+    /// Trade of a tracked order, for journal events
+    fn trade_of(&self, client_order_id: &str) -> Option<String> {
+        self.orders.get(client_order_id).and_then(|o| o.trade_id.clone())
+    }
+
+    fn notify(&self) {
+        self.updates.send_modify(|version| *version = version.wrapping_add(1));
+    }
+
+    /// Receiver that changes whenever any managed order changes.
+    pub fn subscribe(&self) -> watch::Receiver<u64> {
+        self.updates.subscribe()
+    }
+
+    /// Record whether `venue`'s private stream is connected.
+    pub fn set_stream_connected(&self, venue: &str, connected: bool) {
+        if connected {
+            self.live_streams.insert(venue.to_string(), ());
+        } else {
+            self.live_streams.remove(venue);
+        }
+    }
+
+    /// Whether order state for `venue` is being pushed by a private stream.
+    pub fn has_live_stream(&self, venue: &str) -> bool {
+        self.live_streams.contains_key(venue)
+    }
+
+    /// Whether any venue's order state is being pushed by a private stream.
+    pub fn has_any_live_stream(&self) -> bool {
+        !self.live_streams.is_empty()
+    }
+
+    /// Latest position pushed for `symbol` on `venue`.
+    pub fn position(&self, venue: &str, symbol: &str) -> Option<PositionUpdate> {
+        self.positions.get(&(venue.to_string(), symbol.to_string())).map(|p| p.clone())
+    }
+
+    /// Generate a unique client order ID (`arb-<ms hex>-<seq>`, at most 36 chars).
+    pub fn next_client_order_id(&self) -> String {
+        let millis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64;
+        let seq = self.sequence.fetch_add(1, Ordering::Relaxed);
+        format!("{}-{:x}-{}", CLIENT_ORDER_ID_PREFIX, millis, seq)
+    }
+
+    /// Register an order and return its client order ID.
+    ///
+    /// A non-empty `order.id` is kept as the caller reference: registering the
+    /// same reference again returns the existing client order ID, or an error
+    /// if the side or size differ.
+    pub fn register(&self, order: &SimulatedOrder) -> Result<String, String> {
+        if order.id.is_empty() {
+            return Ok(self.insert_new(order, None));
+        }
+
+        // Hold the reference entry while inserting so concurrent retries share one order
+        match self.by_request_ref.entry(order.id.clone()) {
+            dashmap::mapref::entry::Entry::Occupied(existing) => {
+                let client_order_id = existing.get().clone();
+                if let Some(managed) = self.orders.get(&client_order_id) {
+                    if managed.order.side != order.side || (managed.order.size - order.size).abs() > FILL_EPSILON {
+                        return Err(format!("Order reference {} reused for a different order", order.id));
+                    }
+                }
+                Ok(client_order_id)
+            }
+            dashmap::mapref::entry::Entry::Vacant(slot) => {
+                let client_order_id = self.insert_new(order, Some(order.id.clone()));
+                slot.insert(client_order_id.clone());
+                Ok(client_order_id)
+            }
+        }
+    }
+
+    fn insert_new(&self, order: &SimulatedOrder, request_ref: Option<String>) -> String {
+        let client_order_id = self.next_client_order_id();
+        let now = now_secs();
+        let mut snapshot = order.clone();
+        snapshot.id = client_order_id.clone();
+        self.orders.insert(client_order_id.clone(), ManagedOrder {
+            client_order_id: client_order_id.clone(),
+            request_ref,
+            exchange_order_id: None,
+            trade_id: current_scope().map(|(trade_id, _)| trade_id),
+            order: snapshot,
+            state: OrderState::New,
+            fills: Vec::new(),
+            filled_quantity: 0.0,
+            avg_fill_price: None,
+            submissions: 0,
+            last_error: None,
+            created_at: now,
+            updated_at: now,
+            reconciled_at: now,
+        });
+        client_order_id
+    }
+
+    /// Resolve a client order ID, exchange order ID or caller reference.
+    pub fn resolve(&self, order_id: &str) -> Option<String> {
+        if self.orders.contains_key(order_id) {
+            return Some(order_id.to_string());
+        }
+        self.by_exchange_id
+            .get(order_id)
+            .map(|c| c.clone())
+            .or_else(|| self.by_request_ref.get(order_id).map(|c| c.clone()))
+    }
+
+    /// Snapshot of an order by any of its IDs.
+    pub fn get(&self, order_id: &str) -> Option<ManagedOrder> {
+        let client_order_id = self.resolve(order_id)?;
+        self.orders.get(&client_order_id).map(|o| o.clone())
+    }
+
+    /// Orders that are not in a terminal state.
+    pub fn open_orders(&self) -> Vec<ManagedOrder> {
+        self.orders
+            .iter()
+            .filter(|o| !o.state.is_terminal())
+            .map(|o| o.clone())
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.orders.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.orders.is_empty()
+    }
+
+    /// Move an order to `next`, rejecting transitions the state machine does not allow.
+    fn transition(order: &mut ManagedOrder, next: OrderState) -> Result<(), String> {
+        if order.state == next {
+            return Ok(());
+        }
+        if !order.state.can_transition_to(next) {
+            return Err(format!("Invalid order transition {} -> {} for {}", order.state, next, order.client_order_id));
+        }
+        order.state = next;
+        order.updated_at = now_secs();
+        Ok(())
+    }
+
+    /// Count a submission attempt (journaled before the request is sent).
+    pub fn on_submit(&self, client_order_id: &str) {
+        let sent = self.orders.get_mut(client_order_id).map(|mut order| {
+            order.submissions += 1;
+            order.updated_at = now_secs();
+            (order.trade_id.clone(), order.order.clone())
+        });
+        if let Some((trade_id, order)) = sent {
+            self.journal(|| JournalEvent::OrderSent { trade_id, purpose: current_purpose(order.order_type), order });
+        }
+    }
+
+    /// Record that a submission failed without a definitive answer; the order stays `New`.
+    pub fn on_submit_error(&self, client_order_id: &str, error: &str) {
+        if let Some(mut order) = self.orders.get_mut(client_order_id) {
+            order.last_error = Some(error.to_string());
+            order.updated_at = now_secs();
+        }
+        self.journal(|| JournalEvent::OrderFailed {
+            trade_id: self.trade_of(client_order_id),
+            client_order_id: client_order_id.to_string(),
+            error: error.to_string(),
+        });
+    }
+
+    /// Record the backend's acknowledgement of a submission.
+    ///
+    /// Orders the backend reports as filled on placement get their fill
+    /// recorded immediately.
+    pub fn on_ack(&self, client_order_id: &str, placed: &SimulatedOrder) -> Result<(), String> {
+        {
+            let mut order = self.orders.get_mut(client_order_id)
+                .ok_or_else(|| format!("Unknown order {}", client_order_id))?;
+            if !placed.id.is_empty() {
+                order.exchange_order_id = Some(placed.id.clone());
+                self.by_exchange_id.insert(placed.id.clone(), client_order_id.to_string());
+            }
+            order.order = placed.clone();
+            order.last_error = None;
+            order.reconciled_at = now_secs();
+            if order.state == OrderState::New {
+                Self::transition(&mut order, OrderState::Acked)?;
+            }
+        }
+        if !placed.id.is_empty() {
+            self.journal(|| JournalEvent::OrderAcked {
+                trade_id: self.trade_of(client_order_id),
+                client_order_id: client_order_id.to_string(),
+                exchange_order_id: placed.id.clone(),
+            });
+        }
+        if placed.status == OrderStatus::Filled {
+            let price = placed.fill_price.unwrap_or(placed.price);
+            let timestamp = placed.filled_at.unwrap_or_else(now_secs);
+            self.record_fill_to(client_order_id, placed.size, price, timestamp)?;
+        }
+        self.notify();
+        Ok(())
+    }
+
+    /// Record an execution of `quantity` at `price`.
+    pub fn record_fill(&self, order_id: &str, quantity: f64, price: f64, timestamp: u64) -> Result<(), String> {
+        let client_order_id = self.resolve(order_id)
+            .ok_or_else(|| format!("Unknown order {}", order_id))?;
+        self.record_fill_to(&client_order_id, quantity, price, timestamp)
+    }
+
+    fn record_fill_to(&self, client_order_id: &str, quantity: f64, price: f64, timestamp: u64) -> Result<(), String> {
+        self.book_fill(client_order_id, quantity, price, timestamp)?;
+        self.notify();
+        Ok(())
+    }
+
+    fn book_fill(&self, client_order_id: &str, quantity: f64, price: f64, timestamp: u64) -> Result<(), String> {
+        let mut order = self.orders.get_mut(client_order_id)
+            .ok_or_else(|| format!("Unknown order {}", client_order_id))?;
+        // Never book more than the order size
+        let quantity = quantity.min(order.remaining_quantity());
+        if quantity <= FILL_EPSILON {
+            return Ok(());
+        }
+
+        let next = if order.remaining_quantity() - quantity <= FILL_EPSILON {
+            OrderState::Filled
+        } else {
+            OrderState::PartiallyFilled
+        };
+        Self::transition(&mut order, next)?;
+
+        let notional = order.avg_fill_price.unwrap_or(0.0) * order.filled_quantity + price * quantity;
+        order.filled_quantity += quantity;
+        order.avg_fill_price = Some(notional / order.filled_quantity);
+        order.fills.push(Fill { quantity, price, timestamp });
+        let trade_id = order.trade_id.clone();
+        let filled = self.risk.as_ref().map(|_| order.order.clone());
+        drop(order);
+        if let (Some(risk), Some(filled)) = (&self.risk, filled) {
+            risk.on_fill(&filled, quantity, price);
+        }
+        self.journal(|| JournalEvent::Fill { trade_id, client_order_id: client_order_id.to_string(), quantity, price });
+        Ok(())
+    }
+
+    /// Reconcile a polled status: book the newly filled quantity at `price_hint`
+    /// and apply cancellations.
+    pub fn apply_status(&self, order_id: &str, info: &OrderStatusInfo, price_hint: f64) -> Result<(), String> {
+        let client_order_id = self.resolve(order_id)
+            .ok_or_else(|| format!("Unknown order {}", order_id))?;
+        let (already_filled, size) = match self.orders.get(&client_order_id) {
+            Some(order) => (order.filled_quantity, order.order.size),
+            None => return Err(format!("Unknown order {}", order_id)),
+        };
+
+        let reported = if info.status == OrderStatus::Filled && info.filled_quantity <= FILL_EPSILON {
+            // Plain status polls carry no quantity: a filled order is filled in full
+            size
+        } else {
+            info.filled_quantity
+        };
+        if reported > already_filled + FILL_EPSILON {
+            self.record_fill_to(&client_order_id, reported - already_filled, price_hint, now_secs())?;
+        }
+
+        if info.status == OrderStatus::Cancelled {
+            self.on_cancelled(&client_order_id)?;
+        }
+        if let Some(mut order) = self.orders.get_mut(&client_order_id) {
+            order.reconciled_at = now_secs();
+        }
+        Ok(())
+    }
+
+    /// Mark an order cancelled (no-op if it already reached a terminal state).
+    pub fn on_cancelled(&self, order_id: &str) -> Result<(), String> {
+        let client_order_id = self.resolve(order_id)
+            .ok_or_else(|| format!("Unknown order {}", order_id))?;
+        let mut order = self.orders.get_mut(&client_order_id)
+            .ok_or_else(|| format!("Unknown order {}", order_id))?;
+        if order.state.is_terminal() {
+            return Ok(());
+        }
+        Self::transition(&mut order, OrderState::Cancelled)?;
+        let trade_id = order.trade_id.clone();
+        drop(order);
+        self.journal(|| JournalEvent::OrderCancelled { trade_id, client_order_id });
+        self.notify();
+        Ok(())
+    }
+
+    /// Record an in-place amend of a working order's price and size.
+    pub fn on_amended(&self, order_id: &str, price: f64, size: f64) -> Result<(), String> {
+        let client_order_id = self.resolve(order_id)
+            .ok_or_else(|| format!("Unknown order {}", order_id))?;
+        let mut order = self.orders.get_mut(&client_order_id)
+            .ok_or_else(|| format!("Unknown order {}", order_id))?;
+        if order.state.is_terminal() {
+            return Err(format!("Cannot amend {} order {}", order.state, client_order_id));
+        }
+        order.order.price = price;
+        order.order.size = size;
+        order.updated_at = now_secs();
+        drop(order);
+        self.notify();
+        Ok(())
+    }
+
+    /// Mark an order rejected, e.g. once retries are exhausted.
+    pub fn reject(&self, order_id: &str, reason: &str) -> Result<(), String> {
+        let client_order_id = self.resolve(order_id)
+            .ok_or_else(|| format!("Unknown order {}", order_id))?;
+        let mut order = self.orders.get_mut(&client_order_id)
+            .ok_or_else(|| format!("Unknown order {}", order_id))?;
+        order.last_error = Some(reason.to_string());
+        Self::transition(&mut order, OrderState::Rejected)?;
+        let trade_id = order.trade_id.clone();
+        drop(order);
+        self.journal(|| JournalEvent::OrderRejected { trade_id, client_order_id, reason: reason.to_string() });
+        self.notify();
+        Ok(())
+    }
+
+    /// Status for `order_id` if a live stream makes REST polling unnecessary:
+    /// the order is terminal, or was reconciled within `RECONCILE_INTERVAL_SECS`.
+    pub fn streamed_status(&self, venue: &str, order_id: &str) -> Option<OrderStatusInfo> {
+        if !self.has_live_stream(venue) {
+            return None;
+        }
+        let order = self.get(order_id)?;
+        if order.state.is_terminal() || now_secs().saturating_sub(order.reconciled_at) < RECONCILE_INTERVAL_SECS {
+            Some(order.status_info())
+        } else {
+            None
+        }
+    }
+
+    /// Apply a private stream event. Returns false for orders the OMS does not own.
+    ///
+    /// Executions book fills (deduplicated by execution ID). Order updates
+    /// drive acks, cancels and rejects, and book any quantity missing once the
+    /// order is final, in case an execution message was lost.
+    pub fn apply_stream_event(&self, event: &UserStreamEvent) -> Result<bool, String> {
+        match event {
+            UserStreamEvent::Position(position) => {
+                self.positions.insert((position.venue.clone(), position.symbol.clone()), position.clone());
+                Ok(true)
+            }
+            UserStreamEvent::Execution(exec) => {
+                let Some(client_order_id) = self.resolve_stream_order(exec.client_order_id.as_deref(), &exec.exchange_order_id) else {
+                    return Ok(false);
+                };
+                let key = format!("{}:{}", exec.venue, exec.execution_id);
+                if self.seen_executions.insert(key, ()).is_some() {
+                    return Ok(true);
+                }
+                self.record_fill_to(&client_order_id, exec.quantity, exec.price, exec.timestamp_ms / 1000)?;
+                Ok(true)
+            }
+            UserStreamEvent::Order(update) => {
+                let Some(client_order_id) = self.resolve_stream_order(update.client_order_id.as_deref(), &update.exchange_order_id) else {
+                    return Ok(false);
+                };
+                let (state, filled) = {
+                    let mut order = self.orders.get_mut(&client_order_id)
+                        .ok_or_else(|| format!("Unknown order {}", client_order_id))?;
+                    // The stream can beat the REST response of the placement
+                    if order.exchange_order_id.is_none() && !update.exchange_order_id.is_empty() {
+                        order.exchange_order_id = Some(update.exchange_order_id.clone());
+                        self.by_exchange_id.insert(update.exchange_order_id.clone(), client_order_id.clone());
+                    }
+                    if order.state == OrderState::New && update.status != StreamOrderStatus::Rejected {
+                        Self::transition(&mut order, OrderState::Acked)?;
+                    }
+                    (order.state, order.filled_quantity)
+                };
+
+                let is_final = matches!(update.status, StreamOrderStatus::Filled | StreamOrderStatus::Cancelled);
+                if is_final && update.filled_quantity > filled + FILL_EPSILON {
+                    let missing = update.filled_quantity - filled;
+                    let booked_notional = self.get(&client_order_id)
+                        .and_then(|o| o.avg_fill_price.map(|p| p * o.filled_quantity))
+                        .unwrap_or(0.0);
+                    let price = if update.avg_price > 0.0 {
+                        ((update.avg_price * update.filled_quantity - booked_notional) / missing).max(0.0)
+                    } else {
+                        0.0
+                    };
+                    self.book_fill(&client_order_id, missing, price, now_secs())?;
+                }
+
+                match update.status {
+                    StreamOrderStatus::Cancelled => self.on_cancelled(&client_order_id)?,
+                    StreamOrderStatus::Rejected if state == OrderState::New => {
+                        self.reject(&client_order_id, update.reason.as_deref().unwrap_or("rejected by exchange"))?
+                    }
+                    _ => {}
+                }
+                self.notify();
+                Ok(true)
+            }
+        }
+    }
+
+    fn resolve_stream_order(&self, client_order_id: Option<&str>, exchange_order_id: &str) -> Option<String> {
+        client_order_id
+            .filter(|c| self.orders.contains_key(*c))
+            .map(|c| c.to_string())
+            .or_else(|| self.by_exchange_id.get(exchange_order_id).map(|c| c.clone()))
+    }
+
+    /// Wait until any of `order_ids` changes state or fill quantity, or `timeout` elapses.
+    /// Returns true if a change was observed.
+    pub async fn wait_for_change(&self, order_ids: &[&str], timeout: Duration) -> bool {
+        let mut rx = self.subscribe();
+        let snapshot = |oms: &OrderManager| -> Vec<Option<(OrderState, f64)>> {
+            order_ids.iter().map(|id| oms.get(id).map(|o| (o.state, o.filled_quantity))).collect()
+        };
+        let before = snapshot(self);
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            match tokio::time::timeout_at(deadline, rx.changed()).await {
+                Ok(Ok(())) => {
+                    if snapshot(self) != before {
+                        return true;
+                    }
+                }
+                _ => return false,
+            }
+        }
+    }
+}
+
+/// `ExecutionBackend` decorator that routes every order through an `OrderManager`.
+pub struct ManagedBackend {
+    inner: Arc<dyn ExecutionBackend>,
+    oms: Arc<OrderManager>,
+}
+
+impl ManagedBackend {
+    pub fn new(inner: Arc<dyn ExecutionBackend>, oms: Arc<OrderManager>) -> Self {
+        Self { inner, oms }
+    }
+
+    pub fn order_manager(&self) -> &Arc<OrderManager> {
+        &self.oms
+    }
+
+    /// Register and submit an order, or return the tracked order if the
+    /// exchange already accepted this reference.
+    async fn submit(&self, order: SimulatedOrder, market: bool) -> Result<SimulatedOrder, ExecutionError> {
+        let client_order_id = self.oms.register(&order)?;
+
+        if let Some(existing) = self.oms.get(&client_order_id) {
+            if existing.state.is_accepted() {
+                eprintln!("[OMS] Duplicate submission of {} suppressed (state: {})", client_order_id, existing.state);
+                return Ok(existing.order);
+            }
+            if existing.state == OrderState::Rejected {
+                return Err(format!("Order {} was rejected: {}", client_order_id,
+                    existing.last_error.unwrap_or_default()).into());
+            }
+        }
+
+        if let Err(violation) = self.oms.check_risk(&client_order_id, &order) {
+            eprintln!("[RISK] Refused {} {:?} {} on {}: {}", client_order_id, order.side, order.symbol, order.exchange, violation);
+            if let Err(e) = self.oms.reject(&client_order_id, &violation.to_string()) {
+                eprintln!("[OMS] {}", e);
+            }
+            return Err(ExecutionError::RiskRejected(violation.to_string()));
+        }
+
+        let mut request = order;
+        request.id = client_order_id.clone();
+        self.oms.on_submit(&client_order_id);
+        self.oms.flush_journal().await;
+
+        let result = if market {
+            self.inner.place_market_order(request).await
+        } else {
+            self.inner.place_order(request).await
+        };
+
+        match result {
+            Ok(placed) => {
+                if let Err(e) = self.oms.on_ack(&client_order_id, &placed) {
+                    eprintln!("[OMS] {}", e);
+                }
+                Ok(placed)
+            }
+            Err(e) => {
+                self.oms.on_submit_error(&client_order_id, &e.to_string());
+                Err(e)
+            }
+        }
+    }
+
+    /// Best price estimate for fills observed by polling.
+    fn price_hint(&self, order_id: &str) -> f64 {
+        self.oms
+            .get(order_id)
+            .map(|o| o.order.fill_price.unwrap_or(o.order.price))
+            .unwrap_or(0.0)
+    }
+}
+
+#[async_trait::async_trait]
+impl ExecutionBackend for ManagedBackend {
+    async fn set_leverage(&self, exchange: &str, symbol: &str, leverage: u8) -> Result<(), ExecutionError> {
+        self.inner.set_leverage(exchange, symbol, leverage).await
+    }
+
+    async fn set_margin_type_isolated(&self, exchange: &str, symbol: &str) -> Result<(), ExecutionError> {
+        self.inner.set_margin_type_isolated(exchange, symbol).await
+    }
+
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        self.submit(order, false).await
+    }
+
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        self.submit(order, true).await
+    }
+
+    async fn cancel_order(&self, exchange: &str, order_id: &str) -> Result<(), ExecutionError> {
+        self.inner.cancel_order(exchange, order_id).await?;
+        if self.oms.resolve(order_id).is_some() {
+            if let Err(e) = self.oms.on_cancelled(order_id) {
+                eprintln!("[OMS] {}", e);
+            }
+        }
+        Ok(())
+    }
+
+    async fn amend_order(&self, order: &SimulatedOrder, new_price: f64, new_size: Option<f64>) -> Result<AmendedOrder, ExecutionError> {
+        if !self.inner.capabilities().supports_amend(&order.exchange) {
+            // Cancel and re-place through the OMS so the replacement is tracked
+            return cancel_replace(self, order, new_price, new_size).await;
+        }
+        let amended = self.inner.amend_order(order, new_price, new_size).await?;
+        if self.oms.resolve(&order.id).is_some() {
+            let result = match amended.method {
+                AmendMethod::InPlace => self.oms.on_amended(&order.id, amended.order.price, amended.order.size),
+                AmendMethod::CancelReplace => self.oms.on_cancelled(&order.id),
+            };
+            if let Err(e) = result {
+                eprintln!("[OMS] {}", e);
+            }
+        }
+        Ok(amended)
+    }
+
+    async fn get_order_status(&self, exchange: &str, order_id: &str) -> Result<OrderStatus, ExecutionError> {
+        if let Some(info) = self.oms.streamed_status(exchange, order_id) {
+            return Ok(info.status);
+        }
+        let status = self.inner.get_order_status(exchange, order_id).await?;
+        if self.oms.resolve(order_id).is_some() {
+            let info = OrderStatusInfo::new(status, 0.0, 0.0);
+            if let Err(e) = self.oms.apply_status(order_id, &info, self.price_hint(order_id)) {
+                eprintln!("[OMS] {}", e);
+            }
+        }
+        Ok(status)
+    }
+
+    async fn get_order_status_detailed(&self, exchange: &str, order_id: &str, symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
+        if let Some(info) = self.oms.streamed_status(exchange, order_id) {
+            let is_market = self.oms.get(order_id).is_some_and(|o| o.order.order_type == OrderType::Market);
+            if info.status != OrderStatus::Pending || !is_market {
+                return Ok(info);
+            }
+            // A market order callers verify right after placement: give the
+            // execution event a moment, then confirm over REST if it is still open
+            self.oms.wait_for_change(&[order_id], MARKET_FILL_WAIT).await;
+            if let Some(info) = self.oms.streamed_status(exchange, order_id) {
+                if info.status != OrderStatus::Pending {
+                    return Ok(info);
+                }
+            }
+        }
+        let info = self.inner.get_order_status_detailed(exchange, order_id, symbol).await?;
+        if self.oms.resolve(order_id).is_some() {
+            if let Err(e) = self.oms.apply_status(order_id, &info, self.price_hint(order_id)) {
+                eprintln!("[OMS] {}", e);
+            }
+        }
+        Ok(info)
+    }
+
+    async fn get_available_balance(&self, exchange: &str) -> Result<f64, ExecutionError> {
+        self.inner.get_available_balance(exchange).await
+    }
+
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
+        self.inner.get_all_balances().await
+    }
+
+    async fn is_symbol_tradeable(&self, exchange: &str, symbol: &str) -> Result<bool, ExecutionError> {
+        self.inner.is_symbol_tradeable(exchange, symbol).await
+    }
+
+    async fn get_order_book_depth(
+        &self,
+        exchange: &str,
+        symbol: &str,
+        levels: usize,
+    ) -> Result<OrderBookDepth, ExecutionError> {
+        self.inner.get_order_book_depth(exchange, symbol, levels).await
+    }
+
+    async fn get_best_bid(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError> {
+        self.inner.get_best_bid(exchange, symbol).await
+    }
+
+    async fn get_best_ask(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError> {
+        self.inner.get_best_ask(exchange, symbol).await
+    }
+
+    fn backend_name(&self) -> &str {
+        self.inner.backend_name()
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        let mut capabilities = self.inner.capabilities();
+        capabilities.supports_private_stream |= self.oms.has_any_live_stream();
+        capabilities
+    }
+
+    async fn get_quantity_step(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError> {
+        self.inner.get_quantity_step(exchange, symbol).await
+    }
+
+    async fn get_spot_position(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError> {
+        self.inner.get_spot_position(exchange, symbol).await
+    }
+
+    async fn get_open_positions(&self, exchange: &str) -> Result<Vec<VenuePosition>, ExecutionError> {
+        self.inner.get_open_positions(exchange).await
+    }
+
+    async fn get_open_orders(&self, exchange: &str) -> Result<Vec<SimulatedOrder>, ExecutionError> {
+        self.inner.get_open_orders(exchange).await
+    }
+
+    async fn get_funding_history(&self, exchange: &str, symbol: &str, since: u64) -> Result<Vec<FundingPayment>, ExecutionError> {
+        self.inner.get_funding_history(exchange, symbol, since).await
+    }
+
+    async fn get_fills(&self, exchange: &str, symbol: &str, since: u64) -> Result<Vec<crate::strategy::types::Fill>, ExecutionError> {
+        self.inner.get_fills(exchange, symbol, since).await
+    }
+
+    async fn get_fee_rates(&self, exchange: &str, symbol: &str) -> Result<FeeRates, ExecutionError> {
+        self.inner.get_fee_rates(exchange, symbol).await
+    }
+
+    async fn wait_for_order_update(&self, order_ids: &[&str], timeout: Duration) -> bool {
+        let streamed = order_ids.iter().any(|id| {
+            self.oms.get(id).is_some_and(|o| self.oms.has_live_stream(&o.order.exchange))
+        });
+        if streamed {
+            self.oms.wait_for_change(order_ids, timeout).await
+        } else {
+            self.inner.wait_for_order_update(order_ids, timeout).await
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::paper_trading_backend::PaperTradingBackend;
+    use crate::strategy::types::OrderSide;
+
+    fn limit_order(id: &str, size: f64) -> SimulatedOrder {
+        SimulatedOrder {
+            id: id.to_string(),
+            exchange: "bybit".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Long,
+            order_type: OrderType::Limit,
+            price: 100.0,
+            size,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_state_machine_transitions() {
+        use OrderState::*;
+        assert!(New.can_transition_to(Acked));
+        assert!(Acked.can_transition_to(PartiallyFilled));
+        assert!(PartiallyFilled.can_transition_to(Filled));
+        assert!(!Filled.can_transition_to(Cancelled));
+        assert!(!Cancelled.can_transition_to(Acked));
+        assert!(!Acked.can_transition_to(Rejected));
+        assert!(Rejected.is_terminal() && !PartiallyFilled.is_terminal());
+    }
+
+    #[test]
+    fn test_partial_fills_track_average_price() {
+        let oms = OrderManager::new();
+        let cid = oms.register(&limit_order("", 2.0)).unwrap();
+        let placed = SimulatedOrder { id: "ex-1".to_string(), ..limit_order(&cid, 2.0) };
+        oms.on_ack(&cid, &placed).unwrap();
+        assert_eq!(oms.get("ex-1").unwrap().state, OrderState::Acked);
+
+        oms.record_fill("ex-1", 0.5, 100.0, 1).unwrap();
+        assert_eq!(oms.get(&cid).unwrap().state, OrderState::PartiallyFilled);
+
+        oms.record_fill("ex-1", 1.5, 104.0, 2).unwrap();
+        let order = oms.get(&cid).unwrap();
+        assert_eq!(order.state, OrderState::Filled);
+        assert_eq!(order.fills.len(), 2);
+        assert!((order.avg_fill_price.unwrap() - 103.0).abs() < 1e-9);
+
+        // Late cancel of a filled order leaves it filled
+        oms.on_cancelled(&cid).unwrap();
+        assert_eq!(oms.get(&cid).unwrap().state, OrderState::Filled);
+    }
+
+    #[test]
+    fn test_polled_status_books_fill_delta_and_cancel() {
+        let oms = OrderManager::new();
+        let cid = oms.register(&limit_order("", 4.0)).unwrap();
+        oms.on_ack(&cid, &limit_order(&cid, 4.0)).unwrap();
+
+        oms.apply_status(&cid, &OrderStatusInfo::new(OrderStatus::Pending, 1.0, 4.0), 100.0).unwrap();
+        oms.apply_status(&cid, &OrderStatusInfo::new(OrderStatus::Cancelled, 1.0, 4.0), 100.0).unwrap();
+
+        let order = oms.get(&cid).unwrap();
+        assert_eq!(order.state, OrderState::Cancelled);
+        assert_eq!(order.filled_quantity, 1.0);
+        assert_eq!(order.fills.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_journal_records_transitions_under_trade_scope() {
+        use crate::strategy::trade_journal::{in_scope, JournalPhase, OrderPurpose};
+
+        let path = std::env::temp_dir().join(format!("oms_journal_{}.jsonl", uuid::Uuid::new_v4().simple()));
+        let journal = Arc::new(TradeJournal::open(&path).unwrap());
+        journal.append(JournalEvent::Intent {
+            trade_id: "intent-1".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            long_exchange: "bybit".to_string(),
+            short_exchange: "okx".to_string(),
+            size_usd: 200.0,
+            kind: Default::default(),
+        }).await.unwrap();
+        let oms = OrderManager::new().with_journal(journal.clone());
+
+        let cid = in_scope("intent-1", JournalPhase::Entry, async {
+            let cid = oms.register(&limit_order("", 2.0)).unwrap();
+            oms.on_submit(&cid);
+            cid
+        }).await;
+        // Fills arrive later, outside the scope, and still belong to the trade
+        oms.on_ack(&cid, &SimulatedOrder { id: "ex-9".to_string(), ..limit_order(&cid, 2.0) }).unwrap();
+        oms.record_fill("ex-9", 0.5, 100.0, 1).unwrap();
+
+        let replay = journal.replay().await.unwrap();
+        let entry = &replay.pending_entries[0];
+        assert_eq!(entry.orders.len(), 1);
+        assert_eq!(entry.orders[0].purpose, OrderPurpose::Entry);
+        assert_eq!(entry.orders[0].exchange_order_id.as_deref(), Some("ex-9"));
+        assert_eq!(entry.orders[0].filled_quantity, 0.5);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_reference_reuse_for_different_order_is_refused() {
+        let oms = OrderManager::new();
+        let first = oms.register(&limit_order("hedge_1", 1.0)).unwrap();
+        assert_eq!(oms.register(&limit_order("hedge_1", 1.0)).unwrap(), first);
+        assert!(oms.register(&limit_order("hedge_1", 0.5)).is_err());
+        assert!(first.len() <= 36);
+    }
+
+    #[tokio::test]
+    async fn test_resubmission_is_idempotent() {
+        let mut balances = HashMap::new();
+        balances.insert("bybit".to_string(), 10000.0);
+        let oms = Arc::new(OrderManager::new());
+        let backend = ManagedBackend::new(Arc::new(PaperTradingBackend::new(balances)), oms.clone());
+
+        let first = backend.place_market_order(limit_order("emergency_close_1", 1.0)).await.unwrap();
+        let retry = backend.place_market_order(limit_order("emergency_close_1", 1.0)).await.unwrap();
+
+        assert_eq!(first.id, retry.id);
+        assert_eq!(oms.len(), 1);
+        let order = oms.get("emergency_close_1").unwrap();
+        assert_eq!(order.submissions, 1);
+        assert_eq!(order.state, OrderState::Filled);
+        assert_eq!(order.avg_fill_price, Some(100.0));
+    }
+
+    #[tokio::test]
+    async fn test_risk_engine_refuses_orders_over_limit() {
+        use crate::strategy::risk_engine::{RiskEngine, RiskLimits};
+        use crate::strategy::symbol_map::SymbolMap;
+
+        let mut balances = HashMap::new();
+        balances.insert("bybit".to_string(), 10000.0);
+        let limits = RiskLimits { max_symbol_notional_usd: 250.0, ..Default::default() };
+        let risk = Arc::new(RiskEngine::new(limits, Arc::new(SymbolMap::new())));
+        let oms = Arc::new(OrderManager::new().with_risk_engine(risk));
+        let backend = ManagedBackend::new(Arc::new(PaperTradingBackend::new(balances)), oms.clone());
+
+        backend.place_market_order(limit_order("entry_1", 2.0)).await.unwrap();
+        let refused = backend.place_market_order(limit_order("entry_2", 1.0)).await.unwrap_err();
+        assert!(matches!(refused, ExecutionError::RiskRejected(_)));
+        assert_eq!(oms.get("entry_2").unwrap().state, OrderState::Rejected);
+
+        // Reducing the filled position is always allowed
+        let close = SimulatedOrder { side: OrderSide::Short, ..limit_order("close_1", 2.0) };
+        backend.place_market_order(close).await.unwrap();
+    }
+}
@@ -1,9 +1,12 @@
-use std::error::Error;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::RwLock;
-use crate::strategy::execution_backend::ExecutionBackend;
-use crate::strategy::types::{SimulatedOrder, OrderStatus};
+use crate::strategy::execution_backend::{BackendCapabilities, ExecutionBackend};
+use crate::strategy::execution_error::ExecutionError;
+use crate::strategy::fee_schedule::FeeSchedule;
+use crate::strategy::fill_accounting::simulated_fill;
+use crate::strategy::instruments::instrument_kind;
+use crate::strategy::types::{Fill, InstrumentKind, OrderSide, SimulatedOrder, OrderStatus};
 use uuid::Uuid;
 
 /// Paper trading backend - simulates order execution without real money
@@ -12,6 +15,12 @@ pub struct PaperTradingBackend {
     balances: Arc<RwLock<HashMap<String, f64>>>,
     /// Simulated open orders
     orders: Arc<RwLock<HashMap<String, SimulatedOrder>>>,
+    /// Base-asset holdings per (spot venue, symbol)
+    spot_holdings: Arc<RwLock<HashMap<(String, String), f64>>>,
+    /// Executions of filled orders, charged at the fee schedule
+    fills: Arc<RwLock<Vec<Fill>>>,
+    /// Account rates the simulated fills are charged at
+    fees: Arc<FeeSchedule>,
 }
 
 impl PaperTradingBackend {
@@ -19,23 +28,75 @@ impl PaperTradingBackend {
         Self {
             balances: Arc::new(RwLock::new(initial_balances)),
             orders: Arc::new(RwLock::new(HashMap::new())),
+            spot_holdings: Arc::new(RwLock::new(HashMap::new())),
+            fills: Arc::new(RwLock::new(Vec::new())),
+            fees: Arc::new(FeeSchedule::default()),
         }
     }
+
+    /// Charge simulated fills at `fees` instead of the regular-tier rates.
+    pub fn with_fee_schedule(mut self, fees: Arc<FeeSchedule>) -> Self {
+        self.fees = fees;
+        self
+    }
+
+    /// Move quote balance and base holdings for a filled spot order.
+    ///
+    /// Spot is fully paid: a buy needs the quote balance, a sell needs the
+    /// base inventory (no borrowing). Perp orders are left untouched.
+    async fn settle_spot_fill(&self, order: &SimulatedOrder) -> Result<(), ExecutionError> {
+        if instrument_kind(&order.exchange) != InstrumentKind::Spot {
+            return Ok(());
+        }
+        
+        let notional = order.size * order.price;
+        let mut balances = self.balances.write().await;
+        let mut holdings = self.spot_holdings.write().await;
+        let key = (order.exchange.clone(), order.symbol.clone());
+        
+        match order.side {
+            OrderSide::Long => {
+                let balance = balances.get_mut(&order.exchange).ok_or("Exchange not found")?;
+                if *balance < notional {
+                    return Err(ExecutionError::InsufficientBalance(format!("Insufficient {} balance for spot buy: {:.2} < {:.2}",
+                        order.exchange, balance, notional)));
+                }
+                *balance -= notional;
+                *holdings.entry(key).or_insert(0.0) += order.size;
+            }
+            OrderSide::Short => {
+                let held = holdings.get(&key).copied().unwrap_or(0.0);
+                if held + 1e-9 < order.size {
+                    return Err(ExecutionError::InsufficientBalance(format!("Insufficient {} {} inventory for spot sell: {} < {}",
+                        order.exchange, order.symbol, held, order.size)));
+                }
+                let remaining = held - order.size;
+                if remaining > 1e-9 {
+                    holdings.insert(key, remaining);
+                } else {
+                    holdings.remove(&key);
+                }
+                *balances.entry(order.exchange.clone()).or_insert(0.0) += notional;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[async_trait::async_trait]
 impl ExecutionBackend for PaperTradingBackend {
-    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
         // Paper trading doesn't need leverage setting
         Ok(())
     }
 
-    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
         // Paper trading doesn't need margin type setting
         Ok(())
     }
 
-    async fn place_order(&self, mut order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_order(&self, mut order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        self.settle_spot_fill(&order).await?;
         let mut orders = self.orders.write().await;
         
         // Generate order ID if not present
@@ -50,14 +111,16 @@ impl ExecutionBackend for PaperTradingBackend {
             .unwrap()
             .as_secs());
         order.fill_price = Some(order.price);
+        self.fills.write().await.push(simulated_fill(&order, order.filled_at.unwrap_or_default(), &self.fees));
         
         orders.insert(order.id.clone(), order.clone());
         
         Ok(order)
     }
 
-    async fn place_market_order(&self, mut order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_market_order(&self, mut order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         // For paper trading, market orders behave the same as limit orders (immediate fill)
+        self.settle_spot_fill(&order).await?;
         let mut orders = self.orders.write().await;
         
         // Generate order ID if not present
@@ -72,19 +135,20 @@ impl ExecutionBackend for PaperTradingBackend {
             .unwrap()
             .as_secs());
         order.fill_price = Some(order.price);
+        self.fills.write().await.push(simulated_fill(&order, order.filled_at.unwrap_or_default(), &self.fees));
         
         orders.insert(order.id.clone(), order.clone());
         
         Ok(order)
     }
     
-    async fn cancel_order(&self, _exchange: &str, order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn cancel_order(&self, _exchange: &str, order_id: &str) -> Result<(), ExecutionError> {
         let mut orders = self.orders.write().await;
         orders.remove(order_id);
         Ok(())
     }
     
-    async fn get_order_status(&self, _exchange: &str, order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status(&self, _exchange: &str, order_id: &str) -> Result<OrderStatus, ExecutionError> {
         let orders = self.orders.read().await;
         match orders.get(order_id) {
             Some(order) => Ok(order.status),
@@ -92,7 +156,7 @@ impl ExecutionBackend for PaperTradingBackend {
         }
     }
 
-    async fn get_order_status_detailed(&self, _exchange: &str, order_id: &str, _symbol: &str) -> Result<crate::strategy::types::OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status_detailed(&self, _exchange: &str, order_id: &str, _symbol: &str) -> Result<crate::strategy::types::OrderStatusInfo, ExecutionError> {
         use crate::strategy::types::OrderStatusInfo;
         
         let orders = self.orders.read().await;
@@ -108,7 +172,7 @@ impl ExecutionBackend for PaperTradingBackend {
         }
     }
     
-    async fn get_available_balance(&self, exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_available_balance(&self, exchange: &str) -> Result<f64, ExecutionError> {
         let balances = self.balances.read().await;
         match balances.get(exchange) {
             Some(&balance) => Ok(balance),
@@ -116,12 +180,12 @@ impl ExecutionBackend for PaperTradingBackend {
         }
     }
     
-    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
         let balances = self.balances.read().await;
         Ok(balances.clone())
     }
     
-    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
         // Paper trading accepts all symbols
         Ok(true)
     }
@@ -131,7 +195,7 @@ impl ExecutionBackend for PaperTradingBackend {
         _exchange: &str,
         _symbol: &str,
         _levels: usize,
-    ) -> Result<crate::strategy::types::OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    ) -> Result<crate::strategy::types::OrderBookDepth, ExecutionError> {
         // TODO: Implement order book depth query for paper trading
         Err("get_order_book_depth not yet implemented for paper trading".into())
     }
@@ -140,7 +204,7 @@ impl ExecutionBackend for PaperTradingBackend {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         // TODO: Implement best bid query for paper trading
         Err("get_best_bid not yet implemented for paper trading".into())
     }
@@ -149,17 +213,78 @@ impl ExecutionBackend for PaperTradingBackend {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         // TODO: Implement best ask query for paper trading
         Err("get_best_ask not yet implemented for paper trading".into())
     }
 
-    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
         // Paper trading uses default step of 0.1
         Ok(0.1)
     }
     
+    async fn get_spot_position(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError> {
+        let holdings = self.spot_holdings.read().await;
+        Ok(holdings.get(&(exchange.to_string(), symbol.to_string())).copied().unwrap_or(0.0))
+    }
+    
+    async fn get_fills(&self, exchange: &str, symbol: &str, since: u64) -> Result<Vec<Fill>, ExecutionError> {
+        let fills = self.fills.read().await;
+        Ok(fills.iter()
+            .filter(|f| f.exchange == exchange && f.symbol == symbol && f.time >= since)
+            .cloned()
+            .collect())
+    }
+    
     fn backend_name(&self) -> &str {
         "PaperTrading"
     }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spot_order(side: OrderSide, size: f64, price: f64) -> SimulatedOrder {
+        SimulatedOrder {
+            exchange: "bybit_spot".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side,
+            price,
+            size,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_spot_buy_and_sell_move_balance_and_inventory() {
+        let mut balances = HashMap::new();
+        balances.insert("bybit_spot".to_string(), 10000.0);
+        let backend = PaperTradingBackend::new(balances);
+
+        backend.place_order(spot_order(OrderSide::Long, 0.1, 50000.0)).await.unwrap();
+        assert_eq!(backend.get_available_balance("bybit_spot").await.unwrap(), 5000.0);
+        assert_eq!(backend.get_spot_position("bybit_spot", "BTCUSDT").await.unwrap(), 0.1);
+
+        // Cannot sell more than held: spot cannot be shorted
+        assert!(backend.place_market_order(spot_order(OrderSide::Short, 0.2, 50000.0)).await.is_err());
+
+        backend.place_market_order(spot_order(OrderSide::Short, 0.1, 51000.0)).await.unwrap();
+        assert_eq!(backend.get_available_balance("bybit_spot").await.unwrap(), 10100.0);
+        assert_eq!(backend.get_spot_position("bybit_spot", "BTCUSDT").await.unwrap(), 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_spot_buy_rejected_without_quote_balance() {
+        let mut balances = HashMap::new();
+        balances.insert("bybit_spot".to_string(), 1000.0);
+        let backend = PaperTradingBackend::new(balances);
+
+        assert!(backend.place_order(spot_order(OrderSide::Long, 0.1, 50000.0)).await.is_err());
+        assert_eq!(backend.get_spot_position("bybit_spot", "BTCUSDT").await.unwrap(), 0.0);
+    }
 }
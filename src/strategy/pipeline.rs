@@ -1088,6 +1088,25 @@ impl ExecutionMetrics {
         assert_eq!(order.size, 2.0);
         assert!(order.is_limit());
         assert!(order.is_sell());
+        assert_eq!(order.tif(), crate::strategy::types::TimeInForce::Gtc);
+        assert!(!order.is_reduce_only());
+    }
+    
+    #[test]
+    fn test_order_flags() {
+        use crate::strategy::types::TimeInForce;
+        
+        let order = OrderRequest::limit(3, 200, 2, 1, 50000.0, 2.0, 2000000)
+            .with_time_in_force(TimeInForce::PostOnly)
+            .with_reduce_only();
+        assert_eq!(order.tif(), TimeInForce::PostOnly);
+        assert!(order.is_reduce_only());
+        assert!(!order.is_close_position());
+        
+        // Closing the whole position implies reduce-only
+        let close = OrderRequest::market(4, 200, 2, 0, 0.0, 2000000).with_close_position();
+        assert!(close.is_reduce_only());
+        assert!(close.is_close_position());
     }
     
     #[test]
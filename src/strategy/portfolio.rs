@@ -1,4 +1,4 @@
-use crate::strategy::types::{PortfolioState, TradeStatus, PaperTrade, PortfolioMetrics};
+use crate::strategy::types::{Fill, FundingPayment, PortfolioState, TradeStatus, PaperTrade, PortfolioMetrics, SerializablePortfolioState};
 use redis::aio::MultiplexedConnection;
 use std::error::Error;
 use tokio::sync::RwLock;
@@ -53,6 +53,39 @@ impl PortfolioManager {
         Ok(manager)
     }
 
+    /// Active trades persisted by a previous run, for startup reconciliation.
+    /// Must be read before `new`, which clears the persisted state.
+    pub async fn load_active_trades(
+        redis_conn: &MultiplexedConnection,
+        redis_prefix: &str,
+    ) -> Result<Vec<PaperTrade>, Box<dyn Error + Send + Sync>> {
+        let state_key = format!("strategy:{}:portfolio:state", redis_prefix);
+        let json: Option<String> = redis::cmd("GET")
+            .arg(&state_key)
+            .query_async(&mut redis_conn.clone())
+            .await?;
+        match json {
+            Some(json) => Ok(serde_json::from_str::<SerializablePortfolioState>(&json)?.active_trades),
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Take over a trade that is already open on the venues (startup
+    /// reconciliation). Capital is reserved without the entry checks, since
+    /// the position exists either way.
+    pub async fn restore_trade(&mut self, trade: PaperTrade) -> Result<(), Box<dyn Error + Send + Sync>> {
+        {
+            let mut state = self.state.write().await;
+            if state.active_trades.iter().any(|t| t.id == trade.id) {
+                return Ok(());
+            }
+            state.available_capital -= trade.position_size_usd;
+            state.total_open_positions += trade.position_size_usd;
+            state.active_trades.push(trade);
+        }
+        self.persist_state().await
+    }
+
     /// Fast read-only access to portfolio state
     /// Uses read lock to allow concurrent access from multiple threads
     #[inline]
@@ -119,7 +152,9 @@ impl PortfolioManager {
 
     /// Close a trade with optimized locking
     /// Minimizes critical section by performing calculations outside the lock
-    pub async fn close_trade(&mut self, trade_id: &str, actual_profit: f64, exit_reason: String) -> Result<(), Box<dyn Error + Send + Sync>> {
+    /// `actual_profit` includes the net of `funding_payments`; `fills` are the
+    /// venue executions of both legs, when known
+    pub async fn close_trade(&mut self, trade_id: &str, actual_profit: f64, funding_payments: Vec<FundingPayment>, fills: Vec<Fill>, exit_reason: String) -> Result<(), Box<dyn Error + Send + Sync>> {
         // Prepare data outside the lock
         let exit_time = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
         let is_profit = actual_profit > 0.0;
@@ -131,6 +166,8 @@ impl PortfolioManager {
             if let Some(pos) = state.active_trades.iter().position(|t| t.id == trade_id) {
                 let mut trade = state.active_trades.remove(pos);
                 trade.actual_profit_usd = actual_profit;
+                trade.funding_payments = funding_payments;
+                trade.fills = fills;
                 trade.exit_reason = Some(exit_reason.clone());
                 trade.status = TradeStatus::Closed;
                 trade.exit_time = Some(exit_time);
@@ -139,6 +176,7 @@ impl PortfolioManager {
                 state.total_open_positions -= trade.position_size_usd;
                 state.available_capital += trade.position_size_usd + actual_profit;
                 state.cumulative_pnl += actual_profit;
+                state.cumulative_funding_pnl += trade.funding_pnl_usd();
 
                 // Track leg-out losses
                 let is_leg_out = trade.leg_out_event.is_some();
@@ -218,6 +256,7 @@ impl PortfolioManager {
             total_trades,
             win_rate,
             cumulative_pnl: state.cumulative_pnl,
+            funding_pnl: state.cumulative_funding_pnl,
             pnl_percentage,
             available_capital: state.available_capital,
             utilization_pct,
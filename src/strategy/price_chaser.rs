@@ -1,9 +1,13 @@
 use crate::strategy::types::{SimulatedOrder, OrderSide, OrderStatus};
-use crate::strategy::execution_backend::ExecutionBackend;
+use crate::strategy::execution_backend::{AmendMethod, AmendedOrder, ExecutionBackend};
+use crate::strategy::execution_error::ExecutionError;
 use std::sync::Arc;
 use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use serde::{Serialize, Deserialize};
 
+/// Placements of a post-only order before a crossing rejection is returned
+const MAX_POST_ONLY_ATTEMPTS: u32 = 3;
+
 /// Configuration for repricing behavior
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct RepricingConfig {
@@ -82,6 +86,8 @@ pub struct RepricingEvent {
     pub elapsed_ms: u128,
     pub exchange: String,
     pub side: OrderSide,
+    /// Whether the venue amended in place or we cancelled and re-placed
+    pub method: AmendMethod,
 }
 
 /// Tracks repricing metrics for a trade
@@ -94,6 +100,8 @@ pub struct RepricingMetrics {
     pub price_improvement_bps: f64,
     pub repricing_events: Vec<RepricingEvent>,
     pub max_reprices_reached: bool,
+    pub amend_count: u32,
+    pub cancel_replace_count: u32,
 }
 
 impl RepricingMetrics {
@@ -106,6 +114,8 @@ impl RepricingMetrics {
             price_improvement_bps: 0.0,
             repricing_events: Vec::new(),
             max_reprices_reached: false,
+            amend_count: 0,
+            cancel_replace_count: 0,
         }
     }
     
@@ -133,8 +143,9 @@ impl PriceChaser {
         price_deviation > self.config.reprice_threshold_bps
     }
     
-    /// Reprice an order by cancelling and placing new order at current best price
-    /// Returns the new order and updates metrics
+    /// Reprice an order to the current best price
+    /// Amends in place where the venue supports it, otherwise cancels and re-places.
+    /// Returns the working order and updates metrics
     pub async fn reprice_order(
         &self,
         order: &SimulatedOrder,
@@ -143,21 +154,16 @@ impl PriceChaser {
     ) -> Result<SimulatedOrder, String> {
         let start = Instant::now();
         
-        // Cancel existing order
-        self.backend.cancel_order(&order.exchange, &order.id).await
-            .map_err(|e| format!("Failed to cancel order: {}", e))?;
-        
-        // Create new order at new price
-        let mut new_order = order.clone();
-        new_order.price = new_price;
-        new_order.id = String::new();  // Will be assigned by exchange
-        new_order.created_at = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        
-        let placed_order = self.backend.place_order(new_order).await
-            .map_err(|e| format!("Failed to place repriced order: {}", e))?;
+        let amended = match self.backend.amend_order(order, new_price, None).await {
+            // A rejected replacement leaves nothing on the book; re-post behind the touch
+            Err(ExecutionError::PostOnlyRejected(_)) if !self.backend.capabilities().supports_amend(&order.exchange) => {
+                let replacement = SimulatedOrder { id: String::new(), price: new_price, ..order.clone() };
+                let placed = self.place_resting(replacement).await
+                    .map_err(|e| format!("Failed to reprice order: {}", e))?;
+                AmendedOrder { order: placed, method: AmendMethod::CancelReplace }
+            }
+            result => result.map_err(|e| format!("Failed to reprice order: {}", e))?,
+        };
         
         // Record repricing event
         let elapsed_ms = start.elapsed().as_millis();
@@ -172,16 +178,42 @@ impl PriceChaser {
             elapsed_ms,
             exchange: order.exchange.clone(),
             side: order.side,
+            method: amended.method,
         };
         
         metrics.reprice_count += 1;
+        match amended.method {
+            AmendMethod::InPlace => metrics.amend_count += 1,
+            AmendMethod::CancelReplace => metrics.cancel_replace_count += 1,
+        }
         metrics.reprice_total_time_ms += elapsed_ms;
         metrics.final_price = new_price;
         metrics.repricing_events.push(event);
         
-        Ok(placed_order)
+        Ok(amended.order)
     }
     
+    /// Place a resting limit order. A post-only order the venue rejects for
+    /// crossing the book is moved to the passive touch and tried again.
+    pub async fn place_resting(&self, mut order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        let mut attempt = 1;
+        loop {
+            match self.backend.place_order(order.clone()).await {
+                Err(ExecutionError::PostOnlyRejected(reason)) if attempt < MAX_POST_ONLY_ATTEMPTS => {
+                    let touch = match order.side {
+                        OrderSide::Long => self.backend.get_best_bid(&order.exchange, &order.symbol).await?,
+                        OrderSide::Short => self.backend.get_best_ask(&order.exchange, &order.symbol).await?,
+                    };
+                    eprintln!("[PRICE CHASER] Post-only {:?} on {} rejected at {:.4} ({}), re-posting at {:.4}",
+                        order.side, order.exchange, order.price, reason, touch);
+                    order.price = touch;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
     /// Get the best price for an order based on its side
     /// For long orders: use best bid (we want to buy at the best available price)
     /// For short orders: use best ask (we want to sell at the best available price)
@@ -259,6 +291,9 @@ mod tests {
             okx: None,
             kucoin: None,
             bitget: None,
+            gateio: None,
+            hyperliquid: None,
+            paradex: None,
             single_exchange_mode: false,
             primary_exchange: "bybit".to_string(),
         };
@@ -329,6 +364,34 @@ mod tests {
         assert_eq!(metrics.reprice_total_time_ms, 0);
         assert!(!metrics.max_reprices_reached);
         assert_eq!(metrics.repricing_events.len(), 0);
+        assert_eq!(metrics.amend_count, 0);
+        assert_eq!(metrics.cancel_replace_count, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_reprice_falls_back_to_cancel_replace() {
+        let backend = Arc::new(crate::strategy::paper_trading_backend::PaperTradingBackend::new(
+            std::collections::HashMap::from([("gateio".to_string(), 10_000.0)]),
+        ));
+        let chaser = PriceChaser::new(backend.clone(), RepricingConfig::balanced());
+        let order = backend.place_order(SimulatedOrder {
+            exchange: "gateio".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Long,
+            price: 100.0,
+            size: 1.0,
+            ..Default::default()
+        }).await.unwrap();
+        
+        let mut metrics = RepricingMetrics::new(order.price);
+        let repriced = chaser.reprice_order(&order, 100.5, &mut metrics).await.unwrap();
+        
+        assert_ne!(repriced.id, order.id);
+        assert_eq!(repriced.price, 100.5);
+        assert_eq!(metrics.cancel_replace_count, 1);
+        assert_eq!(metrics.amend_count, 0);
+        assert_eq!(metrics.repricing_events[0].method, AmendMethod::CancelReplace);
+        assert_eq!(metrics.final_price, 100.5);
     }
     
     #[test]
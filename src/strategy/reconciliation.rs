@@ -0,0 +1,351 @@
+//! Startup reconciliation of venue state against persisted trades
+//!
+//! After a crash or restart the strategy only knows the trades it persisted.
+//! Before trading resumes, each venue's open positions and resting orders are
+//! compared with those trades:
+//!
+//! - a trade whose long and short legs are both held is restored and monitored
+//!   again;
+//! - a held position no restored trade accounts for is a naked leg;
+//! - a persisted leg with no position behind it is a missing leg;
+//! - a held size that differs from the restored trades' legs is a size mismatch;
+//! - a resting order that is not a restored trade's exit order is an orphan.
+//!
+//! Any discrepancy blocks trading until it is resolved on the venue or an
+//! operator acknowledges the report.
+//!
+//! Venues aggregate positions per instrument, so legs of several trades on the
+//! same (venue, symbol, side) are summed before sizes are compared. Spot legs
+//! are checked against the coin balance, which may also hold unrelated coins,
+//! so only a shortfall counts there.
+
+use crate::strategy::execution_backend::ExecutionBackend;
+use crate::strategy::instruments::instrument_kind;
+use crate::strategy::types::{InstrumentKind, OrderSide, PaperTrade, PositionMargin, SimulatedOrder, VenuePosition};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Relative size difference tolerated between held and expected legs
+/// (rounding to the venue's quantity step)
+pub const SIZE_TOLERANCE: f64 = 0.02;
+
+/// Something on a venue that the persisted trades don't account for, or vice versa
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Discrepancy {
+    /// Position held on a venue that no restored trade owns
+    NakedLeg { position: VenuePosition },
+    /// Leg of a persisted trade with no position behind it; the trade is not restored
+    MissingLeg { trade_id: String, exchange: String, symbol: String, side: OrderSide, expected: f64 },
+    /// Held size differs from the sum of the restored trades' legs
+    SizeMismatch { exchange: String, symbol: String, side: OrderSide, expected: f64, actual: f64 },
+    /// Resting order that is not an exit order of a restored trade
+    OrphanOrder { order: SimulatedOrder },
+    /// Venue could not be queried; its legs are assumed held
+    VenueUnavailable { exchange: String, error: String },
+}
+
+impl fmt::Display for Discrepancy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Discrepancy::NakedLeg { position } => write!(
+                f, "naked {:?} {} {} on {} @ {:.4}",
+                position.side, position.size, position.symbol, position.exchange, position.entry_price
+            ),
+            Discrepancy::MissingLeg { trade_id, exchange, symbol, side, expected } => write!(
+                f, "trade {} missing {:?} {} {} on {}", trade_id, side, expected, symbol, exchange
+            ),
+            Discrepancy::SizeMismatch { exchange, symbol, side, expected, actual } => write!(
+                f, "{:?} {} on {} holds {} (expected {})", side, symbol, exchange, actual, expected
+            ),
+            Discrepancy::OrphanOrder { order } => write!(
+                f, "orphan order {} {:?} {} {} @ {} on {}",
+                order.id, order.side, order.size, order.symbol, order.price, order.exchange
+            ),
+            Discrepancy::VenueUnavailable { exchange, error } => write!(f, "{} unavailable: {}", exchange, error),
+        }
+    }
+}
+
+/// Outcome of one reconciliation pass
+#[derive(Debug, Clone, Serialize)]
+pub struct ReconciliationReport {
+    /// Persisted trades whose legs are all held, to be monitored again
+    pub restored: Vec<PaperTrade>,
+    pub discrepancies: Vec<Discrepancy>,
+    pub checked_at: u64,
+}
+
+impl ReconciliationReport {
+    /// True when trading can start without an acknowledgement
+    pub fn is_clean(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+type LegKey = (String, String, OrderSide);
+
+/// (venue, symbol, side, size) of both legs of a trade
+fn legs(trade: &PaperTrade) -> [(LegKey, f64); 2] {
+    [
+        ((trade.long_exchange.clone(), trade.symbol.clone(), OrderSide::Long), trade.long_order.size),
+        ((trade.short_exchange.clone(), trade.symbol.clone(), OrderSide::Short), trade.short_order.size),
+    ]
+}
+
+fn sizes_match(venue: &str, expected: f64, actual: f64) -> bool {
+    let tolerance = expected.abs() * SIZE_TOLERANCE + 1e-9;
+    match instrument_kind(venue) {
+        // The balance may also hold coins outside the strategy
+        InstrumentKind::Spot => actual >= expected - tolerance,
+        InstrumentKind::Perp => (actual - expected).abs() <= tolerance,
+    }
+}
+
+/// Match persisted trades against venue state.
+///
+/// `unavailable` lists venues that could not be queried; legs there are
+/// assumed held so their trades stay monitored, and the caller reports the
+/// venue itself as a discrepancy.
+pub fn reconcile(
+    trades: &[PaperTrade],
+    positions: &[VenuePosition],
+    open_orders: &[SimulatedOrder],
+    unavailable: &HashSet<String>,
+) -> ReconciliationReport {
+    let mut held: HashMap<LegKey, f64> = HashMap::new();
+    let mut by_key: HashMap<LegKey, &VenuePosition> = HashMap::new();
+    for position in positions.iter().filter(|p| p.size > 0.0) {
+        let key = (position.exchange.clone(), position.symbol.clone(), position.side);
+        *held.entry(key.clone()).or_default() += position.size;
+        by_key.entry(key).or_insert(position);
+    }
+
+    let mut restored = Vec::new();
+    let mut discrepancies = Vec::new();
+    let mut claimed: HashMap<LegKey, f64> = HashMap::new();
+
+    for trade in trades {
+        let legs = legs(trade);
+        let missing: Vec<&(LegKey, f64)> = legs
+            .iter()
+            .filter(|(key, _)| !unavailable.contains(&key.0) && !held.contains_key(key))
+            .collect();
+
+        if missing.is_empty() {
+            for (key, size) in legs {
+                *claimed.entry(key).or_default() += size;
+            }
+            restored.push(trade.clone());
+        } else {
+            for ((exchange, symbol, side), expected) in missing {
+                discrepancies.push(Discrepancy::MissingLeg {
+                    trade_id: trade.id.clone(),
+                    exchange: exchange.clone(),
+                    symbol: symbol.clone(),
+                    side: *side,
+                    expected: *expected,
+                });
+            }
+        }
+    }
+
+    let mut held: Vec<(LegKey, f64)> = held.into_iter().collect();
+    held.sort_by(|((ea, sa, da), _), ((eb, sb, db), _)| (ea, sa, *da as u8).cmp(&(eb, sb, *db as u8)));
+    for (key, actual) in held {
+        match claimed.get(&key) {
+            None if instrument_kind(&key.0) == InstrumentKind::Spot => {}
+            None => discrepancies.push(Discrepancy::NakedLeg { position: VenuePosition { size: actual, ..by_key[&key].clone() } }),
+            Some(&expected) if !sizes_match(&key.0, expected, actual) => {
+                let (exchange, symbol, side) = key;
+                discrepancies.push(Discrepancy::SizeMismatch { exchange, symbol, side, expected, actual });
+            }
+            Some(_) => {}
+        }
+    }
+
+    let exit_orders: HashSet<&str> = restored
+        .iter()
+        .flat_map(|t| [t.long_exit_order.as_ref(), t.short_exit_order.as_ref()])
+        .flatten()
+        .map(|o| o.id.as_str())
+        .collect();
+    discrepancies.extend(
+        open_orders
+            .iter()
+            .filter(|o| !exit_orders.contains(o.id.as_str()))
+            .map(|o| Discrepancy::OrphanOrder { order: o.clone() }),
+    );
+
+    ReconciliationReport {
+        restored,
+        discrepancies,
+        checked_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+    }
+}
+
+/// Query `venues` through `backend` and reconcile them against `trades`.
+///
+/// Perp venues report positions directly; spot venues are checked through the
+/// coin balance of every symbol a trade holds there.
+pub async fn fetch_and_reconcile(
+    backend: &dyn ExecutionBackend,
+    venues: &[String],
+    trades: &[PaperTrade],
+) -> ReconciliationReport {
+    let mut positions = Vec::new();
+    let mut open_orders = Vec::new();
+    let mut unavailable = HashSet::new();
+    let mut errors = Vec::new();
+
+    for venue in venues {
+        let fetched = match instrument_kind(venue) {
+            InstrumentKind::Perp => backend.get_open_positions(venue).await,
+            InstrumentKind::Spot => {
+                let symbols: HashSet<&str> = trades
+                    .iter()
+                    .filter(|t| &t.long_exchange == venue)
+                    .map(|t| t.symbol.as_str())
+                    .collect();
+                let mut held = Ok(Vec::new());
+                for symbol in symbols {
+                    match backend.get_spot_position(venue, symbol).await {
+                        Ok(size) => {
+                            if let Ok(held) = held.as_mut() {
+                                held.push(VenuePosition {
+                                    exchange: venue.clone(),
+                                    symbol: symbol.to_string(),
+                                    side: OrderSide::Long,
+                                    size,
+                                    entry_price: 0.0,
+                                    margin: PositionMargin::default(),
+                                });
+                            }
+                        }
+                        Err(e) => held = Err(e),
+                    }
+                }
+                held
+            }
+        };
+        let orders = backend.get_open_orders(venue).await;
+
+        match (fetched, orders) {
+            (Ok(held), Ok(orders)) => {
+                positions.extend(held);
+                open_orders.extend(orders);
+            }
+            (Err(e), _) | (_, Err(e)) => {
+                unavailable.insert(venue.clone());
+                errors.push(Discrepancy::VenueUnavailable { exchange: venue.clone(), error: e.to_string() });
+            }
+        }
+    }
+
+    let mut report = reconcile(trades, &positions, &open_orders, &unavailable);
+    report.discrepancies.extend(errors);
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::types::{OrderStatus, OrderType};
+
+    fn order(id: &str, exchange: &str, side: OrderSide, size: f64) -> SimulatedOrder {
+        SimulatedOrder {
+            id: id.to_string(),
+            exchange: exchange.to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side,
+            order_type: OrderType::Limit,
+            price: 50_000.0,
+            size,
+            status: OrderStatus::Filled,
+            ..Default::default()
+        }
+    }
+
+    fn trade(id: &str, long: &str, short: &str, size: f64) -> PaperTrade {
+        PaperTrade {
+            entry_long_price: 50_000.0,
+            entry_short_price: 50_010.0,
+            entry_spread_bps: 2.0,
+            position_size_usd: size * 50_000.0,
+            long_order: order(&format!("{}-long", id), long, OrderSide::Long, size),
+            short_order: order(&format!("{}-short", id), short, OrderSide::Short, size),
+            ..PaperTrade::test_trade(id, long, short)
+        }
+    }
+
+    fn position(exchange: &str, side: OrderSide, size: f64) -> VenuePosition {
+        VenuePosition {
+            exchange: exchange.to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side,
+            size,
+            entry_price: 50_000.0,
+            margin: PositionMargin::default(),
+        }
+    }
+
+    #[test]
+    fn test_matched_trades_are_restored() {
+        let mut with_exit = trade("t2", "bybit", "okx", 0.01);
+        with_exit.long_exit_order = Some(order("exit-1", "bybit", OrderSide::Short, 0.01));
+        let trades = vec![trade("t1", "bybit", "okx", 0.02), with_exit];
+        let positions = vec![position("bybit", OrderSide::Long, 0.03), position("okx", OrderSide::Short, 0.0299)];
+        let orders = vec![order("exit-1", "bybit", OrderSide::Short, 0.01)];
+
+        let report = reconcile(&trades, &positions, &orders, &HashSet::new());
+        assert!(report.is_clean(), "{:?}", report.discrepancies);
+        assert_eq!(report.restored.len(), 2);
+    }
+
+    #[test]
+    fn test_missing_leg_leaves_the_other_leg_naked() {
+        let trades = vec![trade("t1", "bybit", "okx", 0.02)];
+        let positions = vec![position("bybit", OrderSide::Long, 0.02)];
+
+        let report = reconcile(&trades, &positions, &[], &HashSet::new());
+        assert!(report.restored.is_empty());
+        assert_eq!(report.discrepancies.len(), 2);
+        assert!(matches!(&report.discrepancies[0],
+            Discrepancy::MissingLeg { trade_id, exchange, side: OrderSide::Short, expected, .. }
+                if trade_id == "t1" && exchange == "okx" && *expected == 0.02));
+        assert!(matches!(&report.discrepancies[1],
+            Discrepancy::NakedLeg { position } if *position == self::position("bybit", OrderSide::Long, 0.02)));
+    }
+
+    #[test]
+    fn test_size_mismatch_and_orphan_orders() {
+        let trades = vec![trade("t1", "bybit", "okx", 0.02)];
+        let positions = vec![position("bybit", OrderSide::Long, 0.02), position("okx", OrderSide::Short, 0.05)];
+        let orders = vec![order("stale-7", "okx", OrderSide::Long, 0.01)];
+
+        let report = reconcile(&trades, &positions, &orders, &HashSet::new());
+        assert_eq!(report.restored.len(), 1);
+        assert_eq!(report.discrepancies.len(), 2);
+        assert!(matches!(&report.discrepancies[0],
+            Discrepancy::SizeMismatch { exchange, actual, .. } if exchange == "okx" && *actual == 0.05));
+        assert!(matches!(&report.discrepancies[1], Discrepancy::OrphanOrder { order } if order.id == "stale-7"));
+    }
+
+    #[test]
+    fn test_spot_balance_and_unavailable_venues() {
+        // Spot balance above the leg is fine; an unreachable venue's leg is assumed held
+        let trades = vec![trade("t1", "bybit_spot", "okx", 0.02), trade("t2", "binance", "okx", 0.01)];
+        let positions = vec![position("bybit_spot", OrderSide::Long, 0.5), position("okx", OrderSide::Short, 0.03)];
+        let unavailable: HashSet<String> = ["binance".to_string()].into_iter().collect();
+
+        let report = reconcile(&trades, &positions, &[], &unavailable);
+        assert!(report.is_clean(), "{:?}", report.discrepancies);
+        assert_eq!(report.restored.len(), 2);
+
+        let short = vec![position("bybit_spot", OrderSide::Long, 0.01), position("okx", OrderSide::Short, 0.02)];
+        let report = reconcile(&trades[..1], &short, &[], &HashSet::new());
+        assert!(matches!(report.discrepancies[..], [Discrepancy::SizeMismatch { .. }]));
+    }
+}
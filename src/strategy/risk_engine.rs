@@ -0,0 +1,669 @@
+//! Pre-trade risk engine
+//!
+//! The OMS asks the `RiskEngine` before every order it sends
+//! (`OrderManager::with_risk_engine`), and the runner asks it before starting
+//! a trade. Orders that add exposure are refused when they would break a limit:
+//!
+//! - notional of one symbol on one venue, of one venue, and of one base asset
+//!   across all venues (filled positions plus working orders plus the order);
+//! - orders sent per minute;
+//! - limit price further than the band from the mid in the engine's
+//!   `MarketDataStore` (`market_data`), which the quote bridge in `main` writes
+//!   every update to and the runner adopts in `set_risk_engine`.
+//!
+//! Some conditions halt trading instead of rejecting one order: the day's
+//! realised loss reaching its limit, a base asset staying unhedged (net
+//! notional across venues) for longer than the naked-leg limit, and the
+//! execution layer's own `halt_trading`, which the engine adopts. A halt stays
+//! until an operator acknowledges it (`resume`, driven by the runner's
+//! control-plane key).
+//!
+//! Risk-reducing orders (reduce-only, exits, entry hedges, or any order that
+//! shrinks the current position) always pass, halted or not, so positions can
+//! still be flattened.
+
+use crate::strategy::atomic_execution::{is_trading_halted, resume_trading, trading_halt_reason};
+use crate::strategy::market_data::MarketDataStore;
+use crate::strategy::symbol_map::SymbolMap;
+use crate::strategy::trade_journal::OrderPurpose;
+use crate::strategy::types::{OrderSide, SimulatedOrder};
+use dashmap::DashMap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Quantity below which a position counts as flat
+const QTY_EPSILON: f64 = 1e-9;
+
+/// Window of the order rate limit
+const ORDER_RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Risk limits; all notionals in USD.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RiskLimits {
+    /// Max notional of one symbol on one venue
+    pub max_symbol_notional_usd: f64,
+    /// Max gross notional on one venue
+    pub max_venue_notional_usd: f64,
+    /// Max gross notional of one base asset across venues
+    pub max_asset_notional_usd: f64,
+    pub max_open_trades: usize,
+    pub max_orders_per_minute: usize,
+    /// Realised loss (UTC day) that halts trading
+    pub max_daily_loss_usd: f64,
+    /// How long a base asset may stay unhedged before trading halts
+    pub max_naked_leg_secs: u64,
+    /// Net notional per base asset still counted as hedged
+    pub naked_tolerance_usd: f64,
+    /// Max distance of a limit price from the mid
+    pub price_band_bps: f64,
+    /// Mids older than this are not used for the price band
+    pub max_mid_age_ms: u64,
+}
+
+impl Default for RiskLimits {
+    fn default() -> Self {
+        Self {
+            max_symbol_notional_usd: 5_000.0,
+            max_venue_notional_usd: 20_000.0,
+            max_asset_notional_usd: 10_000.0,
+            max_open_trades: 10,
+            max_orders_per_minute: 120,
+            max_daily_loss_usd: 1_000.0,
+            max_naked_leg_secs: 30,
+            naked_tolerance_usd: 50.0,
+            price_band_bps: 200.0,
+            max_mid_age_ms: 5_000,
+        }
+    }
+}
+
+impl RiskLimits {
+    /// Override limits from `RISK_MAX_SYMBOL_NOTIONAL`, `RISK_MAX_VENUE_NOTIONAL`,
+    /// `RISK_MAX_ASSET_NOTIONAL`, `RISK_MAX_OPEN_TRADES`, `RISK_MAX_ORDERS_PER_MINUTE`,
+    /// `RISK_MAX_DAILY_LOSS`, `RISK_MAX_NAKED_LEG_SECS` and `RISK_PRICE_BAND_BPS`,
+    /// keeping defaults for the rest.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let parse = |var: &str, default: f64| -> f64 {
+            std::env::var(var)
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|v| v.is_finite() && *v >= 0.0)
+                .unwrap_or(default)
+        };
+
+        Self {
+            max_symbol_notional_usd: parse("RISK_MAX_SYMBOL_NOTIONAL", defaults.max_symbol_notional_usd),
+            max_venue_notional_usd: parse("RISK_MAX_VENUE_NOTIONAL", defaults.max_venue_notional_usd),
+            max_asset_notional_usd: parse("RISK_MAX_ASSET_NOTIONAL", defaults.max_asset_notional_usd),
+            max_open_trades: parse("RISK_MAX_OPEN_TRADES", defaults.max_open_trades as f64) as usize,
+            max_orders_per_minute: parse("RISK_MAX_ORDERS_PER_MINUTE", defaults.max_orders_per_minute as f64) as usize,
+            max_daily_loss_usd: parse("RISK_MAX_DAILY_LOSS", defaults.max_daily_loss_usd),
+            max_naked_leg_secs: parse("RISK_MAX_NAKED_LEG_SECS", defaults.max_naked_leg_secs as f64) as u64,
+            price_band_bps: parse("RISK_PRICE_BAND_BPS", defaults.price_band_bps),
+            ..defaults
+        }
+    }
+}
+
+/// Why trading is halted
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum HaltReason {
+    DailyLoss { realised_usd: f64, limit_usd: f64 },
+    /// `asset` stayed unhedged by `exposure_usd` for `secs`
+    NakedLeg { asset: String, exposure_usd: f64, secs: u64 },
+    /// Halted by the execution layer (`halt_trading`)
+    Execution { reason: String },
+}
+
+impl fmt::Display for HaltReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HaltReason::DailyLoss { realised_usd, limit_usd } => {
+                write!(f, "daily realised loss ${:.2} reached limit ${:.2}", -realised_usd, limit_usd)
+            }
+            HaltReason::NakedLeg { asset, exposure_usd, secs } => {
+                write!(f, "{} unhedged by ${:.2} for {}s", asset, exposure_usd, secs)
+            }
+            HaltReason::Execution { reason } => write!(f, "{}", reason),
+        }
+    }
+}
+
+/// Active halt
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Halt {
+    pub reason: HaltReason,
+    /// Unix seconds
+    pub since: u64,
+}
+
+/// Why an order or trade was refused
+#[derive(Debug, Clone, PartialEq)]
+pub enum RiskViolation {
+    Halted(HaltReason),
+    MaxOpenTrades { open: usize, limit: usize },
+    OrderRate { sent: usize, limit: usize },
+    SymbolNotional { exchange: String, symbol: String, notional: f64, limit: f64 },
+    VenueNotional { exchange: String, notional: f64, limit: f64 },
+    AssetNotional { asset: String, notional: f64, limit: f64 },
+    PriceBand { exchange: String, symbol: String, price: f64, mid: f64, limit_bps: f64 },
+}
+
+impl fmt::Display for RiskViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RiskViolation::Halted(reason) => write!(f, "trading halted: {}", reason),
+            RiskViolation::MaxOpenTrades { open, limit } => write!(f, "{} open trades, limit {}", open, limit),
+            RiskViolation::OrderRate { sent, limit } => write!(f, "{} orders in the last minute, limit {}", sent, limit),
+            RiskViolation::SymbolNotional { exchange, symbol, notional, limit } => {
+                write!(f, "{} on {} would reach ${:.2}, limit ${:.2}", symbol, exchange, notional, limit)
+            }
+            RiskViolation::VenueNotional { exchange, notional, limit } => {
+                write!(f, "{} would reach ${:.2}, limit ${:.2}", exchange, notional, limit)
+            }
+            RiskViolation::AssetNotional { asset, notional, limit } => {
+                write!(f, "{} would reach ${:.2} across venues, limit ${:.2}", asset, notional, limit)
+            }
+            RiskViolation::PriceBand { exchange, symbol, price, mid, limit_bps } => {
+                write!(f, "{} on {} priced {} vs mid {} (band {:.0}bps)", symbol, exchange, price, mid, limit_bps)
+            }
+        }
+    }
+}
+
+/// Snapshot published on the control plane
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskStatus {
+    pub halt: Option<Halt>,
+    pub realised_today_usd: f64,
+    /// UTC day (days since the Unix epoch) `realised_today_usd` belongs to
+    #[serde(default)]
+    pub realised_day: u64,
+    pub orders_last_minute: usize,
+    /// Net notional per base asset across venues
+    pub net_exposure_usd: HashMap<String, f64>,
+}
+
+/// Filled position on one (venue, symbol)
+#[derive(Debug, Clone, Copy, Default)]
+struct Exposure {
+    /// Signed base quantity, positive long
+    quantity: f64,
+    last_price: f64,
+}
+
+#[derive(Debug, Default)]
+struct DailyPnl {
+    /// Days since the Unix epoch (UTC)
+    day: u64,
+    realised_usd: f64,
+}
+
+/// Base asset of a USDT-quoted symbol
+pub fn base_asset(symbol: &str) -> &str {
+    symbol.trim_end_matches("USDT")
+}
+
+fn signed(side: OrderSide, quantity: f64) -> f64 {
+    match side {
+        OrderSide::Long => quantity,
+        OrderSide::Short => -quantity,
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+fn now_us() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_micros() as u64
+}
+
+/// Limits, exposure and halt state shared by the OMS and the runner
+pub struct RiskEngine {
+    limits: RiskLimits,
+    symbol_map: Arc<SymbolMap>,
+    /// Reference mids for the price band, fed by the runner
+    mids: Arc<RwLock<MarketDataStore>>,
+    /// (venue, symbol) -> filled position
+    positions: DashMap<(String, String), Exposure>,
+    recent_orders: Mutex<VecDeque<Instant>>,
+    daily: Mutex<DailyPnl>,
+    /// Base asset -> when it became unhedged
+    naked_since: DashMap<String, Instant>,
+    halt: Mutex<Option<Halt>>,
+}
+
+impl fmt::Debug for RiskEngine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RiskEngine")
+            .field("limits", &self.limits)
+            .field("positions", &self.positions)
+            .field("halt", &self.halt)
+            .finish_non_exhaustive()
+    }
+}
+
+impl RiskEngine {
+    pub fn new(limits: RiskLimits, symbol_map: Arc<SymbolMap>) -> Self {
+        Self {
+            limits,
+            symbol_map,
+            mids: Arc::new(RwLock::new(MarketDataStore::new())),
+            positions: DashMap::new(),
+            recent_orders: Mutex::new(VecDeque::new()),
+            daily: Mutex::new(DailyPnl::default()),
+            naked_since: DashMap::new(),
+            halt: Mutex::new(None),
+        }
+    }
+
+    pub fn limits(&self) -> &RiskLimits {
+        &self.limits
+    }
+
+    /// Store the reference mids are read from; whoever consumes quotes writes to it.
+    pub fn market_data(&self) -> Arc<RwLock<MarketDataStore>> {
+        self.mids.clone()
+    }
+
+    /// Fresh mid of `symbol` on `exchange`.
+    pub fn mid(&self, exchange: &str, symbol: &str) -> Option<f64> {
+        let symbol_id = self.symbol_map.get_id(exchange, symbol)?;
+        let mids = self.mids.read().ok()?;
+        let updated_us = mids.get_timestamp(symbol_id)?;
+        if now_us().saturating_sub(updated_us) > self.limits.max_mid_age_ms * 1_000 {
+            return None;
+        }
+        mids.get_mid_price(symbol_id).filter(|mid| *mid > 0.0)
+    }
+
+    /// Current halt, adopting one raised by the execution layer.
+    pub fn halted(&self) -> Option<Halt> {
+        let mut halt = self.halt.lock().ok()?;
+        if halt.is_none() && is_trading_halted() {
+            let reason = trading_halt_reason().unwrap_or_else(|| "halted by execution".to_string());
+            *halt = Some(Halt { reason: HaltReason::Execution { reason }, since: now_secs() });
+        }
+        halt.clone()
+    }
+
+    /// Halt trading; the first reason is kept until an operator resumes.
+    pub fn halt(&self, reason: HaltReason) {
+        let Ok(mut halt) = self.halt.lock() else { return };
+        if halt.is_some() {
+            return;
+        }
+        eprintln!("[RISK] 🛑 Trading halted: {}", reason);
+        *halt = Some(Halt { reason, since: now_secs() });
+    }
+
+    /// Restore a halt persisted by a previous run.
+    pub fn restore_halt(&self, restored: Halt) {
+        if let Ok(mut halt) = self.halt.lock() {
+            eprintln!("[RISK] 🛑 Trading still halted since {}: {}", restored.since, restored.reason);
+            *halt = Some(restored);
+        }
+    }
+
+    /// Restore the realised PnL of `day` persisted by a previous run; other days are ignored.
+    pub fn restore_daily_pnl(&self, day: u64, realised_usd: f64) {
+        if day != now_secs() / 86_400 {
+            return;
+        }
+        if let Ok(mut daily) = self.daily.lock() {
+            eprintln!("[RISK] Realised PnL today so far: ${:.2}", realised_usd);
+            *daily = DailyPnl { day, realised_usd };
+        }
+    }
+
+    /// Clear the halt after operator acknowledgement, including one raised by the
+    /// execution layer; returns the halt that was cleared.
+    pub fn resume(&self) -> Option<Halt> {
+        let cleared = self.halt.lock().ok()?.take();
+        // Naked-leg timers restart so a remaining imbalance gets the full grace period
+        self.naked_since.clear();
+        if is_trading_halted() {
+            resume_trading();
+        }
+        if let Some(halt) = &cleared {
+            eprintln!("[RISK] ▶️  Trading resumed by operator (was: {})", halt.reason);
+        }
+        cleared
+    }
+
+    /// Whether a new trade may start while `open_trades` are open.
+    pub fn check_new_trade(&self, open_trades: usize) -> Result<(), RiskViolation> {
+        if let Some(halt) = self.halted() {
+            return Err(RiskViolation::Halted(halt.reason));
+        }
+        if open_trades >= self.limits.max_open_trades {
+            return Err(RiskViolation::MaxOpenTrades { open: open_trades, limit: self.limits.max_open_trades });
+        }
+        Ok(())
+    }
+
+    /// Pre-trade check of `order`; `working` are the orders already resting,
+    /// with `size` set to their unfilled quantity.
+    ///
+    /// Passing orders count toward the order rate.
+    pub fn check_order(&self, order: &SimulatedOrder, purpose: OrderPurpose, working: &[SimulatedOrder]) -> Result<(), RiskViolation> {
+        let reducing = order.reduce_only
+            || order.close_position
+            || matches!(purpose, OrderPurpose::Exit | OrderPurpose::Hedge)
+            || self.reduces_position(order);
+        if reducing {
+            self.count_order();
+            return Ok(());
+        }
+
+        if let Some(halt) = self.halted() {
+            return Err(RiskViolation::Halted(halt.reason));
+        }
+        let sent = self.orders_last_minute();
+        if sent >= self.limits.max_orders_per_minute {
+            return Err(RiskViolation::OrderRate { sent, limit: self.limits.max_orders_per_minute });
+        }
+        let mid = self.mid(&order.exchange, &order.symbol);
+        if let Some(mid) = mid {
+            if order.price > 0.0 && ((order.price - mid) / mid).abs() * 10_000.0 > self.limits.price_band_bps {
+                return Err(RiskViolation::PriceBand {
+                    exchange: order.exchange.clone(),
+                    symbol: order.symbol.clone(),
+                    price: order.price,
+                    mid,
+                    limit_bps: self.limits.price_band_bps,
+                });
+            }
+        }
+        self.check_notional(order, mid, working)?;
+
+        self.count_order();
+        Ok(())
+    }
+
+    /// Projected notional after `order` fills, on top of positions and working orders.
+    fn check_notional(&self, order: &SimulatedOrder, mid: Option<f64>, working: &[SimulatedOrder]) -> Result<(), RiskViolation> {
+        let asset = base_asset(&order.symbol);
+        let touches = |exchange: &str, symbol: &str| exchange == order.exchange || base_asset(symbol) == asset;
+
+        // (venue, symbol) -> (signed quantity, price)
+        let mut projected: HashMap<(String, String), (f64, f64)> = HashMap::new();
+        for entry in self.positions.iter() {
+            let (exchange, symbol) = entry.key();
+            if touches(exchange, symbol) {
+                projected.insert(entry.key().clone(), (entry.quantity, entry.last_price));
+            }
+        }
+        let resting = working.iter().filter(|o| !o.reduce_only && touches(&o.exchange, &o.symbol));
+        for o in resting.chain(std::iter::once(order)) {
+            let leg = projected.entry((o.exchange.clone(), o.symbol.clone())).or_insert((0.0, 0.0));
+            leg.0 += signed(o.side, o.size);
+            if leg.1 <= 0.0 {
+                leg.1 = o.price;
+            }
+        }
+
+        let price = mid.filter(|_| order.price <= 0.0).unwrap_or(order.price);
+        let notional = |(exchange, symbol): &(String, String), (quantity, last): &(f64, f64)| {
+            let mark = if exchange == &order.exchange && symbol == &order.symbol && price > 0.0 {
+                price
+            } else {
+                self.mid(exchange, symbol).unwrap_or(*last)
+            };
+            quantity.abs() * mark
+        };
+
+        let key = (order.exchange.clone(), order.symbol.clone());
+        let symbol_notional = projected.get(&key).map(|leg| notional(&key, leg)).unwrap_or(0.0);
+        if symbol_notional > self.limits.max_symbol_notional_usd {
+            return Err(RiskViolation::SymbolNotional {
+                exchange: order.exchange.clone(),
+                symbol: order.symbol.clone(),
+                notional: symbol_notional,
+                limit: self.limits.max_symbol_notional_usd,
+            });
+        }
+        let venue_notional: f64 = projected.iter().filter(|(k, _)| k.0 == order.exchange).map(|(k, leg)| notional(k, leg)).sum();
+        if venue_notional > self.limits.max_venue_notional_usd {
+            return Err(RiskViolation::VenueNotional {
+                exchange: order.exchange.clone(),
+                notional: venue_notional,
+                limit: self.limits.max_venue_notional_usd,
+            });
+        }
+        let asset_notional: f64 = projected.iter().filter(|(k, _)| base_asset(&k.1) == asset).map(|(k, leg)| notional(k, leg)).sum();
+        if asset_notional > self.limits.max_asset_notional_usd {
+            return Err(RiskViolation::AssetNotional {
+                asset: asset.to_string(),
+                notional: asset_notional,
+                limit: self.limits.max_asset_notional_usd,
+            });
+        }
+        Ok(())
+    }
+
+    /// Whether `order` only shrinks the filled position on its (venue, symbol).
+    fn reduces_position(&self, order: &SimulatedOrder) -> bool {
+        let held = self.positions
+            .get(&(order.exchange.clone(), order.symbol.clone()))
+            .map(|p| p.quantity)
+            .unwrap_or(0.0);
+        let change = signed(order.side, order.size);
+        held.abs() > QTY_EPSILON && held.signum() != change.signum() && order.size <= held.abs() + QTY_EPSILON
+    }
+
+    fn count_order(&self) {
+        if let Ok(mut recent) = self.recent_orders.lock() {
+            recent.push_back(Instant::now());
+        }
+    }
+
+    pub fn orders_last_minute(&self) -> usize {
+        let Ok(mut recent) = self.recent_orders.lock() else { return 0 };
+        while recent.front().is_some_and(|sent| sent.elapsed() > ORDER_RATE_WINDOW) {
+            recent.pop_front();
+        }
+        recent.len()
+    }
+
+    /// Book a fill of `quantity` at `price` against the order's position.
+    pub fn on_fill(&self, order: &SimulatedOrder, quantity: f64, price: f64) {
+        let mut position = self.positions.entry((order.exchange.clone(), order.symbol.clone())).or_default();
+        position.quantity += signed(order.side, quantity);
+        if position.quantity.abs() <= QTY_EPSILON {
+            position.quantity = 0.0;
+        }
+        if price > 0.0 {
+            position.last_price = price;
+        }
+    }
+
+    /// Add a closed trade's realised PnL to today's total; halts at the daily loss limit.
+    pub fn record_realised_pnl(&self, pnl_usd: f64) {
+        let realised = {
+            let Ok(mut daily) = self.daily.lock() else { return };
+            let today = now_secs() / 86_400;
+            if daily.day != today {
+                *daily = DailyPnl { day: today, realised_usd: 0.0 };
+            }
+            daily.realised_usd += pnl_usd;
+            daily.realised_usd
+        };
+        if pnl_usd < 0.0 && -realised >= self.limits.max_daily_loss_usd {
+            self.halt(HaltReason::DailyLoss { realised_usd: realised, limit_usd: self.limits.max_daily_loss_usd });
+        }
+    }
+
+    fn realised_today(&self) -> f64 {
+        self.daily
+            .lock()
+            .ok()
+            .filter(|daily| daily.day == now_secs() / 86_400)
+            .map(|daily| daily.realised_usd)
+            .unwrap_or(0.0)
+    }
+
+    /// Net notional per base asset across venues.
+    pub fn net_exposure(&self) -> HashMap<String, f64> {
+        let mut net: HashMap<String, f64> = HashMap::new();
+        for entry in self.positions.iter() {
+            let (exchange, symbol) = entry.key();
+            if entry.quantity == 0.0 {
+                continue;
+            }
+            let mark = self.mid(exchange, symbol).unwrap_or(entry.last_price);
+            *net.entry(base_asset(symbol).to_string()).or_default() += entry.quantity * mark;
+        }
+        net
+    }
+
+    /// Run the time-based checks; halts when an asset stayed unhedged too long.
+    pub fn evaluate(&self) -> Option<Halt> {
+        let net = self.net_exposure();
+        self.naked_since.retain(|asset, _| net.get(asset).is_some_and(|n| n.abs() > self.limits.naked_tolerance_usd));
+        for (asset, exposure) in net {
+            if exposure.abs() <= self.limits.naked_tolerance_usd {
+                continue;
+            }
+            let since = *self.naked_since.entry(asset.clone()).or_insert_with(Instant::now);
+            let secs = since.elapsed().as_secs();
+            if secs >= self.limits.max_naked_leg_secs {
+                self.halt(HaltReason::NakedLeg { asset, exposure_usd: exposure.abs(), secs });
+            }
+        }
+        self.halted()
+    }
+
+    pub fn status(&self) -> RiskStatus {
+        RiskStatus {
+            halt: self.halted(),
+            realised_today_usd: self.realised_today(),
+            realised_day: now_secs() / 86_400,
+            orders_last_minute: self.orders_last_minute(),
+            net_exposure_usd: self.net_exposure(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::types::{MarketUpdate, OrderType};
+
+    fn engine(limits: RiskLimits) -> RiskEngine {
+        RiskEngine::new(limits, Arc::new(SymbolMap::new()))
+    }
+
+    fn order(exchange: &str, side: OrderSide, price: f64, size: f64) -> SimulatedOrder {
+        SimulatedOrder {
+            exchange: exchange.to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side,
+            order_type: OrderType::Limit,
+            price,
+            size,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_notional_limits_include_positions_and_working_orders() {
+        let risk = engine(RiskLimits { max_symbol_notional_usd: 1_000.0, max_asset_notional_usd: 1_500.0, ..Default::default() });
+        risk.on_fill(&order("bybit", OrderSide::Long, 100.0, 6.0), 6.0, 100.0);
+
+        assert!(risk.check_order(&order("bybit", OrderSide::Long, 100.0, 3.0), OrderPurpose::Entry, &[]).is_ok());
+        let working = [order("bybit", OrderSide::Long, 100.0, 3.0)];
+        assert!(matches!(
+            risk.check_order(&order("bybit", OrderSide::Long, 100.0, 3.0), OrderPurpose::Entry, &working),
+            Err(RiskViolation::SymbolNotional { .. })
+        ));
+        // Gross across venues: 600 long on bybit + 1000 short on okx
+        assert!(matches!(
+            risk.check_order(&order("okx", OrderSide::Short, 100.0, 10.0), OrderPurpose::Entry, &[]),
+            Err(RiskViolation::AssetNotional { .. })
+        ));
+    }
+
+    #[test]
+    fn test_halt_blocks_new_exposure_but_not_exits() {
+        let risk = engine(RiskLimits { max_daily_loss_usd: 100.0, ..Default::default() });
+        risk.on_fill(&order("bybit", OrderSide::Long, 100.0, 1.0), 1.0, 100.0);
+        risk.record_realised_pnl(-60.0);
+        assert!(risk.halted().is_none());
+        risk.record_realised_pnl(-50.0);
+        assert!(matches!(risk.halted().map(|h| h.reason), Some(HaltReason::DailyLoss { .. })));
+
+        assert!(matches!(risk.check_new_trade(0), Err(RiskViolation::Halted(_))));
+        assert!(risk.check_order(&order("okx", OrderSide::Long, 100.0, 1.0), OrderPurpose::Entry, &[]).is_err());
+        // Closing the held position, and anything in an exit, still goes out
+        assert!(risk.check_order(&order("bybit", OrderSide::Short, 100.0, 1.0), OrderPurpose::Other, &[]).is_ok());
+        assert!(risk.check_order(&order("okx", OrderSide::Short, 100.0, 1.0), OrderPurpose::Exit, &[]).is_ok());
+
+        assert!(risk.resume().is_some());
+        assert!(risk.check_new_trade(0).is_ok());
+    }
+
+    #[test]
+    fn test_restored_daily_pnl_counts_toward_the_limit() {
+        let risk = engine(RiskLimits { max_daily_loss_usd: 100.0, ..Default::default() });
+        let today = now_secs() / 86_400;
+        // A previous day's loss does not carry over
+        risk.restore_daily_pnl(today - 1, -90.0);
+        risk.record_realised_pnl(-20.0);
+        assert!(risk.halted().is_none());
+
+        risk.restore_daily_pnl(today, -90.0);
+        assert_eq!(risk.status().realised_today_usd, -90.0);
+        risk.record_realised_pnl(-20.0);
+        assert!(matches!(risk.halted().map(|h| h.reason), Some(HaltReason::DailyLoss { .. })));
+        risk.resume();
+    }
+
+    #[test]
+    fn test_naked_leg_halts_after_grace_period() {
+        let risk = engine(RiskLimits { max_naked_leg_secs: 0, ..Default::default() });
+        risk.on_fill(&order("bybit", OrderSide::Long, 100.0, 5.0), 5.0, 100.0);
+        risk.on_fill(&order("okx", OrderSide::Short, 100.0, 5.0), 5.0, 100.0);
+        assert!(risk.evaluate().is_none());
+
+        risk.on_fill(&order("okx", OrderSide::Long, 100.0, 5.0), 5.0, 100.0);
+        let halt = risk.evaluate().expect("naked BTC leg should halt");
+        assert!(matches!(halt.reason, HaltReason::NakedLeg { ref asset, .. } if asset == "BTC"));
+        risk.resume();
+    }
+
+    #[test]
+    fn test_order_rate_and_open_trades() {
+        let risk = engine(RiskLimits { max_orders_per_minute: 2, max_open_trades: 1, ..Default::default() });
+        for _ in 0..2 {
+            assert!(risk.check_order(&order("bybit", OrderSide::Long, 100.0, 0.1), OrderPurpose::Entry, &[]).is_ok());
+        }
+        assert!(matches!(
+            risk.check_order(&order("bybit", OrderSide::Long, 100.0, 0.1), OrderPurpose::Entry, &[]),
+            Err(RiskViolation::OrderRate { sent: 2, limit: 2 })
+        ));
+        assert!(matches!(risk.check_new_trade(1), Err(RiskViolation::MaxOpenTrades { .. })));
+    }
+
+    #[test]
+    fn test_price_band_against_fresh_mid() {
+        let symbol_map = Arc::new(SymbolMap::new());
+        let risk = RiskEngine::new(RiskLimits { price_band_bps: 100.0, ..Default::default() }, symbol_map.clone());
+        // No reference price yet: nothing to compare against
+        assert!(risk.check_order(&order("bybit", OrderSide::Long, 150.0, 0.1), OrderPurpose::Entry, &[]).is_ok());
+
+        let update = MarketUpdate::new(symbol_map.get_or_insert("bybit", "BTCUSDT"), 99.9, 100.1, now_us());
+        risk.market_data().write().unwrap().update_from_market_update(&update);
+        assert!(risk.check_order(&order("bybit", OrderSide::Long, 100.5, 0.1), OrderPurpose::Entry, &[]).is_ok());
+        assert!(matches!(
+            risk.check_order(&order("bybit", OrderSide::Long, 102.0, 0.1), OrderPurpose::Entry, &[]),
+            Err(RiskViolation::PriceBand { .. })
+        ));
+    }
+}
@@ -4,21 +4,49 @@ use crate::strategy::entry::EntryExecutor;
 use crate::strategy::positions::PositionManager;
 use crate::strategy::portfolio::PortfolioManager;
 use crate::strategy::atomic_execution::{AtomicExecutor, NegativeFundingTracker};
-use crate::strategy::execution_backend::ExecutionBackend;
-use crate::strategy::fill_probability::FillProbabilityEstimator;
+use crate::strategy::execution_backend::{fit_close_order, ExecutionBackend};
+use crate::strategy::execution_worker::{ExecutionHandle, ExecutionReport, WorkerBackend};
+use crate::strategy::reconciliation::fetch_and_reconcile;
+use crate::strategy::risk_engine::{RiskEngine, RiskStatus};
+use crate::strategy::margin::{assess_trade, LeverageConfig, MarginAction, MarginMonitorConfig, MARGIN_DERISK_EXIT_REASON};
+use crate::strategy::sliced_execution::{SliceConfig, SlicedExecutor};
+use crate::strategy::collateral::{execute_transfer, plan_rebalance, CollateralConfig, MarginTopUp, OpportunityFlow, TransferApi};
+use crate::strategy::trade_journal::{in_scope, JournalEvent, JournalPhase, TradeJournal};
+use crate::strategy::fee_schedule::FeeSchedule;
+use crate::strategy::exit_policy::{ExitContext, ExitPolicyConfig, ExitPolicySet, ExitState, StopLoss, StrategyExitPolicies};
+use crate::strategy::exit_executor::{exit_orders_timed_out, ExitExecutor, TradeLeg, EXIT_ORDER_TIMEOUT_SECS};
 use crate::strategy::pipeline::MarketConsumer;
 use crate::strategy::market_data::MarketDataStore;
 use crate::strategy::opportunity_queue::OpportunityConsumer;
 use crate::strategy::symbol_map::SymbolMap;
+use crate::strategy::funding_carry::{CarryHoldDecision, FundingCarryConfig, FundingCarryPosition};
+use crate::strategy::funding_rates::FundingRateStore;
+use crate::strategy::funding_accrual::{last_settlement, merge_payments, simulate_leg, ACCRUAL_INTERVAL_SECS};
+use crate::strategy::fill_accounting::{merge_fills, FillPnl, FillReconciliation, RECONCILE_INTERVAL_SECS, RECONCILIATION_HISTORY};
 use crate::exchange_parser::get_parser;
 use redis::aio::MultiplexedConnection;
 use dashmap::DashMap;
+use std::collections::{HashMap, VecDeque};
 use std::error::Error;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tokio::time::Duration;
 use uuid::Uuid;
 
+/// Global shutdown flag for coordinating graceful shutdown across threads
+static SHUTDOWN_REQUESTED: AtomicBool = AtomicBool::new(false);
+
+/// Check if shutdown has been requested
+pub fn is_shutdown_requested() -> bool {
+    SHUTDOWN_REQUESTED.load(Ordering::Relaxed)
+}
+
+/// Request graceful shutdown
+pub fn request_shutdown() {
+    SHUTDOWN_REQUESTED.store(true, Ordering::Relaxed);
+}
+
 pub struct StrategyRunner {
     portfolio_manager: Arc<tokio::sync::RwLock<PortfolioManager>>,
     redis_conn: MultiplexedConnection,
@@ -26,12 +54,51 @@ pub struct StrategyRunner {
     negative_funding_trackers: Arc<DashMap<String, NegativeFundingTracker>>,
     execution_backend: Arc<dyn ExecutionBackend>,
     _allowed_exchanges: Option<Vec<String>>,  // Kept for backward compatibility
-    fill_probability_estimator: FillProbabilityEstimator,
     // New fields for streaming architecture
     market_consumer: Option<MarketConsumer>,
-    market_data_store: MarketDataStore,
+    market_data_store: Arc<std::sync::RwLock<MarketDataStore>>,
     opportunity_consumer: Option<OpportunityConsumer>,
     symbol_map: Arc<SymbolMap>,  // Dynamic symbol mapping for all incoming data
+    // Funding-carry mode
+    funding_store: Option<Arc<FundingRateStore>>,
+    carry_config: FundingCarryConfig,
+    // Off-thread order execution
+    execution_handle: Option<ExecutionHandle>,
+    worker_orders: DashMap<u64, String>,  // Worker order id -> venue order ID, from its ack until its final report
+    // Startup reconciliation
+    redis_prefix: String,
+    persisted_trades: Vec<PaperTrade>,  // Active trades of the previous run, until reconciled
+    // Write-ahead trade journal
+    journal: Option<Arc<TradeJournal>>,
+    journaled_state: DashMap<String, String>,  // Trade id -> fingerprint of its last journaled snapshot
+    // Pre-trade risk limits and halts
+    risk_engine: Option<Arc<RiskEngine>>,
+    risk_checked_ms: std::sync::atomic::AtomicU64,  // Unix ms of the last supervise_risk() pass
+    // Leverage and liquidation distance
+    leverage_config: LeverageConfig,
+    margin_config: MarginMonitorConfig,
+    margin_checked_ms: std::sync::atomic::AtomicU64,  // Unix ms of the last monitor_margin() pass
+    // Cross-venue collateral rebalancing
+    collateral_config: CollateralConfig,
+    opportunity_flow: std::sync::Mutex<OpportunityFlow>,
+    margin_top_ups: DashMap<String, MarginTopUp>,  // Trade id -> margin the monitor wants added to its thin leg
+    transfer_api: Option<Arc<dyn TransferApi>>,
+    collateral_checked_ms: std::sync::atomic::AtomicU64,  // Unix ms of the last plan_collateral() pass
+    last_transfer_secs: std::sync::atomic::AtomicU64,
+    // Sliced execution of large entries and exits
+    slice_config: SliceConfig,
+    // Funding settled on open trades
+    funding_accrued_ms: std::sync::atomic::AtomicU64,  // Unix ms of the last accrue_funding() pass
+    // Exit decisions (per strategy) and exit order mechanics
+    exit_policies: StrategyExitPolicies,
+    exit_states: DashMap<String, ExitState>,  // Trade id -> state of its stateful exit policies
+    exit_executor: ExitExecutor,
+    // Fill-based accounting
+    fills_reconciled_ms: std::sync::atomic::AtomicU64,  // Unix ms of the last reconcile_fills() pass
+    closed_reconciliations: std::sync::Mutex<VecDeque<FillReconciliation>>,  // Newest last, capped at RECONCILIATION_HISTORY
+    // Account maker/taker rates
+    fee_schedule: Arc<FeeSchedule>,
+    fee_rates_refreshed_ms: std::sync::atomic::AtomicU64,  // Unix ms of the last refresh_fee_rates() pass
 }
 
 impl StrategyRunner {
@@ -50,13 +117,22 @@ impl StrategyRunner {
         eprintln!("[STRATEGY] Using starting capital: ${:.2}", actual_starting_capital);
         eprintln!("[STRATEGY] Position size per trade (10%): ${:.2}", actual_starting_capital * 0.10);
         
+        // Read what the previous run left open before the portfolio state is cleared;
+        // it is only trusted once reconciled against the venues
+        let persisted_trades = PortfolioManager::load_active_trades(&redis_conn, &redis_prefix)
+            .await
+            .unwrap_or_else(|e| {
+                eprintln!("[RECONCILE] Could not load persisted trades: {}", e);
+                Vec::new()
+            });
+
         let portfolio_manager = PortfolioManager::new(
             redis_conn.clone(), 
             actual_starting_capital,
             Some(redis_prefix.clone()),
         ).await?;
 
-        // Start with fresh active trades (don't load stale data from previous runs)
+        // Start with fresh active trades; reconciled trades are restored in run_scanning_loop()
         let active_trades = Arc::new(DashMap::new());
         let negative_funding_trackers = Arc::new(DashMap::new());
 
@@ -68,6 +144,8 @@ impl StrategyRunner {
                 execution_backend.backend_name(), redis_prefix);
         }
 
+        let exit_executor = ExitExecutor::new(execution_backend.clone());
+
         Ok(Self {
             portfolio_manager: Arc::new(tokio::sync::RwLock::new(portfolio_manager)),
             redis_conn,
@@ -75,11 +153,38 @@ impl StrategyRunner {
             negative_funding_trackers,
             execution_backend,
             _allowed_exchanges: allowed_exchanges,
-            fill_probability_estimator: FillProbabilityEstimator::new(),
             market_consumer: None,  // Will be set via set_market_consumer()
-            market_data_store: MarketDataStore::new(),
+            market_data_store: Arc::new(std::sync::RwLock::new(MarketDataStore::new())),
             opportunity_consumer: None,  // Will be set via set_opportunity_consumer()
             symbol_map,  // Store the dynamic symbol map
+            funding_store: None,  // Will be set via set_funding_store()
+            carry_config: FundingCarryConfig::from_env(),
+            execution_handle: None,  // Will be set via set_execution_handle()
+            worker_orders: DashMap::new(),
+            redis_prefix,
+            persisted_trades,
+            journal: None,  // Will be set via set_trade_journal()
+            journaled_state: DashMap::new(),
+            risk_engine: None,  // Will be set via set_risk_engine()
+            risk_checked_ms: std::sync::atomic::AtomicU64::new(0),
+            leverage_config: LeverageConfig::from_env(),
+            margin_config: MarginMonitorConfig::from_env(),
+            margin_checked_ms: std::sync::atomic::AtomicU64::new(0),
+            collateral_config: CollateralConfig::from_env(),
+            opportunity_flow: std::sync::Mutex::new(OpportunityFlow::new()),
+            margin_top_ups: DashMap::new(),
+            transfer_api: None,  // Will be set via set_transfer_api()
+            collateral_checked_ms: std::sync::atomic::AtomicU64::new(0),
+            last_transfer_secs: std::sync::atomic::AtomicU64::new(0),
+            slice_config: SliceConfig::from_env(),
+            funding_accrued_ms: std::sync::atomic::AtomicU64::new(0),
+            exit_policies: StrategyExitPolicies::from_config(&ExitPolicyConfig::from_env()),
+            exit_states: DashMap::new(),
+            exit_executor,
+            fills_reconciled_ms: std::sync::atomic::AtomicU64::new(0),
+            closed_reconciliations: std::sync::Mutex::new(VecDeque::new()),
+            fee_schedule: Arc::new(FeeSchedule::from_env()),
+            fee_rates_refreshed_ms: std::sync::atomic::AtomicU64::new(0),
         })
     }
 
@@ -103,6 +208,72 @@ impl StrategyRunner {
         self.opportunity_consumer = Some(consumer);
     }
 
+    /// Set the live funding rate store used by the funding-carry holding policy.
+    ///
+    /// Without it, funding-carry trades are only closed by the negative funding
+    /// tracker fallback or manually.
+    pub fn set_funding_store(&mut self, store: Arc<FundingRateStore>) {
+        self.funding_store = Some(store);
+    }
+
+    /// Cost trades with `schedule`, shared with the detector shards so rates
+    /// refreshed from the venues reach the profit filter as well. The schedule
+    /// learns whether this runner's backend rests orders post-only.
+    pub fn set_fee_schedule(&mut self, schedule: Arc<FeeSchedule>) {
+        schedule.set_post_only(self.execution_backend.capabilities().supports_post_only);
+        self.fee_schedule = schedule;
+    }
+
+    /// Place entry and exit orders through the execution worker.
+    ///
+    /// The backend is wrapped in a `WorkerBackend`, and the worker's reports
+    /// update the open trades. Without it, every order is placed inline on the
+    /// strategy task.
+    pub fn set_execution_handle(&mut self, handle: ExecutionHandle) {
+        self.execution_backend = Arc::new(WorkerBackend::new(self.execution_backend.clone(), handle.clone(), self.symbol_map.clone()));
+        self.exit_executor = ExitExecutor::new(self.execution_backend.clone());
+        self.execution_handle = Some(handle);
+    }
+
+    /// Journal trade transitions to `journal` and reconcile against its replay on startup.
+    ///
+    /// Without it, startup reconciliation falls back to the portfolio state persisted in Redis.
+    pub fn set_trade_journal(&mut self, journal: Arc<TradeJournal>) {
+        self.journal = Some(journal);
+    }
+
+    fn journal(&self, event: JournalEvent) {
+        if let Some(journal) = &self.journal {
+            journal.record(event);
+        }
+    }
+
+    /// Gate new trades on `risk`, feed it quotes and realised PnL, and publish its
+    /// status to `strategy:{prefix}:risk`.
+    ///
+    /// A halt and the day's realised PnL persist across restarts; a halt is only
+    /// lifted once an operator sets `strategy:{prefix}:risk:ack`. Attach the same
+    /// engine to the `OrderManager` so every order is checked as well.
+    pub fn set_risk_engine(&mut self, risk: Arc<RiskEngine>) {
+        // One store for both: whoever receives quotes (the market consumer, if
+        // set, or the quote bridge in main) writes to it
+        self.market_data_store = risk.market_data();
+        self.risk_engine = Some(risk);
+    }
+
+    /// Set the withdrawal/deposit API used to execute collateral transfers.
+    ///
+    /// Without it (or without `COLLATERAL_AUTO_EXECUTE`) the collateral plan is
+    /// only published to `strategy:{prefix}:collateral`.
+    pub fn set_transfer_api(&mut self, api: Arc<dyn TransferApi>) {
+        self.transfer_api = Some(api);
+    }
+
+    /// Replace the exit policies of one strategy (defaults come from `EXIT_*`).
+    pub fn set_exit_policies(&mut self, kind: OpportunityKind, policies: ExitPolicySet) {
+        self.exit_policies.set(kind, policies);
+    }
+
     pub async fn run_scanning_loop(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
         // Pin strategy thread to core 1 for optimal cache performance
         // Requirement: 4.1 (Pin strategy thread to core 1)
@@ -111,6 +282,13 @@ impl StrategyRunner {
             eprintln!("[THREAD-PIN] Continuing without thread pinning (performance may be degraded)");
         }
         
+        self.restore_risk_state().await?;
+        self.reconcile_on_startup().await?;
+        if is_shutdown_requested() {
+            eprintln!("[RECONCILE] Shutdown requested during reconciliation, not starting trading");
+            return Ok(());
+        }
+
         // Require opportunity consumer
         // Requirement: 3.1 (Always use streaming mode)
         let opportunity_consumer = self.opportunity_consumer.as_ref()
@@ -131,7 +309,9 @@ impl StrategyRunner {
                 // Process up to 100 market updates per iteration to avoid blocking
                 for _ in 0..100 {
                     if let Some(update) = consumer.pop() {
-                        self.market_data_store.update_from_market_update(&update);
+                        if let Ok(mut store) = self.market_data_store.write() {
+                            store.update_from_market_update(&update);
+                        }
                     } else {
                         break;
                     }
@@ -145,24 +325,553 @@ impl StrategyRunner {
                 self.execute_opportunity(opportunity).await;
             }
             
+            self.process_execution_reports();
+            if let Err(e) = self.supervise_risk().await {
+                eprintln!("[RISK] Control plane error: {}", e);
+            }
+            
             // Small sleep to avoid busy-waiting when queue is empty
             tokio::time::sleep(Duration::from_micros(100)).await;
             
             // Run monitoring tasks in parallel (non-blocking)
-            let (monitor_result, exit_result) = tokio::join!(
+            let (monitor_result, exit_result, margin_result, collateral_result, funding_result, fills_result, fees_result) = tokio::join!(
                 self.monitor_active_positions(),
-                self.check_exits()
+                self.check_exits(),
+                self.monitor_margin(),
+                self.plan_collateral(),
+                self.accrue_funding(),
+                self.reconcile_fills(),
+                self.refresh_fee_rates()
             );
             
             if let Err(e) = monitor_result {
                 eprintln!("Error monitoring positions: {}", e);
             }
+            if let Err(e) = margin_result {
+                eprintln!("[MARGIN] Monitor error: {}", e);
+            }
+            if let Err(e) = funding_result {
+                eprintln!("[FUNDING] Accrual error: {}", e);
+            }
+            if let Err(e) = fills_result {
+                eprintln!("[FILLS] Reconciliation error: {}", e);
+            }
+            if let Err(e) = fees_result {
+                eprintln!("[FEES] Refresh error: {}", e);
+            }
+            if let Err(e) = collateral_result {
+                eprintln!("[COLLATERAL] Planner error: {}", e);
+            }
             if let Err(e) = exit_result {
                 eprintln!("Error checking exits: {}", e);
             }
         }
     }
 
+    /// Compare venue positions and orders with the trades persisted by the previous
+    /// run before any trading starts.
+    ///
+    /// Matched trades are restored and monitored again. While discrepancies remain,
+    /// the report is published to `strategy:{prefix}:reconciliation` and the check
+    /// repeats every 10s until it comes back clean or an operator sets
+    /// `strategy:{prefix}:reconciliation:ack`.
+    async fn reconcile_on_startup(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let capabilities = self.execution_backend.capabilities();
+        if capabilities.is_simulated {
+            eprintln!("[RECONCILE] Simulated backend, nothing to reconcile");
+            return Ok(());
+        }
+
+        if let Some(journal) = &self.journal {
+            let replay = journal.replay().await?;
+            eprintln!("[RECONCILE] Replayed {} journal record(s) from {}", replay.records, journal.path().display());
+            for entry in &replay.pending_entries {
+                // Whatever these filled shows up below as naked legs
+                eprintln!("[RECONCILE] ⚠️  Entry {} on {} ({} / {}) was interrupted: {} order(s) sent, {} with fills",
+                    entry.trade_id, entry.symbol, entry.long_exchange, entry.short_exchange,
+                    entry.orders.len(), entry.filled_orders().count());
+                self.journal(JournalEvent::Aborted { trade_id: entry.trade_id.clone(), reason: "interrupted".to_string() });
+            }
+            self.persisted_trades = replay.active_trades;
+        }
+
+        // A simulated backend has no fixed venue list; it only holds what the journal says it holds
+        let venues = match capabilities.supported_exchanges {
+            Some(venues) => venues,
+            None => {
+                let mut venues: Vec<String> = self.persisted_trades.iter()
+                    .flat_map(|trade| [trade.long_exchange.clone(), trade.short_exchange.clone()])
+                    .collect();
+                venues.sort();
+                venues.dedup();
+                venues
+            }
+        };
+
+        let report_key = format!("strategy:{}:reconciliation", self.redis_prefix);
+        let ack_key = format!("strategy:{}:reconciliation:ack", self.redis_prefix);
+        // Only an acknowledgement given during this startup counts
+        redis::cmd("DEL").arg(&ack_key).query_async::<_, ()>(&mut self.redis_conn.clone()).await?;
+        eprintln!("[RECONCILE] Checking {} persisted trade(s) against {}", self.persisted_trades.len(), venues.join(", "));
+
+        loop {
+            let report = fetch_and_reconcile(self.execution_backend.as_ref(), &venues, &self.persisted_trades).await;
+
+            for trade in &report.restored {
+                if self.active_trades.contains_key(&trade.id) {
+                    continue;
+                }
+                eprintln!("[RECONCILE] Restored trade {} ({} {} / {})", trade.id, trade.symbol, trade.long_exchange, trade.short_exchange);
+                let mut trade = trade.clone();
+                if trade.kind == OpportunityKind::FundingCarry && trade.carry.is_none() {
+                    // Journaled before holding state was persisted: start it over
+                    trade.carry = Some(FundingCarryPosition::new(&trade.symbol, &trade.long_exchange, &trade.short_exchange, trade.entry_time));
+                }
+                self.portfolio_manager.write().await.restore_trade(trade.clone()).await?;
+                if let Some(risk) = &self.risk_engine {
+                    risk.on_fill(&trade.long_order, trade.long_order.size, trade.entry_long_price);
+                    risk.on_fill(&trade.short_order, trade.short_order.size, trade.entry_short_price);
+                }
+                self.active_trades.insert(trade.id.clone(), trade.clone());
+            }
+
+            redis::cmd("SET")
+                .arg(&report_key)
+                .arg(serde_json::to_string(&report)?)
+                .query_async::<_, ()>(&mut self.redis_conn.clone())
+                .await?;
+
+            if report.is_clean() {
+                eprintln!("[RECONCILE] Venues match persisted state");
+                break;
+            }
+
+            eprintln!("[RECONCILE] {} discrepancy(ies), trading blocked:", report.discrepancies.len());
+            for discrepancy in &report.discrepancies {
+                eprintln!("[RECONCILE]   {}", discrepancy);
+            }
+
+            let acknowledged: Option<String> = redis::cmd("GET")
+                .arg(&ack_key)
+                .query_async(&mut self.redis_conn.clone())
+                .await?;
+            if acknowledged.is_some() {
+                // One acknowledgement covers one startup
+                redis::cmd("DEL").arg(&ack_key).query_async::<_, ()>(&mut self.redis_conn.clone()).await?;
+                eprintln!("[RECONCILE] Discrepancies acknowledged by operator, starting anyway");
+                break;
+            }
+            eprintln!("[RECONCILE] Resolve on the venues or SET {} to continue; rechecking in 10s", ack_key);
+            for _ in 0..10 {
+                if is_shutdown_requested() {
+                    break;
+                }
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+            if is_shutdown_requested() {
+                eprintln!("[RECONCILE] Shutdown requested, leaving discrepancies unresolved");
+                return Ok(());
+            }
+        }
+
+        // Trades that could not be restored are no longer tracked
+        for trade in self.persisted_trades.drain(..) {
+            if !self.active_trades.contains_key(&trade.id) {
+                if let Some(journal) = &self.journal {
+                    journal.record(JournalEvent::Abandoned { trade_id: trade.id, reason: "unreconciled".to_string() });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Re-apply a halt the previous run published and nobody acknowledged, and
+    /// the day's realised PnL it had booked.
+    async fn restore_risk_state(&mut self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let Some(risk) = &self.risk_engine else { return Ok(()) };
+        let status: Option<String> = redis::cmd("GET")
+            .arg(format!("strategy:{}:risk", self.redis_prefix))
+            .query_async(&mut self.redis_conn.clone())
+            .await?;
+        let Some(status) = status.and_then(|json| serde_json::from_str::<RiskStatus>(&json).ok()) else {
+            return Ok(());
+        };
+        risk.restore_daily_pnl(status.realised_day, status.realised_today_usd);
+        if let Some(halt) = status.halt {
+            risk.restore_halt(halt);
+        }
+        Ok(())
+    }
+
+    /// Once a second: run the engine's time-based checks, publish its status and
+    /// resume if an operator acknowledged the halt.
+    async fn supervise_risk(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let Some(risk) = &self.risk_engine else { return Ok(()) };
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let last_ms = self.risk_checked_ms.load(std::sync::atomic::Ordering::Relaxed);
+        if now_ms.saturating_sub(last_ms) < 1_000 {
+            return Ok(());
+        }
+        self.risk_checked_ms.store(now_ms, std::sync::atomic::Ordering::Relaxed);
+
+        let status_key = format!("strategy:{}:risk", self.redis_prefix);
+        let ack_key = format!("strategy:{}:risk:ack", self.redis_prefix);
+        if risk.evaluate().is_some() {
+            let acknowledged: Option<String> = redis::cmd("GET")
+                .arg(&ack_key)
+                .query_async(&mut self.redis_conn.clone())
+                .await?;
+            if acknowledged.is_some() {
+                redis::cmd("DEL").arg(&ack_key).query_async::<_, ()>(&mut self.redis_conn.clone()).await?;
+                risk.resume();
+            }
+        } else {
+            // An acknowledgement only lifts a halt that is already in place
+            redis::cmd("DEL").arg(&ack_key).query_async::<_, ()>(&mut self.redis_conn.clone()).await?;
+        }
+
+        redis::cmd("SET")
+            .arg(&status_key)
+            .arg(serde_json::to_string(&risk.status())?)
+            .query_async::<_, ()>(&mut self.redis_conn.clone())
+            .await?;
+        Ok(())
+    }
+
+    /// Record the funding settled on the perp legs of open trades.
+    ///
+    /// Live backends report settlements from the venue's funding history;
+    /// simulated backends, and venues without a history API, simulate them
+    /// from the funding stream.
+    async fn accrue_funding(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let last_ms = self.funding_accrued_ms.load(std::sync::atomic::Ordering::Relaxed);
+        if now_ms.saturating_sub(last_ms) < ACCRUAL_INTERVAL_SECS * 1_000 {
+            return Ok(());
+        }
+        self.funding_accrued_ms.store(now_ms, std::sync::atomic::Ordering::Relaxed);
+        let now = now_ms / 1_000;
+        let is_simulated = self.execution_backend.capabilities().is_simulated;
+
+        let trades: Vec<PaperTrade> = self.active_trades.iter()
+            .filter(|entry| entry.value().status != TradeStatus::Closed && !entry.key().starts_with("placeholder_"))
+            .map(|entry| entry.value().clone())
+            .collect();
+
+        for trade in trades {
+            let mut payments = Vec::new();
+            for (exchange, side, instrument) in [
+                (&trade.long_exchange, OrderSide::Long, trade.long_instrument()),
+                (&trade.short_exchange, OrderSide::Short, trade.short_instrument()),
+            ] {
+                if instrument != InstrumentKind::Perp {
+                    continue;
+                }
+                let reported = if is_simulated {
+                    None
+                } else {
+                    self.execution_backend
+                        .get_funding_history(exchange, &trade.symbol, last_settlement(&trade, exchange))
+                        .await
+                        .ok()
+                };
+                match (reported, &self.funding_store) {
+                    (Some(history), _) => payments.extend(history.into_iter().map(|p| FundingPayment { side, ..p })),
+                    (None, Some(store)) => payments.extend(simulate_leg(store, &trade, side, now, self.carry_config.max_rate_age_secs)),
+                    (None, None) => {}
+                }
+            }
+
+            if let Some(mut live) = self.active_trades.get_mut(&trade.id) {
+                if merge_payments(&mut live, payments) > 0 {
+                    eprintln!("[FUNDING] {} | {} settlement(s) recorded | Net funding: ${:.4}",
+                        live.id, live.funding_payments.len(), live.funding_pnl_usd());
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Every `RECONCILE_INTERVAL_SECS`: record the venue fills of open trades,
+    /// value their open quantity at the venue mark price (the book where no
+    /// mark is reported) and publish estimate-vs-fill figures for open and
+    /// recently closed trades to `strategy:{prefix}:fill_reconciliation`.
+    async fn reconcile_fills(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let last_ms = self.fills_reconciled_ms.load(std::sync::atomic::Ordering::Relaxed);
+        if now_ms.saturating_sub(last_ms) < RECONCILE_INTERVAL_SECS * 1_000 {
+            return Ok(());
+        }
+        self.fills_reconciled_ms.store(now_ms, std::sync::atomic::Ordering::Relaxed);
+        let is_simulated = self.execution_backend.capabilities().is_simulated;
+
+        let trades: Vec<PaperTrade> = self.active_trades.iter()
+            .filter(|entry| entry.value().status != TradeStatus::Closed && !entry.key().starts_with("placeholder_"))
+            .map(|entry| entry.value().clone())
+            .collect();
+
+        let mut marks: HashMap<(String, String), f64> = HashMap::new();
+        if !is_simulated {
+            let mut venues: Vec<&String> = trades.iter()
+                .flat_map(|t| [(&t.long_exchange, t.long_instrument()), (&t.short_exchange, t.short_instrument())])
+                .filter(|(_, instrument)| *instrument == InstrumentKind::Perp)
+                .map(|(exchange, _)| exchange)
+                .collect();
+            venues.sort();
+            venues.dedup();
+            for venue in venues {
+                match self.execution_backend.get_open_positions(venue).await {
+                    Ok(positions) => marks.extend(positions.into_iter()
+                        .filter_map(|p| Some(((p.exchange, p.symbol), p.margin.mark_price?)))),
+                    Err(e) => eprintln!("[FILLS] Could not fetch marks on {}: {}", venue, e),
+                }
+            }
+        }
+
+        let mut report = Vec::with_capacity(trades.len());
+        for trade in trades {
+            let mut fills = Vec::new();
+            for exchange in [&trade.long_exchange, &trade.short_exchange] {
+                match self.execution_backend.get_fills(exchange, &trade.symbol, trade.entry_time).await {
+                    Ok(venue_fills) => fills.extend(venue_fills),
+                    Err(e) if !is_simulated => eprintln!("[FILLS] Could not fetch fills of {} on {}: {}", trade.id, exchange, e),
+                    Err(_) => {}
+                }
+            }
+            let trade = match self.active_trades.get_mut(&trade.id) {
+                Some(mut live) => {
+                    merge_fills(&mut live, fills);
+                    live.clone()
+                }
+                None => continue,
+            };
+
+            let Ok((book_long, book_short)) = self.get_current_prices_for_opportunity(&trade.symbol, &trade.long_exchange, &trade.short_exchange) else {
+                continue;
+            };
+            let mark = |exchange: &String, book: f64| marks.get(&(exchange.clone(), trade.symbol.clone())).copied().unwrap_or(book);
+            let fee_bps = self.estimated_fee_bps(&trade);
+            let spread_reduction_bps = trade.entry_spread_bps - OpportunityScanner::calculate_spread_bps(book_long, book_short);
+            let estimated_pnl = ((spread_reduction_bps - fee_bps) / 10000.0) * trade.position_size_usd + trade.funding_pnl_usd();
+            report.push(FillReconciliation::new(
+                &trade,
+                fee_bps,
+                estimated_pnl,
+                mark(&trade.long_exchange, book_long),
+                mark(&trade.short_exchange, book_short),
+                &self.fee_schedule,
+            ));
+        }
+        report.extend(self.closed_reconciliations.lock().unwrap().iter().cloned());
+
+        redis::cmd("SET")
+            .arg(format!("strategy:{}:fill_reconciliation", self.redis_prefix))
+            .arg(serde_json::to_string(&report)?)
+            .query_async::<_, ()>(&mut self.redis_conn.clone())
+            .await?;
+        Ok(())
+    }
+
+    /// Every `refresh_interval_secs` (0 disables): ask the venues for the
+    /// account's rates on the symbols of open trades and record them in the
+    /// fee schedule.
+    async fn refresh_fee_rates(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let interval_secs = self.fee_schedule.refresh_interval_secs;
+        if interval_secs == 0 || self.execution_backend.capabilities().is_simulated {
+            return Ok(());
+        }
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let last_ms = self.fee_rates_refreshed_ms.load(std::sync::atomic::Ordering::Relaxed);
+        if now_ms.saturating_sub(last_ms) < interval_secs * 1_000 {
+            return Ok(());
+        }
+        self.fee_rates_refreshed_ms.store(now_ms, std::sync::atomic::Ordering::Relaxed);
+
+        let mut legs: Vec<(String, String)> = self.active_trades.iter()
+            .filter(|entry| !entry.key().starts_with("placeholder_"))
+            .flat_map(|entry| {
+                let trade = entry.value();
+                [(trade.long_exchange.clone(), trade.symbol.clone()), (trade.short_exchange.clone(), trade.symbol.clone())]
+            })
+            .collect();
+        legs.sort();
+        legs.dedup();
+
+        for (exchange, symbol) in legs {
+            match self.execution_backend.get_fee_rates(&exchange, &symbol).await {
+                Ok(rates) => {
+                    let previous = self.fee_schedule.rates(&exchange, &symbol);
+                    if previous != rates {
+                        eprintln!("[FEES] {} {} | maker {:.2}bps taker {:.2}bps (was {:.2}/{:.2})",
+                            exchange, symbol, rates.maker_bps, rates.taker_bps, previous.maker_bps, previous.taker_bps);
+                    }
+                    self.fee_schedule.record_reported(&exchange, &symbol, rates);
+                }
+                Err(e) => eprintln!("[FEES] Could not fetch rates of {} on {}: {}", symbol, exchange, e),
+            }
+        }
+        Ok(())
+    }
+
+    /// Every `check_interval_secs`: measure each open trade's distance to
+    /// liquidation from the venues' positions, close trades whose thinnest leg
+    /// is below `derisk_distance` and publish the assessment to
+    /// `strategy:{prefix}:margin`.
+    async fn monitor_margin(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if self.execution_backend.capabilities().is_simulated {
+            return Ok(());
+        }
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let last_ms = self.margin_checked_ms.load(std::sync::atomic::Ordering::Relaxed);
+        if now_ms.saturating_sub(last_ms) < self.margin_config.check_interval_secs * 1_000 {
+            return Ok(());
+        }
+        self.margin_checked_ms.store(now_ms, std::sync::atomic::Ordering::Relaxed);
+
+        let trades: Vec<PaperTrade> = self.active_trades.iter()
+            .filter(|entry| entry.value().status == TradeStatus::Active && !entry.key().starts_with("placeholder_"))
+            .map(|entry| entry.value().clone())
+            .collect();
+
+        let mut venues: Vec<String> = trades.iter()
+            .flat_map(|t| [
+                (t.long_exchange.clone(), t.long_instrument()),
+                (t.short_exchange.clone(), t.short_instrument()),
+            ])
+            .filter(|(_, instrument)| *instrument == InstrumentKind::Perp)
+            .map(|(exchange, _)| exchange)
+            .collect();
+        venues.sort();
+        venues.dedup();
+
+        let mut positions = Vec::new();
+        for venue in &venues {
+            match self.execution_backend.get_open_positions(venue).await {
+                Ok(venue_positions) => positions.extend(venue_positions),
+                Err(e) => eprintln!("[MARGIN] Could not fetch positions on {}: {}", venue, e),
+            }
+        }
+        // Venues that report a liquidation price but no mark are measured from the book
+        for position in positions.iter_mut().filter(|p| p.margin.mark_price.is_none()) {
+            let bid = self.execution_backend.get_best_bid(&position.exchange, &position.symbol).await;
+            let ask = self.execution_backend.get_best_ask(&position.exchange, &position.symbol).await;
+            if let (Ok(bid), Ok(ask)) = (bid, ask) {
+                position.margin = PositionMargin::new(
+                    Some((bid + ask) / 2.0),
+                    position.margin.liquidation_price,
+                    position.margin.margin_usd,
+                    position.margin.leverage,
+                );
+            }
+        }
+
+        let mut report = Vec::with_capacity(trades.len());
+        self.margin_top_ups.clear();
+        for trade in &trades {
+            let assessment = assess_trade(trade, &positions, &self.margin_config);
+            match &assessment.action {
+                MarginAction::Hold => {}
+                MarginAction::Rebalance { from_exchange, to_exchange, distance } => {
+                    eprintln!("[MARGIN] ⚠️  {} {} leg on {} is {:.1}% from liquidation: move collateral from {}",
+                        trade.id, trade.symbol, to_exchange, distance * 100.0, from_exchange);
+                    // Distance grows roughly in proportion to the leg's margin
+                    let leg_margin = assessment.legs.iter()
+                        .find(|leg| &leg.exchange == to_exchange)
+                        .and_then(|leg| leg.margin.margin_usd)
+                        .unwrap_or_else(|| self.leverage_config.required_margin(to_exchange, &trade.symbol, trade.position_size_usd));
+                    self.margin_top_ups.insert(trade.id.clone(), MarginTopUp {
+                        trade_id: trade.id.clone(),
+                        from_exchange: from_exchange.clone(),
+                        to_exchange: to_exchange.clone(),
+                        amount_usd: leg_margin * (self.margin_config.rebalance_distance / distance.max(1e-6) - 1.0),
+                    });
+                }
+                MarginAction::DeRisk { exchange, distance } => {
+                    eprintln!("[MARGIN] ❌ {} {} leg on {} is {:.1}% from liquidation: closing trade",
+                        trade.id, trade.symbol, exchange, distance * 100.0);
+                    let exit_spread_bps = self.get_current_prices(&trade.symbol)
+                        .map(|(long, short)| OpportunityScanner::calculate_spread_bps(long, short))
+                        .ok();
+                    if let Some(mut active) = self.active_trades.get_mut(&trade.id) {
+                        if active.status == TradeStatus::Active {
+                            active.exit_spread_bps = exit_spread_bps.or(Some(active.entry_spread_bps));
+                            active.exit_reason = Some(MARGIN_DERISK_EXIT_REASON.to_string());
+                            active.status = TradeStatus::Exiting;
+                            println!("[EXIT SIGNAL] {} | Reason: {}", active.id, MARGIN_DERISK_EXIT_REASON);
+                        }
+                    }
+                }
+            }
+            report.push(assessment);
+        }
+
+        redis::cmd("SET")
+            .arg(format!("strategy:{}:margin", self.redis_prefix))
+            .arg(serde_json::to_string(&report)?)
+            .query_async::<_, ()>(&mut self.redis_conn.clone())
+            .await?;
+        Ok(())
+    }
+
+    /// Every `check_interval_secs`: plan collateral transfers between venues from
+    /// free balances, position margin, recent opportunity flow and the margin
+    /// monitor's top-ups, publish the plan to `strategy:{prefix}:collateral` and,
+    /// if enabled, execute it through the transfer API.
+    async fn plan_collateral(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        if self.execution_backend.capabilities().is_simulated {
+            return Ok(());
+        }
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let last_ms = self.collateral_checked_ms.load(std::sync::atomic::Ordering::Relaxed);
+        if now_ms.saturating_sub(last_ms) < self.collateral_config.check_interval_secs * 1_000 {
+            return Ok(());
+        }
+        self.collateral_checked_ms.store(now_ms, std::sync::atomic::Ordering::Relaxed);
+        let now = now_ms / 1_000;
+
+        let free = self.execution_backend.get_all_balances().await?;
+        let mut margin_used = HashMap::new();
+        for exchange in free.keys() {
+            if let Ok(positions) = self.execution_backend.get_open_positions(exchange).await {
+                let used: f64 = positions.iter().filter_map(|p| p.margin.margin_usd).sum();
+                margin_used.insert(exchange.clone(), used);
+            }
+        }
+        let demand = self.opportunity_flow.lock().unwrap().demand(now, self.collateral_config.flow_window_secs);
+        let top_ups: Vec<MarginTopUp> = self.margin_top_ups.iter().map(|entry| entry.value().clone()).collect();
+
+        let plan = plan_rebalance(&free, &margin_used, &demand, &top_ups, &self.leverage_config, &self.collateral_config);
+        for transfer in &plan.transfers {
+            eprintln!("[COLLATERAL] Move ${:.2} {} -> {} ({:?})",
+                transfer.amount_usd, transfer.from_exchange, transfer.to_exchange, transfer.reason);
+        }
+        redis::cmd("SET")
+            .arg(format!("strategy:{}:collateral", self.redis_prefix))
+            .arg(serde_json::to_string(&plan)?)
+            .query_async::<_, ()>(&mut self.redis_conn.clone())
+            .await?;
+
+        let Some(api) = &self.transfer_api else { return Ok(()) };
+        let last_transfer = self.last_transfer_secs.load(std::sync::atomic::Ordering::Relaxed);
+        if !self.collateral_config.auto_execute
+            || plan.transfers.is_empty()
+            || now.saturating_sub(last_transfer) < self.collateral_config.transfer_cooldown_secs
+        {
+            return Ok(());
+        }
+        self.last_transfer_secs.store(now, std::sync::atomic::Ordering::Relaxed);
+        for transfer in &plan.transfers {
+            match execute_transfer(api.as_ref(), transfer, &self.collateral_config.transfer_asset).await {
+                Ok(id) => eprintln!("[COLLATERAL] ✅ Withdrawal {} sent: ${:.2} {} -> {}",
+                    id, transfer.amount_usd, transfer.from_exchange, transfer.to_exchange),
+                Err(e) => eprintln!("[COLLATERAL] ❌ Transfer {} -> {} failed: {}",
+                    transfer.from_exchange, transfer.to_exchange, e),
+            }
+        }
+        Ok(())
+    }
+
     /// Execute a single opportunity from the streaming queue.
     ///
     /// This method validates the opportunity and executes the trade if all checks pass.
@@ -190,6 +899,13 @@ impl StrategyRunner {
             println!("[SKIPPED] {} - DUPLICATE SYMBOL | Already have active or exiting trade", opportunity.symbol);
             return;
         }
+
+        if let Some(risk) = &self.risk_engine {
+            if let Err(violation) = risk.check_new_trade(self.active_trades.len()) {
+                println!("[SKIPPED] {} - RISK | {}", opportunity.symbol, violation);
+                return;
+            }
+        }
         
         // Reserve symbol with placeholder
         let placeholder_trade_id = format!("placeholder_{}", uuid::Uuid::new_v4());
@@ -218,6 +934,10 @@ impl StrategyRunner {
             stop_loss_long_price: 0.0,
             stop_loss_short_price: 0.0,
             leg_out_event: None,
+            kind: opportunity.kind,
+            funding_payments: Vec::new(),
+            fills: Vec::new(),
+            carry: None,
         };
         
         self.active_trades.insert(placeholder_trade_id.clone(), placeholder_trade);
@@ -231,6 +951,14 @@ impl StrategyRunner {
             }};
         }
         
+        // Both legs must be routable by the backend
+        let capabilities = self.execution_backend.capabilities();
+        if !capabilities.supports_exchange(&opportunity.long_exchange) || !capabilities.supports_exchange(&opportunity.short_exchange) {
+            println!("[SKIPPED] {} - VENUE NOT SUPPORTED | {} / {} on {} backend", opportunity.symbol,
+                opportunity.long_exchange, opportunity.short_exchange, self.execution_backend.backend_name());
+            skip_and_cleanup!("venue not supported by backend");
+        }
+
         // Validate prices
         let (current_long_price, current_short_price) = match self.get_current_prices_for_opportunity(&opportunity.symbol, &opportunity.long_exchange, &opportunity.short_exchange) {
             Ok((long, short)) => (long, short),
@@ -241,19 +969,27 @@ impl StrategyRunner {
         };
         
         // Validate spread
+        // Funding-carry trades are entered on the funding differential alone,
+        // so only the entry basis is bounded
+        let is_carry = opportunity.kind == OpportunityKind::FundingCarry;
         let current_spread_bps = OpportunityScanner::calculate_spread_bps(current_long_price, current_short_price);
-        if current_spread_bps <= 0.0 {
+        if is_carry {
+            if current_spread_bps.abs() > self.carry_config.max_entry_basis_bps {
+                println!("[SKIPPED] {} - CARRY BASIS TOO WIDE | Current: {:.2}bps", opportunity.symbol, current_spread_bps);
+                skip_and_cleanup!("carry basis too wide");
+            }
+        } else if current_spread_bps <= 0.0 {
             println!("[SKIPPED] {} - NEGATIVE SPREAD | Current: {:.2}bps", opportunity.symbol, current_spread_bps);
             skip_and_cleanup!("negative spread");
         }
         
         // Calculate fees and profit
-        let long_taker_bps = self.get_exchange_taker_fee(&opportunity.long_exchange);
-        let short_taker_bps = self.get_exchange_taker_fee(&opportunity.short_exchange);
-        let total_fee_bps = long_taker_bps + short_taker_bps;
+        let (long_fee_bps, short_fee_bps) = self.fee_schedule.spread_round_trip_bps(
+            &opportunity.long_exchange, &opportunity.short_exchange, &opportunity.symbol);
+        let total_fee_bps = long_fee_bps + short_fee_bps;
         let net_profit_bps = current_spread_bps - total_fee_bps;
         
-        if net_profit_bps <= 0.0 {
+        if !is_carry && net_profit_bps <= 0.0 {
             println!("[SKIPPED] {} - UNPROFITABLE | Net: {:.2}bps", opportunity.symbol, net_profit_bps);
             skip_and_cleanup!("unprofitable");
         }
@@ -279,32 +1015,63 @@ impl StrategyRunner {
             println!("[SKIPPED] {} - INVALID POSITION SIZE", opportunity.symbol);
             skip_and_cleanup!("invalid position size");
         }
+
+        // Opportunity flow shapes where the collateral planner wants free balance
+        {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+            let mut flow = self.opportunity_flow.lock().unwrap();
+            flow.record(now, &opportunity.long_exchange, &opportunity.symbol, position_size, &self.leverage_config);
+            flow.record(now, &opportunity.short_exchange, &opportunity.symbol, position_size, &self.leverage_config);
+        }
         
         // Execute trade
-        let backend_name = self.execution_backend.backend_name();
-        let is_real_trading = backend_name == "Demo" || backend_name == "Live";
+        let is_real_trading = !capabilities.is_simulated;
+        
+        // Journal the intent before any order goes out; the placeholder id names the entry
+        self.journal(JournalEvent::Intent {
+            trade_id: placeholder_trade_id.clone(),
+            symbol: opportunity.symbol.clone(),
+            long_exchange: opportunity.long_exchange.clone(),
+            short_exchange: opportunity.short_exchange.clone(),
+            size_usd: position_size,
+            kind: opportunity.kind,
+        });
         
         let trade_result = if is_real_trading {
-            EntryExecutor::execute_atomic_entry_real(
+            in_scope(&placeholder_trade_id, JournalPhase::Entry, EntryExecutor::execute_atomic_entry_real(
                 &opportunity, 
                 available_capital, 
                 position_size,
-                self.execution_backend.clone()
-            ).await
+                self.execution_backend.clone(),
+                &self.leverage_config,
+                &self.slice_config,
+            )).await
         } else {
             EntryExecutor::execute_atomic_entry(&opportunity, available_capital, position_size)
         };
         
         match trade_result {
             Ok(mut trade) => {
-                let projected_profit_bps = (current_spread_bps * 0.9) - total_fee_bps;
-                trade.projected_profit_usd = ((projected_profit_bps) / 10000.0) * position_size;
+                if is_carry {
+                    // Net carry over the holding horizon (already net of round-trip costs)
+                    trade.projected_profit_usd = (opportunity.projected_profit_after_slippage / 10000.0) * position_size;
+                    trade.carry = Some(FundingCarryPosition::new(&trade.symbol, &trade.long_exchange, &trade.short_exchange, trade.entry_time));
+                } else {
+                    let projected_profit_bps = (current_spread_bps * 0.9) - total_fee_bps;
+                    trade.projected_profit_usd = ((projected_profit_bps) / 10000.0) * position_size;
+                }
                 
                 println!(
                     "[ENTRY] Executed trade {} on {} | Size: ${:.2} | Spread: {:.2}bps | Projected Profit: ${:.2}",
                     trade.id, trade.symbol, trade.position_size_usd, current_spread_bps, trade.projected_profit_usd
                 );
+                if trade.is_spot_perp() {
+                    println!("[ENTRY] {} legs: long {:?} on {} | short {:?} on {}",
+                        trade.id, trade.long_instrument(), trade.long_exchange,
+                        trade.short_instrument(), trade.short_exchange);
+                }
                 
+                self.journal(JournalEvent::Opened { intent_id: placeholder_trade_id.clone(), trade: trade.clone() });
                 self.active_trades.remove(&placeholder_trade_id);
                 self.active_trades.insert(trade.id.clone(), trade.clone());
                 
@@ -314,6 +1081,7 @@ impl StrategyRunner {
             }
             Err(e) => {
                 println!("[SKIPPED] {} - EXECUTION FAILED | Reason: {}", opportunity.symbol, e);
+                self.journal(JournalEvent::Aborted { trade_id: placeholder_trade_id.clone(), reason: e });
                 self.active_trades.remove(&placeholder_trade_id);
             }
         }
@@ -330,1142 +1098,187 @@ impl StrategyRunner {
         }
 
         // Check if we're using real trading backend
-        let backend_name = self.execution_backend.backend_name();
-        let is_real_trading = backend_name == "Demo" || backend_name == "Live";
+        let is_real_trading = !self.execution_backend.capabilities().is_simulated;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
 
         for trade_id in trade_ids {
-            // Get trade info first
-            let (symbol, entry_spread_bps, position_size_usd, entry_projected_profit, has_exit_orders, stop_loss_long, stop_loss_short, stop_loss_triggered, long_exchange, short_exchange) = {
-                if let Some(trade) = self.active_trades.get(&trade_id) {
-                    (
-                        trade.symbol.clone(), 
-                        trade.entry_spread_bps, 
-                        trade.position_size_usd, 
-                        trade.projected_profit_usd,
-                        trade.long_exit_order.is_some() && trade.short_exit_order.is_some(),
-                        trade.stop_loss_long_price,
-                        trade.stop_loss_short_price,
-                        trade.stop_loss_triggered,
-                        trade.long_exchange.clone(),
-                        trade.short_exchange.clone(),
-                    )
-                } else {
-                    continue;
-                }
+            let trade = match self.active_trades.get(&trade_id) {
+                Some(trade) if trade.status == TradeStatus::Active => trade.clone(),
+                _ => continue,
             };
 
             // Get current prices from market data store (no Redis in hot path)
             // CRITICAL FIX: Don't let price fetch failure stop monitoring of other trades
-            let (current_long_price, current_short_price) = match self.get_current_prices(&symbol) {
+            let (current_long_price, current_short_price) = match self.get_current_prices(&trade.symbol) {
                 Ok(prices) => prices,
                 Err(e) => {
-                    eprintln!("[MONITOR] ⚠️  Failed to get prices for {}: {} - skipping this trade", symbol, e);
+                    eprintln!("[MONITOR] ⚠️  Failed to get prices for {}: {} - skipping this trade", trade.symbol, e);
                     continue;
                 }
             };
             let current_spread_bps = OpportunityScanner::calculate_spread_bps(current_long_price, current_short_price);
 
-            // ============================================================================
-            // REAL TRADING MONITORING (Demo/Live with exit orders)
-            // ============================================================================
-            if is_real_trading && has_exit_orders {
-                eprintln!("[REAL MONITOR] {} | Checking exit orders and stop-loss", trade_id);
-
-                // 1. Check if exit orders have filled
-                let (long_exit_id, short_exit_id) = {
-                    if let Some(trade) = self.active_trades.get(&trade_id) {
-                        (
-                            trade.long_exit_order.as_ref().map(|o| o.id.clone()),
-                            trade.short_exit_order.as_ref().map(|o| o.id.clone()),
-                        )
-                    } else {
+            // Real trades rest an exit order per leg: settle their fills first
+            if is_real_trading && trade.long_exit_order.is_some() && trade.short_exit_order.is_some() {
+                let fills = self.exit_executor.poll_exit_orders(&trade).await;
+                match (fills.long_filled, fills.short_filled) {
+                    (true, true) => {
+                        eprintln!("[TRADE COMPLETE] {} | Both exit orders filled! Closing trade...", trade_id);
+                        self.mark_exiting(&trade_id, "Exit orders filled (90% capture)".to_string(), current_spread_bps);
                         continue;
                     }
-                };
-
-                let mut long_exit_filled = false;
-                let mut short_exit_filled = false;
-
-                // Check long exit order status
-                if let Some(ref order_id) = long_exit_id {
-                    match self.execution_backend.get_order_status(&long_exchange, order_id).await {
-                        Ok(OrderStatus::Filled) => {
-                            eprintln!("[EXIT FILLED] {} | Long exit order filled!", trade_id);
-                            long_exit_filled = true;
-                        }
-                        Ok(OrderStatus::Cancelled) => {
-                            eprintln!("[EXIT CANCELLED] {} | Long exit order was cancelled", trade_id);
-                        }
-                        Ok(OrderStatus::Pending) => {
-                            // Still waiting
+                    (true, false) | (false, true) => {
+                        let (open_leg, mark) = if fills.long_filled {
+                            (TradeLeg::Short, current_short_price)
+                        } else {
+                            (TradeLeg::Long, current_long_price)
+                        };
+                        eprintln!("[PARTIAL EXIT] {} | {:?} leg still open - HEDGING NOW", trade_id, open_leg);
+                        // Mark Exiting immediately so the symbol is not re-entered while hedging
+                        if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
+                            trade.status = TradeStatus::Exiting;
                         }
-                        Err(e) => {
-                            eprintln!("[EXIT ERROR] {} | Error checking long exit: {}", trade_id, e);
+                        if let Some(reason) = self.exit_executor.hedge_open_leg(&trade, open_leg, mark).await {
+                            self.mark_exiting(&trade_id, reason.to_string(), current_spread_bps);
                         }
+                        continue;
                     }
-                }
-
-                // Check short exit order status
-                if let Some(ref order_id) = short_exit_id {
-                    match self.execution_backend.get_order_status(&short_exchange, order_id).await {
-                        Ok(OrderStatus::Filled) => {
-                            eprintln!("[EXIT FILLED] {} | Short exit order filled!", trade_id);
-                            short_exit_filled = true;
-                        }
-                        Ok(OrderStatus::Cancelled) => {
-                            eprintln!("[EXIT CANCELLED] {} | Short exit order was cancelled", trade_id);
-                        }
-                        Ok(OrderStatus::Pending) => {
-                            // Still waiting
-                        }
-                        Err(e) => {
-                            eprintln!("[EXIT ERROR] {} | Error checking short exit: {}", trade_id, e);
-                        }
+                    (false, false) if exit_orders_timed_out(&trade, now) => {
+                        eprintln!("[EXIT TIMEOUT] {} | Exit orders not filled after {}s - forcing close", trade_id, EXIT_ORDER_TIMEOUT_SECS);
+                        self.exit_executor.flatten(&trade, current_long_price, current_short_price, "[EXIT TIMEOUT]").await;
+                        self.mark_exiting(&trade_id, format!("Exit timeout ({}s) - forced close", EXIT_ORDER_TIMEOUT_SECS), current_spread_bps);
+                        continue;
                     }
+                    (false, false) => {}
                 }
+            }
 
-                // If both exit orders filled, close the trade
-                if long_exit_filled && short_exit_filled {
-                    if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                        trade.status = TradeStatus::Exiting;
-                        trade.exit_reason = Some("Exit orders filled (90% capture)".to_string());
-                        trade.exit_spread_bps = Some(current_spread_bps);
-                        eprintln!("[TRADE COMPLETE] {} | Both exit orders filled! Closing trade...", trade_id);
-                    }
-                    continue; // Move to check_exits for final processing
+            let ctx = ExitContext {
+                trade: &trade,
+                now,
+                long_price: current_long_price,
+                short_price: current_short_price,
+                spread_bps: current_spread_bps,
+                funding_delta_8h: self.live_funding_delta(&trade, now),
+            };
+            eprintln!("[MONITOR] {} | Entry: {:.2}bps | Current: {:.2}bps | Spread Reduction: {:.2}bps | P&L: ${:.2} | Projected: ${:.2}",
+                trade.symbol, trade.entry_spread_bps, current_spread_bps, ctx.spread_reduction_bps(), ctx.pnl_usd(), trade.projected_profit_usd);
+
+            let signal = {
+                let mut state = self.exit_states.entry(trade_id.clone()).or_default();
+                self.exit_policies.for_kind(trade.kind).evaluate(&ctx, &mut state)
+            };
+            let Some(signal) = signal else {
+                if trade.carry.is_none() {
+                    self.record_leg_out(&trade_id, current_long_price, current_short_price);
                 }
+                continue;
+            };
 
-                // TIMEOUT CHECK: If NEITHER exit order filled after 60 seconds, force close both
-                // This prevents positions from staying open indefinitely
-                if !long_exit_filled && !short_exit_filled {
-                    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
-                    
-                    // Get exit order creation times
-                    let (long_exit_age, short_exit_age) = {
-                        if let Some(trade) = self.active_trades.get(&trade_id) {
-                            let long_age = trade.long_exit_order.as_ref().map(|o| now.saturating_sub(o.created_at)).unwrap_or(0);
-                            let short_age = trade.short_exit_order.as_ref().map(|o| now.saturating_sub(o.created_at)).unwrap_or(0);
-                            (long_age, short_age)
-                        } else {
-                            continue;
-                        }
-                    };
-                    
-                    // If both exit orders are older than 3 seconds, force close
-                    const EXIT_TIMEOUT_SECONDS: u64 = 3;
-                    if long_exit_age > EXIT_TIMEOUT_SECONDS && short_exit_age > EXIT_TIMEOUT_SECONDS {
-                        eprintln!("[EXIT TIMEOUT] {} | Exit orders not filled after {}s - forcing close", trade_id, EXIT_TIMEOUT_SECONDS);
-                        
-                        // Get position sizes
-                        let (long_quantity, short_quantity) = if let Some(trade) = self.active_trades.get(&trade_id) {
-                            (trade.long_order.size, trade.short_order.size)
-                        } else {
-                            continue;
-                        };
-                        
-                        // Track cumulative fills
-                        let mut long_filled_from_cancel = 0.0;
-                        let mut short_filled_from_cancel = 0.0;
-                        
-                        // Cancel both exit orders and check if they filled
-                        if let Some(ref order_id) = long_exit_id {
-                            eprintln!("[EXIT TIMEOUT] Cancelling long exit order: {}", order_id);
-                            let _ = self.execution_backend.cancel_order(&long_exchange, order_id).await;
-                            
-                            match self.execution_backend.get_order_status_detailed(&long_exchange, order_id, &symbol).await {
-                                Ok(status_info) => {
-                                    long_filled_from_cancel = status_info.filled_quantity;
-                                    if long_filled_from_cancel > 0.0 {
-                                        eprintln!("[EXIT TIMEOUT] Long exit filled {:.4} before cancellation", long_filled_from_cancel);
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("[EXIT TIMEOUT] ⚠️  Failed to check long exit: {}", e);
-                                }
-                            }
-                        }
-                        
-                        if let Some(ref order_id) = short_exit_id {
-                            eprintln!("[EXIT TIMEOUT] Cancelling short exit order: {}", order_id);
-                            let _ = self.execution_backend.cancel_order(&short_exchange, order_id).await;
-                            
-                            match self.execution_backend.get_order_status_detailed(&short_exchange, order_id, &symbol).await {
-                                Ok(status_info) => {
-                                    short_filled_from_cancel = status_info.filled_quantity;
-                                    if short_filled_from_cancel > 0.0 {
-                                        eprintln!("[EXIT TIMEOUT] Short exit filled {:.4} before cancellation", short_filled_from_cancel);
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("[EXIT TIMEOUT] ⚠️  Failed to check short exit: {}", e);
-                                }
-                            }
-                        }
-                        
-                        // Try aggressive limits for remaining quantities (2 second window)
-                        let remaining_long = long_quantity - long_filled_from_cancel;
-                        let remaining_short = short_quantity - short_filled_from_cancel;
-                        
-                        if remaining_long > 0.001 {
-                            eprintln!("[EXIT TIMEOUT] Trying aggressive limit for long: {:.4} contracts", remaining_long);
-                            match self.execution_backend.get_best_bid(&long_exchange, &symbol).await {
-                                Ok(best_bid) => {
-                                    let limit_order = SimulatedOrder {
-                                        id: format!("timeout_exit_long_{}", uuid::Uuid::new_v4()),
-                                        exchange: long_exchange.clone(),
-                                        symbol: symbol.clone(),
-                                        side: OrderSide::Short,
-                                        order_type: OrderType::Limit,
-                                        price: best_bid,
-                                        size: remaining_long,
-                                        queue_position: None,
-                                        created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                        filled_at: None,
-                                        fill_price: None,
-                                        status: OrderStatus::Pending,
-                                    };
-                                    
-                                    if let Ok(placed_order) = self.execution_backend.place_order(limit_order).await {
-                                        tokio::time::sleep(Duration::from_secs(2)).await;
-                                        
-                                        match self.execution_backend.get_order_status_detailed(&long_exchange, &placed_order.id, &symbol).await {
-                                            Ok(status_info) => {
-                                                long_filled_from_cancel += status_info.filled_quantity;
-                                                if status_info.filled_quantity > 0.0 {
-                                                    eprintln!("[EXIT TIMEOUT] Long limit filled {:.4} contracts", status_info.filled_quantity);
-                                                }
-                                                let _ = self.execution_backend.cancel_order(&long_exchange, &placed_order.id).await;
-                                            }
-                                            Err(_) => {
-                                                let _ = self.execution_backend.cancel_order(&long_exchange, &placed_order.id).await;
-                                            }
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("[EXIT TIMEOUT] ⚠️  Failed to get best bid: {}", e);
-                                }
-                            }
-                        }
-                        
-                        if remaining_short > 0.001 {
-                            eprintln!("[EXIT TIMEOUT] Trying aggressive limit for short: {:.4} contracts", remaining_short);
-                            match self.execution_backend.get_best_ask(&short_exchange, &symbol).await {
-                                Ok(best_ask) => {
-                                    let limit_order = SimulatedOrder {
-                                        id: format!("timeout_exit_short_{}", uuid::Uuid::new_v4()),
-                                        exchange: short_exchange.clone(),
-                                        symbol: symbol.clone(),
-                                        side: OrderSide::Long,
-                                        order_type: OrderType::Limit,
-                                        price: best_ask,
-                                        size: remaining_short,
-                                        queue_position: None,
-                                        created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                        filled_at: None,
-                                        fill_price: None,
-                                        status: OrderStatus::Pending,
-                                    };
-                                    
-                                    if let Ok(placed_order) = self.execution_backend.place_order(limit_order).await {
-                                        tokio::time::sleep(Duration::from_secs(2)).await;
-                                        
-                                        match self.execution_backend.get_order_status_detailed(&short_exchange, &placed_order.id, &symbol).await {
-                                            Ok(status_info) => {
-                                                short_filled_from_cancel += status_info.filled_quantity;
-                                                if status_info.filled_quantity > 0.0 {
-                                                    eprintln!("[EXIT TIMEOUT] Short limit filled {:.4} contracts", status_info.filled_quantity);
-                                                }
-                                                let _ = self.execution_backend.cancel_order(&short_exchange, &placed_order.id).await;
-                                            }
-                                            Err(_) => {
-                                                let _ = self.execution_backend.cancel_order(&short_exchange, &placed_order.id).await;
-                                            }
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("[EXIT TIMEOUT] ⚠️  Failed to get best ask: {}", e);
-                                }
-                            }
-                        }
-                        
-                        // Market orders for any remaining quantities
-                        let final_remaining_long = long_quantity - long_filled_from_cancel;
-                        let final_remaining_short = short_quantity - short_filled_from_cancel;
-                        
-                        if final_remaining_long > 0.001 {
-                            eprintln!("[EXIT TIMEOUT] Market closing long: {:.4} contracts", final_remaining_long);
-                            let market_order = SimulatedOrder {
-                                id: format!("timeout_market_long_{}", uuid::Uuid::new_v4()),
-                                exchange: long_exchange.clone(),
-                                symbol: symbol.clone(),
-                                side: OrderSide::Short,
-                                order_type: OrderType::Market,
-                                price: current_long_price,
-                                size: final_remaining_long,
-                                queue_position: None,
-                                created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                filled_at: None,
-                                fill_price: None,
-                                status: OrderStatus::Pending,
-                            };
-                            
-                            match self.execution_backend.place_market_order(market_order).await {
-                                Ok(_) => {
-                                    eprintln!("[EXIT TIMEOUT] ✅ Long position closed");
-                                }
-                                Err(e) => {
-                                    eprintln!("[EXIT TIMEOUT] ❌ Failed to close long: {}", e);
-                                }
-                            }
-                        }
-                        
-                        if final_remaining_short > 0.001 {
-                            eprintln!("[EXIT TIMEOUT] Market closing short: {:.4} contracts", final_remaining_short);
-                            let market_order = SimulatedOrder {
-                                id: format!("timeout_market_short_{}", uuid::Uuid::new_v4()),
-                                exchange: short_exchange.clone(),
-                                symbol: symbol.clone(),
-                                side: OrderSide::Long,
-                                order_type: OrderType::Market,
-                                price: current_short_price,
-                                size: final_remaining_short,
-                                queue_position: None,
-                                created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                filled_at: None,
-                                fill_price: None,
-                                status: OrderStatus::Pending,
-                            };
-                            
-                            match self.execution_backend.place_market_order(market_order).await {
-                                Ok(_) => {
-                                    eprintln!("[EXIT TIMEOUT] ✅ Short position closed");
-                                }
-                                Err(e) => {
-                                    eprintln!("[EXIT TIMEOUT] ❌ Failed to close short: {}", e);
-                                }
-                            }
-                        }
-                        
-                        // Mark trade as exiting
-                        if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                            trade.status = TradeStatus::Exiting;
-                            trade.exit_reason = Some(format!("Exit timeout ({}s) - forced close", EXIT_TIMEOUT_SECONDS));
-                            trade.exit_spread_bps = Some(current_spread_bps);
-                        }
-                        
-                        eprintln!("[EXIT TIMEOUT] ✅ Both positions closed after timeout");
-                        continue;
-                    }
-                }
+            println!("[EXIT SIGNAL] {} | Reason: {} | {}", trade_id, signal.policy, signal.reason);
+            // Carry legs are flattened by check_exits
+            if is_real_trading && trade.carry.is_none() {
+                self.exit_executor.flatten(&trade, current_long_price, current_short_price, "[EXIT]").await;
+            }
+            if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
+                trade.stop_loss_triggered |= signal.policy == StopLoss::NAME;
+            }
+            self.mark_exiting(&trade_id, signal.reason, current_spread_bps);
+        }
 
-                // CRITICAL: If only ONE exit filled, hedge the other immediately
-                // Strategy: Try aggressive limit first (maker fee), fallback to market (taker fee)
-                // FIX: Track cumulative fills to prevent double-fill race conditions
-                if long_exit_filled && !short_exit_filled {
-                    eprintln!("[PARTIAL EXIT] {} | Long exit filled, but short still open - HEDGING NOW", trade_id);
-                    
-                    // CRITICAL: Mark trade as Exiting IMMEDIATELY to prevent duplicate symbol entries
-                    if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                        trade.status = TradeStatus::Exiting;
-                        eprintln!("[PARTIAL EXIT] Trade marked as Exiting to prevent race conditions");
-                    }
-                    
-                    // Track cumulative filled quantity across all orders
-                    let mut total_filled = 0.0;
-                    
-                    // Get target position size
-                    let short_quantity = if let Some(trade) = self.active_trades.get(&trade_id) {
-                        trade.short_order.size
-                    } else {
-                        continue;
-                    };
-                    
-                    // STEP 1: Cancel the original short exit order
-                    if let Some(ref order_id) = short_exit_id {
-                        eprintln!("[PARTIAL EXIT] Cancelling original short exit order: {}", order_id);
-                        let _ = self.execution_backend.cancel_order(&short_exchange, order_id).await;
-                        
-                        // CRITICAL FIX: Check if cancelled order actually filled before cancellation took effect
-                        eprintln!("[PARTIAL EXIT] Checking if cancelled exit order filled...");
-                        match self.execution_backend.get_order_status_detailed(&short_exchange, order_id, &symbol).await {
-                            Ok(status_info) => {
-                                total_filled += status_info.filled_quantity;
-                                if status_info.filled_quantity > 0.0 {
-                                    eprintln!("[PARTIAL EXIT] ⚠️  Cancelled exit order filled {:.4} contracts before cancellation!", status_info.filled_quantity);
-                                } else {
-                                    eprintln!("[PARTIAL EXIT] ✅ Cancelled exit order did not fill");
-                                }
-                            }
-                            Err(e) => {
-                                eprintln!("[PARTIAL EXIT] ⚠️  Failed to check cancelled order status: {} (assuming 0 fill)", e);
-                            }
-                        }
-                    }
-                    
-                    // Check if we're already fully hedged from the cancelled order
-                    if total_filled >= short_quantity {
-                        eprintln!("[PARTIAL EXIT] ✅ Already fully hedged from cancelled exit order ({:.4} contracts)", total_filled);
-                        if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                            trade.status = TradeStatus::Exiting;
-                            trade.exit_reason = Some("Partial exit hedged (cancelled order filled)".to_string());
-                            trade.exit_spread_bps = Some(current_spread_bps);
-                        }
-                        continue;
-                    }
-                    
-                    // STEP 2: Try smart aggressive limit using order book depth
-                    let remaining_after_cancel = short_quantity - total_filled;
-                    eprintln!("[PARTIAL EXIT] Attempting smart aggressive limit for remaining {:.4} contracts...", remaining_after_cancel);
-                    
-                    // Fetch order book depth to find a price likely to fill quickly
-                    match self.execution_backend.get_order_book_depth(&short_exchange, &symbol, 5).await {
-                        Ok(order_book) => {
-                            // For closing short (buying), we want to look at asks
-                            // Place order 2-3 levels into the book for higher fill probability
-                            let aggressive_price = if order_book.asks.len() >= 3 {
-                                // Use 3rd level (index 2) for very high fill probability
-                                order_book.asks[2].price
-                            } else if order_book.asks.len() >= 2 {
-                                // Use 2nd level if 3rd not available
-                                order_book.asks[1].price
-                            } else if !order_book.asks.is_empty() {
-                                // Fallback to best ask
-                                order_book.asks[0].price
-                            } else {
-                                // No order book data, skip limit and go straight to market
-                                eprintln!("[PARTIAL EXIT] ⚠️  No order book data, skipping limit order");
-                                0.0 // Signal to skip limit order
-                            };
-                            
-                            if aggressive_price > 0.0 {
-                                eprintln!("[PARTIAL EXIT] Placing limit at ${:.4} ({}th level in book)", 
-                                    aggressive_price, 
-                                    if order_book.asks.len() >= 3 { "3rd" } else if order_book.asks.len() >= 2 { "2nd" } else { "1st" });
-                                
-                                let limit_order = SimulatedOrder {
-                                    id: format!("aggressive_exit_{}", uuid::Uuid::new_v4()),
-                                    exchange: short_exchange.clone(),
-                                    symbol: symbol.clone(),
-                                    side: OrderSide::Long, // Closing short = buy
-                                    order_type: OrderType::Limit,
-                                    price: aggressive_price,
-                                    size: remaining_after_cancel,
-                                    queue_position: None,
-                                    created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                    filled_at: None,
-                                    fill_price: None,
-                                    status: OrderStatus::Pending,
-                                };
-                                
-                                match self.execution_backend.place_order(limit_order.clone()).await {
-                                    Ok(placed_order) => {
-                                        eprintln!("[PARTIAL EXIT] Smart aggressive limit placed: {}", placed_order.id);
-                                        
-                                        // Wait only 200ms for fill (much faster than before)
-                                        tokio::time::sleep(Duration::from_millis(200)).await;
-                                        
-                                        // Check if filled
-                                        match self.execution_backend.get_order_status_detailed(&short_exchange, &placed_order.id, &symbol).await {
-                                            Ok(status_info) => {
-                                                total_filled += status_info.filled_quantity;
-                                                if status_info.filled_quantity >= remaining_after_cancel {
-                                                    eprintln!("[PARTIAL EXIT] ✅ Smart limit fully filled! Hedge complete (maker fee)");
-                                                    if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                                                        trade.status = TradeStatus::Exiting;
-                                                        trade.exit_reason = Some("Partial exit hedged with smart limit".to_string());
-                                                        trade.exit_spread_bps = Some(current_spread_bps);
-                                                    }
-                                                    continue;
-                                                } else if status_info.filled_quantity > 0.0 {
-                                                    eprintln!("[PARTIAL EXIT] ⚠️  Limit partially filled {:.4} contracts, cancelling for market order", status_info.filled_quantity);
-                                                    let _ = self.execution_backend.cancel_order(&short_exchange, &placed_order.id).await;
-                                                } else {
-                                                    eprintln!("[PARTIAL EXIT] ⚠️  Limit didn't fill - cancelling and using market order");
-                                                    let _ = self.execution_backend.cancel_order(&short_exchange, &placed_order.id).await;
-                                                }
-                                            }
-                                            Err(e) => {
-                                                eprintln!("[PARTIAL EXIT] ⚠️  Failed to check limit order status: {} (assuming 0 fill)", e);
-                                                let _ = self.execution_backend.cancel_order(&short_exchange, &placed_order.id).await;
-                                            }
-                                        }
-                                    }
-                                    Err(e) => {
-                                        eprintln!("[PARTIAL EXIT] ⚠️  Failed to place smart limit: {}", e);
-                                    }
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("[PARTIAL EXIT] ⚠️  Failed to get order book: {}", e);
-                        }
-                    }
-                    
-                    // Check if we're already fully hedged
-                    if total_filled >= short_quantity {
-                        eprintln!("[PARTIAL EXIT] ✅ Already fully hedged ({:.4} contracts total)", total_filled);
-                        if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                            trade.status = TradeStatus::Exiting;
-                            trade.exit_reason = Some("Partial exit hedged (limit partial fill)".to_string());
-                            trade.exit_spread_bps = Some(current_spread_bps);
-                        }
-                        continue;
-                    }
-                    
-                    // STEP 3: Market order for REMAINING quantity only
-                    let remaining_quantity = short_quantity - total_filled;
-                    eprintln!("[PARTIAL EXIT] Placing market order for REMAINING {:.4} contracts (total filled so far: {:.4})", 
-                        remaining_quantity, total_filled);
-                    
-                    let market_order = SimulatedOrder {
-                        id: format!("market_hedge_{}", uuid::Uuid::new_v4()),
-                        exchange: short_exchange.clone(),
-                        symbol: symbol.clone(),
-                        side: OrderSide::Long, // Closing short = buy
-                        order_type: OrderType::Market,
-                        price: current_short_price,
-                        size: remaining_quantity,  // FIX: Use remaining quantity, not full quantity
-                        queue_position: None,
-                        created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                        filled_at: None,
-                        fill_price: None,
-                        status: OrderStatus::Pending,
-                    };
-                    
-                    match self.execution_backend.place_market_order(market_order).await {
-                        Ok(_) => {
-                            eprintln!("[PARTIAL EXIT] ✅ Market order filled! Hedge complete (taker fee)");
-                            eprintln!("[PARTIAL EXIT] Total filled: {:.4} contracts (target: {:.4})", total_filled + remaining_quantity, short_quantity);
-                            if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                                trade.status = TradeStatus::Exiting;
-                                trade.exit_reason = Some("Partial exit hedged with market order".to_string());
-                                trade.exit_spread_bps = Some(current_spread_bps);
-                            }
-                        }
-                        Err(e) => {
-                            eprintln!("[PARTIAL EXIT] ❌ CRITICAL: Market order failed: {}", e);
-                            eprintln!("[PARTIAL EXIT] 🚨 UNHEDGED SHORT POSITION ON {}", short_exchange);
-                        }
-                    }
-                    continue;
-                }
-                
-                if short_exit_filled && !long_exit_filled {
-                    eprintln!("[PARTIAL EXIT] {} | Short exit filled, but long still open - INTELLIGENT HEDGE", trade_id);
-                    
-                    // CRITICAL: Mark trade as Exiting IMMEDIATELY to prevent duplicate symbol entries
-                    if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                        trade.status = TradeStatus::Exiting;
-                        eprintln!("[PARTIAL EXIT] Trade marked as Exiting to prevent race conditions");
-                    }
-                    
-                    // Get target position size
-                    let long_quantity = if let Some(trade) = self.active_trades.get(&trade_id) {
-                        trade.long_order.size
-                    } else {
-                        continue;
-                    };
-                    
-                    // Calculate position size in USD for probability estimation
-                    let position_size_usd = long_quantity * current_long_price;
-                    
-                    // STEP 1: Cancel the original long exit order (fire and forget)
-                    if let Some(ref order_id) = long_exit_id {
-                        eprintln!("[PARTIAL EXIT] Cancelling original long exit order: {}", order_id);
-                        let _ = self.execution_backend.cancel_order(&long_exchange, order_id).await;
-                    }
-                    
-                    // STEP 2: Fetch order book and make intelligent decision
-                    match self.execution_backend.get_order_book_depth(&long_exchange, &symbol, 5).await {
-                        Ok(order_book) => {
-                            // Use probability estimator to decide: limit or market?
-                            let decision = self.fill_probability_estimator.should_try_limit(
-                                &order_book,
-                                OrderSide::Short, // Closing long = sell
-                                position_size_usd,
-                            );
-                            
-                            eprintln!("[PROBABILITY] {} | Use limit: {} | Probability: {:.0}% | Reason: {}", 
-                                symbol, decision.use_limit, decision.probability * 100.0, decision.reason);
-                            
-                            if decision.use_limit {
-                                // HIGH PROBABILITY - Try limit order with intelligent pricing
-                                let book_side = &order_book.bids;
-                                let limit_price = if decision.price_level < book_side.len() {
-                                    book_side[decision.price_level].price
-                                } else {
-                                    book_side[0].price // Fallback to best bid
-                                };
-                                
-                                eprintln!("[PARTIAL EXIT] Placing INTELLIGENT limit at ${:.4} (level {}, {:.0}% fill probability)", 
-                                    limit_price, decision.price_level, decision.probability * 100.0);
-                                
-                                let limit_order = SimulatedOrder {
-                                    id: format!("smart_exit_{}", uuid::Uuid::new_v4()),
-                                    exchange: long_exchange.clone(),
-                                    symbol: symbol.clone(),
-                                    side: OrderSide::Short,
-                                    order_type: OrderType::Limit,
-                                    price: limit_price,
-                                    size: long_quantity,
-                                    queue_position: None,
-                                    created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                    filled_at: None,
-                                    fill_price: None,
-                                    status: OrderStatus::Pending,
-                                };
-                                
-                                match self.execution_backend.place_order(limit_order).await {
-                                    Ok(placed_order) => {
-                                        eprintln!("[PARTIAL EXIT] Smart limit placed: {}", placed_order.id);
-                                        
-                                        // Wait based on probability estimate
-                                        tokio::time::sleep(Duration::from_millis(decision.wait_time_ms)).await;
-                                        
-                                        // Check if filled
-                                        match self.execution_backend.get_order_status_detailed(&long_exchange, &placed_order.id, &symbol).await {
-                                            Ok(status_info) if status_info.is_fully_filled() => {
-                                                eprintln!("[PARTIAL EXIT] ✅ Smart limit FULLY filled! Hedge complete (maker fee saved)");
-                                                if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                                                    trade.status = TradeStatus::Exiting;
-                                                    trade.exit_reason = Some("Partial exit hedged with smart limit".to_string());
-                                                    trade.exit_spread_bps = Some(current_spread_bps);
-                                                }
-                                                continue;
-                                            }
-                                            Ok(status_info) if status_info.filled_quantity > 0.0 => {
-                                                eprintln!("[PARTIAL EXIT] ⚠️  Limit PARTIALLY filled {:.4}/{:.4} contracts", 
-                                                    status_info.filled_quantity, long_quantity);
-                                                let _ = self.execution_backend.cancel_order(&long_exchange, &placed_order.id).await;
-                                                
-                                                // Market order for remaining
-                                                let remaining = long_quantity - status_info.filled_quantity;
-                                                eprintln!("[PARTIAL EXIT] Market order for remaining {:.4} contracts", remaining);
-                                                
-                                                let market_order = SimulatedOrder {
-                                                    id: format!("market_remaining_{}", uuid::Uuid::new_v4()),
-                                                    exchange: long_exchange.clone(),
-                                                    symbol: symbol.clone(),
-                                                    side: OrderSide::Short,
-                                                    order_type: OrderType::Market,
-                                                    price: current_long_price,
-                                                    size: remaining,
-                                                    queue_position: None,
-                                                    created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                                    filled_at: None,
-                                                    fill_price: None,
-                                                    status: OrderStatus::Pending,
-                                                };
-                                                
-                                                let _ = self.execution_backend.place_market_order(market_order).await;
-                                                if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                                                    trade.status = TradeStatus::Exiting;
-                                                    trade.exit_reason = Some("Partial exit hedged (limit partial + market)".to_string());
-                                                    trade.exit_spread_bps = Some(current_spread_bps);
-                                                }
-                                                continue;
-                                            }
-                                            _ => {
-                                                eprintln!("[PARTIAL EXIT] ⚠️  Limit didn't fill - using market order");
-                                                let _ = self.execution_backend.cancel_order(&long_exchange, &placed_order.id).await;
-                                            }
-                                        }
-                                    }
-                                    Err(e) => {
-                                        eprintln!("[PARTIAL EXIT] ⚠️  Failed to place smart limit: {}", e);
-                                    }
-                                }
-                            }
-                            
-                            // LOW PROBABILITY or limit failed - Use market order
-                            eprintln!("[PARTIAL EXIT] Using INSTANT market order for {:.4} contracts", long_quantity);
-                            
-                            let market_order = SimulatedOrder {
-                                id: format!("instant_hedge_{}", uuid::Uuid::new_v4()),
-                                exchange: long_exchange.clone(),
-                                symbol: symbol.clone(),
-                                side: OrderSide::Short,
-                                order_type: OrderType::Market,
-                                price: current_long_price,
-                                size: long_quantity,
-                                queue_position: None,
-                                created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                filled_at: None,
-                                fill_price: None,
-                                status: OrderStatus::Pending,
-                            };
-                            
-                            match self.execution_backend.place_market_order(market_order).await {
-                                Ok(_) => {
-                                    eprintln!("[PARTIAL EXIT] ✅ INSTANT market hedge complete!");
-                                    if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                                        trade.status = TradeStatus::Exiting;
-                                        trade.exit_reason = Some("Partial exit hedged with instant market".to_string());
-                                        trade.exit_spread_bps = Some(current_spread_bps);
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("[PARTIAL EXIT] ❌ CRITICAL: Market order failed: {}", e);
-                                    eprintln!("[PARTIAL EXIT] 🚨 UNHEDGED LONG POSITION ON {}", long_exchange);
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            // No order book - go straight to market
-                            eprintln!("[PARTIAL EXIT] ⚠️  Failed to get order book: {} - using market order", e);
-                            
-                            let market_order = SimulatedOrder {
-                                id: format!("instant_hedge_{}", uuid::Uuid::new_v4()),
-                                exchange: long_exchange.clone(),
-                                symbol: symbol.clone(),
-                                side: OrderSide::Short,
-                                order_type: OrderType::Market,
-                                price: current_long_price,
-                                size: long_quantity,
-                                queue_position: None,
-                                created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                filled_at: None,
-                                fill_price: None,
-                                status: OrderStatus::Pending,
-                            };
-                            
-                            let _ = self.execution_backend.place_market_order(market_order).await;
-                            if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                                trade.status = TradeStatus::Exiting;
-                                trade.exit_reason = Some("Partial exit hedged with market (no order book)".to_string());
-                                trade.exit_spread_bps = Some(current_spread_bps);
-                            }
-                        }
-                    }
-                    continue;
-                }
+        self.exit_states.retain(|id, _| self.active_trades.contains_key(id));
+        self.journal_trade_updates();
 
-                // 2. Check stop-loss conditions (30% spread widening)
-                if !stop_loss_triggered {
-                    let stop_loss_hit = current_long_price <= stop_loss_long || current_short_price >= stop_loss_short;
-                    
-                    if stop_loss_hit {
-                        eprintln!("[STOP LOSS] {} | TRIGGERED! Long: ${:.2} <= ${:.2} OR Short: ${:.2} >= ${:.2}", 
-                            trade_id, current_long_price, stop_loss_long, current_short_price, stop_loss_short);
-                        
-                        // Get position sizes
-                        let (long_quantity, short_quantity) = if let Some(trade) = self.active_trades.get(&trade_id) {
-                            (trade.long_order.size, trade.short_order.size)
-                        } else {
-                            continue;
-                        };
-                        
-                        // STEP 1: Cancel exit orders and check if they filled
-                        let mut long_filled_from_cancel = 0.0;
-                        let mut short_filled_from_cancel = 0.0;
-                        
-                        if let Some(ref order_id) = long_exit_id {
-                            eprintln!("[STOP LOSS] Cancelling long exit order: {}", order_id);
-                            let _ = self.execution_backend.cancel_order(&long_exchange, order_id).await;
-                            
-                            // Check if cancelled order filled before cancellation
-                            match self.execution_backend.get_order_status_detailed(&long_exchange, order_id, &symbol).await {
-                                Ok(status_info) => {
-                                    long_filled_from_cancel = status_info.filled_quantity;
-                                    if long_filled_from_cancel > 0.0 {
-                                        eprintln!("[STOP LOSS] ⚠️  Cancelled long exit order filled {:.4} contracts before cancellation", 
-                                            long_filled_from_cancel);
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("[STOP LOSS] ⚠️  Failed to check cancelled long exit order: {} (assuming 0 fill)", e);
-                                }
-                            }
-                        }
-                        
-                        if let Some(ref order_id) = short_exit_id {
-                            eprintln!("[STOP LOSS] Cancelling short exit order: {}", order_id);
-                            let _ = self.execution_backend.cancel_order(&short_exchange, order_id).await;
-                            
-                            // Check if cancelled order filled before cancellation
-                            match self.execution_backend.get_order_status_detailed(&short_exchange, order_id, &symbol).await {
-                                Ok(status_info) => {
-                                    short_filled_from_cancel = status_info.filled_quantity;
-                                    if short_filled_from_cancel > 0.0 {
-                                        eprintln!("[STOP LOSS] ⚠️  Cancelled short exit order filled {:.4} contracts before cancellation", 
-                                            short_filled_from_cancel);
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("[STOP LOSS] ⚠️  Failed to check cancelled short exit order: {} (assuming 0 fill)", e);
-                                }
-                            }
-                        }
+        Ok(())
+    }
 
-                        // Mark stop-loss as triggered
-                        if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                            trade.stop_loss_triggered = true;
-                            trade.status = TradeStatus::Exiting;
-                            trade.exit_reason = Some(format!("Stop-loss triggered (30% widening) | Long: ${:.2} | Short: ${:.2}", 
-                                current_long_price, current_short_price));
-                            trade.exit_spread_bps = Some(current_spread_bps);
-                        }
+    /// Hand a trade to `check_exits` for finalization.
+    fn mark_exiting(&self, trade_id: &str, reason: String, exit_spread_bps: f64) {
+        if let Some(mut trade) = self.active_trades.get_mut(trade_id) {
+            trade.status = TradeStatus::Exiting;
+            trade.exit_reason = Some(reason);
+            trade.exit_spread_bps = Some(exit_spread_bps);
+        }
+    }
 
-                        // Check if already fully closed from cancelled orders
-                        if long_filled_from_cancel >= long_quantity && short_filled_from_cancel >= short_quantity {
-                            eprintln!("[STOP LOSS] ✅ Both positions already closed from cancelled orders!");
-                            continue;
-                        }
-                        
-                        // STEP 2: Try aggressive limit orders first (to save on taker fees)
-                        eprintln!("[STOP LOSS] {} | Attempting aggressive limit orders to minimize fees...", trade_id);
-                        
-                        // Try aggressive limit for long position (if not fully closed)
-                        if long_filled_from_cancel < long_quantity {
-                            let remaining_long = long_quantity - long_filled_from_cancel;
-                            eprintln!("[STOP LOSS] Trying aggressive limit for long exit: {:.4} contracts at best bid", remaining_long);
-                            
-                            match self.execution_backend.get_best_bid(&long_exchange, &symbol).await {
-                                Ok(best_bid) => {
-                                    let limit_order = SimulatedOrder {
-                                        id: format!("stop_loss_limit_long_{}", uuid::Uuid::new_v4()),
-                                        exchange: long_exchange.clone(),
-                                        symbol: symbol.clone(),
-                                        side: OrderSide::Short, // Closing long = sell
-                                        order_type: OrderType::Limit,
-                                        price: best_bid,
-                                        size: remaining_long,
-                                        queue_position: None,
-                                        created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                        filled_at: None,
-                                        fill_price: None,
-                                        status: OrderStatus::Pending,
-                                    };
-                                    
-                                    match self.execution_backend.place_order(limit_order.clone()).await {
-                                        Ok(placed_order) => {
-                                            eprintln!("[STOP LOSS] Long limit placed: {} at ${:.4}", placed_order.id, best_bid);
-                                            tokio::time::sleep(Duration::from_secs(2)).await;
-                                            
-                                            match self.execution_backend.get_order_status_detailed(&long_exchange, &placed_order.id, &symbol).await {
-                                                Ok(status_info) => {
-                                                    long_filled_from_cancel += status_info.filled_quantity;
-                                                    if status_info.filled_quantity > 0.0 {
-                                                        eprintln!("[STOP LOSS] Long limit filled {:.4} contracts (maker fee)", status_info.filled_quantity);
-                                                    }
-                                                    let _ = self.execution_backend.cancel_order(&long_exchange, &placed_order.id).await;
-                                                }
-                                                Err(e) => {
-                                                    eprintln!("[STOP LOSS] ⚠️  Failed to check long limit status: {}", e);
-                                                    let _ = self.execution_backend.cancel_order(&long_exchange, &placed_order.id).await;
-                                                }
-                                            }
-                                        }
-                                        Err(e) => {
-                                            eprintln!("[STOP LOSS] ⚠️  Failed to place long limit: {}", e);
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("[STOP LOSS] ⚠️  Failed to get best bid: {}", e);
-                                }
-                            }
-                        }
-                        
-                        // Try aggressive limit for short position (if not fully closed)
-                        if short_filled_from_cancel < short_quantity {
-                            let remaining_short = short_quantity - short_filled_from_cancel;
-                            eprintln!("[STOP LOSS] Trying aggressive limit for short exit: {:.4} contracts at best ask", remaining_short);
-                            
-                            match self.execution_backend.get_best_ask(&short_exchange, &symbol).await {
-                                Ok(best_ask) => {
-                                    let limit_order = SimulatedOrder {
-                                        id: format!("stop_loss_limit_short_{}", uuid::Uuid::new_v4()),
-                                        exchange: short_exchange.clone(),
-                                        symbol: symbol.clone(),
-                                        side: OrderSide::Long, // Closing short = buy
-                                        order_type: OrderType::Limit,
-                                        price: best_ask,
-                                        size: remaining_short,
-                                        queue_position: None,
-                                        created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                        filled_at: None,
-                                        fill_price: None,
-                                        status: OrderStatus::Pending,
-                                    };
-                                    
-                                    match self.execution_backend.place_order(limit_order.clone()).await {
-                                        Ok(placed_order) => {
-                                            eprintln!("[STOP LOSS] Short limit placed: {} at ${:.4}", placed_order.id, best_ask);
-                                            tokio::time::sleep(Duration::from_secs(2)).await;
-                                            
-                                            match self.execution_backend.get_order_status_detailed(&short_exchange, &placed_order.id, &symbol).await {
-                                                Ok(status_info) => {
-                                                    short_filled_from_cancel += status_info.filled_quantity;
-                                                    if status_info.filled_quantity > 0.0 {
-                                                        eprintln!("[STOP LOSS] Short limit filled {:.4} contracts (maker fee)", status_info.filled_quantity);
-                                                    }
-                                                    let _ = self.execution_backend.cancel_order(&short_exchange, &placed_order.id).await;
-                                                }
-                                                Err(e) => {
-                                                    eprintln!("[STOP LOSS] ⚠️  Failed to check short limit status: {}", e);
-                                                    let _ = self.execution_backend.cancel_order(&short_exchange, &placed_order.id).await;
-                                                }
-                                            }
-                                        }
-                                        Err(e) => {
-                                            eprintln!("[STOP LOSS] ⚠️  Failed to place short limit: {}", e);
-                                        }
-                                    }
-                                }
-                                Err(e) => {
-                                    eprintln!("[STOP LOSS] ⚠️  Failed to get best ask: {}", e);
-                                }
-                            }
-                        }
-                        
-                        // Check if fully closed after aggressive limits
-                        if long_filled_from_cancel >= long_quantity && short_filled_from_cancel >= short_quantity {
-                            eprintln!("[STOP LOSS] ✅ Both positions closed with aggressive limits (maker fees)!");
-                            continue;
-                        }
-                        
-                        // STEP 3: Place market orders for REMAINING quantities only
-                        eprintln!("[STOP LOSS] {} | Placing market orders for remaining positions...", trade_id);
-                        
-                        let remaining_long = long_quantity - long_filled_from_cancel;
-                        let remaining_short = short_quantity - short_filled_from_cancel;
-                        
-                        eprintln!("[STOP LOSS] Remaining to close: Long={:.4}, Short={:.4}", remaining_long, remaining_short);
-                        
-                        // Close remaining long position (if any)
-                        let long_result = if remaining_long > 0.001 {
-                            let long_close_order = SimulatedOrder {
-                                id: format!("stop_loss_market_long_{}", uuid::Uuid::new_v4()),
-                                exchange: long_exchange.clone(),
-                                symbol: symbol.clone(),
-                                side: OrderSide::Short, // Closing long = sell
-                                order_type: OrderType::Market,
-                                price: current_long_price,
-                                size: remaining_long,
-                                queue_position: None,
-                                created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                filled_at: None,
-                                fill_price: None,
-                                status: OrderStatus::Pending,
-                            };
-                            eprintln!("[STOP LOSS] Placing market order: {} | {} | SELL | {:.4}", 
-                                long_exchange, symbol, remaining_long);
-                            self.execution_backend.place_market_order(long_close_order).await
-                        } else {
-                            eprintln!("[STOP LOSS] ✅ Long position already fully closed ({:.4} contracts)", long_filled_from_cancel);
-                            Ok(SimulatedOrder::default())
-                        };
-                        
-                        // Close remaining short position (if any)
-                        let short_result = if remaining_short > 0.001 {
-                            let short_close_order = SimulatedOrder {
-                                id: format!("stop_loss_market_short_{}", uuid::Uuid::new_v4()),
-                                exchange: short_exchange.clone(),
-                                symbol: symbol.clone(),
-                                side: OrderSide::Long, // Closing short = buy
-                                order_type: OrderType::Market,
-                                price: current_short_price,
-                                size: remaining_short,
-                                queue_position: None,
-                                created_at: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                                filled_at: None,
-                                fill_price: None,
-                                status: OrderStatus::Pending,
-                            };
-                            eprintln!("[STOP LOSS] Placing market order: {} | {} | BUY | {:.4}", 
-                                short_exchange, symbol, remaining_short);
-                            self.execution_backend.place_market_order(short_close_order).await
-                        } else {
-                            eprintln!("[STOP LOSS] ✅ Short position already fully closed ({:.4} contracts)", short_filled_from_cancel);
-                            Ok(SimulatedOrder::default())
-                        };
-                        
-                        match (long_result, short_result) {
-                            (Ok(_), Ok(_)) => {
-                                eprintln!("[STOP LOSS] ✅ Both positions closed successfully");
-                                eprintln!("[STOP LOSS] Total closed: Long={:.4}, Short={:.4}", long_quantity, short_quantity);
-                            }
-                            (Err(e), Ok(_)) => {
-                                eprintln!("[STOP LOSS] ❌ CRITICAL: Long position failed to close: {}", e);
-                                eprintln!("[STOP LOSS] 🚨 UNHEDGED LONG POSITION ON {} ({:.4} contracts)", long_exchange, remaining_long);
-                            }
-                            (Ok(_), Err(e)) => {
-                                eprintln!("[STOP LOSS] ❌ CRITICAL: Short position failed to close: {}", e);
-                                eprintln!("[STOP LOSS] 🚨 UNHEDGED SHORT POSITION ON {} ({:.4} contracts)", short_exchange, remaining_short);
-                            }
-                            (Err(e1), Err(e2)) => {
-                                eprintln!("[STOP LOSS] ❌ CRITICAL: BOTH positions failed to close!");
-                                eprintln!("[STOP LOSS] Long error: {}", e1);
-                                eprintln!("[STOP LOSS] Short error: {}", e2);
-                                eprintln!("[STOP LOSS] 🚨 UNHEDGED POSITIONS - Long: {:.4} on {}, Short: {:.4} on {}", 
-                                    remaining_long, long_exchange, remaining_short, short_exchange);
-                            }
-                        }
-                    }
-                }
+    /// Live 8h funding differential (short - long) of a trade's legs, if both
+    /// venues have fresh rates.
+    fn live_funding_delta(&self, trade: &PaperTrade, now: u64) -> Option<f64> {
+        let store = self.funding_store.as_ref()?;
+        let max_age = self.carry_config.max_rate_age_secs;
+        let long_rate = store.get_fresh_leg(&trade.long_exchange, &trade.symbol, now, max_age)?;
+        let short_rate = store.get_fresh_leg(&trade.short_exchange, &trade.symbol, now, max_age)?;
+        Some(short_rate.rate_8h() - long_rate.rate_8h())
+    }
 
-                continue; // Skip paper trading logic
-            }
+    /// Record a leg-out event the first time only one leg of a trade is filled.
+    fn record_leg_out(&self, trade_id: &str, current_long_price: f64, current_short_price: f64) {
+        let Some(mut trade) = self.active_trades.get_mut(trade_id) else {
+            return;
+        };
+        let time_since_entry = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_millis() as u64
+            - (trade.entry_time * 1000);
+
+        if PositionManager::detect_leg_out(
+            trade.long_order.status == OrderStatus::Filled,
+            trade.short_order.status == OrderStatus::Filled,
+            time_since_entry,
+        )
+            && trade.leg_out_event.is_none() {
+                // Log leg-out event
+                let filled_leg = if trade.long_order.status == OrderStatus::Filled {
+                    "long".to_string()
+                } else {
+                    "short".to_string()
+                };
 
-            // ============================================================================
-            // PAPER TRADING MONITORING (Original logic)
-            // ============================================================================
+                println!(
+                    "[LEG-OUT] {} | Filled leg: {} | Time since entry: {}ms",
+                    trade.id, filled_leg, time_since_entry
+                );
 
-            // Get current prices from market data store (no Redis in hot path)
-            let (current_long_price, current_short_price) = self.get_current_prices(&symbol)?;
-            let current_spread_bps = OpportunityScanner::calculate_spread_bps(current_long_price, current_short_price);
+                trade.leg_out_event = Some(LegOutEvent {
+                    filled_leg,
+                    filled_at: trade.long_order.filled_at.unwrap_or(0),
+                    unfilled_leg: if trade.long_order.status != OrderStatus::Filled {
+                        "long".to_string()
+                    } else {
+                        "short".to_string()
+                    },
+                    hedge_executed: true,
+                    hedge_price: current_long_price.max(current_short_price),
+                });
+            }
+    }
 
-            // Calculate spread-based P&L (what matters for arbitrage)
-            let spread_reduction_bps = entry_spread_bps - current_spread_bps;
-            
-            // Calculate current P&L based on spread reduction only
-            // Fees are already accounted for in the projected_profit calculation
-            let current_pnl = (spread_reduction_bps / 10000.0) * position_size_usd;
-
-            // Get funding rates before mutable borrow
-            // Use the funding delta from the opportunity (already calculated by dashboard)
-            let entry_funding_delta = if let Some(trade) = self.active_trades.get(&trade_id) {
-                trade.funding_delta_entry
-            } else {
-                0.0
+    /// Journal a snapshot of every trade whose status, exit orders, stop-loss
+    /// state, funding settlements, fills or carry holding state changed since
+    /// its last snapshot.
+    fn journal_trade_updates(&self) {
+        if self.journal.is_none() {
+            return;
+        }
+        for entry in self.active_trades.iter() {
+            let trade = entry.value();
+            if trade.id.starts_with("placeholder_") {
+                continue;
+            }
+            let exit_order = |o: &Option<SimulatedOrder>| {
+                o.as_ref().map(|o| format!("{}:{:?}:{}:{}", o.id, o.status, o.price, o.size)).unwrap_or_default()
             };
-            
-            // For now, we don't have live funding rate updates, so use the entry value
-            let current_funding_delta = entry_funding_delta;
-
-            eprintln!("[MONITOR] {} | Entry: {:.2}bps | Current: {:.2}bps | Spread Reduction: {:.2}bps | P&L: ${:.2} | Projected: ${:.2} (Target: ${:.2}, Stop: ${:.2})",
-                symbol, entry_spread_bps, current_spread_bps, spread_reduction_bps, current_pnl, entry_projected_profit, entry_projected_profit * 0.9, entry_projected_profit * -0.2);
-
-            // Now update the trade with exit conditions
-            if let Some(mut trade) = self.active_trades.get_mut(&trade_id) {
-                eprintln!("[MONITOR] {} | Checking exit conditions | P&L: ${:.2} | Projected: ${:.2}", trade.id, current_pnl, entry_projected_profit);
-                
-                // Exit conditions:
-                // 1. Profit target: 90% of the spread has been closed (narrowed)
-                // Spread closed = entry_spread - current_spread
-                // Target = 90% of entry_spread
-                // CRITICAL: Only exit if current spread is positive (valid arbitrage)
-                let spread_closed_pct = (spread_reduction_bps / entry_spread_bps) * 100.0;
-                
-                eprintln!("[PROFIT_CHECK] {} | Spread Closed: {:.1}% | Current Spread: {:.2}bps | Entry Spread: {:.2}bps | P&L: ${:.2} | Projected: ${:.2}",
-                    trade.id, spread_closed_pct, current_spread_bps, entry_spread_bps, current_pnl, entry_projected_profit);
-                
-                if spread_closed_pct >= 90.0 && entry_spread_bps > 0.0 && current_spread_bps > 0.0 {
-                    // Capture the exit spread at the moment we trigger the exit
-                    trade.exit_spread_bps = Some(current_spread_bps);
-                    trade.exit_reason = Some(format!("Profit hit | Entry: {:.2}bps | Exit: {:.2}bps | Captured: {:.2}bps", entry_spread_bps, current_spread_bps, spread_reduction_bps));
-                    trade.status = TradeStatus::Exiting;
-                    println!(
-                        "[EXIT SIGNAL] {} | Reason: profit_target | Entry Spread: {:.2}bps | Current: {:.2}bps | Closed: {:.1}% | P&L: ${:.2}",
-                        trade.id, entry_spread_bps, current_spread_bps, spread_closed_pct, current_pnl
-                    );
-                } else if spread_closed_pct >= 90.0 {
-                    eprintln!("[PROFIT_CHECK_FAILED] {} | Spread closed {:.1}% but conditions not met: entry_spread > 0: {} | current_spread > 0: {}",
-                        trade.id, spread_closed_pct, entry_spread_bps > 0.0, current_spread_bps > 0.0);
-                }
-                
-                // 2. Stop loss: we've lost 20% of the projected profit (spread widened significantly)
-                if current_pnl <= entry_projected_profit * -0.2 && entry_projected_profit > 0.0 && trade.exit_reason.is_none() {
-                    trade.exit_spread_bps = Some(current_spread_bps);
-                    trade.exit_reason = Some(format!("Stop loss | Entry: {:.2}bps | Exit: {:.2}bps", entry_spread_bps, current_spread_bps));
-                    trade.status = TradeStatus::Exiting;
-                    println!(
-                        "[EXIT SIGNAL] {} | Reason: stop_loss | Spread Reduction: {:.2}bps | P&L: ${:.2} (Stop: ${:.2})",
-                        trade.id, spread_reduction_bps, current_pnl, entry_projected_profit * -0.2
-                    );
-                }
-                
-                // 2b. Stop loss: absolute loss threshold (lose more than $5 or 50% of projected profit)
-                let max_loss = (entry_projected_profit * 0.5).max(5.0);
-                eprintln!("[MONITOR] {} | Checking absolute loss: P&L: ${:.2} vs Max Loss: ${:.2}", trade.id, current_pnl, -max_loss);
-                if current_pnl <= -max_loss && trade.exit_reason.is_none() {
-                    trade.exit_spread_bps = Some(current_spread_bps);
-                    trade.exit_reason = Some(format!("Stop loss | Entry: {:.2}bps | Exit: {:.2}bps", entry_spread_bps, current_spread_bps));
-                    trade.status = TradeStatus::Exiting;
-                    println!(
-                        "[EXIT SIGNAL] {} | Reason: stop_loss (absolute loss) | P&L: ${:.2} (Max Loss: ${:.2})",
-                        trade.id, current_pnl, -max_loss
-                    );
-                }
-                
-                // 2c. Stop loss: spread has widened significantly (more than 30% wider than entry)
-                if current_spread_bps > entry_spread_bps * 1.3 && trade.exit_reason.is_none() {
-                    trade.exit_spread_bps = Some(current_spread_bps);
-                    trade.exit_reason = Some(format!("Stop loss | Entry: {:.2}bps | Exit: {:.2}bps", entry_spread_bps, current_spread_bps));
-                    trade.status = TradeStatus::Exiting;
-                    println!(
-                        "[EXIT SIGNAL] {} | Reason: stop_loss (spread widened) | Entry: {:.2}bps | Current: {:.2}bps",
-                        trade.id, entry_spread_bps, current_spread_bps
-                    );
-                }
-                
-                // 3. Funding convergence: funding delta has converged significantly (reduced by 80%)
-                // Only check if we have meaningful funding delta to begin with
-                if entry_funding_delta.abs() > 0.0001 && current_funding_delta.abs() < entry_funding_delta.abs() * 0.2 && trade.exit_reason.is_none() {
-                    trade.exit_spread_bps = Some(current_spread_bps);
-                    trade.exit_reason = Some("funding_convergence".to_string());
-                    trade.status = TradeStatus::Exiting;
-                    println!(
-                        "[EXIT SIGNAL] {} | Reason: funding_convergence | Entry Funding Delta: {:.6} | Current: {:.6}",
-                        trade.id, entry_funding_delta, current_funding_delta
-                    );
-                }
-                
-                // 4. Funding convergence: funding delta < 0.005%
-                if current_funding_delta.abs() < 0.00005 && trade.exit_reason.is_none() {
-                    trade.exit_spread_bps = Some(current_spread_bps);
-                    trade.exit_reason = Some("funding_convergence".to_string());
-                    trade.status = TradeStatus::Exiting;
-                    println!(
-                        "[EXIT SIGNAL] {} | Reason: funding_convergence | Funding Delta: {:.6}",
-                        trade.id, current_funding_delta
-                    );
-                }
-
-                // Check leg-out risk
-                let time_since_entry = SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .unwrap()
-                    .as_millis() as u64
-                    - (trade.entry_time * 1000);
-
-                if PositionManager::detect_leg_out(
-                    trade.long_order.status == OrderStatus::Filled,
-                    trade.short_order.status == OrderStatus::Filled,
-                    time_since_entry,
-                )
-                    && trade.leg_out_event.is_none() {
-                        // Log leg-out event
-                        let filled_leg = if trade.long_order.status == OrderStatus::Filled {
-                            "long".to_string()
-                        } else {
-                            "short".to_string()
-                        };
-                        
-                        println!(
-                            "[LEG-OUT] {} | Filled leg: {} | Time since entry: {}ms",
-                            trade.id, filled_leg, time_since_entry
-                        );
-
-                        trade.leg_out_event = Some(LegOutEvent {
-                            filled_leg,
-                            filled_at: trade.long_order.filled_at.unwrap_or(0),
-                            unfilled_leg: if trade.long_order.status != OrderStatus::Filled {
-                                "long".to_string()
-                            } else {
-                                "short".to_string()
-                            },
-                            hedge_executed: true,
-                            hedge_price: current_long_price.max(current_short_price),
-                        });
-                    }
+            let fingerprint = format!("{:?}|{}|{}|{}|{}|{:?}|{}|{}|{:?}",
+                trade.status, exit_order(&trade.long_exit_order), exit_order(&trade.short_exit_order),
+                trade.stop_loss_triggered, trade.leg_out_event.is_some(), trade.exit_reason, trade.funding_payments.len(), trade.fills.len(),
+                trade.carry.as_ref().map(|c| (c.negative_cycles(), c.below_exit_since())));
+            if self.journaled_state.get(&trade.id).is_some_and(|f| *f == fingerprint) {
+                continue;
             }
+            self.journal(JournalEvent::Updated { trade: trade.clone() });
+            self.journaled_state.insert(trade.id.clone(), fingerprint);
         }
-
-        Ok(())
     }
 
     async fn check_exits(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
@@ -1487,6 +1300,9 @@ impl StrategyRunner {
             };
 
             if let Some(symbol) = symbol {
+                // Funding-carry holding policy (carry decay / negative carry cycles)
+                self.evaluate_carry_exit(&trade_id, &symbol);
+
                 // Check negative funding exit
                 let should_exit = {
                     if let Some(tracker) = self.negative_funding_trackers.get(&symbol) {
@@ -1515,7 +1331,8 @@ impl StrategyRunner {
                     }
                 }
 
-                if let Some(trade) = self.active_trades.get(&trade_id) {
+                // A snapshot: the map must stay writable while the exit awaits venue calls
+                if let Some(trade) = self.active_trades.get(&trade_id).map(|t| t.clone()) {
                     if trade.status == TradeStatus::Exiting {
                         // Use the exit spread that was captured when the exit was triggered
                         // This ensures we don't report negative spreads due to price movements after exit signal
@@ -1530,50 +1347,94 @@ impl StrategyRunner {
                         let spread_reduction_bps = trade.entry_spread_bps - exit_spread_bps;
                         
                         // Get exchange-specific taker fees
-                        let long_taker_bps = self.get_exchange_taker_fee(&trade.long_exchange);
-                        let short_taker_bps = self.get_exchange_taker_fee(&trade.short_exchange);
-                        let total_fee_bps = long_taker_bps + short_taker_bps;
+                        let total_fee_bps = self.estimated_fee_bps(&trade);
                         
-                        // Calculate profit: spread reduction minus fees
-                        let actual_profit = ((spread_reduction_bps - total_fee_bps) / 10000.0) * trade.position_size_usd;
+                        // Estimated profit: spread reduction minus fees, plus funding settled while open
+                        let funding_pnl = trade.funding_pnl_usd();
+                        let estimated_profit = ((spread_reduction_bps - total_fee_bps) / 10000.0) * trade.position_size_usd + funding_pnl;
 
                         let exit_reason = trade.exit_reason.clone().unwrap_or_else(|| "manual_exit".to_string());
 
-                        // OPTIMIZATION: Only try to cancel exit orders if they exist AND haven't been cancelled yet
-                        // Skip if orders were already cancelled during partial exit hedging
-                        // This prevents redundant API calls that slow down cleanup
-                        if let Some(ref long_exit_order) = trade.long_exit_order {
-                            if long_exit_order.status != OrderStatus::Cancelled {
-                                eprintln!("[CLEANUP] Cancelling long exit order: {} on {}", long_exit_order.id, trade.long_exchange);
-                                if let Err(e) = self.execution_backend.cancel_order(&trade.long_exchange, &long_exit_order.id).await {
-                                    // Ignore "already cancelled" errors - this is expected
-                                    if !e.to_string().contains("Unknown order") && !e.to_string().contains("does not exist") {
-                                        eprintln!("[CLEANUP] ⚠️  Failed to cancel long exit order: {}", e);
+                        // Orders sent while unwinding are journaled as exit orders of this trade
+                        in_scope(&trade_id, JournalPhase::Exit, async {
+                            // OPTIMIZATION: Only try to cancel exit orders if they exist AND haven't been cancelled yet
+                            // Skip if orders were already cancelled during partial exit hedging
+                            // This prevents redundant API calls that slow down cleanup
+                            if let Some(ref long_exit_order) = trade.long_exit_order {
+                                if long_exit_order.status != OrderStatus::Cancelled {
+                                    eprintln!("[CLEANUP] Cancelling long exit order: {} on {}", long_exit_order.id, trade.long_exchange);
+                                    if let Err(e) = self.execution_backend.cancel_order(&trade.long_exchange, &long_exit_order.id).await {
+                                        // Ignore orders that are already gone - this is expected
+                                        if !e.is_order_gone() {
+                                            eprintln!("[CLEANUP] ⚠️  Failed to cancel long exit order: {}", e);
+                                        }
                                     }
                                 }
                             }
-                        }
                         
-                        if let Some(ref short_exit_order) = trade.short_exit_order {
-                            if short_exit_order.status != OrderStatus::Cancelled {
-                                eprintln!("[CLEANUP] Cancelling short exit order: {} on {}", short_exit_order.id, trade.short_exchange);
-                                if let Err(e) = self.execution_backend.cancel_order(&trade.short_exchange, &short_exit_order.id).await {
-                                    // Ignore "already cancelled" errors - this is expected
-                                    if !e.to_string().contains("order not exists") && !e.to_string().contains("too late to cancel") {
-                                        eprintln!("[CLEANUP] ⚠️  Failed to cancel short exit order: {}", e);
+                            if let Some(ref short_exit_order) = trade.short_exit_order {
+                                if short_exit_order.status != OrderStatus::Cancelled {
+                                    eprintln!("[CLEANUP] Cancelling short exit order: {} on {}", short_exit_order.id, trade.short_exchange);
+                                    if let Err(e) = self.execution_backend.cancel_order(&trade.short_exchange, &short_exit_order.id).await {
+                                        // Ignore orders that are already gone - this is expected
+                                        if !e.is_order_gone() {
+                                            eprintln!("[CLEANUP] ⚠️  Failed to cancel short exit order: {}", e);
+                                        }
                                     }
                                 }
                             }
+
+                            // Funding-carry trades have no resting exit orders and margin
+                            // de-risking cannot wait for them: flatten both legs
+                            let is_carry = trade.carry.is_some();
+                            if (is_carry || exit_reason == MARGIN_DERISK_EXIT_REASON)
+                                && !self.execution_backend.capabilities().is_simulated
+                            {
+                                self.close_legs(&trade).await;
+                            }
+                        }).await;
+
+                        // Book the fills instead once both legs are flat on the venues
+                        let mut settled = trade.clone();
+                        for exchange in [&trade.long_exchange, &trade.short_exchange] {
+                            if let Ok(fills) = self.execution_backend.get_fills(exchange, &trade.symbol, trade.entry_time).await {
+                                merge_fills(&mut settled, fills);
+                            }
+                        }
+                        settled.status = TradeStatus::Closed;
+                        let (exit_long, exit_short) = self
+                            .get_current_prices_for_opportunity(&trade.symbol, &trade.long_exchange, &trade.short_exchange)
+                            .unwrap_or((trade.entry_long_price, trade.entry_short_price));
+                        let actual_profit = match FillPnl::of(&settled, exit_long, exit_short, &self.fee_schedule) {
+                            Some(pnl) if pnl.flat => pnl.net_usd,
+                            _ => estimated_profit,
+                        };
+                        {
+                            let mut closed = self.closed_reconciliations.lock().unwrap();
+                            if closed.len() >= RECONCILIATION_HISTORY {
+                                closed.pop_front();
+                            }
+                            closed.push_back(FillReconciliation::new(&settled, total_fee_bps, estimated_profit, exit_long, exit_short, &self.fee_schedule));
                         }
 
                         // Close trade
                         self.portfolio_manager.write().await
-                            .close_trade(&trade_id, actual_profit, exit_reason.clone())
+                            .close_trade(&trade_id, actual_profit, trade.funding_payments.clone(), settled.fills, exit_reason.clone())
                             .await?;
+                        self.journal(JournalEvent::Closed {
+                            trade_id: trade_id.clone(),
+                            profit_usd: actual_profit,
+                            exit_reason: exit_reason.clone(),
+                            exit_time: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+                        });
+                        self.journaled_state.remove(&trade_id);
+                        if let Some(risk) = &self.risk_engine {
+                            risk.record_realised_pnl(actual_profit);
+                        }
 
                         println!(
-                            "[EXIT COMPLETED] {} | Reason: {} | Entry Spread: {:.2}bps | Exit Spread: {:.2}bps | Fees: {:.2}bps | Actual Profit: ${:.2}",
-                            trade_id, exit_reason, trade.entry_spread_bps, exit_spread_bps, total_fee_bps, actual_profit
+                            "[EXIT COMPLETED] {} | Reason: {} | Entry Spread: {:.2}bps | Exit Spread: {:.2}bps | Fees: {:.2}bps | Funding: ${:.2} | Estimated Profit: ${:.2} | Actual Profit: ${:.2}",
+                            trade_id, exit_reason, trade.entry_spread_bps, exit_spread_bps, total_fee_bps, funding_pnl, estimated_profit, actual_profit
                         );
 
                         // Reset negative funding tracker
@@ -1596,6 +1457,201 @@ impl StrategyRunner {
 
 
 
+    /// Apply the funding-carry holding policy to an open carry trade.
+    ///
+    /// Marks the trade `Exiting` when the carry has decayed below the exit
+    /// threshold for the grace period or was negative at 2 consecutive funding
+    /// timestamps. Missing or stale funding rates keep the position open.
+    fn evaluate_carry_exit(&self, trade_id: &str, symbol: &str) {
+        let store = match self.funding_store {
+            Some(ref store) => store,
+            None => return,
+        };
+
+        let decision = {
+            let mut trade = match self.active_trades.get_mut(trade_id) {
+                Some(trade) => trade,
+                None => return,
+            };
+            let Some(position) = trade.carry.as_mut() else {
+                return;
+            };
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+            let max_age = self.carry_config.max_rate_age_secs;
+            let long_rate = store.get_fresh_leg(&position.long_exchange, symbol, now, max_age);
+            let short_rate = store.get_fresh_leg(&position.short_exchange, symbol, now, max_age);
+            match (long_rate, short_rate) {
+                (Some(long_rate), Some(short_rate)) => {
+                    position.evaluate(&self.carry_config, &long_rate, &short_rate, now)
+                }
+                _ => return,
+            }
+        };
+
+        if let CarryHoldDecision::Exit(reason) = decision {
+            let exit_spread_bps = self.get_current_prices(symbol)
+                .map(|(long, short)| OpportunityScanner::calculate_spread_bps(long, short))
+                .ok();
+
+            if let Some(mut trade) = self.active_trades.get_mut(trade_id) {
+                if trade.status == TradeStatus::Active {
+                    trade.exit_spread_bps = exit_spread_bps.or(Some(trade.entry_spread_bps));
+                    trade.exit_reason = Some(reason.to_string());
+                    trade.status = TradeStatus::Exiting;
+                    println!("[EXIT SIGNAL] {} | Reason: {}", trade.id, reason);
+                }
+            }
+        }
+    }
+
+    /// Close both legs of a trade with reduce-only market orders.
+    ///
+    /// Positions of at least `SliceConfig::min_notional_usd` are first closed
+    /// in hedged slices; whatever the slices leave is closed at market.
+    async fn close_legs(&self, trade: &PaperTrade) {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let mut long_size = trade.long_order.size;
+        let mut short_size = trade.short_order.size;
+
+        if trade.position_size_usd >= self.slice_config.min_notional_usd && long_size > 0.0 && short_size > 0.0 {
+            // Slices are sized well under the position, so they only need the flag where it exists
+            let reduce_only = self.execution_backend.capabilities().supports_reduce_only;
+            let close_template = |exchange: &String, side: OrderSide| SimulatedOrder {
+                exchange: exchange.clone(),
+                symbol: trade.symbol.clone(),
+                side,
+                reduce_only,
+                created_at: now,
+                ..Default::default()
+            };
+            let step = match (
+                self.execution_backend.get_quantity_step(&trade.long_exchange, &trade.symbol).await,
+                self.execution_backend.get_quantity_step(&trade.short_exchange, &trade.symbol).await,
+            ) {
+                (Ok(long_step), Ok(short_step)) => long_step.max(short_step),
+                _ => 0.1,
+            };
+            let fill = SlicedExecutor::new(self.execution_backend.clone(), self.slice_config.clone())
+                .execute(
+                    &close_template(&trade.long_exchange, OrderSide::Short),
+                    &close_template(&trade.short_exchange, OrderSide::Long),
+                    long_size.min(short_size),
+                    step,
+                    false,
+                )
+                .await;
+            if let Some(abort) = &fill.abort {
+                eprintln!("[EXIT] ⚠️  Sliced close of {} stopped after {} slice(s): {}", trade.id, fill.slices.len(), abort);
+            }
+            long_size = (long_size - fill.first.quantity).max(0.0);
+            short_size = (short_size - fill.hedge.quantity).max(0.0);
+        }
+
+        let legs = [
+            (&trade.long_exchange, OrderSide::Short, long_size, trade.entry_long_price), // Closing long = sell
+            (&trade.short_exchange, OrderSide::Long, short_size, trade.entry_short_price), // Closing short = buy
+        ];
+
+        for (exchange, side, size, price) in legs {
+            if size <= 0.0 {
+                continue;
+            }
+            let close_order = SimulatedOrder {
+                id: format!("close_{}", uuid::Uuid::new_v4()),
+                exchange: exchange.clone(),
+                symbol: trade.symbol.clone(),
+                side,
+                order_type: OrderType::Market,
+                price,
+                size,
+                created_at: now,
+                status: OrderStatus::Pending,
+                reduce_only: true,
+                ..Default::default()
+            };
+            let close_order = fit_close_order(self.execution_backend.as_ref(), close_order).await;
+            if close_order.size <= 0.0 {
+                eprintln!("[EXIT] {} shows no {} position left to close", exchange, trade.symbol);
+                continue;
+            }
+            if let Err(e) = self.execution_backend.place_market_order(close_order).await {
+                eprintln!("[EXIT] ❌ CRITICAL: Failed to close {} leg on {} ({:.4} contracts): {}",
+                    trade.symbol, exchange, size, e);
+            }
+        }
+    }
+
+    /// Apply the reports published by the execution worker.
+    ///
+    /// Acks map worker ids to venue order IDs; fills and venue cancels of an
+    /// open trade's orders are written onto the trade (and so journaled).
+    fn process_execution_reports(&self) {
+        let Some(handle) = &self.execution_handle else {
+            return;
+        };
+        while let Some(report) = handle.poll_report() {
+            let order_id = report.order_id();
+            if let ExecutionReport::Ack { exchange_order_id, latency_us, .. } = &report {
+                eprintln!("[EXECUTION] Order {} acked as {} in {:.1}ms", order_id, exchange_order_id, *latency_us as f64 / 1000.0);
+                self.worker_orders.insert(order_id, exchange_order_id.clone());
+            }
+            let venue_id = self.worker_orders.get(&order_id).map(|id| id.clone()).unwrap_or_default();
+            match &report {
+                ExecutionReport::Ack { .. } => {}
+                ExecutionReport::Fill { price, size, fee, fee_asset, liquidity, complete, .. } => {
+                    eprintln!("[EXECUTION] {} filled {:.4} @ ${:.4} | {:?} fee {:.6} {}{}", venue_id, size, price, liquidity, fee, fee_asset,
+                        if *complete { " (complete)" } else { "" });
+                    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+                    self.update_trade_order(&venue_id, |order| {
+                        order.fill_price = Some(*price);
+                        order.filled_at = Some(now);
+                        if *complete {
+                            order.status = OrderStatus::Filled;
+                        }
+                    });
+                }
+                ExecutionReport::Cancelled { filled_size, .. } => {
+                    eprintln!("[EXECUTION] ⚠️  {} cancelled by venue after {:.4} filled", venue_id, filled_size);
+                    self.update_trade_order(&venue_id, |order| {
+                        if order.status == OrderStatus::Pending {
+                            order.status = OrderStatus::Cancelled;
+                        }
+                    });
+                }
+                ExecutionReport::Rejected { error, .. } => {
+                    // The placing call got the same error inline
+                    eprintln!("[EXECUTION] ❌ Order {} rejected: {}", order_id, error);
+                }
+                ExecutionReport::Handover { filled_size, .. } => {
+                    eprintln!("[EXECUTION] {} still resting with {:.4} filled, exit polling takes over", venue_id, filled_size);
+                }
+            }
+            if report.is_final() {
+                self.worker_orders.remove(&order_id);
+            }
+        }
+    }
+
+    /// Apply `update` to the open trade order with venue ID `venue_id`, if any.
+    fn update_trade_order(&self, venue_id: &str, update: impl Fn(&mut SimulatedOrder)) {
+        if venue_id.is_empty() {
+            return;
+        }
+        for mut entry in self.active_trades.iter_mut() {
+            let trade = entry.value_mut();
+            let orders = [
+                Some(&mut trade.long_order),
+                Some(&mut trade.short_order),
+                trade.long_exit_order.as_mut(),
+                trade.short_exit_order.as_mut(),
+            ];
+            if let Some(order) = orders.into_iter().flatten().find(|o| o.id == venue_id) {
+                update(order);
+                return;
+            }
+        }
+    }
+
     async fn get_funding_rates(&self, pair: &str) -> Result<Option<(f64, f64)>, Box<dyn Error + Send + Sync>> {
         // Try all exchanges - same as dashboard does
         let exchanges = vec!["binance", "bybit", "okx", "kucoin", "bitget", "gateio", "hyperliquid", "paradex"];
@@ -1691,12 +1747,14 @@ impl StrategyRunner {
         let long_symbol_id = self.symbol_map.get_or_insert(long_exchange, pair);
         let short_symbol_id = self.symbol_map.get_or_insert(short_exchange, pair);
         
+        let store = self.market_data_store.read().map_err(|_| "Market data store lock poisoned")?;
+        
         // Get ask price from long exchange (hot path - array access)
-        let long_ask = self.market_data_store.get_ask(long_symbol_id)
+        let long_ask = store.get_ask(long_symbol_id)
             .ok_or_else(|| format!("No ask price for {} on {} (symbol_id: {})", pair, long_exchange, long_symbol_id))?;
         
         // Get bid price from short exchange (hot path - array access)
-        let short_bid = self.market_data_store.get_bid(short_symbol_id)
+        let short_bid = store.get_bid(short_symbol_id)
             .ok_or_else(|| format!("No bid price for {} on {} (symbol_id: {})", pair, short_exchange, short_symbol_id))?;
         
         // Validate prices are reasonable (non-zero)
@@ -1710,30 +1768,20 @@ impl StrategyRunner {
         Ok((long_ask, short_bid))
     }
 
-    /// Fetch prices from Redis (warm path - for monitoring/persistence only).
-    ///
-    /// This method is kept for backward compatibility and monitoring purposes.
-    /// It should NOT be called in the hot path (strategy decision/execution).
-    ///
-    /// Requirement: 1.3 (Redis only for monitoring/persistence)
-    #[allow(dead_code)]
-    /// Get exchange taker fee in basis points (inlined for hot path performance).
-    ///
-    /// Requirement: 6.1 (Inline fee calculation)
-    #[inline(always)]
-    fn get_exchange_taker_fee(&self, exchange: &str) -> f64 {
-        // Returns taker fee in basis points (bps)
-        match exchange.to_lowercase().as_str() {
-            "binance" => 4.0,      // 0.04%
-            "okx" => 5.0,          // 0.05%
-            "bybit" => 5.5,        // 0.055%
-            "bitget" => 6.0,       // 0.06%
-            "kucoin" => 6.0,       // 0.06%
-            "hyperliquid" => 4.5,  // 0.035%
-            "paradex" => 5.0,      // 0.05%
-            "gateio" => 6.0,       // 0.06% (default)
-            _ => 6.0,              // Default fallback
-        }
+    /// Round-trip fee estimate of a trade in bps, at the liquidity each leg
+    /// took: post-only orders as maker, everything else as taker. Exits not
+    /// yet filled are assumed to cross.
+    fn estimated_fee_bps(&self, trade: &PaperTrade) -> f64 {
+        let liquidity = |order: &SimulatedOrder| if order.is_post_only() { Liquidity::Maker } else { Liquidity::Taker };
+        let leg = |exchange: &str, entry: &SimulatedOrder, exit: &Option<SimulatedOrder>| {
+            let exit_liquidity = exit.as_ref()
+                .filter(|o| o.status == OrderStatus::Filled)
+                .map_or(Liquidity::Taker, liquidity);
+            self.fee_schedule.fee_bps(exchange, &trade.symbol, liquidity(entry))
+                + self.fee_schedule.fee_bps(exchange, &trade.symbol, exit_liquidity)
+        };
+        leg(&trade.long_exchange, &trade.long_order, &trade.long_exit_order)
+            + leg(&trade.short_exchange, &trade.short_order, &trade.short_exit_order)
     }
 
     #[allow(dead_code)]
@@ -1785,6 +1833,10 @@ impl StrategyRunner {
                 stop_loss_long_price: 0.0,
                 stop_loss_short_price: 0.0,
                 leg_out_event: None,
+                kind: opportunity.kind,
+                funding_payments: Vec::new(),
+                fills: Vec::new(),
+                carry: None,
             };
 
             // Initialize negative funding tracker for this symbol
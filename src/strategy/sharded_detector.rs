@@ -0,0 +1,521 @@
+//! Sharded Opportunity Detection
+//!
+//! Partitions opportunity detection by canonical instrument across N pinned
+//! worker threads. Each shard owns an `OpportunityDetector` with its own
+//! `MarketDataStore` slice and publishes to the shared opportunity bus.
+//!
+//! # Architecture
+//!
+//! ```text
+//!                                   ┌→ shard pipeline 0 → OpportunityDetector 0 (core N)   ─┐
+//! MarketPipeline → router thread ───┼→ shard pipeline 1 → OpportunityDetector 1 (core N+1) ─┼→ OpportunityQueue
+//!                  (hash(symbol))   └→ shard pipeline k → OpportunityDetector k (core N+k) ─┘
+//! ```
+//!
+//! All venues for one canonical symbol always land on the same shard, so each
+//! shard sees every exchange quote it needs for cross-venue detection.
+//!
+//! # Metrics
+//!
+//! Per-shard load (updates processed, queue depth, drops) and per-update
+//! latency are tracked with atomics and published to Redis under
+//! `SHARD_METRICS_KEY` every 10 seconds, together with the merged filter
+//! analytics and symbol identity reports from all shards.
+
+use crate::strategy::fee_schedule::FeeSchedule;
+use crate::strategy::filter_analytics::{FilterAnalyticsSnapshot, FILTER_ANALYTICS_KEY};
+use crate::strategy::funding_rates::FundingRateStore;
+use crate::strategy::latency_tracker::LatencyStats;
+use crate::strategy::opportunity_detector::OpportunityDetector;
+use crate::strategy::opportunity_queue::OpportunityProducer;
+use crate::strategy::pipeline::{MarketConsumer, MarketPipeline, MarketProducer};
+use crate::strategy::symbol_identity::{SymbolIdentityReport, SYMBOL_IDENTITY_KEY};
+use crate::strategy::symbol_map::SymbolMap;
+use crate::strategy::thread_pinning::{get_core_count, spawn_pinned_thread};
+use core_affinity::CoreId;
+use crossbeam_queue::ArrayQueue;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// Redis key the sharded detector publishes per-shard metrics under
+pub const SHARD_METRICS_KEY: &str = "detector:shard_metrics";
+
+/// Default per-shard queue capacity (market updates)
+const SHARD_QUEUE_CAPACITY: usize = 4096;
+
+/// Interval between metrics/analytics publications
+const REPORT_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Idle back-off when there is nothing to process
+const IDLE_SLEEP: Duration = Duration::from_micros(10);
+
+/// Configuration for the sharded detector.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShardedDetectorConfig {
+    /// Number of detector shards (worker threads)
+    pub shard_count: usize,
+
+    /// First core to pin shard threads to (shard i → core first_core + i)
+    pub first_core: usize,
+
+    /// Capacity of each shard's input queue
+    pub shard_queue_capacity: usize,
+}
+
+impl Default for ShardedDetectorConfig {
+    /// Single shard pinned after the strategy and WebSocket cores (core 8).
+    fn default() -> Self {
+        Self {
+            shard_count: 1,
+            first_core: 8,
+            shard_queue_capacity: SHARD_QUEUE_CAPACITY,
+        }
+    }
+}
+
+impl ShardedDetectorConfig {
+    /// Load configuration from environment variables.
+    ///
+    /// - `DETECTOR_SHARDS`: number of shards (default 1, minimum 1)
+    /// - `DETECTOR_FIRST_CORE`: first core for shard threads (default 8)
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let shard_count = std::env::var("DETECTOR_SHARDS")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(defaults.shard_count)
+            .max(1);
+        let first_core = std::env::var("DETECTOR_FIRST_CORE")
+            .ok()
+            .and_then(|v| v.parse::<usize>().ok())
+            .unwrap_or(defaults.first_core);
+
+        Self {
+            shard_count,
+            first_core,
+            ..defaults
+        }
+    }
+
+    /// Core assigned to a shard, wrapped to the available cores.
+    pub fn core_for_shard(&self, shard: usize) -> CoreId {
+        let core_count = get_core_count().max(1);
+        CoreId { id: (self.first_core + shard) % core_count }
+    }
+}
+
+/// Map a canonical symbol onto a shard index.
+///
+/// Uses FNV-1a so the assignment is stable across runs and processes.
+pub fn shard_for_symbol(symbol: &str, shard_count: usize) -> usize {
+    if shard_count <= 1 {
+        return 0;
+    }
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in symbol.as_bytes() {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    (hash % shard_count as u64) as usize
+}
+
+/// Live per-shard counters (updated by the shard thread, read by anyone).
+pub struct ShardMetrics {
+    /// Market updates processed by this shard
+    pub updates_processed: AtomicU64,
+
+    /// Market updates routed to this shard
+    pub updates_routed: AtomicU64,
+
+    /// Per-update processing latency
+    pub latency: LatencyStats,
+
+    /// Distinct (exchange, symbol) ids assigned to this shard
+    pub symbols_assigned: AtomicU64,
+}
+
+impl ShardMetrics {
+    fn new() -> Self {
+        Self {
+            updates_processed: AtomicU64::new(0),
+            updates_routed: AtomicU64::new(0),
+            latency: LatencyStats::new(),
+            symbols_assigned: AtomicU64::new(0),
+        }
+    }
+}
+
+/// Point-in-time view of one shard's load and latency.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShardMetricsSnapshot {
+    pub shard: usize,
+    pub core: usize,
+    pub symbols_assigned: u64,
+    pub updates_routed: u64,
+    pub updates_processed: u64,
+    pub queue_depth: usize,
+    pub queue_drops: u64,
+    pub latency_p50_ns: u64,
+    pub latency_p99_ns: u64,
+    pub latency_max_ns: u64,
+}
+
+/// Snapshot of all shards, as published to Redis.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShardedDetectorSnapshot {
+    pub timestamp: u64,
+    pub shards: Vec<ShardMetricsSnapshot>,
+}
+
+/// State shared between the router, the shard threads and the handle.
+struct Shared {
+    config: ShardedDetectorConfig,
+    pipelines: Vec<Arc<MarketPipeline>>,
+    metrics: Vec<Arc<ShardMetrics>>,
+    analytics: Mutex<Vec<Option<FilterAnalyticsSnapshot>>>,
+    identity: Mutex<Vec<Option<SymbolIdentityReport>>>,
+    shutdown: AtomicBool,
+}
+
+impl Shared {
+    fn snapshot(&self) -> ShardedDetectorSnapshot {
+        let shards = self
+            .metrics
+            .iter()
+            .zip(self.pipelines.iter())
+            .enumerate()
+            .map(|(i, (m, p))| {
+                let latency = m.latency.snapshot();
+                ShardMetricsSnapshot {
+                    shard: i,
+                    core: self.config.core_for_shard(i).id,
+                    symbols_assigned: m.symbols_assigned.load(Ordering::Relaxed),
+                    updates_routed: m.updates_routed.load(Ordering::Relaxed),
+                    updates_processed: m.updates_processed.load(Ordering::Relaxed),
+                    queue_depth: p.depth(),
+                    queue_drops: p.metrics().drop_count,
+                    latency_p50_ns: latency.p50_ns,
+                    latency_p99_ns: latency.p99_ns,
+                    latency_max_ns: latency.max_ns,
+                }
+            })
+            .collect();
+
+        ShardedDetectorSnapshot {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            shards,
+        }
+    }
+
+    fn merged_analytics(&self) -> FilterAnalyticsSnapshot {
+        let slots = self.analytics.lock().unwrap();
+        let mut merged = FilterAnalyticsSnapshot::default();
+        for snapshot in slots.iter().flatten() {
+            merged.merge(snapshot);
+        }
+        merged
+    }
+
+    fn merged_identity(&self) -> SymbolIdentityReport {
+        let slots = self.identity.lock().unwrap();
+        let mut merged = SymbolIdentityReport::default();
+        for report in slots.iter().flatten() {
+            merged.merge(report);
+        }
+        merged
+    }
+}
+
+/// Multi-core opportunity detector.
+///
+/// Owns the router thread and one pinned thread per shard. Dropping the
+/// handle does not stop the threads; call `shutdown()` for a clean stop.
+pub struct ShardedDetector {
+    shared: Arc<Shared>,
+    router_handle: Option<thread::JoinHandle<()>>,
+    shard_handles: Vec<thread::JoinHandle<()>>,
+}
+
+impl ShardedDetector {
+    /// Start the router and shard threads.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Shard count and core placement
+    /// * `market_consumer` - Consumer of the global MarketPipeline
+    /// * `symbol_map` - Shared symbol mapping service
+    /// * `opportunity_producer` - Shared opportunity bus (cloned per shard)
+    /// * `publisher` - Optional Redis write queue for metrics and analytics
+    /// * `funding_store` - Optional live funding rates; enables the funding-carry path
+    /// * `fee_schedule` - Account fee schedule shared with the strategy runner
+    pub fn start(
+        config: ShardedDetectorConfig,
+        market_consumer: MarketConsumer,
+        symbol_map: Arc<SymbolMap>,
+        opportunity_producer: OpportunityProducer,
+        publisher: Option<Arc<ArrayQueue<(String, String)>>>,
+        funding_store: Option<Arc<FundingRateStore>>,
+        fee_schedule: Arc<FeeSchedule>,
+    ) -> Self {
+        let shard_count = config.shard_count.max(1);
+        let pipelines: Vec<Arc<MarketPipeline>> = (0..shard_count)
+            .map(|_| Arc::new(MarketPipeline::with_capacity(config.shard_queue_capacity)))
+            .collect();
+        let metrics: Vec<Arc<ShardMetrics>> =
+            (0..shard_count).map(|_| Arc::new(ShardMetrics::new())).collect();
+
+        let shared = Arc::new(Shared {
+            config: ShardedDetectorConfig { shard_count, ..config },
+            pipelines,
+            metrics,
+            analytics: Mutex::new(vec![None; shard_count]),
+            identity: Mutex::new(vec![None; shard_count]),
+            shutdown: AtomicBool::new(false),
+        });
+
+        eprintln!(
+            "[SHARDED-DETECTOR] Starting {} shard(s) from core {}",
+            shard_count, shared.config.first_core
+        );
+
+        let shard_handles = (0..shard_count)
+            .map(|shard| {
+                let shared = shared.clone();
+                let mut detector = OpportunityDetector::new(
+                    shared.pipelines[shard].consumer(),
+                    symbol_map.clone(),
+                    opportunity_producer.clone(),
+                );
+                if let Some(ref store) = funding_store {
+                    detector = detector.with_funding_store(store.clone());
+                }
+                detector = detector.with_fee_schedule(fee_schedule.clone());
+                let core = shared.config.core_for_shard(shard);
+                spawn_pinned_thread(core, &format!("detector-shard-{}", shard), move || {
+                    run_shard(shard, detector, &shared);
+                })
+            })
+            .collect();
+
+        let router_shared = shared.clone();
+        let router_handle = thread::Builder::new()
+            .name("detector-router".to_string())
+            .spawn(move || {
+                run_router(market_consumer, symbol_map, &router_shared, publisher);
+            })
+            .expect("Failed to spawn detector router thread");
+
+        Self {
+            shared,
+            router_handle: Some(router_handle),
+            shard_handles,
+        }
+    }
+
+    /// Number of shards.
+    pub fn shard_count(&self) -> usize {
+        self.shared.config.shard_count
+    }
+
+    /// Current per-shard load and latency metrics.
+    pub fn metrics_snapshot(&self) -> ShardedDetectorSnapshot {
+        self.shared.snapshot()
+    }
+
+    /// Filter analytics merged across all shards (as of their last report).
+    pub fn filter_analytics_snapshot(&self) -> FilterAnalyticsSnapshot {
+        self.shared.merged_analytics()
+    }
+
+    /// Symbol identity report merged across all shards (as of their last report).
+    pub fn symbol_identity_report(&self) -> SymbolIdentityReport {
+        self.shared.merged_identity()
+    }
+
+    /// Stop the router and all shards and wait for them to exit.
+    pub fn shutdown(mut self) {
+        self.shared.shutdown.store(true, Ordering::Relaxed);
+        if let Some(handle) = self.router_handle.take() {
+            if let Err(e) = handle.join() {
+                eprintln!("[SHARDED-DETECTOR] Router join error: {:?}", e);
+            }
+        }
+        for (i, handle) in self.shard_handles.drain(..).enumerate() {
+            if let Err(e) = handle.join() {
+                eprintln!("[SHARDED-DETECTOR] Shard {} join error: {:?}", i, e);
+            }
+        }
+    }
+}
+
+/// Router loop: assign each update to the shard owning its canonical symbol.
+fn run_router(
+    market_consumer: MarketConsumer,
+    symbol_map: Arc<SymbolMap>,
+    shared: &Shared,
+    publisher: Option<Arc<ArrayQueue<(String, String)>>>,
+) {
+    let shard_count = shared.config.shard_count;
+    let producers: Vec<MarketProducer> = shared.pipelines.iter().map(|p| p.producer()).collect();
+
+    // symbol_id → shard, so the symbol map is only consulted once per id
+    let mut assignment: HashMap<u32, usize> = HashMap::new();
+    let mut last_report = Instant::now();
+
+    while !shared.shutdown.load(Ordering::Relaxed) {
+        let mut routed = false;
+        while let Some(update) = market_consumer.pop() {
+            routed = true;
+            let shard = match assignment.get(&update.symbol_id) {
+                Some(shard) => *shard,
+                None => {
+                    let Some((_, symbol)) = symbol_map.get(update.symbol_id) else {
+                        continue;
+                    };
+                    let shard = shard_for_symbol(&symbol, shard_count);
+                    assignment.insert(update.symbol_id, shard);
+                    shared.metrics[shard].symbols_assigned.fetch_add(1, Ordering::Relaxed);
+                    shard
+                }
+            };
+            shared.metrics[shard].updates_routed.fetch_add(1, Ordering::Relaxed);
+            producers[shard].push(update);
+        }
+
+        if last_report.elapsed() >= REPORT_INTERVAL {
+            report(shared, publisher.as_deref());
+            last_report = Instant::now();
+        }
+
+        if !routed {
+            thread::sleep(IDLE_SLEEP);
+        }
+    }
+}
+
+/// Shard loop: drain the shard's pipeline through its own detector.
+fn run_shard(shard: usize, mut detector: OpportunityDetector, shared: &Shared) {
+    let metrics = &shared.metrics[shard];
+    let mut last_report = Instant::now();
+
+    while !shared.shutdown.load(Ordering::Relaxed) {
+        let start = Instant::now();
+        if detector.poll_once() {
+            metrics.latency.record(start.elapsed().as_nanos() as u64);
+            metrics.updates_processed.fetch_add(1, Ordering::Relaxed);
+        } else {
+            thread::sleep(IDLE_SLEEP);
+        }
+
+        if last_report.elapsed() >= REPORT_INTERVAL {
+            shared.analytics.lock().unwrap()[shard] = Some(detector.filter_analytics_snapshot());
+            shared.identity.lock().unwrap()[shard] = Some(detector.symbol_identity_report());
+            last_report = Instant::now();
+        }
+    }
+}
+
+/// Log shard load and publish metrics plus merged analytics.
+fn report(shared: &Shared, publisher: Option<&ArrayQueue<(String, String)>>) {
+    let snapshot = shared.snapshot();
+    for s in &snapshot.shards {
+        eprintln!(
+            "[SHARDED-DETECTOR] Shard {} (core {}) | symbols: {} | processed: {} | depth: {} | drops: {} | p99: {:.1}μs",
+            s.shard, s.core, s.symbols_assigned, s.updates_processed, s.queue_depth, s.queue_drops,
+            s.latency_p99_ns as f64 / 1000.0
+        );
+    }
+
+    let Some(queue) = publisher else {
+        return;
+    };
+    if let Ok(json) = serde_json::to_string(&snapshot) {
+        let _ = queue.push((SHARD_METRICS_KEY.to_string(), json));
+    }
+    if let Ok(json) = serde_json::to_string(&shared.merged_analytics()) {
+        let _ = queue.push((FILTER_ANALYTICS_KEY.to_string(), json));
+    }
+    if let Ok(json) = serde_json::to_string(&shared.merged_identity()) {
+        let _ = queue.push((SYMBOL_IDENTITY_KEY.to_string(), json));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::opportunity_queue::OpportunityQueue;
+    use crate::strategy::types::MarketUpdate;
+
+    #[test]
+    fn test_shard_for_symbol_is_stable_and_in_range() {
+        for symbol in ["BTCUSDT", "ETHUSDT", "SOLUSDT", "1000PEPEUSDT"] {
+            let shard = shard_for_symbol(symbol, 4);
+            assert!(shard < 4);
+            assert_eq!(shard, shard_for_symbol(symbol, 4));
+        }
+        assert_eq!(shard_for_symbol("BTCUSDT", 1), 0);
+        assert_eq!(shard_for_symbol("BTCUSDT", 0), 0);
+    }
+
+    #[test]
+    fn test_config_default_is_single_shard() {
+        let config = ShardedDetectorConfig::default();
+        assert_eq!(config.shard_count, 1);
+        assert!(config.core_for_shard(0).id < get_core_count().max(1));
+    }
+
+    #[test]
+    fn test_sharded_detector_publishes_opportunities() {
+        let pipeline = MarketPipeline::new();
+        let pipeline_producer = pipeline.producer();
+        let symbol_map = Arc::new(SymbolMap::new());
+        let queue = OpportunityQueue::new();
+        let opp_consumer = queue.consumer();
+
+        let config = ShardedDetectorConfig {
+            shard_count: 2,
+            first_core: 0,
+            shard_queue_capacity: 64,
+        };
+        let detector = ShardedDetector::start(
+            config,
+            pipeline.consumer(),
+            symbol_map.clone(),
+            queue.producer(),
+            None,
+            None,
+            Arc::new(FeeSchedule::default()),
+        );
+
+        // 50 bps spread between bybit and okx
+        let bybit_id = symbol_map.get_or_insert("bybit", "BTCUSDT");
+        let okx_id = symbol_map.get_or_insert("okx", "BTCUSDT");
+        pipeline_producer.push(MarketUpdate::new(bybit_id, 49990.0, 50000.0, 1000000));
+        pipeline_producer.push(MarketUpdate::new(okx_id, 50250.0, 50260.0, 1000000));
+
+        let deadline = Instant::now() + Duration::from_secs(2);
+        let mut opportunities = Vec::new();
+        while opportunities.is_empty() && Instant::now() < deadline {
+            opportunities = opp_consumer.pop_batch(10);
+            thread::sleep(Duration::from_millis(5));
+        }
+
+        let snapshot = detector.metrics_snapshot();
+        detector.shutdown();
+
+        assert!(!opportunities.is_empty(), "Sharded detector should publish opportunities");
+        assert_eq!(snapshot.shards.len(), 2);
+
+        // Both venues of the symbol must land on the same shard
+        let owner = shard_for_symbol("BTCUSDT", 2);
+        assert_eq!(snapshot.shards[owner].symbols_assigned, 2);
+        assert_eq!(snapshot.shards[1 - owner].symbols_assigned, 0);
+    }
+}
@@ -0,0 +1,429 @@
+//! Sliced execution for positions larger than the top of the book
+//!
+//! The atomic entry sends the full `contract_quantity` as one limit order and
+//! hedges it with one market order, which slips badly (or legs out) once the
+//! size exceeds what the books hold near the touch. `SlicedExecutor` splits the
+//! quantity into child slices instead:
+//!
+//! 1. Read both books; the slice is `depth_fraction` of the thinner side within
+//!    `depth_levels`, capped so one unhedged slice never exceeds `max_naked_usd`.
+//! 2. If a spread guard is set and the touch-to-touch spread is below it, stop.
+//! 3. Send the first leg as an IOC limit at the touch plus `max_slippage_bps`.
+//! 4. Hedge whatever filled with a market order on the other leg before the
+//!    next slice is sent.
+//! 5. Wait `slice_interval_ms` (TWAP pacing) and repeat.
+//!
+//! Every slice records its own `HedgeTimingMetrics`. Execution stops early on
+//! the spread guard, an empty book, `max_slices` or a failed hedge; what was
+//! hedged so far is kept and any naked remainder is reported to the caller.
+
+use crate::strategy::atomic_execution::{HedgeLogger, HedgeTimingMetrics};
+use crate::strategy::execution_backend::ExecutionBackend;
+use crate::strategy::types::{OrderBookDepth, OrderSide, OrderType, SimulatedOrder, TimeInForce};
+use std::fmt;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Configuration of sliced execution.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SliceConfig {
+    /// Positions at least this large (USD) are executed in slices
+    pub min_notional_usd: f64,
+    /// Share of the visible depth one slice may take
+    pub depth_fraction: f64,
+    /// Book levels counted as visible depth
+    pub depth_levels: usize,
+    /// Largest unhedged exposure (USD) while a slice is being hedged
+    pub max_naked_usd: f64,
+    pub max_slices: u32,
+    /// Pause between slices
+    pub slice_interval_ms: u64,
+    /// Limit price of the first leg beyond the touch (bps)
+    pub max_slippage_bps: f64,
+    /// Entries stop once the live spread falls below this (bps)
+    pub abort_spread_bps: f64,
+}
+
+impl Default for SliceConfig {
+    fn default() -> Self {
+        Self {
+            min_notional_usd: 10_000.0,
+            depth_fraction: 0.5,
+            depth_levels: 5,
+            max_naked_usd: 2_000.0,
+            max_slices: 20,
+            slice_interval_ms: 500,
+            max_slippage_bps: 5.0,
+            abort_spread_bps: 0.0,
+        }
+    }
+}
+
+impl SliceConfig {
+    /// Override defaults from `SLICE_MIN_NOTIONAL_USD`, `SLICE_DEPTH_FRACTION`,
+    /// `SLICE_MAX_NAKED_USD`, `SLICE_MAX_SLICES`, `SLICE_INTERVAL_MS`,
+    /// `SLICE_MAX_SLIPPAGE_BPS` and `SLICE_ABORT_SPREAD_BPS`.
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+        let parse = |var: &str, default: f64| -> f64 {
+            std::env::var(var)
+                .ok()
+                .and_then(|v| v.parse::<f64>().ok())
+                .filter(|v| v.is_finite())
+                .unwrap_or(default)
+        };
+
+        Self {
+            min_notional_usd: parse("SLICE_MIN_NOTIONAL_USD", defaults.min_notional_usd),
+            depth_fraction: parse("SLICE_DEPTH_FRACTION", defaults.depth_fraction).clamp(0.01, 1.0),
+            max_naked_usd: parse("SLICE_MAX_NAKED_USD", defaults.max_naked_usd).max(0.0),
+            max_slices: parse("SLICE_MAX_SLICES", defaults.max_slices as f64).max(1.0) as u32,
+            slice_interval_ms: parse("SLICE_INTERVAL_MS", defaults.slice_interval_ms as f64).max(0.0) as u64,
+            max_slippage_bps: parse("SLICE_MAX_SLIPPAGE_BPS", defaults.max_slippage_bps).max(0.0),
+            abort_spread_bps: parse("SLICE_ABORT_SPREAD_BPS", defaults.abort_spread_bps),
+            ..defaults
+        }
+    }
+}
+
+/// Why a sliced execution stopped before the full quantity was done
+#[derive(Debug, Clone, PartialEq)]
+pub enum SliceAbort {
+    /// Live spread fell below the guard
+    SpreadClosed { spread_bps: f64 },
+    /// A book was empty or could not be read
+    NoDepth(String),
+    /// `max_slices` reached
+    SliceLimit,
+    /// The first leg of a slice filled but its hedge did not (fully)
+    HedgeFailed(String),
+}
+
+impl fmt::Display for SliceAbort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SliceAbort::SpreadClosed { spread_bps } => write!(f, "spread closed to {:.2}bps", spread_bps),
+            SliceAbort::NoDepth(reason) => write!(f, "no depth: {}", reason),
+            SliceAbort::SliceLimit => write!(f, "slice limit reached"),
+            SliceAbort::HedgeFailed(reason) => write!(f, "hedge failed: {}", reason),
+        }
+    }
+}
+
+/// Quantity and average price executed on one leg
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct LegFill {
+    pub quantity: f64,
+    pub notional: f64,
+}
+
+impl LegFill {
+    fn add(&mut self, quantity: f64, price: f64) {
+        self.quantity += quantity;
+        self.notional += quantity * price;
+    }
+
+    pub fn average_price(&self) -> Option<f64> {
+        (self.quantity > 0.0).then(|| self.notional / self.quantity)
+    }
+}
+
+/// One executed slice
+#[derive(Debug, Clone)]
+pub struct SliceReport {
+    pub index: u32,
+    pub target_quantity: f64,
+    pub first_filled: f64,
+    pub hedge_filled: f64,
+    /// Touch-to-touch spread when the slice was sent (bps)
+    pub spread_bps: f64,
+    pub metrics: HedgeTimingMetrics,
+}
+
+/// Result of a sliced execution
+#[derive(Debug, Clone, Default)]
+pub struct SlicedFill {
+    pub first: LegFill,
+    pub hedge: LegFill,
+    pub slices: Vec<SliceReport>,
+    pub abort: Option<SliceAbort>,
+}
+
+impl SlicedFill {
+    /// First-leg quantity left without a hedge
+    pub fn naked_quantity(&self) -> f64 {
+        (self.first.quantity - self.hedge.quantity).max(0.0)
+    }
+}
+
+/// Touch price an order on `side` trades against: asks for buys, bids for sells
+fn touch(book: &OrderBookDepth, side: OrderSide) -> Option<f64> {
+    let levels = if side == OrderSide::Long { &book.asks } else { &book.bids };
+    levels.first().map(|level| level.price).filter(|price| *price > 0.0)
+}
+
+/// Quantity an order on `side` can take within the first `levels` levels
+fn visible_depth(book: &OrderBookDepth, side: OrderSide, levels: usize) -> f64 {
+    let book_side = if side == OrderSide::Long { &book.asks } else { &book.bids };
+    book_side.iter().take(levels).map(|level| level.quantity).sum()
+}
+
+/// Spread captured by buying at `buy_price` and selling at `sell_price` (bps)
+fn spread_bps(buy_price: f64, sell_price: f64) -> f64 {
+    (sell_price - buy_price) / buy_price * 10_000.0
+}
+
+/// Size of the next slice: a share of the thinner book, capped by the naked
+/// exposure limit and the remaining quantity, rounded down to `step`.
+pub fn slice_quantity(remaining: f64, first_depth: f64, hedge_depth: f64, price: f64, step: f64, config: &SliceConfig) -> f64 {
+    let by_depth = first_depth.min(hedge_depth) * config.depth_fraction;
+    let by_naked = if price > 0.0 { config.max_naked_usd / price } else { 0.0 };
+    let quantity = remaining.min(by_depth).min(by_naked);
+    if step > 0.0 {
+        // Small epsilon keeps exact multiples from rounding down a step
+        ((quantity / step) + 1e-9).floor() * step
+    } else {
+        quantity
+    }
+}
+
+/// Executes a two-leg order in depth-sized, hedged slices.
+pub struct SlicedExecutor {
+    backend: Arc<dyn ExecutionBackend>,
+    config: SliceConfig,
+    logger: HedgeLogger,
+}
+
+impl SlicedExecutor {
+    pub fn new(backend: Arc<dyn ExecutionBackend>, config: SliceConfig) -> Self {
+        Self { backend, config, logger: HedgeLogger::default_level() }
+    }
+
+    /// Execute `quantity` of `first` and hedge every slice with `hedge`.
+    ///
+    /// The templates give exchange, symbol, side and `reduce_only`; ids, prices
+    /// and sizes are filled in per slice. With `spread_guard`, execution stops
+    /// once buying on the long side and selling on the short side would capture
+    /// less than `abort_spread_bps`.
+    pub async fn execute(
+        &self,
+        first: &SimulatedOrder,
+        hedge: &SimulatedOrder,
+        quantity: f64,
+        step: f64,
+        spread_guard: bool,
+    ) -> SlicedFill {
+        let mut fill = SlicedFill::default();
+        let mut index = 0;
+
+        while quantity - fill.hedge.quantity >= step.max(1e-12) {
+            if index >= self.config.max_slices {
+                fill.abort = Some(SliceAbort::SliceLimit);
+                break;
+            }
+            index += 1;
+
+            let mut metrics = HedgeTimingMetrics::default();
+            metrics.record_depth_check_initiated();
+            let (first_book, hedge_book) = tokio::join!(
+                self.backend.get_order_book_depth(&first.exchange, &first.symbol, self.config.depth_levels),
+                self.backend.get_order_book_depth(&hedge.exchange, &hedge.symbol, self.config.depth_levels)
+            );
+            metrics.record_depth_check_completed();
+            let (first_book, hedge_book) = match (first_book, hedge_book) {
+                (Ok(first_book), Ok(hedge_book)) => (first_book, hedge_book),
+                (Err(e), _) | (_, Err(e)) => {
+                    fill.abort = Some(SliceAbort::NoDepth(e.to_string()));
+                    break;
+                }
+            };
+            let (Some(first_touch), Some(hedge_touch)) = (touch(&first_book, first.side), touch(&hedge_book, hedge.side)) else {
+                fill.abort = Some(SliceAbort::NoDepth("empty book".to_string()));
+                break;
+            };
+
+            let (buy_price, sell_price) = if first.side == OrderSide::Long { (first_touch, hedge_touch) } else { (hedge_touch, first_touch) };
+            let live_spread_bps = spread_bps(buy_price, sell_price);
+            if spread_guard && live_spread_bps < self.config.abort_spread_bps {
+                eprintln!("[SLICE] Spread closed to {:.2}bps after {} slice(s), stopping", live_spread_bps, fill.slices.len());
+                fill.abort = Some(SliceAbort::SpreadClosed { spread_bps: live_spread_bps });
+                break;
+            }
+
+            let target = slice_quantity(
+                quantity - fill.hedge.quantity,
+                visible_depth(&first_book, first.side, self.config.depth_levels),
+                visible_depth(&hedge_book, hedge.side, self.config.depth_levels),
+                first_touch,
+                step,
+                &self.config,
+            );
+            if target <= 0.0 {
+                fill.abort = Some(SliceAbort::NoDepth(format!("slice rounds to zero (step {})", step)));
+                break;
+            }
+
+            // First leg: IOC at the touch plus the slippage allowance
+            let slippage = self.config.max_slippage_bps / 10_000.0;
+            let limit_price = if first.side == OrderSide::Long { first_touch * (1.0 + slippage) } else { first_touch * (1.0 - slippage) };
+            let first_order = SimulatedOrder {
+                id: String::new(),
+                order_type: OrderType::Limit,
+                price: limit_price,
+                size: target,
+                time_in_force: TimeInForce::Ioc,
+                ..first.clone()
+            };
+            let first_filled = match self.place_and_confirm(first_order, &mut metrics).await {
+                Ok(filled) => filled,
+                Err(e) => {
+                    eprintln!("[SLICE] ⚠️  Slice {} first leg failed on {}: {}", index, first.exchange, e);
+                    0.0
+                }
+            };
+            metrics.fill_detected_at = Some(Instant::now());
+            metrics.record_slice(index, target);
+            fill.first.add(first_filled, first_touch);
+
+            let mut hedge_filled = 0.0;
+            if first_filled > 0.0 {
+                metrics.record_market_order_initiated();
+                let hedge_order = SimulatedOrder {
+                    id: String::new(),
+                    order_type: OrderType::Market,
+                    price: hedge_touch,
+                    size: first_filled,
+                    time_in_force: TimeInForce::Gtc,
+                    ..hedge.clone()
+                };
+                match self.hedge(hedge_order, first_filled, &mut metrics).await {
+                    Ok(filled) => hedge_filled = filled,
+                    Err(e) => fill.abort = Some(SliceAbort::HedgeFailed(e)),
+                }
+                metrics.record_market_order_filled();
+                metrics.finalize();
+                fill.hedge.add(hedge_filled, hedge_touch);
+                if fill.abort.is_none() && hedge_filled + 1e-9 < first_filled {
+                    fill.abort = Some(SliceAbort::HedgeFailed(format!(
+                        "hedged {:.4} of {:.4} on {}", hedge_filled, first_filled, hedge.exchange
+                    )));
+                }
+            }
+
+            eprintln!("[SLICE] {}/{} {} | target {:.4} | first {:.4} on {} | hedge {:.4} on {} | spread {:.2}bps",
+                index, self.config.max_slices, first.symbol, target, first_filled, first.exchange,
+                hedge_filled, hedge.exchange, live_spread_bps);
+            self.logger.log_timing_summary(&metrics, &first.exchange, &first.symbol);
+            fill.slices.push(SliceReport {
+                index,
+                target_quantity: target,
+                first_filled,
+                hedge_filled,
+                spread_bps: live_spread_bps,
+                metrics,
+            });
+
+            if fill.abort.is_some() {
+                break;
+            }
+            if quantity - fill.hedge.quantity >= step.max(1e-12) {
+                tokio::time::sleep(Duration::from_millis(self.config.slice_interval_ms)).await;
+            }
+        }
+
+        fill
+    }
+
+    /// Send an order and return how much of it filled.
+    async fn place_and_confirm(&self, order: SimulatedOrder, metrics: &mut HedgeTimingMetrics) -> Result<f64, String> {
+        let api_start = Instant::now();
+        let placed = self.backend.place_order(order.clone()).await.map_err(|e| e.to_string())?;
+        metrics.record_api_response(format!("place_order({})", order.exchange), api_start.elapsed());
+
+        let api_start = Instant::now();
+        let status = self.backend
+            .get_order_status_detailed(&placed.exchange, &placed.id, &order.symbol)
+            .await
+            .map_err(|e| e.to_string())?;
+        metrics.record_api_response(format!("get_order_status_detailed({})", order.exchange), api_start.elapsed());
+        Ok(status.filled_quantity.min(order.size))
+    }
+
+    /// Market-hedge `quantity`, re-sending the remainder of partial fills up to 3 times.
+    async fn hedge(&self, order: SimulatedOrder, quantity: f64, metrics: &mut HedgeTimingMetrics) -> Result<f64, String> {
+        const MAX_ATTEMPTS: u32 = 3;
+        let mut filled = 0.0;
+        let mut last_error = String::new();
+
+        for _ in 0..MAX_ATTEMPTS {
+            let remaining = quantity - filled;
+            if remaining <= 1e-9 {
+                break;
+            }
+            let api_start = Instant::now();
+            let placed = match self.backend.place_market_order(SimulatedOrder { size: remaining, ..order.clone() }).await {
+                Ok(placed) => placed,
+                Err(e) => {
+                    last_error = e.to_string();
+                    continue;
+                }
+            };
+            metrics.record_api_response(format!("place_market_order({})", order.exchange), api_start.elapsed());
+            metrics.record_market_order_accepted();
+
+            match self.backend.get_order_status_detailed(&placed.exchange, &placed.id, &order.symbol).await {
+                Ok(status) => filled += status.filled_quantity.min(remaining),
+                Err(e) => {
+                    // An unconfirmed hedge is not retried: re-sending could double the position
+                    return Err(format!("could not confirm hedge {} on {}: {}", placed.id, order.exchange, e));
+                }
+            }
+        }
+
+        if filled > 0.0 || last_error.is_empty() {
+            Ok(filled)
+        } else {
+            Err(last_error)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::types::PriceLevel;
+
+    fn book(bids: &[(f64, f64)], asks: &[(f64, f64)]) -> OrderBookDepth {
+        let levels = |side: &[(f64, f64)]| side.iter().map(|&(price, quantity)| PriceLevel { price, quantity }).collect();
+        OrderBookDepth { bids: levels(bids), asks: levels(asks), timestamp: 0 }
+    }
+
+    #[test]
+    fn test_slice_sized_from_thinner_book_and_naked_cap() {
+        let config = SliceConfig { max_naked_usd: 10_000.0, ..SliceConfig::default() };
+        let long_book = book(&[(99.0, 50.0)], &[(100.0, 30.0), (100.5, 30.0)]);
+        let short_book = book(&[(101.0, 10.0), (100.9, 10.0)], &[(101.1, 50.0)]);
+
+        let long_depth = visible_depth(&long_book, OrderSide::Long, config.depth_levels);
+        let short_depth = visible_depth(&short_book, OrderSide::Short, config.depth_levels);
+        assert_eq!((long_depth, short_depth), (60.0, 20.0));
+
+        // Half of the thinner (short) side
+        assert_eq!(slice_quantity(100.0, long_depth, short_depth, 100.0, 0.1, &config), 10.0);
+        // $500 naked cap at $100 = 5 contracts
+        let capped = SliceConfig { max_naked_usd: 500.0, ..config.clone() };
+        assert_eq!(slice_quantity(100.0, long_depth, short_depth, 100.0, 0.1, &capped), 5.0);
+        // The last slice takes the remainder, rounded down to the step
+        assert!((slice_quantity(3.25, long_depth, short_depth, 100.0, 0.1, &config) - 3.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_touch_and_spread() {
+        let long_book = book(&[(99.9, 1.0)], &[(100.0, 1.0)]);
+        let short_book = book(&[(100.2, 1.0)], &[(100.3, 1.0)]);
+        let buy = touch(&long_book, OrderSide::Long).unwrap();
+        let sell = touch(&short_book, OrderSide::Short).unwrap();
+        assert!((spread_bps(buy, sell) - 20.0).abs() < 1e-9);
+        assert_eq!(touch(&book(&[], &[]), OrderSide::Long), None);
+    }
+}
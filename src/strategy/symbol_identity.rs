@@ -0,0 +1,551 @@
+//! Cross-Venue Symbol Identity Checks
+//!
+//! Different venues sometimes list unrelated tokens under the same ticker, or
+//! use rebased contracts (`1000PEPE` vs `PEPE`). After `normalize_symbol` these
+//! collapse onto one canonical symbol and show up as huge fake spreads that
+//! easily pass the detector's confidence filter.
+//!
+//! `SymbolIdentityChecker` compares mid-price ratios between venues for every
+//! (symbol, venue pair) and quarantines pairs whose ratio is far from 1 or
+//! unstable. Quarantined pairs are skipped by the `OpportunityDetector`.
+//!
+//! # Lifecycle
+//!
+//! ```text
+//! Warming ──(warm-up samples, ratio ≈ 1, stable)──→ Verified
+//!    │                                                 │
+//!    └──(ratio far from 1 / unstable / denied)──→ Quarantined ←──(ratio jump)──┘
+//!                                                     │
+//!                       (recovery_samples good ratios in a row)──→ Warming / Verified
+//! ```
+//!
+//! While warming, a pair is only tradeable if the instantaneous ratio is within
+//! `max_ratio_deviation`, so rebased contracts are blocked from the first quote.
+//! A quarantined pair is only cleared after `recovery_samples` consecutive
+//! in-band observations, so a ratio hovering at the threshold does not flap.
+//! Out-of-band ratios never enter the running statistics, and the statistics
+//! start over when a quarantine begins, so a pair that recovers warms up again
+//! on clean ratios only.
+//!
+//! # Manual overrides
+//!
+//! Entries are either a canonical symbol (`PEPEUSDT`) or a symbol plus venue
+//! pair (`PEPEUSDT:bybit:okx`, order-insensitive). Allow-listed entries skip
+//! the ratio checks; deny-listed entries are always quarantined.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Redis key the detector publishes its identity report under
+pub const SYMBOL_IDENTITY_KEY: &str = "detector:symbol_identity";
+
+/// Configuration for symbol identity checks.
+#[derive(Debug, Clone)]
+pub struct SymbolIdentityConfig {
+    /// Ratio samples required before a pair can be verified
+    pub warmup_samples: u32,
+
+    /// Maximum |ratio - 1| for a pair to be considered the same instrument
+    pub max_ratio_deviation: f64,
+
+    /// Maximum coefficient of variation of the ratio (std-dev / mean)
+    pub max_ratio_cv: f64,
+
+    /// Consecutive in-band observations required to clear a quarantine
+    pub recovery_samples: u32,
+
+    /// Symbols or symbol:venue:venue entries that bypass the checks
+    pub allow: HashSet<String>,
+
+    /// Symbols or symbol:venue:venue entries that are always quarantined
+    pub deny: HashSet<String>,
+}
+
+impl Default for SymbolIdentityConfig {
+    /// Defaults:
+    /// - warmup_samples: 20
+    /// - max_ratio_deviation: 0.05 (500 bps)
+    /// - max_ratio_cv: 0.02
+    /// - recovery_samples: 20
+    fn default() -> Self {
+        Self {
+            warmup_samples: 20,
+            max_ratio_deviation: 0.05,
+            max_ratio_cv: 0.02,
+            recovery_samples: 20,
+            allow: HashSet::new(),
+            deny: HashSet::new(),
+        }
+    }
+}
+
+impl SymbolIdentityConfig {
+    /// Load allow/deny lists from `SYMBOL_IDENTITY_ALLOW` / `SYMBOL_IDENTITY_DENY`
+    /// (comma-separated), keeping the default thresholds.
+    pub fn from_env() -> Self {
+        let parse_list = |var: &str| -> HashSet<String> {
+            std::env::var(var)
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(normalize_entry)
+                .collect()
+        };
+
+        Self {
+            allow: parse_list("SYMBOL_IDENTITY_ALLOW"),
+            deny: parse_list("SYMBOL_IDENTITY_DENY"),
+            ..Self::default()
+        }
+    }
+}
+
+/// Canonicalize a list entry: upper-case symbol, lower-case venues in sorted order.
+fn normalize_entry(entry: &str) -> String {
+    let parts: Vec<&str> = entry.split(':').collect();
+    match parts.as_slice() {
+        [symbol, a, b] => pair_entry(&symbol.to_uppercase(), &a.to_lowercase(), &b.to_lowercase()),
+        _ => entry.to_uppercase(),
+    }
+}
+
+fn pair_entry(symbol: &str, ex_a: &str, ex_b: &str) -> String {
+    let (a, b) = ordered(ex_a, ex_b);
+    format!("{}:{}:{}", symbol, a, b)
+}
+
+fn ordered<'a>(ex_a: &'a str, ex_b: &'a str) -> (&'a str, &'a str) {
+    if ex_a <= ex_b {
+        (ex_a, ex_b)
+    } else {
+        (ex_b, ex_a)
+    }
+}
+
+/// Why a pair was quarantined.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum QuarantineReason {
+    /// Deny-listed by an operator
+    ManualDeny,
+
+    /// Ratio is close to a power of ten (e.g. 1000PEPE vs PEPE)
+    Rebased { factor: f64 },
+
+    /// Ratio is far from 1 (likely a different token under the same ticker)
+    RatioOffset { ratio: f64 },
+
+    /// Ratio fluctuates too much to be the same instrument
+    Unstable { cv: f64 },
+}
+
+/// Identity status of a (symbol, venue pair).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum IdentityStatus {
+    Warming,
+    Verified,
+    Allowed,
+    Quarantined(QuarantineReason),
+}
+
+impl IdentityStatus {
+    pub fn is_quarantined(&self) -> bool {
+        matches!(self, IdentityStatus::Quarantined(_))
+    }
+}
+
+/// Running ratio statistics for one pair (Welford's algorithm).
+#[derive(Debug, Clone)]
+struct PairState {
+    samples: u32,
+    mean: f64,
+    m2: f64,
+    last_ratio: f64,
+    /// Consecutive in-band observations while quarantined
+    good_streak: u32,
+    status: IdentityStatus,
+}
+
+impl PairState {
+    fn new() -> Self {
+        Self {
+            samples: 0,
+            mean: 0.0,
+            m2: 0.0,
+            last_ratio: 0.0,
+            good_streak: 0,
+            status: IdentityStatus::Warming,
+        }
+    }
+
+    fn observe(&mut self, ratio: f64) {
+        self.samples += 1;
+        let delta = ratio - self.mean;
+        self.mean += delta / self.samples as f64;
+        self.m2 += delta * (ratio - self.mean);
+        self.last_ratio = ratio;
+    }
+
+    /// Drop the accumulated statistics, e.g. once they have led to a quarantine
+    fn reset_stats(&mut self) {
+        self.samples = 0;
+        self.mean = 0.0;
+        self.m2 = 0.0;
+    }
+
+    fn cv(&self) -> f64 {
+        if self.samples < 2 || self.mean <= 0.0 {
+            return 0.0;
+        }
+        (self.m2 / (self.samples - 1) as f64).sqrt() / self.mean
+    }
+}
+
+/// Classify a ratio that is far from 1.
+fn offset_reason(ratio: f64) -> QuarantineReason {
+    // 10x, 100x, 1000x ... within 5% → rebased contract
+    let log = ratio.abs().log10();
+    let nearest = log.round();
+    if nearest != 0.0 && (log - nearest).abs() < 0.02 {
+        QuarantineReason::Rebased { factor: 10f64.powf(nearest) }
+    } else {
+        QuarantineReason::RatioOffset { ratio }
+    }
+}
+
+/// One line of the identity report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PairIdentityEntry {
+    pub symbol: String,
+    pub venue_a: String,
+    pub venue_b: String,
+    pub samples: u32,
+    pub mean_ratio: f64,
+    pub ratio_cv: f64,
+    pub status: IdentityStatus,
+}
+
+/// Summary of all tracked pairs, as published to Redis.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SymbolIdentityReport {
+    pub timestamp: u64,
+    pub warming: usize,
+    pub verified: usize,
+    pub allowed: usize,
+    /// Quarantined pairs with their statistics
+    pub quarantined: Vec<PairIdentityEntry>,
+}
+
+impl SymbolIdentityReport {
+    /// Fold another report (e.g. from a different detector shard) into this one
+    pub fn merge(&mut self, other: &SymbolIdentityReport) {
+        self.timestamp = self.timestamp.max(other.timestamp);
+        self.warming += other.warming;
+        self.verified += other.verified;
+        self.allowed += other.allowed;
+        self.quarantined.extend(other.quarantined.iter().cloned());
+    }
+}
+
+/// Tracks mid-price ratios between venues and decides which pairs are tradeable.
+///
+/// Not thread-safe: owned by the detector and mutated on its thread only.
+pub struct SymbolIdentityChecker {
+    config: SymbolIdentityConfig,
+    /// Interned symbol and venue names, so the hot path only hashes integers
+    names: Names,
+    /// Keyed by (symbol id, venue_a id, venue_b id) with venues in sorted order
+    pairs: HashMap<(u32, u32, u32), PairState>,
+}
+
+/// Name <-> id table; names are only allocated the first time they are seen.
+#[derive(Default)]
+struct Names {
+    ids: HashMap<String, u32>,
+    names: Vec<String>,
+}
+
+impl Names {
+    fn get(&self, name: &str) -> Option<u32> {
+        self.ids.get(name).copied()
+    }
+
+    fn intern(&mut self, name: &str) -> u32 {
+        if let Some(id) = self.get(name) {
+            return id;
+        }
+        let id = self.names.len() as u32;
+        self.names.push(name.to_string());
+        self.ids.insert(name.to_string(), id);
+        id
+    }
+
+    fn name(&self, id: u32) -> &str {
+        &self.names[id as usize]
+    }
+}
+
+impl SymbolIdentityChecker {
+    pub fn new(config: SymbolIdentityConfig) -> Self {
+        Self {
+            config,
+            names: Names::default(),
+            pairs: HashMap::new(),
+        }
+    }
+
+    /// Feed one pair of mid prices and return whether the pair is tradeable.
+    pub fn observe(&mut self, symbol: &str, ex_a: &str, mid_a: f64, ex_b: &str, mid_b: f64) -> bool {
+        if mid_a <= 0.0 || mid_b <= 0.0 {
+            return false;
+        }
+
+        // Always store the ratio in sorted-venue order
+        let (venue_a, venue_b, ratio) = if ex_a <= ex_b {
+            (ex_a, ex_b, mid_a / mid_b)
+        } else {
+            (ex_b, ex_a, mid_b / mid_a)
+        };
+
+        let key = (self.names.intern(symbol), self.names.intern(venue_a), self.names.intern(venue_b));
+        let is_new = !self.pairs.contains_key(&key);
+        let manual = if is_new { self.manual_status(symbol, venue_a, venue_b) } else { None };
+        let state = self.pairs.entry(key).or_insert_with(PairState::new);
+        if let Some(status) = manual {
+            state.status = status;
+        }
+
+        match state.status {
+            IdentityStatus::Allowed => {
+                state.observe(ratio);
+                return true;
+            }
+            IdentityStatus::Quarantined(QuarantineReason::ManualDeny) => return false,
+            _ => {}
+        }
+
+        let previous = state.status.clone();
+        let deviation = (ratio - 1.0).abs();
+
+        let next = if deviation > self.config.max_ratio_deviation {
+            // Instantaneous check: a single far-off ratio blocks the pair and is
+            // kept out of the statistics, which only ever hold in-band ratios
+            IdentityStatus::Quarantined(offset_reason(ratio))
+        } else {
+            state.observe(ratio);
+            if state.samples < self.config.warmup_samples {
+                IdentityStatus::Warming
+            } else if state.cv() > self.config.max_ratio_cv {
+                IdentityStatus::Quarantined(QuarantineReason::Unstable { cv: state.cv() })
+            } else {
+                IdentityStatus::Verified
+            }
+        };
+
+        // Hysteresis: a quarantine is only cleared by a run of good observations
+        state.good_streak = if next.is_quarantined() { 0 } else { state.good_streak + 1 };
+        state.status = if previous.is_quarantined() && !next.is_quarantined() && state.good_streak < self.config.recovery_samples {
+            previous.clone()
+        } else {
+            next
+        };
+
+        if state.status.is_quarantined() && !previous.is_quarantined() {
+            eprintln!(
+                "[SYMBOL-IDENTITY] ⚠️  Quarantined {} {}/{} | ratio: {:.4} | samples: {} | {:?}",
+                symbol, venue_a, venue_b, ratio, state.samples, state.status
+            );
+            // Statistics that led here must not outlive the quarantine; the pair
+            // warms up again from the ratios seen during recovery
+            state.reset_stats();
+        }
+
+        !state.status.is_quarantined()
+    }
+
+    /// Current status of a pair (venue order does not matter).
+    pub fn status(&self, symbol: &str, ex_a: &str, ex_b: &str) -> Option<&IdentityStatus> {
+        let (a, b) = ordered(ex_a, ex_b);
+        let key = (self.names.get(symbol)?, self.names.get(a)?, self.names.get(b)?);
+        self.pairs.get(&key).map(|s| &s.status)
+    }
+
+    /// Build a report of all tracked pairs.
+    pub fn report(&self) -> SymbolIdentityReport {
+        let mut report = SymbolIdentityReport {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            ..Default::default()
+        };
+
+        for (&(symbol, venue_a, venue_b), state) in &self.pairs {
+            match &state.status {
+                IdentityStatus::Warming => report.warming += 1,
+                IdentityStatus::Verified => report.verified += 1,
+                IdentityStatus::Allowed => report.allowed += 1,
+                IdentityStatus::Quarantined(_) => report.quarantined.push(PairIdentityEntry {
+                    symbol: self.names.name(symbol).to_string(),
+                    venue_a: self.names.name(venue_a).to_string(),
+                    venue_b: self.names.name(venue_b).to_string(),
+                    samples: state.samples,
+                    mean_ratio: state.mean,
+                    ratio_cv: state.cv(),
+                    status: state.status.clone(),
+                }),
+            }
+        }
+
+        report.quarantined.sort_by(|a, b| {
+            (&a.symbol, &a.venue_a, &a.venue_b).cmp(&(&b.symbol, &b.venue_a, &b.venue_b))
+        });
+        report
+    }
+
+    fn manual_status(&self, symbol: &str, ex_a: &str, ex_b: &str) -> Option<IdentityStatus> {
+        let pair = pair_entry(symbol, ex_a, ex_b);
+        if self.config.deny.contains(symbol) || self.config.deny.contains(&pair) {
+            Some(IdentityStatus::Quarantined(QuarantineReason::ManualDeny))
+        } else if self.config.allow.contains(symbol) || self.config.allow.contains(&pair) {
+            Some(IdentityStatus::Allowed)
+        } else {
+            None
+        }
+    }
+}
+
+impl Default for SymbolIdentityChecker {
+    fn default() -> Self {
+        Self::new(SymbolIdentityConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matching_prices_verify_after_warmup() {
+        let mut checker = SymbolIdentityChecker::default();
+        for i in 0..20 {
+            let wiggle = (i % 3) as f64 * 0.5;
+            assert!(checker.observe("BTCUSDT", "bybit", 50000.0 + wiggle, "okx", 50010.0));
+        }
+        assert_eq!(checker.status("BTCUSDT", "okx", "bybit"), Some(&IdentityStatus::Verified));
+    }
+
+    #[test]
+    fn test_rebased_contract_quarantined_immediately() {
+        let mut checker = SymbolIdentityChecker::default();
+        // 1000PEPE normalized to PEPEUSDT on one venue, PEPE on the other
+        assert!(!checker.observe("PEPEUSDT", "bybit", 0.0112, "hyperliquid", 0.0000112));
+
+        match checker.status("PEPEUSDT", "bybit", "hyperliquid") {
+            Some(IdentityStatus::Quarantined(QuarantineReason::Rebased { factor })) => {
+                assert_eq!(*factor, 1000.0);
+            }
+            other => panic!("expected rebased quarantine, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_unrelated_token_quarantined_as_offset() {
+        let mut checker = SymbolIdentityChecker::default();
+        assert!(!checker.observe("ABCUSDT", "bitget", 2.0, "okx", 1.3));
+        assert!(matches!(
+            checker.status("ABCUSDT", "bitget", "okx"),
+            Some(IdentityStatus::Quarantined(QuarantineReason::RatioOffset { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_unstable_ratio_quarantined() {
+        let config = SymbolIdentityConfig {
+            warmup_samples: 10,
+            max_ratio_cv: 0.005,
+            ..Default::default()
+        };
+        let mut checker = SymbolIdentityChecker::new(config);
+        for i in 0..10 {
+            let mid_b = if i % 2 == 0 { 1.03 } else { 0.97 };
+            checker.observe("XYZUSDT", "bybit", 1.0, "kucoin", mid_b);
+        }
+        assert!(matches!(
+            checker.status("XYZUSDT", "bybit", "kucoin"),
+            Some(IdentityStatus::Quarantined(QuarantineReason::Unstable { .. }))
+        ));
+    }
+
+    #[test]
+    fn test_quarantine_clears_only_after_recovery_streak() {
+        let config = SymbolIdentityConfig {
+            recovery_samples: 3,
+            ..Default::default()
+        };
+        let mut checker = SymbolIdentityChecker::new(config);
+        for _ in 0..20 {
+            checker.observe("ETHUSDT", "bybit", 3000.0, "okx", 3000.0);
+        }
+        assert_eq!(checker.status("ETHUSDT", "bybit", "okx"), Some(&IdentityStatus::Verified));
+
+        // One outlier quarantines; a single in-band ratio must not clear it
+        assert!(!checker.observe("ETHUSDT", "bybit", 3000.0, "okx", 2000.0));
+        assert!(!checker.observe("ETHUSDT", "bybit", 3000.0, "okx", 3000.0));
+        assert!(!checker.observe("ETHUSDT", "bybit", 3000.0, "okx", 2000.0));
+        assert!(!checker.observe("ETHUSDT", "bybit", 3000.0, "okx", 3000.0));
+        assert!(!checker.observe("ETHUSDT", "bybit", 3000.0, "okx", 3000.0));
+        assert!(checker.observe("ETHUSDT", "bybit", 3000.0, "okx", 3000.0));
+
+        // The outliers left no trace: the pair warms up again on clean ratios only
+        assert_eq!(checker.status("ETHUSDT", "bybit", "okx"), Some(&IdentityStatus::Warming));
+        for _ in 0..17 {
+            assert!(checker.observe("ETHUSDT", "bybit", 3000.0, "okx", 3000.0));
+        }
+        assert_eq!(checker.status("ETHUSDT", "bybit", "okx"), Some(&IdentityStatus::Verified));
+    }
+
+    #[test]
+    fn test_unstable_pair_recovers_once_ratio_settles() {
+        let config = SymbolIdentityConfig {
+            warmup_samples: 10,
+            max_ratio_cv: 0.005,
+            recovery_samples: 3,
+            ..Default::default()
+        };
+        let mut checker = SymbolIdentityChecker::new(config);
+        for i in 0..10 {
+            let mid_b = if i % 2 == 0 { 1.03 } else { 0.97 };
+            checker.observe("XYZUSDT", "bybit", 1.0, "kucoin", mid_b);
+        }
+        assert!(checker.status("XYZUSDT", "bybit", "kucoin").unwrap().is_quarantined());
+
+        for _ in 0..3 {
+            checker.observe("XYZUSDT", "bybit", 1.0, "kucoin", 1.0);
+        }
+        assert_eq!(checker.status("XYZUSDT", "bybit", "kucoin"), Some(&IdentityStatus::Warming));
+        for _ in 0..7 {
+            assert!(checker.observe("XYZUSDT", "bybit", 1.0, "kucoin", 1.0));
+        }
+        assert_eq!(checker.status("XYZUSDT", "bybit", "kucoin"), Some(&IdentityStatus::Verified));
+    }
+
+    #[test]
+    fn test_allow_and_deny_lists() {
+        let config = SymbolIdentityConfig {
+            allow: [normalize_entry("pepeusdt:OKX:bybit")].into_iter().collect(),
+            deny: [normalize_entry("btcusdt")].into_iter().collect(),
+            ..Default::default()
+        };
+        let mut checker = SymbolIdentityChecker::new(config);
+
+        assert!(checker.observe("PEPEUSDT", "bybit", 0.0112, "okx", 0.0000112));
+        assert!(!checker.observe("BTCUSDT", "bybit", 50000.0, "okx", 50000.0));
+
+        let report = checker.report();
+        assert_eq!(report.allowed, 1);
+        assert_eq!(report.quarantined.len(), 1);
+        assert_eq!(report.quarantined[0].status, IdentityStatus::Quarantined(QuarantineReason::ManualDeny));
+    }
+}
@@ -136,6 +136,14 @@ impl SymbolMap {
         }
     }
     
+    /// Look up the ID of an (exchange, symbol) pair without allocating one.
+    ///
+    /// # Returns
+    /// Some(id) if the pair has been mapped, None otherwise
+    pub fn get_id(&self, exchange: &str, symbol: &str) -> Option<u32> {
+        self.to_id.get(&(exchange.to_string(), symbol.to_string())).map(|id| *id)
+    }
+    
     /// Get the (exchange, symbol) pair for a given ID.
     ///
     /// # Arguments
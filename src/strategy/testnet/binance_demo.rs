@@ -2,8 +2,10 @@ use std::error::Error;
 use std::collections::HashMap;
 use reqwest::Client;
 use serde_json::Value;
-use crate::strategy::types::{SimulatedOrder, OrderStatus, OrderSide};
+use crate::strategy::types::{FeeRates, Fill, FundingPayment, Liquidity, SimulatedOrder, OrderStatus, OrderSide, PositionMargin, TimeInForce, VenuePosition};
+use super::{as_f64, open_order};
 use crate::strategy::testnet_config::ExchangeCredentials;
+use crate::strategy::execution_error::{http_error, ExecutionError};
 use crate::strategy::rate_limiter::RateLimiter;
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
@@ -21,6 +23,85 @@ type SymbolPrecision = (u32, u32, f64, f64);
 // Binance DEMO trading environment (futures demo)
 const BINANCE_DEMO_URL: &str = "https://testnet.binancefuture.com";
 
+/// Map a Binance USDⓈ-M error `code` onto `ExecutionError`
+pub fn binance_error(code: i64, msg: &str) -> ExecutionError {
+    let text = msg.to_string();
+    match code {
+        // Unknown order sent / Order does not exist
+        -2011 | -2013 => ExecutionError::OrderNotFound(text),
+        -2018 | -2019 => ExecutionError::InsufficientBalance(text),
+        -1003 | -1015 => ExecutionError::RateLimited { retry_after: None },
+        -1002 | -1022 | -2014 | -2015 => ExecutionError::Auth(text),
+        -5022 => ExecutionError::PostOnlyRejected(text),
+        // Precision, quantity below zero / above max, notional below minimum
+        -1111 | -4003 | -4005 | -4164 => ExecutionError::InvalidQuantity(text),
+        // ClientOrderId is duplicated
+        -4116 => ExecutionError::DuplicateOrder(text),
+        _ => ExecutionError::rejected(code, text),
+    }
+}
+
+/// USDⓈ-M `timeInForce`; `GTX` is post-only and is rejected with -5022 when it would cross
+fn binance_time_in_force(tif: TimeInForce) -> &'static str {
+    match tif {
+        TimeInForce::Gtc => "GTC",
+        TimeInForce::Ioc => "IOC",
+        TimeInForce::Fok => "FOK",
+        TimeInForce::PostOnly => "GTX",
+    }
+}
+
+/// `reduceOnly` parameter for an order. `closePosition` only exists on stop and
+/// take-profit market orders, so close-position orders are sent reduce-only.
+fn binance_reduce_only_param(order: &SimulatedOrder) -> &'static str {
+    if order.is_reduce_only() {
+        "&reduceOnly=true"
+    } else {
+        ""
+    }
+}
+
+/// `newClientOrderId` parameter for an order. Binance refuses a second order
+/// with the same ID (-4116), so a retried submission can't be placed twice.
+fn binance_client_order_id_param(order: &SimulatedOrder) -> String {
+    let valid = !order.id.is_empty()
+        && order.id.len() <= 36
+        && order.id.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '.' | ':' | '/' | '_' | '-'));
+    if valid {
+        format!("&newClientOrderId={}", order.id)
+    } else {
+        String::new()
+    }
+}
+
+/// Rebuild a placed order from a `GET /fapi/v1/order` response
+fn binance_queried_order(mut order: SimulatedOrder, response: &Value) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    order.id = response
+        .get("orderId")
+        .and_then(|v| v.as_i64())
+        .ok_or("Failed to extract order ID from response")?
+        .to_string();
+    if let Some(size) = response.get("origQty").and_then(as_f64).filter(|&q| q > 0.0) {
+        order.size = size;
+    }
+    if let Some(price) = response.get("price").and_then(as_f64).filter(|&p| p > 0.0) {
+        order.price = price;
+    }
+    order.created_at = response.get("time").and_then(|v| v.as_u64()).unwrap_or(order.created_at);
+    order.status = match response.get("status").and_then(|v| v.as_str()).unwrap_or("") {
+        "FILLED" => OrderStatus::Filled,
+        "CANCELED" | "CANCELLED" | "EXPIRED" => OrderStatus::Cancelled,
+        _ => OrderStatus::Pending,
+    };
+    if order.status == OrderStatus::Filled {
+        let avg_price = response.get("avgPrice").and_then(as_f64).filter(|&p| p > 0.0).unwrap_or(order.price);
+        order.price = avg_price;
+        order.fill_price = Some(avg_price);
+        order.filled_at = response.get("updateTime").and_then(|v| v.as_u64());
+    }
+    Ok(order)
+}
+
 // Cache TTL for order book data (100ms as per spec)
 const ORDER_BOOK_CACHE_TTL: Duration = Duration::from_millis(100);
 
@@ -84,6 +165,12 @@ impl BinanceDemoClient {
         Ok(self.client.delete(url).header("X-MBX-APIKEY", &self.api_key).send().await?)
     }
 
+    /// Helper method to make rate-limited HTTP PUT request with API key
+    async fn rate_limited_put(&self, url: &str) -> Result<reqwest::Response, Box<dyn Error + Send + Sync>> {
+        BINANCE_RATE_LIMITER.acquire().await;
+        Ok(self.client.put(url).header("X-MBX-APIKEY", &self.api_key).send().await?)
+    }
+
     /// Helper method to make rate-limited HTTP GET request with API key
     async fn rate_limited_get_with_key(&self, url: &str) -> Result<reqwest::Response, Box<dyn Error + Send + Sync>> {
         BINANCE_RATE_LIMITER.acquire().await;
@@ -211,6 +298,12 @@ impl BinanceDemoClient {
     }
 
     /// Round quantity to the correct precision
+    /// Quantity step for a symbol, derived from its quantity precision
+    pub async fn get_qty_step(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let (qty_precision, _, _, _) = self.get_symbol_precision(symbol).await?;
+        Ok(10_f64.powi(-(qty_precision as i32)))
+    }
+
     fn round_quantity(quantity: f64, precision: u32) -> f64 {
         let multiplier = 10_f64.powi(precision as i32);
         (quantity * multiplier).floor() / multiplier
@@ -244,15 +337,15 @@ impl BinanceDemoClient {
         encode(mac.finalize().into_bytes()).to_lowercase()
     }
 
-    /// Set leverage for a symbol to 1x
+    /// Set leverage for a symbol
     /// Must be called BEFORE placing any orders for the symbol
-    pub async fn set_leverage(&self, symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    pub async fn set_leverage(&self, symbol: &str, leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
         let timestamp = self.get_timestamp().await?;
 
         // Build query string for signature
         let query_string = format!(
-            "symbol={}&leverage=1&recvWindow=5000&timestamp={}",
-            symbol, timestamp
+            "symbol={}&leverage={}&recvWindow=5000&timestamp={}",
+            symbol, leverage, timestamp
         );
 
         let signature = self.generate_signature(&query_string);
@@ -262,7 +355,7 @@ impl BinanceDemoClient {
             BINANCE_DEMO_URL, query_string, signature
         );
 
-        eprintln!("[BINANCE DEMO] Setting leverage to 1x for {}", symbol);
+        eprintln!("[BINANCE DEMO] Setting leverage to {}x for {}", leverage, symbol);
 
         let response = self.rate_limited_post(&url).await?;
 
@@ -276,13 +369,13 @@ impl BinanceDemoClient {
                     .get("msg")
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
-                eprintln!("[BINANCE DEMO] ⚠️  Failed to set leverage: {}", msg);
-                // Don't fail - leverage might already be set
-                return Ok(());
+                // Setting the leverage it already has succeeds, so any error is real
+                eprintln!("[BINANCE DEMO] ❌ Failed to set leverage: {}", msg);
+                return Err(format!("Binance set leverage failed (code {}): {}", code, msg).into());
             }
         }
 
-        eprintln!("[BINANCE DEMO] ✅ Leverage set to 1x for {}", symbol);
+        eprintln!("[BINANCE DEMO] ✅ Leverage set to {}x for {}", leverage, symbol);
         Ok(())
     }
 
@@ -377,8 +470,9 @@ impl BinanceDemoClient {
 
         // Build query string for signature (no price for market orders)
         let query_string = format!(
-            "symbol={}&side={}&type=MARKET&quantity={}&recvWindow=5000&timestamp={}",
-            order.symbol, side, rounded_qty, timestamp
+            "symbol={}&side={}&type=MARKET&quantity={}{}{}&recvWindow=5000&timestamp={}",
+            order.symbol, side, rounded_qty, binance_reduce_only_param(&order),
+            binance_client_order_id_param(&order), timestamp
         );
 
         let signature = self.generate_signature(&query_string);
@@ -402,7 +496,10 @@ impl BinanceDemoClient {
                     .get("msg")
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
-                return Err(format!("Binance API error: {}", msg).into());
+                return match binance_error(code, msg) {
+                    ExecutionError::DuplicateOrder(_) => self.get_order_by_client_id(order).await,
+                    error => Err(error.into()),
+                };
             }
         }
 
@@ -505,8 +602,9 @@ impl BinanceDemoClient {
 
         // Build query string for signature
         let query_string = format!(
-            "symbol={}&side={}&type=LIMIT&timeInForce=GTC&quantity={}&price={}&leverage=1&recvWindow=5000&timestamp={}",
-            order.symbol, side, rounded_qty, rounded_price_str, timestamp
+            "symbol={}&side={}&type=LIMIT&timeInForce={}&quantity={}&price={}{}{}&leverage=1&recvWindow=5000&timestamp={}",
+            order.symbol, side, binance_time_in_force(order.time_in_force), rounded_qty, rounded_price_str,
+            binance_reduce_only_param(&order), binance_client_order_id_param(&order), timestamp
         );
 
         let signature = self.generate_signature(&query_string);
@@ -528,7 +626,10 @@ impl BinanceDemoClient {
                     .get("msg")
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
-                return Err(format!("Binance API error: {}", msg).into());
+                return match binance_error(code, msg) {
+                    ExecutionError::DuplicateOrder(_) => self.get_order_by_client_id(order).await,
+                    error => Err(error.into()),
+                };
             }
         }
 
@@ -552,6 +653,19 @@ impl BinanceDemoClient {
         Ok(filled_order)
     }
 
+    /// Look up an order by the client order ID it was sent with.
+    ///
+    /// Used when Binance reports the ID as duplicated: an earlier submission
+    /// whose answer was lost (timeout) was accepted, and this is that order.
+    async fn get_order_by_client_id(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        eprintln!("[BINANCE DEMO] Client order ID {} already accepted, looking the order up", order.id);
+        let params = format!("symbol={}&origClientOrderId={}", order.symbol, order.id);
+        let response = self.signed_get("/fapi/v1/order", &params).await?;
+        let placed = binance_queried_order(order, &response)?;
+        eprintln!("[BINANCE DEMO] Found order {} | Symbol: {} | Status: {:?}", placed.id, placed.symbol, placed.status);
+        Ok(placed)
+    }
+
     /// Get order status from Binance testnet
     pub async fn get_order_status(&self, order_id: &str, symbol: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
         let timestamp = self.get_timestamp().await?;
@@ -635,7 +749,7 @@ impl BinanceDemoClient {
                     .get("msg")
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
-                return Err(format!("Binance API error: {}", msg).into());
+                return Err(binance_error(code, msg).into());
             }
         }
 
@@ -667,6 +781,137 @@ impl BinanceDemoClient {
         Ok(OrderStatusInfo::new(status, executed_qty, orig_qty))
     }
 
+    /// Signed GET on an account endpoint; error objects carry `code` and `msg`
+    async fn signed_get(&self, path: &str, params: &str) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let timestamp = self.get_timestamp().await?;
+        let query_string = if params.is_empty() {
+            format!("recvWindow=5000&timestamp={}", timestamp)
+        } else {
+            format!("{}&recvWindow=5000&timestamp={}", params, timestamp)
+        };
+        let signature = self.generate_signature(&query_string);
+        let url = format!("{}{}?{}&signature={}", BINANCE_DEMO_URL, path, query_string, signature);
+
+        let response = self.rate_limited_get_with_key(&url).await?;
+        let response_json: Value = serde_json::from_str(&response.text().await?)?;
+        if let Some(code) = response_json.get("code").and_then(|v| v.as_i64()) {
+            let msg = response_json.get("msg").and_then(|v| v.as_str()).unwrap_or("Unknown error");
+            return Err(binance_error(code, msg).into());
+        }
+        Ok(response_json)
+    }
+
+    /// Open positions; `positionAmt` is signed (one-way mode)
+    pub async fn get_open_positions(&self) -> Result<Vec<VenuePosition>, Box<dyn Error + Send + Sync>> {
+        let positions = self.signed_get("/fapi/v2/positionRisk", "").await?;
+        Ok(positions
+            .as_array()
+            .ok_or("Unexpected positionRisk response")?
+            .iter()
+            .filter_map(|p| {
+                let amount = p.get("positionAmt").and_then(as_f64)?;
+                if amount == 0.0 {
+                    return None;
+                }
+                Some(VenuePosition {
+                    exchange: "binance".to_string(),
+                    symbol: p.get("symbol")?.as_str()?.to_string(),
+                    side: if amount > 0.0 { OrderSide::Long } else { OrderSide::Short },
+                    size: amount.abs(),
+                    entry_price: p.get("entryPrice").and_then(as_f64).unwrap_or(0.0),
+                    margin: PositionMargin::new(
+                        p.get("markPrice").and_then(as_f64),
+                        p.get("liquidationPrice").and_then(as_f64),
+                        p.get("isolatedMargin").and_then(as_f64),
+                        p.get("leverage").and_then(as_f64),
+                    ),
+                })
+            })
+            .collect())
+    }
+
+    /// Working orders across all symbols
+    pub async fn get_open_orders(&self) -> Result<Vec<SimulatedOrder>, Box<dyn Error + Send + Sync>> {
+        let orders = self.signed_get("/fapi/v1/openOrders", "").await?;
+        Ok(orders
+            .as_array()
+            .ok_or("Unexpected openOrders response")?
+            .iter()
+            .filter_map(|o| {
+                let orig_qty = o.get("origQty").and_then(as_f64)?;
+                let executed_qty = o.get("executedQty").and_then(as_f64).unwrap_or(0.0);
+                Some(open_order(
+                    "binance",
+                    o.get("orderId")?.as_i64()?.to_string(),
+                    o.get("symbol")?.as_str()?.to_string(),
+                    if o.get("side")?.as_str()? == "BUY" { OrderSide::Long } else { OrderSide::Short },
+                    o.get("price").and_then(as_f64).unwrap_or(0.0),
+                    orig_qty - executed_qty,
+                    o.get("reduceOnly").and_then(|v| v.as_bool()).unwrap_or(false),
+                ))
+            })
+            .collect())
+    }
+
+    /// Funding fees settled on `symbol` since `since` (unix seconds); `income`
+    /// is positive when received. The income endpoint reports neither the
+    /// rate nor the side, so `rate` is 0 and `side` is a placeholder.
+    pub async fn get_funding_history(&self, symbol: &str, since: u64) -> Result<Vec<FundingPayment>, Box<dyn Error + Send + Sync>> {
+        let params = format!("symbol={}&incomeType=FUNDING_FEE&startTime={}&limit=1000", symbol, since * 1000);
+        let income = self.signed_get("/fapi/v1/income", &params).await?;
+        Ok(income
+            .as_array()
+            .ok_or("Unexpected income response")?
+            .iter()
+            .filter_map(|i| {
+                Some(FundingPayment {
+                    exchange: "binance".to_string(),
+                    symbol: i.get("symbol")?.as_str()?.to_string(),
+                    side: OrderSide::Long,
+                    rate: 0.0,
+                    amount_usd: i.get("income").and_then(as_f64)?,
+                    funding_time: i.get("time")?.as_u64()? / 1000,
+                })
+            })
+            .collect())
+    }
+
+    /// Account trades of `symbol` since `since` (unix seconds); `commission`
+    /// is negative for maker rebates.
+    pub async fn get_fills(&self, symbol: &str, since: u64) -> Result<Vec<Fill>, Box<dyn Error + Send + Sync>> {
+        let params = format!("symbol={}&startTime={}&limit=1000", symbol, since * 1000);
+        let trades = self.signed_get("/fapi/v1/userTrades", &params).await?;
+        Ok(trades
+            .as_array()
+            .ok_or("Unexpected userTrades response")?
+            .iter()
+            .filter_map(|t| {
+                Some(Fill {
+                    id: t.get("id")?.as_i64()?.to_string(),
+                    order_id: t.get("orderId")?.as_i64()?.to_string(),
+                    exchange: "binance".to_string(),
+                    symbol: t.get("symbol")?.as_str()?.to_string(),
+                    side: if t.get("side")?.as_str()? == "BUY" { OrderSide::Long } else { OrderSide::Short },
+                    price: t.get("price").and_then(as_f64)?,
+                    quantity: t.get("qty").and_then(as_f64)?,
+                    fee: t.get("commission").and_then(as_f64).unwrap_or(0.0),
+                    fee_asset: t.get("commissionAsset").and_then(|a| a.as_str()).unwrap_or("USDT").to_string(),
+                    liquidity: if t.get("maker").and_then(|m| m.as_bool()).unwrap_or(false) { Liquidity::Maker } else { Liquidity::Taker },
+                    time: t.get("time")?.as_u64()? / 1000,
+                })
+            })
+            .collect())
+    }
+
+    /// Account maker/taker commission of `symbol`, in bps (reported as fractions)
+    pub async fn get_fee_rates(&self, symbol: &str) -> Result<FeeRates, Box<dyn Error + Send + Sync>> {
+        let rates = self.signed_get("/fapi/v1/commissionRate", &format!("symbol={}", symbol)).await?;
+        Ok(FeeRates {
+            maker_bps: rates.get("makerCommissionRate").and_then(as_f64).ok_or("Missing makerCommissionRate")? * 10_000.0,
+            taker_bps: rates.get("takerCommissionRate").and_then(as_f64).ok_or("Missing takerCommissionRate")? * 10_000.0,
+        })
+    }
+
     /// Cancel an order on Binance testnet
     pub async fn cancel_order(&self, order_id: &str, symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
         let timestamp = self.get_timestamp().await?;
@@ -693,7 +938,7 @@ impl BinanceDemoClient {
                     .get("msg")
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
-                return Err(format!("Failed to cancel order: {}", msg).into());
+                return Err(binance_error(code, msg).into());
             }
         }
 
@@ -701,6 +946,50 @@ impl BinanceDemoClient {
         Ok(())
     }
 
+    /// Modify a resting limit order's price and quantity in place on Binance testnet.
+    /// Binance requires side and quantity on every modify, even when only the price moves.
+    pub async fn amend_order(&self, order_id: &str, symbol: &str, side: OrderSide, price: f64, quantity: f64) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let timestamp = self.get_timestamp().await?;
+
+        let (qty_precision, _price_precision, _min_price, tick_size) = self.get_symbol_precision(symbol).await?;
+        let rounded_qty = Self::round_quantity(quantity, qty_precision);
+        let rounded_price_str = Self::round_price_to_tick(price, tick_size);
+        let side = match side {
+            OrderSide::Long => "BUY",
+            OrderSide::Short => "SELL",
+        };
+
+        let query_string = format!(
+            "symbol={}&orderId={}&side={}&quantity={}&price={}&recvWindow=5000&timestamp={}",
+            symbol, order_id, side, rounded_qty, rounded_price_str, timestamp
+        );
+
+        let signature = self.generate_signature(&query_string);
+
+        let url = format!(
+            "{}/fapi/v1/order?{}&signature={}",
+            BINANCE_DEMO_URL, query_string, signature
+        );
+
+        let response = self.rate_limited_put(&url).await?;
+
+        let response_json: Value = serde_json::from_str(&response.text().await?)?;
+
+        if let Some(code) = response_json.get("code").and_then(|v| v.as_i64()) {
+            // -5027: nothing to modify (already at this price and quantity)
+            if code != 0 && code != -5027 {
+                let msg = response_json
+                    .get("msg")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Unknown error");
+                return Err(binance_error(code, msg).into());
+            }
+        }
+
+        eprintln!("[BINANCE DEMO] Order amended: {} | Price: {} | Size: {}", order_id, rounded_price_str, rounded_qty);
+        Ok(())
+    }
+
     /// Get account balance from Binance testnet
     pub async fn get_balance(&self) -> Result<f64, Box<dyn Error + Send + Sync>> {
         let timestamp = self.get_timestamp().await?;
@@ -719,7 +1008,7 @@ impl BinanceDemoClient {
         let response_text = response.text().await?;
 
         if !status.is_success() {
-            return Err(format!("HTTP {}: {}", status, response_text).into());
+            return Err(http_error(status.as_u16(), None, &response_text).into());
         }
 
         if response_text.is_empty() {
@@ -810,7 +1099,7 @@ impl BinanceDemoClient {
         let response_text = response.text().await?;
 
         if !status.is_success() {
-            return Err(format!("HTTP {}: {}", status, response_text).into());
+            return Err(http_error(status.as_u16(), None, &response_text).into());
         }
 
         let response_json: Value = serde_json::from_str(&response_text)?;
@@ -822,7 +1111,7 @@ impl BinanceDemoClient {
                     .get("msg")
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
-                return Err(format!("Binance API error: {}", msg).into());
+                return Err(binance_error(code, msg).into());
             }
         }
 
@@ -909,3 +1198,62 @@ impl BinanceDemoClient {
         Ok(best_ask)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn order(id: &str) -> SimulatedOrder {
+        SimulatedOrder {
+            id: id.to_string(),
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Long,
+            price: 50_000.0,
+            size: 0.01,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_client_order_id_is_sent_when_binance_accepts_it() {
+        assert_eq!(binance_client_order_id_param(&order("arb-18c2f-7")), "&newClientOrderId=arb-18c2f-7");
+        assert_eq!(binance_client_order_id_param(&order("")), "");
+        assert_eq!(binance_client_order_id_param(&order("hedge 1")), "");
+        assert_eq!(binance_client_order_id_param(&order(&"x".repeat(37))), "");
+    }
+
+    #[test]
+    fn test_duplicate_client_order_id_maps_to_duplicate_order() {
+        assert!(matches!(
+            binance_error(-4116, "ClientOrderId is duplicated."),
+            ExecutionError::DuplicateOrder(_)
+        ));
+    }
+
+    #[test]
+    fn test_queried_order_carries_the_exchange_id_and_fill() {
+        let response = serde_json::json!({
+            "orderId": 4077357821i64,
+            "clientOrderId": "arb-18c2f-7",
+            "status": "FILLED",
+            "price": "0",
+            "avgPrice": "50012.30",
+            "origQty": "0.010",
+            "executedQty": "0.010",
+            "time": 1700000000000u64,
+            "updateTime": 1700000000123u64
+        });
+        let placed = binance_queried_order(order("arb-18c2f-7"), &response).unwrap();
+        assert_eq!(placed.id, "4077357821");
+        assert_eq!(placed.status, OrderStatus::Filled);
+        assert_eq!(placed.fill_price, Some(50012.3));
+        assert_eq!(placed.size, 0.01);
+
+        let resting = serde_json::json!({ "orderId": 1, "status": "NEW", "price": "49990", "origQty": "0.010" });
+        let placed = binance_queried_order(order("arb-18c2f-8"), &resting).unwrap();
+        assert_eq!(placed.status, OrderStatus::Pending);
+        assert_eq!(placed.price, 49990.0);
+        assert_eq!(placed.fill_price, None);
+    }
+}
@@ -1,8 +1,13 @@
 use std::error::Error;
+use std::collections::HashMap;
+use std::sync::Arc;
 use reqwest::Client;
 use serde_json::{json, Value};
-use crate::strategy::types::{SimulatedOrder, OrderStatus, OrderSide};
+use tokio::sync::Mutex;
+use crate::strategy::types::{SimulatedOrder, OrderStatus, OrderSide, OrderStatusInfo, OrderBookDepth, PositionMargin, PriceLevel, TimeInForce, VenuePosition};
 use crate::strategy::testnet_config::ExchangeCredentials;
+use crate::strategy::execution_error::{http_error, ExecutionError};
+use super::{as_f64, format_decimal, open_order, round_to_step};
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
 use base64::{engine::general_purpose, Engine as _};
@@ -11,11 +16,58 @@ type HmacSha256 = Hmac<Sha256>;
 
 const BITGET_DEMO_URL: &str = "https://api.bitget.com";
 
+/// Map a Bitget v2 error `code` onto `ExecutionError`
+pub fn bitget_error(code: &str, msg: &str) -> ExecutionError {
+    let text = msg.to_string();
+    match code {
+        "40768" | "43001" | "43025" => ExecutionError::OrderNotFound(text),
+        "40762" | "43012" => ExecutionError::InsufficientBalance(text),
+        "429" | "40018" => ExecutionError::RateLimited { retry_after: None },
+        "40006" | "40009" | "40012" | "40037" => ExecutionError::Auth(text),
+        // Below minimum quantity / amount
+        "45110" | "45111" => ExecutionError::InvalidQuantity(text),
+        _ => ExecutionError::rejected(code, text),
+    }
+}
+
+/// Bitget `force` for limit orders; a crossing `post_only` order is cancelled by the venue
+fn bitget_force(tif: TimeInForce) -> &'static str {
+    match tif {
+        TimeInForce::Gtc => "gtc",
+        TimeInForce::Ioc => "ioc",
+        TimeInForce::Fok => "fok",
+        TimeInForce::PostOnly => "post_only",
+    }
+}
+
+/// `side` and `tradeSide` in hedge mode. Closing orders name the side of the
+/// position they close (selling out of a long is `buy` + `close`), which also
+/// makes them reduce-only: a close can never open the opposite position.
+fn bitget_sides(order: &SimulatedOrder) -> (&'static str, &'static str) {
+    match (order.side, order.is_reduce_only()) {
+        (OrderSide::Long, false) => ("buy", "open"),
+        (OrderSide::Short, false) => ("sell", "open"),
+        (OrderSide::Short, true) => ("buy", "close"),
+        (OrderSide::Long, true) => ("sell", "close"),
+    }
+}
+
+/// Order sizing rules of a USDT-M contract
+#[derive(Debug, Clone, Copy)]
+struct ContractSpec {
+    /// Quantity step (`sizeMultiplier`)
+    size_step: f64,
+    /// Price decimals (`pricePlace`)
+    price_place: u32,
+}
+
 pub struct BitgetDemoClient {
     client: Client,
     api_key: String,
     api_secret: String,
     passphrase: String,
+    // Cache of contract sizing rules: symbol -> ContractSpec
+    contract_cache: Arc<Mutex<HashMap<String, ContractSpec>>>,
 }
 
 impl BitgetDemoClient {
@@ -25,7 +77,186 @@ impl BitgetDemoClient {
             api_key: credentials.api_key,
             api_secret: credentials.api_secret,
             passphrase: credentials.passphrase.unwrap_or_default(),
+            contract_cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn timestamp() -> Result<String, Box<dyn Error + Send + Sync>> {
+        Ok(std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)?
+            .as_millis()
+            .to_string())
+    }
+
+    /// Fail on a non-`00000` response code, otherwise return `data`
+    fn response_data(response_json: Value) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        if let Some(code) = response_json.get("code").and_then(|v| v.as_str()) {
+            if code != "00000" {
+                let msg = response_json
+                    .get("msg")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Unknown error");
+                return Err(bitget_error(code, msg).into());
+            }
+        }
+        Ok(response_json.get("data").cloned().unwrap_or(Value::Null))
+    }
+
+    /// Signed POST against the demo (paper trading) environment
+    async fn signed_post(&self, path: &str, body: &Value) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let timestamp = Self::timestamp()?;
+        let signature = self.generate_signature(&timestamp, "POST", path, "", &body.to_string());
+
+        let response = self
+            .client
+            .post(format!("{}{}", BITGET_DEMO_URL, path))
+            .header("ACCESS-KEY", &self.api_key)
+            .header("ACCESS-SIGN", signature)
+            .header("ACCESS-TIMESTAMP", &timestamp)
+            .header("ACCESS-PASSPHRASE", &self.passphrase)
+            .header("paptrading", "1")
+            .header("Content-Type", "application/json")
+            .json(body)
+            .send()
+            .await?;
+
+        Self::response_data(serde_json::from_str(&response.text().await?)?)
+    }
+
+    /// Signed GET against the demo (paper trading) environment
+    async fn signed_get(&self, path: &str, query_string: &str) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let timestamp = Self::timestamp()?;
+        let signature = self.generate_signature(&timestamp, "GET", path, query_string, "");
+
+        let response = self
+            .client
+            .get(format!("{}{}?{}", BITGET_DEMO_URL, path, query_string))
+            .header("ACCESS-KEY", &self.api_key)
+            .header("ACCESS-SIGN", signature)
+            .header("ACCESS-TIMESTAMP", &timestamp)
+            .header("ACCESS-PASSPHRASE", &self.passphrase)
+            .header("paptrading", "1")
+            .send()
+            .await?;
+
+        Self::response_data(serde_json::from_str(&response.text().await?)?)
+    }
+
+    /// Sizing rules for a contract (cached)
+    async fn contract_spec(&self, symbol: &str) -> Result<ContractSpec, Box<dyn Error + Send + Sync>> {
+        {
+            let cache = self.contract_cache.lock().await;
+            if let Some(&spec) = cache.get(symbol) {
+                return Ok(spec);
+            }
+        }
+
+        let url = format!("{}/api/v2/mix/market/contracts?productType=usdt-futures&symbol={}", BITGET_DEMO_URL, symbol);
+        let response = self.client.get(&url).send().await?;
+        let data = Self::response_data(serde_json::from_str(&response.text().await?)?)?;
+        let contract = data
+            .as_array()
+            .and_then(|a| a.first())
+            .ok_or_else(|| format!("Bitget contract {} not found", symbol))?;
+
+        let spec = ContractSpec {
+            size_step: contract.get("sizeMultiplier")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse::<f64>().ok())
+                .ok_or("Failed to extract sizeMultiplier from response")?,
+            price_place: contract.get("pricePlace")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse::<u32>().ok())
+                .unwrap_or(4),
+        };
+
+        let mut cache = self.contract_cache.lock().await;
+        cache.insert(symbol.to_string(), spec);
+        Ok(spec)
+    }
+
+    /// Quantity step for a symbol
+    pub async fn get_qty_step(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        Ok(self.contract_spec(symbol).await?.size_step)
+    }
+
+    /// Set leverage for a symbol
+    pub async fn set_leverage(&self, symbol: &str, leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let body = json!({
+            "symbol": symbol,
+            "productType": "USDT-FUTURES",
+            "marginCoin": "USDT",
+            "leverage": leverage.to_string(),
+        });
+        self.signed_post("/api/v2/mix/account/set-leverage", &body).await?;
+        eprintln!("[BITGET DEMO] Leverage set to {}x for {}", leverage, symbol);
+        Ok(())
+    }
+
+    /// Switch a symbol to isolated margin
+    pub async fn set_margin_mode_isolated(&self, symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let body = json!({
+            "symbol": symbol,
+            "productType": "USDT-FUTURES",
+            "marginCoin": "USDT",
+            "marginMode": "isolated",
+        });
+        self.signed_post("/api/v2/mix/account/set-margin-mode", &body).await?;
+        Ok(())
+    }
+
+    /// Request body for an order, with size and price rounded to the contract rules
+    async fn order_body(&self, order: &SimulatedOrder, market: bool) -> Result<(Value, f64, f64), Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(&order.symbol).await?;
+        let size = round_to_step(order.size, spec.size_step);
+        if size <= 0.0 {
+            return Err(ExecutionError::InvalidQuantity(format!("Order size {} below Bitget step {} for {}", order.size, spec.size_step, order.symbol)).into());
+        }
+        let factor = 10f64.powi(spec.price_place as i32);
+        let price = (order.price * factor).round() / factor;
+
+        let (side, trade_side) = bitget_sides(order);
+
+        let mut body = json!({
+            "symbol": order.symbol.clone(),
+            "productType": "USDT-FUTURES",
+            "marginMode": "isolated",
+            "marginCoin": "USDT",
+            "size": format_decimal(size),
+            "side": side,
+            "tradeSide": trade_side,
+            "orderType": if market { "market" } else { "limit" },
+        });
+        if !market {
+            body["price"] = json!(format_decimal(price));
+            body["force"] = json!(bitget_force(order.time_in_force));
+        }
+        // Client order ID lets Bitget reject duplicate submissions
+        if !order.id.is_empty() && order.id.len() <= 50 {
+            body["clientOid"] = json!(order.id.clone());
         }
+        Ok((body, size, price))
+    }
+
+    /// Place a market order on Bitget demo
+    pub async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let (body, size, _) = self.order_body(&order, true).await?;
+        let data = self.signed_post("/api/v2/mix/order/place-order", &body).await?;
+
+        let order_id = data
+            .get("orderId")
+            .and_then(|v| v.as_str())
+            .ok_or("Failed to extract order ID from response")?
+            .to_string();
+
+        let mut placed = order;
+        placed.id = order_id;
+        placed.size = size;
+        placed.status = OrderStatus::Pending;
+        placed.created_at = Self::timestamp()?.parse().unwrap_or(0);
+
+        eprintln!("[BITGET DEMO] Market order placed: {} | Symbol: {} | Size: {}", placed.id, placed.symbol, placed.size);
+        Ok(placed)
     }
 
     /// Generate signature for Bitget API
@@ -82,122 +313,92 @@ impl BitgetDemoClient {
 
     /// Place an order on Bitget demo
     pub async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
-        let timestamp = (std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)?
-            .as_millis())
-            .to_string();
-
-        let side = match order.side {
-            OrderSide::Long => "buy",
-            OrderSide::Short => "sell",
-        };
-
-        let request_body = json!({
-            "symbol": order.symbol.clone(),
-            "productType": "USDT-FUTURES",
-            "marginMode": "crossed",
-            "marginCoin": "USDT",
-            "size": order.size.to_string(),
-            "price": order.price.to_string(),
-            "side": side,
-            "tradeSide": "open",
-            "orderType": "limit",
-            "force": "gtc",
-        });
-
-        let body_str = request_body.to_string();
-        let path = "/api/v2/mix/order/place-order";
-        let signature = self.generate_signature(&timestamp, "POST", path, "", &body_str);
-
-        let url = format!("{}{}", BITGET_DEMO_URL, path);
-
-        let response = self
-            .client
-            .post(&url)
-            .header("ACCESS-KEY", &self.api_key)
-            .header("ACCESS-SIGN", signature)
-            .header("ACCESS-TIMESTAMP", &timestamp)
-            .header("ACCESS-PASSPHRASE", &self.passphrase)
-            .header("paptrading", "1")
-            .header("Content-Type", "application/json")
-            .json(&request_body)
-            .send()
-            .await?;
-
-        let response_text = response.text().await?;
-        let response_json: Value = serde_json::from_str(&response_text)?;
-
-        // Check for errors
-        if let Some(code) = response_json.get("code").and_then(|v| v.as_str()) {
-            if code != "00000" {
-                let msg = response_json
-                    .get("msg")
-                    .and_then(|v| v.as_str())
-                    .unwrap_or("Unknown error");
-                return Err(format!("Bitget API error: {}", msg).into());
-            }
-        }
+        let (body, size, price) = self.order_body(&order, false).await?;
+        let data = self.signed_post("/api/v2/mix/order/place-order", &body).await?;
 
         // Extract order ID from response
-        let order_id = response_json
-            .get("data")
-            .and_then(|d| d.get("orderId"))
+        let order_id = data
+            .get("orderId")
             .and_then(|v| v.as_str())
             .ok_or("Failed to extract order ID from response")?
             .to_string();
 
         let mut filled_order = order;
         filled_order.id = order_id;
+        filled_order.size = size;
+        filled_order.price = price;
         filled_order.status = OrderStatus::Pending;
-        filled_order.created_at = timestamp.parse().unwrap_or(0);
+        filled_order.created_at = Self::timestamp()?.parse().unwrap_or(0);
 
-        eprintln!("[BITGET DEMO] Order placed: {} | Symbol: {} | Side: {} | Size: {} | Price: {}", 
-            filled_order.id, filled_order.symbol, side, filled_order.size, filled_order.price);
+        eprintln!("[BITGET DEMO] Order placed: {} | Symbol: {} | Side: {:?} | Size: {} | Price: {}", 
+            filled_order.id, filled_order.symbol, filled_order.side, filled_order.size, filled_order.price);
 
         Ok(filled_order)
     }
 
     /// Get order status from Bitget demo
     pub async fn get_order_status(&self, order_id: &str, symbol: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
-        let timestamp = (std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)?
-            .as_millis())
-            .to_string();
+        Ok(self.get_order_status_detailed(order_id, symbol).await?.status)
+    }
 
+    /// Get order status with filled quantity from Bitget demo
+    ///
+    /// Uses the order detail endpoint, which (unlike `orders-pending`) also
+    /// returns filled and cancelled orders.
+    pub async fn get_order_status_detailed(&self, order_id: &str, symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
         let query_string = format!("symbol={}&productType=USDT-FUTURES&orderId={}", symbol, order_id);
-        let path = "/api/v2/mix/order/orders-pending";
-        let signature = self.generate_signature(&timestamp, "GET", path, &query_string, "");
-
-        let url = format!("{}{}?{}", BITGET_DEMO_URL, path, query_string);
-
-        let response = self
-            .client
-            .get(&url)
-            .header("ACCESS-KEY", &self.api_key)
-            .header("ACCESS-SIGN", signature)
-            .header("ACCESS-TIMESTAMP", &timestamp)
-            .header("ACCESS-PASSPHRASE", &self.passphrase)
-            .header("paptrading", "1")
-            .send()
-            .await?;
+        let data = self.signed_get("/api/v2/mix/order/detail", &query_string).await?;
+        parse_order_detail(&data)
+    }
 
-        let response_json: Value = serde_json::from_str(&response.text().await?)?;
+    /// Open USDT-futures positions (hedge mode reports one entry per side)
+    pub async fn get_open_positions(&self) -> Result<Vec<VenuePosition>, Box<dyn Error + Send + Sync>> {
+        let data = self.signed_get("/api/v2/mix/position/all-position", "productType=USDT-FUTURES&marginCoin=USDT").await?;
+        Ok(data.as_array().cloned().unwrap_or_default().iter().filter_map(parse_position).collect())
+    }
 
-        let status_str = response_json
-            .get("data")
-            .and_then(|d| d.as_array())
-            .and_then(|a| a.first())
-            .and_then(|f| f.get("status"))
-            .and_then(|v| v.as_str())
-            .unwrap_or("unknown");
+    /// Working USDT-futures orders
+    pub async fn get_open_orders(&self) -> Result<Vec<SimulatedOrder>, Box<dyn Error + Send + Sync>> {
+        let data = self.signed_get("/api/v2/mix/order/orders-pending", "productType=USDT-FUTURES").await?;
+        Ok(data
+            .get("entrustedList")
+            .and_then(|l| l.as_array())
+            .cloned()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(parse_open_order)
+            .collect())
+    }
 
-        let status = match status_str {
-            "filled" => OrderStatus::Filled,
-            "canceled" => OrderStatus::Cancelled,
-            _ => OrderStatus::Pending,
+    /// Get order book depth (top N levels)
+    pub async fn get_order_book_depth(&self, symbol: &str, levels: usize) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+        // merge-depth accepts limit 1, 5, 15, 50 or max
+        let limit = match levels {
+            0..=1 => "1",
+            2..=5 => "5",
+            6..=15 => "15",
+            16..=50 => "50",
+            _ => "max",
         };
+        let url = format!(
+            "{}/api/v2/mix/market/merge-depth?symbol={}&productType=usdt-futures&limit={}",
+            BITGET_DEMO_URL, symbol, limit
+        );
+        let response = self.client.get(&url).send().await?;
+        let data = Self::response_data(serde_json::from_str(&response.text().await?)?)?;
+        parse_depth(&data, levels)
+    }
+
+    /// Best bid from the order book
+    pub async fn get_best_bid(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.bids.first().map(|l| l.price).ok_or_else(|| "No bids available in order book".into())
+    }
 
-        Ok(status)
+    /// Best ask from the order book
+    pub async fn get_best_ask(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.asks.first().map(|l| l.price).ok_or_else(|| "No asks available in order book".into())
     }
 
     /// Cancel an order on Bitget demo
@@ -241,7 +442,7 @@ impl BitgetDemoClient {
                     .get("msg")
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
-                return Err(format!("Failed to cancel order: {}", msg).into());
+                return Err(bitget_error(code, msg).into());
             }
         }
 
@@ -290,7 +491,12 @@ impl BitgetDemoClient {
         let response_text = response.text().await?;
 
         if !status.is_success() {
-            return Err(format!("HTTP {}: {}", status, response_text).into());
+            // Rejections come back as HTTP 400 with the Bitget code in the body
+            let body: Value = serde_json::from_str(&response_text).unwrap_or(Value::Null);
+            return Err(match (body.get("code").and_then(|v| v.as_str()), body.get("msg").and_then(|v| v.as_str())) {
+                (Some(code), Some(msg)) => bitget_error(code, msg),
+                _ => http_error(status.as_u16(), None, &response_text),
+            }.into());
         }
 
         if response_text.is_empty() {
@@ -319,3 +525,138 @@ impl BitgetDemoClient {
         Ok(balance)
     }
 }
+
+/// Parse an `all-position` entry; empty positions are skipped
+fn parse_position(p: &Value) -> Option<VenuePosition> {
+    let size = p.get("total").and_then(as_f64)?;
+    if size <= 0.0 {
+        return None;
+    }
+    Some(VenuePosition {
+        exchange: "bitget".to_string(),
+        symbol: p.get("symbol")?.as_str()?.to_string(),
+        side: if p.get("holdSide")?.as_str()? == "long" { OrderSide::Long } else { OrderSide::Short },
+        size,
+        entry_price: p.get("openPriceAvg").and_then(as_f64).unwrap_or(0.0),
+        margin: PositionMargin::new(
+            p.get("markPrice").and_then(as_f64),
+            p.get("liquidationPrice").and_then(as_f64),
+            p.get("marginSize").and_then(as_f64),
+            p.get("leverage").and_then(as_f64),
+        ),
+    })
+}
+
+/// Parse an `orders-pending` entry. In hedge mode a closing order's `side`
+/// is the side of the position it closes, so flip it back to the trade direction.
+fn parse_open_order(o: &Value) -> Option<SimulatedOrder> {
+    let buy = o.get("side")?.as_str()? == "buy";
+    let closing = o.get("tradeSide").and_then(|v| v.as_str()) == Some("close");
+    let remaining = o.get("size").and_then(as_f64)? - o.get("baseVolume").and_then(as_f64).unwrap_or(0.0);
+    Some(open_order(
+        "bitget",
+        o.get("orderId")?.as_str()?.to_string(),
+        o.get("symbol")?.as_str()?.to_string(),
+        if buy != closing { OrderSide::Long } else { OrderSide::Short },
+        o.get("price").and_then(as_f64).unwrap_or(0.0),
+        remaining,
+        closing || o.get("reduceOnly").and_then(|v| v.as_str()) == Some("YES"),
+    ))
+}
+
+fn parse_order_detail(data: &Value) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    let state = data.get("state").and_then(|v| v.as_str()).ok_or("Missing order state in response")?;
+    let total = data.get("size").and_then(as_f64).unwrap_or(0.0);
+    let filled = data.get("baseVolume").and_then(as_f64).unwrap_or(0.0);
+
+    let status = match state {
+        "filled" => OrderStatus::Filled,
+        "canceled" | "cancelled" => OrderStatus::Cancelled,
+        _ => OrderStatus::Pending,
+    };
+    Ok(OrderStatusInfo::new(status, filled, total))
+}
+
+fn parse_depth(data: &Value, levels: usize) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    let side = |key: &str| -> Result<Vec<PriceLevel>, Box<dyn Error + Send + Sync>> {
+        Ok(data
+            .get(key)
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| format!("Missing {} in response", key))?
+            .iter()
+            .take(levels)
+            .filter_map(|level| {
+                let arr = level.as_array()?;
+                Some(PriceLevel { price: as_f64(arr.first()?)?, quantity: as_f64(arr.get(1)?)? })
+            })
+            .collect())
+    };
+
+    Ok(OrderBookDepth {
+        bids: side("bids")?,
+        asks: side("asks")?,
+        timestamp: data.get("ts").and_then(as_f64).map(|t| t as u64).unwrap_or(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_to_step() {
+        assert_eq!(round_to_step(0.0157, 0.001), 0.015);
+        assert_eq!(round_to_step(0.3, 0.1), 0.3);
+        assert_eq!(round_to_step(12.0, 1.0), 12.0);
+        assert_eq!(format_decimal(0.015), "0.015");
+    }
+
+    #[test]
+    fn test_parse_order_detail_and_depth() {
+        let detail = json!({"orderId": "1", "state": "partially_filled", "size": "2", "baseVolume": "0.5"});
+        let info = parse_order_detail(&detail).unwrap();
+        assert_eq!(info.status, OrderStatus::Pending);
+        assert_eq!(info.filled_quantity, 0.5);
+        assert_eq!(info.total_quantity, 2.0);
+
+        let filled = json!({"state": "filled", "size": "2", "baseVolume": "2"});
+        assert!(parse_order_detail(&filled).unwrap().is_fully_filled());
+
+        let depth = json!({"asks": [[100.5, 3], ["100.6", "1"]], "bids": [["100.4", "2"]], "ts": "1700000000000"});
+        let book = parse_depth(&depth, 1).unwrap();
+        assert_eq!(book.asks.len(), 1);
+        assert_eq!(book.asks[0].price, 100.5);
+        assert_eq!(book.bids[0].quantity, 2.0);
+        assert_eq!(book.timestamp, 1_700_000_000_000);
+    }
+
+    #[test]
+    fn test_closing_orders_use_close_trade_side() {
+        let entry = SimulatedOrder { side: OrderSide::Short, ..Default::default() };
+        assert_eq!(bitget_sides(&entry), ("sell", "open"));
+
+        // Selling out of a long position
+        let exit = SimulatedOrder { side: OrderSide::Short, reduce_only: true, ..Default::default() };
+        assert_eq!(bitget_sides(&exit), ("buy", "close"));
+        let close_all = SimulatedOrder { side: OrderSide::Long, close_position: true, ..Default::default() };
+        assert_eq!(bitget_sides(&close_all), ("sell", "close"));
+
+        assert_eq!(bitget_force(TimeInForce::PostOnly), "post_only");
+    }
+
+    #[test]
+    fn test_parse_positions_and_open_orders() {
+        let position = parse_position(&json!({"symbol": "BTCUSDT", "holdSide": "short", "total": "0.02", "openPriceAvg": "50000"})).unwrap();
+        assert_eq!((position.side, position.size), (OrderSide::Short, 0.02));
+        assert!(parse_position(&json!({"symbol": "BTCUSDT", "holdSide": "long", "total": "0"})).is_none());
+
+        // `buy` + `close` sells out of a long, so it round-trips to the exit order's side
+        let exit = parse_open_order(&json!({
+            "orderId": "9", "symbol": "BTCUSDT", "side": "buy", "tradeSide": "close", "price": "51000", "size": "0.02", "baseVolume": "0.005"
+        })).unwrap();
+        assert_eq!(exit.side, OrderSide::Short);
+        assert!(exit.reduce_only);
+        assert!((exit.size - 0.015).abs() < 1e-12);
+        assert_eq!(bitget_sides(&exit), ("buy", "close"));
+    }
+}
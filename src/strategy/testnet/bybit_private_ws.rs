@@ -0,0 +1,295 @@
+//! Bybit v5 private websocket (demo trading)
+//!
+//! Authenticates with the demo API key, subscribes to the `order`,
+//! `execution` and `position` topics and feeds the parsed events into the
+//! `OrderManager`. The venue is marked live in the OMS only while the
+//! connection is authenticated, so status queries fall back to REST as soon
+//! as the stream drops.
+
+use std::error::Error;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+use futures_util::{SinkExt, StreamExt};
+use hmac::{Hmac, Mac};
+use serde_json::{json, Value};
+use sha2::Sha256;
+use tokio_tungstenite::tungstenite::Message;
+use crate::strategy::order_manager::OrderManager;
+use crate::strategy::testnet_config::ExchangeCredentials;
+use crate::strategy::user_stream::{ExecutionUpdate, OrderUpdate, PositionUpdate, StreamOrderStatus, UserStreamEvent};
+use crate::utils;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const BYBIT_DEMO_PRIVATE_WS_URL: &str = "wss://stream-demo.bybit.com/v5/private";
+
+/// Validity of the auth signature
+const AUTH_EXPIRY_MS: u64 = 10_000;
+
+/// Venues served by one Bybit account stream
+const BYBIT_VENUES: [&str; 2] = ["bybit", "bybit_spot"];
+
+pub struct BybitPrivateStream {
+    api_key: String,
+    api_secret: String,
+    url: String,
+    oms: Arc<OrderManager>,
+}
+
+impl BybitPrivateStream {
+    pub fn new(credentials: ExchangeCredentials, oms: Arc<OrderManager>) -> Self {
+        Self {
+            api_key: credentials.api_key,
+            api_secret: credentials.api_secret,
+            url: BYBIT_DEMO_PRIVATE_WS_URL.to_string(),
+            oms,
+        }
+    }
+
+    /// Run forever, reconnecting with backoff.
+    pub async fn run(self) {
+        let mut backoff_ms: u64 = 0;
+        loop {
+            let res = self.run_connection().await;
+            for venue in BYBIT_VENUES {
+                self.oms.set_stream_connected(venue, false);
+            }
+            match &res {
+                Ok(()) => eprintln!("[{}] [BYBIT PRIVATE] disconnected -> reconnecting", utils::ts_hm()),
+                Err(e) => eprintln!("[{}] [BYBIT PRIVATE] error: {} -> reconnecting", utils::ts_hm(), e),
+            }
+            match res {
+                Ok(()) => utils::reset_backoff(&mut backoff_ms),
+                Err(_) => utils::apply_backoff(&mut backoff_ms).await,
+            }
+        }
+    }
+
+    /// Auth signature: HMAC-SHA256 of `GET/realtime{expires}`
+    fn auth_signature(&self, expires: u64) -> String {
+        let mut mac = HmacSha256::new_from_slice(self.api_secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(format!("GET/realtime{}", expires).as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    async fn run_connection(&self) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (ws, _) = tokio_tungstenite::connect_async(self.url.as_str()).await?;
+        let (mut write, mut read) = ws.split();
+
+        let expires = SystemTime::now().duration_since(UNIX_EPOCH)?.as_millis() as u64 + AUTH_EXPIRY_MS;
+        let auth = json!({
+            "op": "auth",
+            "args": [self.api_key, expires, self.auth_signature(expires)],
+        });
+        write.send(Message::Text(auth.to_string())).await?;
+
+        let mut ping_tick = utils::interval_secs(20);
+
+        loop {
+            tokio::select! {
+                _ = ping_tick.tick() => {
+                    let ping = json!({"op": "ping"});
+                    if write.send(Message::Text(ping.to_string())).await.is_err() {
+                        break;
+                    }
+                }
+                msg = read.next() => {
+                    let text = match msg {
+                        Some(Ok(Message::Text(text))) => text,
+                        Some(Ok(Message::Close(_))) | None => break,
+                        Some(Ok(_)) => continue,
+                        Some(Err(e)) => return Err(e.into()),
+                    };
+                    let v: Value = match serde_json::from_str(&text) {
+                        Ok(v) => v,
+                        Err(_) => continue,
+                    };
+
+                    if v.get("op").and_then(|o| o.as_str()) == Some("auth") {
+                        if v.get("success").and_then(|s| s.as_bool()) != Some(true) {
+                            return Err(format!("Bybit private auth failed: {}", v).into());
+                        }
+                        let subscribe = json!({"op": "subscribe", "args": ["order", "execution", "position"]});
+                        write.send(Message::Text(subscribe.to_string())).await?;
+                        for venue in BYBIT_VENUES {
+                            self.oms.set_stream_connected(venue, true);
+                        }
+                        eprintln!("[BYBIT PRIVATE] ✅ Authenticated, streaming order/execution/position");
+                        continue;
+                    }
+
+                    for event in parse_private_message(&v) {
+                        if let Err(e) = self.oms.apply_stream_event(&event) {
+                            eprintln!("[BYBIT PRIVATE] ⚠️  {}", e);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Venue id for a Bybit v5 `category`
+fn venue_for_category(category: &str) -> &'static str {
+    if category == "spot" { "bybit_spot" } else { "bybit" }
+}
+
+fn str_field<'a>(item: &'a Value, key: &str) -> &'a str {
+    item.get(key).and_then(|v| v.as_str()).unwrap_or("")
+}
+
+fn num_field(item: &Value, key: &str) -> f64 {
+    str_field(item, key).parse().unwrap_or(0.0)
+}
+
+fn link_id(item: &Value) -> Option<String> {
+    Some(str_field(item, "orderLinkId")).filter(|s| !s.is_empty()).map(|s| s.to_string())
+}
+
+fn order_status(status: &str) -> Option<StreamOrderStatus> {
+    match status {
+        "New" | "Untriggered" | "Triggered" => Some(StreamOrderStatus::New),
+        "PartiallyFilled" => Some(StreamOrderStatus::PartiallyFilled),
+        "Filled" => Some(StreamOrderStatus::Filled),
+        "Cancelled" | "PartiallyFilledCanceled" | "Deactivated" => Some(StreamOrderStatus::Cancelled),
+        "Rejected" => Some(StreamOrderStatus::Rejected),
+        _ => None,
+    }
+}
+
+/// Translate one private topic message into stream events.
+pub fn parse_private_message(v: &Value) -> Vec<UserStreamEvent> {
+    let topic = v.get("topic").and_then(|t| t.as_str()).unwrap_or("");
+    let items = match v.get("data").and_then(|d| d.as_array()) {
+        Some(items) => items,
+        None => return Vec::new(),
+    };
+
+    items.iter().filter_map(|item| {
+        let venue = venue_for_category(str_field(item, "category")).to_string();
+        let symbol = str_field(item, "symbol").to_string();
+        match topic {
+            "order" => Some(UserStreamEvent::Order(OrderUpdate {
+                venue,
+                symbol,
+                exchange_order_id: str_field(item, "orderId").to_string(),
+                client_order_id: link_id(item),
+                status: order_status(str_field(item, "orderStatus"))?,
+                filled_quantity: num_field(item, "cumExecQty"),
+                avg_price: num_field(item, "avgPrice"),
+                reason: Some(str_field(item, "rejectReason"))
+                    .filter(|r| !r.is_empty() && *r != "EC_NoError")
+                    .map(|r| r.to_string()),
+            })),
+            // Funding and settlement rows are not fills of our orders
+            "execution" if str_field(item, "execType") == "Trade" => Some(UserStreamEvent::Execution(ExecutionUpdate {
+                venue,
+                symbol,
+                exchange_order_id: str_field(item, "orderId").to_string(),
+                client_order_id: link_id(item),
+                execution_id: str_field(item, "execId").to_string(),
+                quantity: num_field(item, "execQty"),
+                price: num_field(item, "execPrice"),
+                fee: num_field(item, "execFee"),
+                timestamp_ms: str_field(item, "execTime").parse().unwrap_or(0),
+            })),
+            "position" => {
+                let size = num_field(item, "size");
+                Some(UserStreamEvent::Position(PositionUpdate {
+                    venue,
+                    symbol,
+                    size: if str_field(item, "side") == "Sell" { -size } else { size },
+                    entry_price: num_field(item, "entryPrice"),
+                }))
+            }
+            _ => None,
+        }
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::order_manager::OrderState;
+    use crate::strategy::types::{OrderSide, OrderType, SimulatedOrder};
+
+    #[test]
+    fn test_parse_order_and_execution_topics() {
+        let order = json!({
+            "topic": "order",
+            "data": [{"category": "linear", "symbol": "BTCUSDT", "orderId": "1a", "orderLinkId": "arb-1",
+                      "orderStatus": "PartiallyFilledCanceled", "cumExecQty": "0.5", "avgPrice": "100.5",
+                      "rejectReason": "EC_NoError"}]
+        });
+        let events = parse_private_message(&order);
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            UserStreamEvent::Order(u) => {
+                assert_eq!(u.venue, "bybit");
+                assert_eq!(u.client_order_id.as_deref(), Some("arb-1"));
+                assert_eq!(u.status, StreamOrderStatus::Cancelled);
+                assert_eq!(u.filled_quantity, 0.5);
+                assert_eq!(u.reason, None);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+
+        let execution = json!({
+            "topic": "execution",
+            "data": [
+                {"category": "spot", "symbol": "BTCUSDT", "orderId": "2b", "orderLinkId": "", "execId": "e1",
+                 "execType": "Trade", "execQty": "0.1", "execPrice": "99", "execFee": "0.01", "execTime": "1700000000000"},
+                {"category": "linear", "symbol": "BTCUSDT", "orderId": "", "execId": "f1", "execType": "Funding"}
+            ]
+        });
+        let events = parse_private_message(&execution);
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            UserStreamEvent::Execution(e) => {
+                assert_eq!(e.venue, "bybit_spot");
+                assert_eq!(e.client_order_id, None);
+                assert_eq!(e.timestamp_ms, 1_700_000_000_000);
+            }
+            other => panic!("unexpected event {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_stream_events_drive_order_manager() {
+        let oms = OrderManager::new();
+        let cid = oms.register(&SimulatedOrder {
+            exchange: "bybit".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Long,
+            order_type: OrderType::Limit,
+            price: 100.0,
+            size: 1.0,
+            ..Default::default()
+        }).unwrap();
+        oms.set_stream_connected("bybit", true);
+
+        // Execution arrives twice (redelivery) before the REST ack
+        let execution = json!({
+            "topic": "execution",
+            "data": [{"category": "linear", "symbol": "BTCUSDT", "orderId": "77", "orderLinkId": cid,
+                      "execId": "e1", "execType": "Trade", "execQty": "1", "execPrice": "99.5", "execTime": "1"}]
+        });
+        for _ in 0..2 {
+            for event in parse_private_message(&execution) {
+                assert!(oms.apply_stream_event(&event).unwrap());
+            }
+        }
+
+        let order = oms.get(&cid).unwrap();
+        assert_eq!(order.state, OrderState::Filled);
+        assert_eq!(order.fills.len(), 1);
+        assert_eq!(order.avg_fill_price, Some(99.5));
+
+        let status = oms.streamed_status("bybit", &cid).unwrap();
+        assert!(status.is_fully_filled());
+        assert!(oms.streamed_status("okx", &cid).is_none());
+    }
+}
@@ -3,8 +3,10 @@ use std::sync::Arc;
 use std::collections::HashMap;
 use reqwest::Client;
 use serde_json::{json, Value};
-use crate::strategy::types::{SimulatedOrder, OrderStatus, OrderSide};
+use crate::strategy::types::{FeeRates, Fill, FundingPayment, Liquidity, SimulatedOrder, OrderStatus, OrderSide, PositionMargin, TimeInForce, VenuePosition};
+use super::{as_f64, open_order};
 use crate::strategy::testnet_config::ExchangeCredentials;
+use crate::strategy::execution_error::{http_error, ExecutionError};
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
 use hex::encode;
@@ -15,6 +17,61 @@ type HmacSha256 = Hmac<Sha256>;
 
 const BYBIT_DEMO_URL: &str = "https://api-demo.bybit.com";
 
+/// Bybit v5 `category` for a venue: `bybit_spot` trades spot, everything else linear.
+pub fn bybit_category(venue: &str) -> &'static str {
+    match crate::strategy::instruments::instrument_kind(venue) {
+        crate::strategy::types::InstrumentKind::Spot => "spot",
+        crate::strategy::types::InstrumentKind::Perp => "linear",
+    }
+}
+
+/// Client order ID sent as `orderLinkId` (Bybit rejects duplicates; max 36 chars).
+fn order_link_id(id: &str) -> Option<&str> {
+    if id.is_empty() || id.len() > 36 {
+        None
+    } else {
+        Some(id)
+    }
+}
+
+/// Bybit v5 `timeInForce`; a crossing `PostOnly` order is accepted, then cancelled
+/// with `rejectReason` `EC_PostOnlyWillTakeLiquidity`
+fn bybit_time_in_force(tif: TimeInForce) -> &'static str {
+    match tif {
+        TimeInForce::Gtc => "GTC",
+        TimeInForce::Ioc => "IOC",
+        TimeInForce::Fok => "FOK",
+        TimeInForce::PostOnly => "PostOnly",
+    }
+}
+
+/// Map a Bybit v5 `retCode` onto `ExecutionError`
+pub fn bybit_error(ret_code: i64, ret_msg: &str) -> ExecutionError {
+    let msg = ret_msg.to_string();
+    match ret_code {
+        // Order does not exist (linear / spot), or already completed or cancelled
+        110001 | 170213 | 110008 => ExecutionError::OrderNotFound(msg),
+        110004 | 110007 | 110012 | 170131 => ExecutionError::InsufficientBalance(msg),
+        10006 | 10018 => ExecutionError::RateLimited { retry_after: None },
+        10003 | 10004 | 10005 | 10010 | 33004 => ExecutionError::Auth(msg),
+        // Qty truncated to zero, below min order value, spot qty/precision limits
+        110017 | 110094 | 170136 | 170137 | 170140 => ExecutionError::InvalidQuantity(msg),
+        _ => ExecutionError::rejected(ret_code, msg),
+    }
+}
+
+/// A crossing post-only order is accepted and then cancelled by the matching
+/// engine, so the rejection only shows up on the order itself
+fn post_only_rejection(order_data: &Value) -> Option<ExecutionError> {
+    (order_data.get("rejectReason").and_then(|v| v.as_str()) == Some("EC_PostOnlyWillTakeLiquidity"))
+        .then(|| ExecutionError::PostOnlyRejected("EC_PostOnlyWillTakeLiquidity".to_string()))
+}
+
+/// `result.list` of a v5 response
+fn result_list(result: &Value) -> Vec<Value> {
+    result.get("list").and_then(|l| l.as_array()).cloned().unwrap_or_default()
+}
+
 // Cache TTL for order book data (100ms as per spec)
 const ORDER_BOOK_CACHE_TTL: Duration = Duration::from_millis(100);
 
@@ -185,17 +242,17 @@ impl BybitDemoClient {
         encode(mac.finalize().into_bytes()).to_lowercase()
     }
 
-    /// Set leverage for a symbol to 1x
+    /// Set buy and sell leverage for a symbol
     /// Must be called BEFORE placing any orders for the symbol
-    pub async fn set_leverage(&self, symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    pub async fn set_leverage(&self, symbol: &str, leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
         let timestamp = self.get_timestamp().await?;
 
         let recv_window = "5000";
         let request_body = json!({
             "category": "linear",
             "symbol": symbol,
-            "buyLeverage": "1",
-            "sellLeverage": "1",
+            "buyLeverage": leverage.to_string(),
+            "sellLeverage": leverage.to_string(),
         });
 
         let body_str = request_body.to_string();
@@ -203,7 +260,7 @@ impl BybitDemoClient {
 
         let url = format!("{}/v5/position/set-leverage", BYBIT_DEMO_URL);
 
-        eprintln!("[BYBIT DEMO] Setting leverage to 1x for {}", symbol);
+        eprintln!("[BYBIT DEMO] Setting leverage to {}x for {}", leverage, symbol);
 
         let response = self
             .client
@@ -224,18 +281,22 @@ impl BybitDemoClient {
 
         // Check for errors
         if let Some(ret_code) = response_json.get("retCode").and_then(|v| v.as_i64()) {
+            // 110043: leverage not modified, i.e. already at the requested value
+            if ret_code == 110043 {
+                eprintln!("[BYBIT DEMO] ✅ Leverage already {}x for {}", leverage, symbol);
+                return Ok(());
+            }
             if ret_code != 0 {
                 let ret_msg = response_json
                     .get("retMsg")
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
-                eprintln!("[BYBIT DEMO] ⚠️  Failed to set leverage (code {}): {}", ret_code, ret_msg);
-                // Don't fail - leverage might already be set or not changeable
-                return Ok(());
+                eprintln!("[BYBIT DEMO] ❌ Failed to set leverage (code {}): {}", ret_code, ret_msg);
+                return Err(format!("Bybit set leverage failed (code {}): {}", ret_code, ret_msg).into());
             }
         }
 
-        eprintln!("[BYBIT DEMO] ✅ Leverage set to 1x for {}", symbol);
+        eprintln!("[BYBIT DEMO] ✅ Leverage set to {}x for {}", leverage, symbol);
         Ok(())
     }
 
@@ -285,14 +346,24 @@ impl BybitDemoClient {
             .trim_end_matches('.')
             .to_string();
 
-        let request_body = json!({
-            "category": "linear",
+        let category = bybit_category(&order.exchange);
+        let mut request_body = json!({
+            "category": category,
             "symbol": order.symbol.clone(),
             "side": side,
             "orderType": "Market",
             "qty": qty_str,
             "timeInForce": "IOC",  // Immediate-Or-Cancel for market orders
         });
+        if category == "spot" {
+            // Spot market buys are sized in quote coin unless told otherwise
+            request_body["marketUnit"] = json!("baseCoin");
+        } else if order.is_reduce_only() {
+            request_body["reduceOnly"] = json!(true);
+        }
+        if let Some(link_id) = order_link_id(&order.id) {
+            request_body["orderLinkId"] = json!(link_id);
+        }
 
         let body_str = request_body.to_string();
         let signature = self.generate_post_signature(&timestamp, recv_window, &body_str);
@@ -329,7 +400,7 @@ impl BybitDemoClient {
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
                 eprintln!("[BYBIT DEMO] ❌ MARKET order API Error (code {}): {}", ret_code, ret_msg);
-                return Err(format!("Bybit API error ({}): {}", ret_code, ret_msg).into());
+                return Err(bybit_error(ret_code, ret_msg).into());
             }
         }
 
@@ -381,16 +452,25 @@ impl BybitDemoClient {
             .trim_end_matches('.')
             .to_string();
 
-        let request_body = json!({
-            "category": "linear",
+        let category = bybit_category(&order.exchange);
+        let mut request_body = json!({
+            "category": category,
             "symbol": order.symbol.clone(),
             "side": side,
             "orderType": "Limit",
             "qty": qty_str,
             "price": price_str,
-            "timeInForce": "GTC",
-            "leverage": "1",
+            "timeInForce": bybit_time_in_force(order.time_in_force),
         });
+        if category == "linear" {
+            request_body["leverage"] = json!("1");
+            if order.is_reduce_only() {
+                request_body["reduceOnly"] = json!(true);
+            }
+        }
+        if let Some(link_id) = order_link_id(&order.id) {
+            request_body["orderLinkId"] = json!(link_id);
+        }
 
         let body_str = request_body.to_string();
         let signature = self.generate_post_signature(&timestamp, recv_window, &body_str);
@@ -427,7 +507,7 @@ impl BybitDemoClient {
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
                 eprintln!("[BYBIT DEMO] ❌ API Error (code {}): {}", ret_code, ret_msg);
-                return Err(format!("Bybit API error ({}): {}", ret_code, ret_msg).into());
+                return Err(bybit_error(ret_code, ret_msg).into());
             }
         }
 
@@ -451,11 +531,11 @@ impl BybitDemoClient {
     }
 
     /// Get order status from Bybit demo
-    pub async fn get_order_status(&self, order_id: &str, symbol: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+    pub async fn get_order_status(&self, order_id: &str, symbol: &str, category: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
         let timestamp = self.get_timestamp().await?;
 
         let recv_window = "5000";
-        let query_string = format!("category=linear&symbol={}&orderId={}", symbol, order_id);
+        let query_string = format!("category={}&symbol={}&orderId={}", category, symbol, order_id);
         let signature = self.generate_get_signature(&timestamp, recv_window, &query_string);
 
         let url = format!("{}/v5/order/realtime?{}", BYBIT_DEMO_URL, query_string);
@@ -479,11 +559,15 @@ impl BybitDemoClient {
         
         let response_json: Value = serde_json::from_str(&response_text)?;
 
-        let status_str = response_json
+        let order_data = response_json
             .get("result")
             .and_then(|r| r.get("list"))
             .and_then(|l| l.as_array())
-            .and_then(|a| a.first())
+            .and_then(|a| a.first());
+        if let Some(rejection) = order_data.and_then(post_only_rejection) {
+            return Err(rejection.into());
+        }
+        let status_str = order_data
             .and_then(|f| f.get("orderStatus"))
             .and_then(|v| v.as_str())
             .unwrap_or("Unknown");
@@ -500,13 +584,13 @@ impl BybitDemoClient {
     }
 
     /// Get detailed order status including filled quantity from Bybit demo
-    pub async fn get_order_status_detailed(&self, order_id: &str, symbol: &str) -> Result<crate::strategy::types::OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    pub async fn get_order_status_detailed(&self, order_id: &str, symbol: &str, category: &str) -> Result<crate::strategy::types::OrderStatusInfo, Box<dyn Error + Send + Sync>> {
         use crate::strategy::types::OrderStatusInfo;
         
         let timestamp = self.get_timestamp().await?;
 
         let recv_window = "5000";
-        let query_string = format!("category=linear&symbol={}&orderId={}", symbol, order_id);
+        let query_string = format!("category={}&symbol={}&orderId={}", category, symbol, order_id);
         let signature = self.generate_get_signature(&timestamp, recv_window, &query_string);
 
         let url = format!("{}/v5/order/realtime?{}", BYBIT_DEMO_URL, query_string);
@@ -531,7 +615,7 @@ impl BybitDemoClient {
                     .get("retMsg")
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
-                return Err(format!("Bybit API error: {}", ret_msg).into());
+                return Err(bybit_error(ret_code, ret_msg).into());
             }
         }
 
@@ -551,6 +635,10 @@ impl BybitDemoClient {
             "Cancelled" => OrderStatus::Cancelled,
             _ => OrderStatus::Pending,
         };
+        if let Some(rejection) = post_only_rejection(order_data) {
+            eprintln!("[BYBIT DEMO] Post-only order {} cancelled: would have taken liquidity", order_id);
+            return Err(rejection.into());
+        }
 
         // Extract filled and total quantities
         // Bybit uses "cumExecQty" for filled quantity and "qty" for original quantity
@@ -570,13 +658,175 @@ impl BybitDemoClient {
         Ok(OrderStatusInfo::new(status, cum_exec_qty, qty))
     }
 
+    /// Signed GET returning `result`, with `retCode` mapped onto `ExecutionError`
+    async fn signed_get(&self, path: &str, query_string: &str) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let timestamp = self.get_timestamp().await?;
+        let recv_window = "5000";
+        let signature = self.generate_get_signature(&timestamp, recv_window, query_string);
+
+        let response = self
+            .client
+            .get(format!("{}{}?{}", BYBIT_DEMO_URL, path, query_string))
+            .header("X-BAPI-SIGN", signature)
+            .header("X-BAPI-API-KEY", &self.api_key)
+            .header("X-BAPI-TIMESTAMP", &timestamp)
+            .header("X-BAPI-RECV-WINDOW", recv_window)
+            .send()
+            .await?;
+
+        let response_json: Value = serde_json::from_str(&response.text().await?)?;
+        let ret_code = response_json.get("retCode").and_then(|v| v.as_i64()).unwrap_or(-1);
+        if ret_code != 0 {
+            let ret_msg = response_json.get("retMsg").and_then(|v| v.as_str()).unwrap_or("Unknown error");
+            return Err(bybit_error(ret_code, ret_msg).into());
+        }
+        response_json.get("result").cloned().ok_or_else(|| "Missing result in Bybit response".into())
+    }
+
+    /// Open USDT linear positions
+    pub async fn get_open_positions(&self) -> Result<Vec<VenuePosition>, Box<dyn Error + Send + Sync>> {
+        let result = self.signed_get("/v5/position/list", "category=linear&settleCoin=USDT").await?;
+        Ok(result_list(&result)
+            .iter()
+            .filter_map(|p| {
+                let size = p.get("size").and_then(as_f64)?;
+                let side = match p.get("side").and_then(|v| v.as_str())? {
+                    "Buy" => OrderSide::Long,
+                    "Sell" => OrderSide::Short,
+                    _ => return None,  // Empty side: no position
+                };
+                Some(VenuePosition {
+                    exchange: "bybit".to_string(),
+                    symbol: p.get("symbol")?.as_str()?.to_string(),
+                    side,
+                    size,
+                    entry_price: p.get("avgPrice").and_then(as_f64).unwrap_or(0.0),
+                    margin: PositionMargin::new(
+                        p.get("markPrice").and_then(as_f64),
+                        p.get("liqPrice").and_then(as_f64),
+                        p.get("positionBalance").or_else(|| p.get("positionIM")).and_then(as_f64),
+                        p.get("leverage").and_then(as_f64),
+                    ),
+                })
+            })
+            .filter(|p| p.size > 0.0)
+            .collect())
+    }
+
+    /// Funding settlements of `symbol` since `since` (unix seconds), from the
+    /// transaction log. Bybit signs `funding` as an expense, so it is negated.
+    /// The log spans at most 7 days per request; older settlements need an
+    /// earlier call.
+    pub async fn get_funding_history(&self, symbol: &str, since: u64) -> Result<Vec<FundingPayment>, Box<dyn Error + Send + Sync>> {
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs();
+        let start = since.max(now.saturating_sub(7 * 24 * 3600));
+        let query_string = format!(
+            "accountType=UNIFIED&category=linear&type=SETTLEMENT&symbol={}&startTime={}&limit=50",
+            symbol, start * 1000
+        );
+        let result = self.signed_get("/v5/account/transaction-log", &query_string).await?;
+        let mut payments: Vec<FundingPayment> = result_list(&result)
+            .iter()
+            .filter_map(|t| {
+                Some(FundingPayment {
+                    exchange: "bybit".to_string(),
+                    symbol: t.get("symbol")?.as_str()?.to_string(),
+                    side: if t.get("side")?.as_str()? == "Sell" { OrderSide::Short } else { OrderSide::Long },
+                    rate: t.get("feeRate").and_then(as_f64).unwrap_or(0.0),
+                    amount_usd: -t.get("funding").and_then(as_f64)?,
+                    funding_time: t.get("transactionTime").and_then(as_f64)? as u64 / 1000,
+                })
+            })
+            .collect();
+        // Newest first on the wire
+        payments.sort_by_key(|p| p.funding_time);
+        Ok(payments)
+    }
+
+    /// Executions of `symbol` in a category (`linear` or `spot`) since `since`
+    /// (unix seconds). `execFee` is negative for maker rebates; funding
+    /// settlements listed alongside trades are left to `get_funding_history`.
+    pub async fn get_fills(&self, category: &str, symbol: &str, since: u64) -> Result<Vec<Fill>, Box<dyn Error + Send + Sync>> {
+        let now = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH)?.as_secs();
+        let start = since.max(now.saturating_sub(7 * 24 * 3600));
+        let query_string = format!("category={}&symbol={}&startTime={}&limit=100", category, symbol, start * 1000);
+        let result = self.signed_get("/v5/execution/list", &query_string).await?;
+        let venue = if category == "spot" { "bybit_spot" } else { "bybit" };
+        let mut fills: Vec<Fill> = result_list(&result)
+            .iter()
+            .filter(|e| e.get("execType").and_then(|t| t.as_str()) != Some("Funding"))
+            .filter_map(|e| {
+                Some(Fill {
+                    id: e.get("execId")?.as_str()?.to_string(),
+                    order_id: e.get("orderId")?.as_str()?.to_string(),
+                    exchange: venue.to_string(),
+                    symbol: e.get("symbol")?.as_str()?.to_string(),
+                    side: if e.get("side")?.as_str()? == "Buy" { OrderSide::Long } else { OrderSide::Short },
+                    price: e.get("execPrice").and_then(as_f64)?,
+                    quantity: e.get("execQty").and_then(as_f64)?,
+                    fee: e.get("execFee").and_then(as_f64).unwrap_or(0.0),
+                    fee_asset: e.get("feeCurrency")
+                        .and_then(|c| c.as_str())
+                        .filter(|c| !c.is_empty())
+                        .unwrap_or("USDT")
+                        .to_string(),
+                    liquidity: if e.get("isMaker").and_then(|m| m.as_bool()).unwrap_or(false) { Liquidity::Maker } else { Liquidity::Taker },
+                    time: e.get("execTime").and_then(as_f64)? as u64 / 1000,
+                })
+            })
+            .collect();
+        // Newest first on the wire
+        fills.sort_by_key(|f| f.time);
+        Ok(fills)
+    }
+
+    /// Account maker/taker rate of `symbol` in a category, in bps (rates are
+    /// reported as fractions; a negative maker rate is a rebate)
+    pub async fn get_fee_rates(&self, category: &str, symbol: &str) -> Result<FeeRates, Box<dyn Error + Send + Sync>> {
+        let query_string = format!("category={}&symbol={}", category, symbol);
+        let result = self.signed_get("/v5/account/fee-rate", &query_string).await?;
+        let list = result_list(&result);
+        let rates = list.first().ok_or("Empty fee-rate response")?;
+        Ok(FeeRates {
+            maker_bps: rates.get("makerFeeRate").and_then(as_f64).ok_or("Missing makerFeeRate")? * 10_000.0,
+            taker_bps: rates.get("takerFeeRate").and_then(as_f64).ok_or("Missing takerFeeRate")? * 10_000.0,
+        })
+    }
+
+    /// Working orders in a category (`linear` or `spot`)
+    pub async fn get_open_orders(&self, category: &str) -> Result<Vec<SimulatedOrder>, Box<dyn Error + Send + Sync>> {
+        let query_string = if category == "linear" {
+            "category=linear&settleCoin=USDT".to_string()
+        } else {
+            format!("category={}", category)
+        };
+        let result = self.signed_get("/v5/order/realtime", &query_string).await?;
+        let venue = if category == "spot" { "bybit_spot" } else { "bybit" };
+        Ok(result_list(&result)
+            .iter()
+            .filter_map(|o| {
+                let qty = o.get("qty").and_then(as_f64)?;
+                let filled = o.get("cumExecQty").and_then(as_f64).unwrap_or(0.0);
+                Some(open_order(
+                    venue,
+                    o.get("orderId")?.as_str()?.to_string(),
+                    o.get("symbol")?.as_str()?.to_string(),
+                    if o.get("side")?.as_str()? == "Buy" { OrderSide::Long } else { OrderSide::Short },
+                    o.get("price").and_then(as_f64).unwrap_or(0.0),
+                    qty - filled,
+                    o.get("reduceOnly").and_then(|v| v.as_bool()).unwrap_or(false),
+                ))
+            })
+            .collect())
+    }
+
     /// Cancel an order on Bybit demo
-    pub async fn cancel_order(&self, order_id: &str, symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    pub async fn cancel_order(&self, order_id: &str, symbol: &str, category: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
         let timestamp = self.get_timestamp().await?;
 
         let recv_window = "5000";
         let request_body = json!({
-            "category": "linear",
+            "category": category,
             "symbol": symbol.to_string(),
             "orderId": order_id,
         });
@@ -606,7 +856,7 @@ impl BybitDemoClient {
                     .get("retMsg")
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
-                return Err(format!("Failed to cancel order: {}", ret_msg).into());
+                return Err(bybit_error(ret_code, ret_msg).into());
             }
         }
 
@@ -614,6 +864,64 @@ impl BybitDemoClient {
         Ok(())
     }
 
+    /// Amend a resting order's price (and quantity if given) in place on Bybit demo
+    pub async fn amend_order(&self, order_id: &str, symbol: &str, category: &str, price: f64, qty: Option<f64>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let timestamp = self.get_timestamp().await?;
+
+        let recv_window = "5000";
+        let rounded_price = (price * 100000000.0).round() / 100000000.0;
+        let price_str = format!("{:.8}", rounded_price)
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string();
+        let mut request_body = json!({
+            "category": category,
+            "symbol": symbol.to_string(),
+            "orderId": order_id,
+            "price": price_str,
+        });
+        if let Some(qty) = qty {
+            let qty_step = self.get_qty_step(symbol).await?;
+            let rounded_qty = (qty / qty_step).round() * qty_step;
+            request_body["qty"] = json!(format!("{:.8}", rounded_qty)
+                .trim_end_matches('0')
+                .trim_end_matches('.')
+                .to_string());
+        }
+
+        let body_str = request_body.to_string();
+        let signature = self.generate_post_signature(&timestamp, recv_window, &body_str);
+
+        let url = format!("{}/v5/order/amend", BYBIT_DEMO_URL);
+
+        let response = self
+            .client
+            .post(&url)
+            .header("X-BAPI-SIGN", signature)
+            .header("X-BAPI-API-KEY", &self.api_key)
+            .header("X-BAPI-TIMESTAMP", &timestamp)
+            .header("X-BAPI-RECV-WINDOW", recv_window)
+            .header("Content-Type", "application/json")
+            .json(&request_body)
+            .send()
+            .await?;
+
+        let response_json: Value = serde_json::from_str(&response.text().await?)?;
+
+        if let Some(ret_code) = response_json.get("retCode").and_then(|v| v.as_i64()) {
+            if ret_code != 0 {
+                let ret_msg = response_json
+                    .get("retMsg")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Unknown error");
+                return Err(bybit_error(ret_code, ret_msg).into());
+            }
+        }
+
+        eprintln!("[BYBIT DEMO] Order amended: {} | Price: {}", order_id, price_str);
+        Ok(())
+    }
+
     /// Get account balance from Bybit demo
     pub async fn get_balance(&self) -> Result<f64, Box<dyn Error + Send + Sync>> {
         let timestamp = self.get_timestamp().await?;
@@ -638,7 +946,7 @@ impl BybitDemoClient {
         let response_text = response.text().await?;
 
         if !status.is_success() {
-            return Err(format!("HTTP {}: {}", status, response_text).into());
+            return Err(http_error(status.as_u16(), None, &response_text).into());
         }
 
         if response_text.is_empty() {
@@ -694,6 +1002,55 @@ impl BybitDemoClient {
         }
     }
 
+    /// Get the wallet balance of a single coin (spot inventory, e.g. "BTC")
+    pub async fn get_coin_balance(&self, coin: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let timestamp = self.get_timestamp().await?;
+
+        let recv_window = "5000";
+        let query_string = format!("accountType=UNIFIED&coin={}", coin);
+        let signature = self.generate_get_signature(&timestamp, recv_window, &query_string);
+
+        let url = format!("{}/v5/account/wallet-balance?{}", BYBIT_DEMO_URL, query_string);
+
+        let response = self
+            .client
+            .get(&url)
+            .header("X-BAPI-SIGN", &signature)
+            .header("X-BAPI-API-KEY", &self.api_key)
+            .header("X-BAPI-TIMESTAMP", &timestamp)
+            .header("X-BAPI-RECV-WINDOW", recv_window)
+            .send()
+            .await?;
+
+        let response_json: Value = serde_json::from_str(&response.text().await?)?;
+
+        if let Some(ret_code) = response_json.get("retCode").and_then(|v| v.as_i64()) {
+            if ret_code != 0 {
+                let ret_msg = response_json
+                    .get("retMsg")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("Unknown error");
+                return Err(bybit_error(ret_code, ret_msg).into());
+            }
+        }
+
+        // A coin that was never held is simply absent from the list
+        let balance = response_json
+            .get("result")
+            .and_then(|r| r.get("list"))
+            .and_then(|l| l.as_array())
+            .and_then(|a| a.first())
+            .and_then(|f| f.get("coin"))
+            .and_then(|c| c.as_array())
+            .and_then(|a| a.iter().find(|c| c.get("coin").and_then(|v| v.as_str()) == Some(coin)))
+            .and_then(|c| c.get("walletBalance"))
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<f64>().ok())
+            .unwrap_or(0.0);
+
+        Ok(balance)
+    }
+
     /// Get order book depth from Bybit testnet
     /// Returns the top N levels of bids and asks
     /// Uses 100ms cache to reduce API calls
@@ -745,7 +1102,7 @@ impl BybitDemoClient {
         let response_text = response.text().await?;
 
         if !status.is_success() {
-            return Err(format!("HTTP {}: {}", status, response_text).into());
+            return Err(http_error(status.as_u16(), None, &response_text).into());
         }
 
         let response_json: Value = serde_json::from_str(&response_text)?;
@@ -757,7 +1114,7 @@ impl BybitDemoClient {
                     .get("retMsg")
                     .and_then(|v| v.as_str())
                     .unwrap_or("Unknown error");
-                return Err(format!("Bybit API error: {}", ret_msg).into());
+                return Err(bybit_error(ret_code, ret_msg).into());
             }
         }
 
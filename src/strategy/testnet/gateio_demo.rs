@@ -0,0 +1,597 @@
+//! Gate.io futures testnet client (USDT-settled perpetuals)
+//!
+//! Contracts are named `BASE_USDT` and sized in whole contracts of
+//! `quanto_multiplier` coins; sells are negative sizes. This client takes and
+//! reports quantities in coins and converts at the edge.
+
+use std::error::Error;
+use std::collections::HashMap;
+use std::sync::Arc;
+use reqwest::Client;
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+use crate::strategy::types::{SimulatedOrder, OrderStatus, OrderSide, OrderStatusInfo, OrderBookDepth, PositionMargin, PriceLevel, TimeInForce, VenuePosition};
+use crate::strategy::testnet_config::ExchangeCredentials;
+use crate::strategy::execution_error::{http_error, ExecutionError};
+use super::{as_f64, format_decimal, open_order, round_to_step, round_to_tick};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha512};
+
+type HmacSha512 = Hmac<Sha512>;
+
+const GATEIO_DEMO_URL: &str = "https://fx-api-testnet.gateio.ws";
+
+/// Signed paths include this prefix
+const API_PREFIX: &str = "/api/v4";
+
+/// Sizing rules of a futures contract
+#[derive(Debug, Clone, Copy)]
+struct ContractSpec {
+    /// Coins per contract (`quanto_multiplier`)
+    multiplier: f64,
+    /// Price tick (`order_price_round`)
+    tick_size: f64,
+}
+
+impl ContractSpec {
+    /// Coin quantity -> whole contracts, rounded down
+    fn contracts(&self, quantity: f64) -> i64 {
+        round_to_step(quantity / self.multiplier, 1.0) as i64
+    }
+
+    fn coins(&self, contracts: i64) -> f64 {
+        round_to_step(contracts.unsigned_abs() as f64 * self.multiplier, self.multiplier)
+    }
+}
+
+/// Gate.io contract for a normalized symbol (BTCUSDT -> BTC_USDT)
+pub fn gateio_contract(symbol: &str) -> String {
+    format!("{}_USDT", symbol.trim_end_matches("USDT"))
+}
+
+/// Normalized symbol for a USDT contract (BTC_USDT -> BTCUSDT)
+fn gateio_symbol(contract: &str) -> Option<String> {
+    contract.strip_suffix("_USDT").map(|base| format!("{}USDT", base))
+}
+
+/// Order `text` must be `t-` plus at most 28 of `[0-9A-Za-z_.-]`
+fn order_text(id: &str) -> Option<String> {
+    let valid = !id.is_empty()
+        && id.len() <= 28
+        && id.chars().all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.'));
+    valid.then(|| format!("t-{}", id))
+}
+
+/// Map a Gate.io error `label` onto `ExecutionError`
+pub fn gateio_error(label: &str, message: &str) -> ExecutionError {
+    let text = message.to_string();
+    match label {
+        "ORDER_NOT_FOUND" | "ORDER_CLOSED" => ExecutionError::OrderNotFound(text),
+        "INSUFFICIENT_AVAILABLE" | "BALANCE_NOT_ENOUGH" => ExecutionError::InsufficientBalance(text),
+        "TOO_MANY_REQUESTS" => ExecutionError::RateLimited { retry_after: None },
+        "INVALID_KEY" | "INVALID_SIGNATURE" | "MISSING_REQUIRED_HEADER" | "REQUEST_EXPIRED" | "FORBIDDEN" => ExecutionError::Auth(text),
+        // Post-only (`poc`) order would have matched immediately
+        "ORDER_POC_IMMEDIATE" => ExecutionError::PostOnlyRejected(text),
+        "SIZE_TOO_LARGE" => ExecutionError::InvalidQuantity(text),
+        _ => ExecutionError::rejected(label, text),
+    }
+}
+
+pub struct GateioDemoClient {
+    client: Client,
+    base_url: String,
+    api_key: String,
+    api_secret: String,
+    // Cache of contract sizing rules: contract -> ContractSpec
+    contract_cache: Arc<Mutex<HashMap<String, ContractSpec>>>,
+}
+
+impl GateioDemoClient {
+    pub fn new(credentials: ExchangeCredentials) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: GATEIO_DEMO_URL.to_string(),
+            api_key: credentials.api_key,
+            api_secret: credentials.api_secret,
+            contract_cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Point the client at another host (e.g. a local mock server)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    fn timestamp() -> String {
+        chrono::Utc::now().timestamp().to_string()
+    }
+
+    /// Generate signature for Gate.io API v4
+    /// Format: hex(HMAC-SHA512(method\npath\nquery\nhex(SHA512(body))\ntimestamp))
+    fn generate_signature(&self, timestamp: &str, method: &str, path: &str, query: &str, body: &str) -> String {
+        let body_hash = hex::encode(Sha512::digest(body.as_bytes()));
+        let message = format!("{}\n{}\n{}\n{}\n{}", method, path, query, body_hash, timestamp);
+
+        let mut mac = HmacSha512::new_from_slice(self.api_secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(message.as_bytes());
+        hex::encode(mac.finalize().into_bytes())
+    }
+
+    /// Gate.io answers errors with a non-2xx status and `{label, message}`
+    async fn read_response(response: reqwest::Response) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            let detail: Value = serde_json::from_str(&text).unwrap_or(Value::Null);
+            let label = detail.get("label").and_then(|v| v.as_str()).unwrap_or("");
+            let message = detail.get("message").and_then(|v| v.as_str()).unwrap_or(&text);
+            return Err(match label {
+                "" => http_error(status.as_u16(), None, message),
+                _ => gateio_error(label, message),
+            }.into());
+        }
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Signed request; `path` is relative to `/api/v4`
+    async fn signed_request(&self, method: &str, path: &str, query: &str, body: Option<&Value>) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let timestamp = Self::timestamp();
+        let body_str = body.map(|b| b.to_string()).unwrap_or_default();
+        let full_path = format!("{}{}", API_PREFIX, path);
+        let signature = self.generate_signature(&timestamp, method, &full_path, query, &body_str);
+
+        let url = if query.is_empty() {
+            format!("{}{}", self.base_url, full_path)
+        } else {
+            format!("{}{}?{}", self.base_url, full_path, query)
+        };
+        let request = match method {
+            "POST" => self.client.post(&url).header("Content-Type", "application/json").body(body_str),
+            "DELETE" => self.client.delete(&url),
+            _ => self.client.get(&url),
+        };
+
+        let response = request
+            .header("KEY", &self.api_key)
+            .header("SIGN", signature)
+            .header("Timestamp", &timestamp)
+            .header("Accept", "application/json")
+            .send()
+            .await?;
+
+        Self::read_response(response).await
+    }
+
+    async fn public_get(&self, path: &str, query: &str) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let url = if query.is_empty() {
+            format!("{}{}{}", self.base_url, API_PREFIX, path)
+        } else {
+            format!("{}{}{}?{}", self.base_url, API_PREFIX, path, query)
+        };
+        let response = self
+            .client
+            .get(&url)
+            .header("Accept", "application/json")
+            .send()
+            .await?;
+        Self::read_response(response).await
+    }
+
+    async fn contract(&self, contract: &str) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        self.public_get(&format!("/futures/usdt/contracts/{}", contract), "").await
+    }
+
+    /// Sizing rules for a contract (cached)
+    async fn contract_spec(&self, symbol: &str) -> Result<ContractSpec, Box<dyn Error + Send + Sync>> {
+        let contract = gateio_contract(symbol);
+        {
+            let cache = self.contract_cache.lock().await;
+            if let Some(&spec) = cache.get(&contract) {
+                return Ok(spec);
+            }
+        }
+
+        let data = self.contract(&contract).await?;
+        let spec = ContractSpec {
+            multiplier: data.get("quanto_multiplier").and_then(as_f64).ok_or("Failed to extract quanto_multiplier from response")?,
+            tick_size: data.get("order_price_round").and_then(as_f64).unwrap_or(0.0),
+        };
+
+        let mut cache = self.contract_cache.lock().await;
+        cache.insert(contract, spec);
+        Ok(spec)
+    }
+
+    /// Quantity step in coins (one contract)
+    pub async fn get_qty_step(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        Ok(self.contract_spec(symbol).await?.multiplier)
+    }
+
+    /// Set leverage for a symbol. A non-zero leverage also puts the
+    /// position in isolated margin on Gate.io.
+    pub async fn set_leverage(&self, symbol: &str, leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let path = format!("/futures/usdt/positions/{}/leverage", gateio_contract(symbol));
+        self.signed_request("POST", &path, &format!("leverage={}", leverage.max(1)), None).await?;
+        eprintln!("[GATEIO DEMO] Leverage set to {}x for {}", leverage.max(1), symbol);
+        Ok(())
+    }
+
+    /// Isolated margin is implied by the non-zero leverage set in `set_leverage`
+    pub async fn set_margin_mode_isolated(&self, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(())
+    }
+
+    /// Request body for an order, with size and price rounded to the contract rules.
+    /// Returns the body plus the coin size and price actually sent.
+    async fn order_body(&self, order: &SimulatedOrder, market: bool) -> Result<(Value, f64, f64), Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(&order.symbol).await?;
+        let contracts = spec.contracts(order.size);
+        if contracts <= 0 {
+            return Err(ExecutionError::InvalidQuantity(format!("Order size {} below one Gate.io contract ({}) for {}",
+                order.size, spec.multiplier, order.symbol)).into());
+        }
+        let price = round_to_tick(order.price, spec.tick_size);
+
+        let mut body = json!({
+            "contract": gateio_contract(&order.symbol),
+            "size": match order.side {
+                OrderSide::Long => contracts,
+                OrderSide::Short => -contracts,
+            },
+        });
+        if market {
+            // Market orders are price 0 with immediate-or-cancel
+            body["price"] = json!("0");
+            body["tif"] = json!("ioc");
+        } else {
+            body["price"] = json!(format_decimal(price));
+            // `poc` (pending-or-cancelled) is post-only: rejected with ORDER_POC_IMMEDIATE if it would cross
+            body["tif"] = json!(match order.time_in_force {
+                TimeInForce::Gtc => "gtc",
+                TimeInForce::Ioc => "ioc",
+                TimeInForce::Fok => "fok",
+                TimeInForce::PostOnly => "poc",
+            });
+        }
+        if order.close_position {
+            // Closes the whole position, which Gate requires to be sent with size 0
+            body["size"] = json!(0);
+            body["close"] = json!(true);
+        } else if order.reduce_only {
+            body["reduce_only"] = json!(true);
+        }
+        if let Some(text) = order_text(&order.id) {
+            body["text"] = json!(text);
+        }
+        Ok((body, spec.coins(contracts), price))
+    }
+
+    async fn submit(&self, order: SimulatedOrder, market: bool) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let (body, size, price) = self.order_body(&order, market).await?;
+        let data = self.signed_request("POST", "/futures/usdt/orders", "", Some(&body)).await?;
+
+        // Order IDs are JSON numbers
+        let order_id = data
+            .get("id")
+            .and_then(|v| v.as_u64().map(|id| id.to_string()).or_else(|| v.as_str().map(|s| s.to_string())))
+            .ok_or("Failed to extract order ID from response")?;
+
+        let mut placed = order;
+        placed.id = order_id;
+        placed.size = size;
+        if !market {
+            placed.price = price;
+        }
+        placed.status = OrderStatus::Pending;
+        placed.created_at = chrono::Utc::now().timestamp_millis() as u64;
+        Ok(placed)
+    }
+
+    /// Place a limit order on Gate.io testnet
+    pub async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let placed = self.submit(order, false).await?;
+        eprintln!("[GATEIO DEMO] Order placed: {} | Symbol: {} | Side: {:?} | Size: {} | Price: {}",
+            placed.id, placed.symbol, placed.side, placed.size, placed.price);
+        Ok(placed)
+    }
+
+    /// Place a market order on Gate.io testnet
+    pub async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let placed = self.submit(order, true).await?;
+        eprintln!("[GATEIO DEMO] Market order placed: {} | Symbol: {} | Size: {}", placed.id, placed.symbol, placed.size);
+        Ok(placed)
+    }
+
+    /// Cancel an order on Gate.io testnet
+    pub async fn cancel_order(&self, order_id: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.signed_request("DELETE", &format!("/futures/usdt/orders/{}", order_id), "", None)
+            .await?;
+        eprintln!("[GATEIO DEMO] Order cancelled: {}", order_id);
+        Ok(())
+    }
+
+    /// Get order status from Gate.io testnet
+    pub async fn get_order_status(&self, order_id: &str, symbol: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+        Ok(self.get_order_status_detailed(order_id, symbol).await?.status)
+    }
+
+    /// Get order status with filled quantity (in coins) from Gate.io testnet
+    pub async fn get_order_status_detailed(&self, order_id: &str, symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(symbol).await?;
+        let data = self.signed_request("GET", &format!("/futures/usdt/orders/{}", order_id), "", None).await?;
+        parse_order(&data, spec.multiplier)
+    }
+
+    /// Open futures positions, converted from contracts to coins
+    pub async fn get_open_positions(&self) -> Result<Vec<VenuePosition>, Box<dyn Error + Send + Sync>> {
+        let data = self.signed_request("GET", "/futures/usdt/positions", "", None).await?;
+        let mut positions = Vec::new();
+        for p in data.as_array().cloned().unwrap_or_default() {
+            let Some(symbol) = p.get("contract").and_then(|v| v.as_str()).and_then(gateio_symbol) else {
+                continue;
+            };
+            let contracts = p.get("size").and_then(|v| v.as_i64()).unwrap_or(0);
+            if contracts == 0 {
+                continue;
+            }
+            let spec = self.contract_spec(&symbol).await?;
+            positions.push(VenuePosition {
+                exchange: "gateio".to_string(),
+                symbol,
+                side: if contracts > 0 { OrderSide::Long } else { OrderSide::Short },
+                size: spec.coins(contracts),
+                entry_price: p.get("entry_price").and_then(as_f64).unwrap_or(0.0),
+                margin: PositionMargin::new(
+                    p.get("mark_price").and_then(as_f64),
+                    p.get("liq_price").and_then(as_f64),
+                    p.get("margin").and_then(as_f64),
+                    p.get("leverage").and_then(as_f64),
+                ),
+            });
+        }
+        Ok(positions)
+    }
+
+    /// Working futures orders; `size` and `left` are signed contracts
+    pub async fn get_open_orders(&self) -> Result<Vec<SimulatedOrder>, Box<dyn Error + Send + Sync>> {
+        let data = self.signed_request("GET", "/futures/usdt/orders", "status=open", None).await?;
+        let mut orders = Vec::new();
+        for o in data.as_array().cloned().unwrap_or_default() {
+            let Some(symbol) = o.get("contract").and_then(|v| v.as_str()).and_then(gateio_symbol) else {
+                continue;
+            };
+            let spec = self.contract_spec(&symbol).await?;
+            let size = o.get("size").and_then(|v| v.as_i64()).unwrap_or(0);
+            orders.push(open_order(
+                "gateio",
+                o.get("id").and_then(|v| v.as_u64()).map(|id| id.to_string()).unwrap_or_default(),
+                symbol,
+                if size > 0 { OrderSide::Long } else { OrderSide::Short },
+                o.get("price").and_then(as_f64).unwrap_or(0.0),
+                spec.coins(o.get("left").and_then(|v| v.as_i64()).unwrap_or(size)),
+                o.get("is_reduce_only").and_then(|v| v.as_bool()).unwrap_or(false),
+            ));
+        }
+        Ok(orders)
+    }
+
+    /// Get available USDT balance from Gate.io testnet
+    pub async fn get_balance(&self) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let data = self.signed_request("GET", "/futures/usdt/accounts", "", None).await?;
+        data.get("available")
+            .and_then(as_f64)
+            .ok_or_else(|| "Failed to extract USDT balance from response".into())
+    }
+
+    /// Get order book depth (top N levels), quantities in coins
+    pub async fn get_order_book_depth(&self, symbol: &str, levels: usize) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(symbol).await?;
+        let query = format!("contract={}&limit={}", gateio_contract(symbol), levels.clamp(1, 300));
+        let data = self.public_get("/futures/usdt/order_book", &query).await?;
+        parse_depth(&data, levels, spec.multiplier)
+    }
+
+    /// Best bid from the order book
+    pub async fn get_best_bid(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.bids.first().map(|l| l.price).ok_or_else(|| "No bids available in order book".into())
+    }
+
+    /// Best ask from the order book
+    pub async fn get_best_ask(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.asks.first().map(|l| l.price).ok_or_else(|| "No asks available in order book".into())
+    }
+
+    /// Check if a symbol is a listed, non-delisting contract on Gate.io
+    pub async fn is_symbol_tradeable(&self, symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        let contract = gateio_contract(symbol);
+        match self.contract(&contract).await {
+            Ok(data) => {
+                let delisting = data.get("in_delisting").and_then(|v| v.as_bool()).unwrap_or(false);
+                eprintln!("[GATEIO VALIDATION] {} found: in_delisting={}", contract, delisting);
+                Ok(!delisting)
+            }
+            Err(e) => {
+                eprintln!("[GATEIO VALIDATION] {} not tradeable: {}", contract, e);
+                Ok(false)
+            }
+        }
+    }
+}
+
+/// Parse a futures order, converting contracts to coins.
+/// `size` is signed and `left` is what remains unfilled.
+fn parse_order(data: &Value, multiplier: f64) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    let state = data.get("status").and_then(|v| v.as_str()).ok_or("Missing order status in response")?;
+    let total = data.get("size").and_then(as_f64).unwrap_or(0.0).abs();
+    let left = data.get("left").and_then(as_f64).unwrap_or(0.0).abs();
+    let filled = (total - left).max(0.0);
+
+    let status = match (state, data.get("finish_as").and_then(|v| v.as_str())) {
+        ("finished", Some("filled")) => OrderStatus::Filled,
+        ("finished", _) => OrderStatus::Cancelled,
+        _ => OrderStatus::Pending,
+    };
+    Ok(OrderStatusInfo::new(status, filled * multiplier, total * multiplier))
+}
+
+/// Parse `/futures/usdt/order_book`; levels are `{p, s}`, `current` in seconds
+fn parse_depth(data: &Value, levels: usize, multiplier: f64) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    let side = |key: &str| -> Result<Vec<PriceLevel>, Box<dyn Error + Send + Sync>> {
+        Ok(data
+            .get(key)
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| format!("Missing {} in response", key))?
+            .iter()
+            .take(levels)
+            .filter_map(|level| {
+                Some(PriceLevel {
+                    price: level.get("p").and_then(as_f64)?,
+                    quantity: level.get("s").and_then(as_f64)? * multiplier,
+                })
+            })
+            .collect())
+    };
+
+    Ok(OrderBookDepth {
+        bids: side("bids")?,
+        asks: side("asks")?,
+        timestamp: data.get("current").and_then(as_f64).map(|t| (t * 1000.0).round() as u64).unwrap_or(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::testnet::mock_server::MockServer;
+    use crate::strategy::types::OrderType;
+
+    fn client(server: &MockServer) -> GateioDemoClient {
+        GateioDemoClient::new(ExchangeCredentials {
+            api_key: "gate-key".to_string(),
+            api_secret: "gate-secret".to_string(),
+            passphrase: None,
+        })
+        .with_base_url(&server.url)
+    }
+
+    fn contract_route(server: &MockServer) {
+        server.route("GET", "/api/v4/futures/usdt/contracts/BTC_USDT", json!({
+            "name": "BTC_USDT", "quanto_multiplier": "0.0001", "order_price_round": "0.1", "in_delisting": false
+        }));
+    }
+
+    #[test]
+    fn test_contract_and_text_mapping() {
+        assert_eq!(gateio_contract("ETHUSDT"), "ETH_USDT");
+        assert_eq!(gateio_symbol("ETH_USDT").as_deref(), Some("ETHUSDT"));
+        assert_eq!(gateio_symbol("BTC_USD"), None);
+        assert_eq!(order_text("arb-18c2f-7").as_deref(), Some("t-arb-18c2f-7"));
+        assert_eq!(order_text("emergency_close_0123456789abcdef"), None);
+    }
+
+    #[tokio::test]
+    async fn test_place_order_signs_and_sends_signed_contracts() {
+        let server = MockServer::start().await;
+        contract_route(&server);
+        server.route("POST", "/api/v4/futures/usdt/orders", json!({
+            "id": 4_000_001u64, "contract": "BTC_USDT", "size": -157, "left": -157, "status": "open"
+        }));
+        let gate = client(&server);
+
+        let placed = gate.place_market_order(SimulatedOrder {
+            id: "arb-3".to_string(),
+            exchange: "gateio".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Short,
+            order_type: OrderType::Market,
+            price: 50_000.0,
+            size: 0.01578,
+            reduce_only: true,
+            ..Default::default()
+        }).await.unwrap();
+
+        assert_eq!(placed.id, "4000001");
+        assert_eq!(placed.size, 0.0157);
+
+        let request = server.last_request("POST", "/api/v4/futures/usdt/orders").unwrap();
+        let body = request.json();
+        assert_eq!(body["contract"], "BTC_USDT");
+        assert_eq!(body["size"], -157);
+        assert_eq!(body["price"], "0");
+        assert_eq!(body["tif"], "ioc");
+        assert_eq!(body["reduce_only"], true);
+        assert_eq!(body["text"], "t-arb-3");
+
+        assert_eq!(request.header("KEY"), "gate-key");
+        let expected = gate.generate_signature(request.header("Timestamp"), "POST", "/api/v4/futures/usdt/orders", "", &request.body);
+        assert_eq!(request.header("SIGN"), expected);
+    }
+
+    #[tokio::test]
+    async fn test_status_depth_leverage_and_errors() {
+        let server = MockServer::start().await;
+        contract_route(&server);
+        server.route("GET", "/api/v4/futures/usdt/orders/77", json!({
+            "id": 77, "size": -200, "left": -50, "status": "finished", "finish_as": "cancelled"
+        }));
+        server.route("GET", "/api/v4/futures/usdt/order_book", json!({
+            "current": 1_700_000_000.123, "asks": [{"p": "100.5", "s": 3000}], "bids": [{"p": "100.4", "s": 1000}]
+        }));
+        server.route("POST", "/api/v4/futures/usdt/positions/BTC_USDT/leverage", json!({"contract": "BTC_USDT", "leverage": "1"}));
+        server.route_with_status("DELETE", "/api/v4/futures/usdt/orders/78", 404,
+            json!({"label": "ORDER_NOT_FOUND", "message": "Order not found"}));
+        let gate = client(&server);
+
+        let info = gate.get_order_status_detailed("77", "BTCUSDT").await.unwrap();
+        assert_eq!(info.status, OrderStatus::Cancelled);
+        assert!((info.filled_quantity - 0.015).abs() < 1e-12);
+        assert!((info.total_quantity - 0.02).abs() < 1e-12);
+
+        let book = gate.get_order_book_depth("BTCUSDT", 5).await.unwrap();
+        assert!((book.asks[0].quantity - 0.3).abs() < 1e-12);
+        assert_eq!(book.timestamp, 1_700_000_000_123);
+        assert_eq!(gate.get_best_bid("BTCUSDT").await.unwrap(), 100.4);
+
+        gate.set_leverage("BTCUSDT", 1).await.unwrap();
+        let request = server.last_request("POST", "/api/v4/futures/usdt/positions/BTC_USDT/leverage").unwrap();
+        assert_eq!(request.query, "leverage=1");
+        let expected = gate.generate_signature(request.header("Timestamp"), "POST",
+            "/api/v4/futures/usdt/positions/BTC_USDT/leverage", "leverage=1", "");
+        assert_eq!(request.header("SIGN"), expected);
+
+        let err = ExecutionError::from(gate.cancel_order("78", "BTCUSDT").await.unwrap_err());
+        assert_eq!(err, ExecutionError::OrderNotFound("Order not found".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_open_positions_and_orders_in_coins() {
+        let server = MockServer::start().await;
+        contract_route(&server);
+        server.route("GET", "/api/v4/futures/usdt/positions", json!([
+            {"contract": "BTC_USDT", "size": -300, "entry_price": "50010.5"},
+            {"contract": "BTC_USDT", "size": 0, "entry_price": "0"}
+        ]));
+        server.route("GET", "/api/v4/futures/usdt/orders", json!([
+            {"id": 91, "contract": "BTC_USDT", "size": 300, "left": 100, "price": "49990.1", "is_reduce_only": true}
+        ]));
+        let gate = client(&server);
+
+        let positions = gate.get_open_positions().await.unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].side, OrderSide::Short);
+        assert!((positions[0].size - 0.03).abs() < 1e-12);
+        assert_eq!(positions[0].entry_price, 50010.5);
+
+        let orders = gate.get_open_orders().await.unwrap();
+        assert_eq!(orders[0].id, "91");
+        assert_eq!(orders[0].side, OrderSide::Long);
+        assert!((orders[0].size - 0.01).abs() < 1e-12);
+        assert!(orders[0].reduce_only);
+        let request = server.last_request("GET", "/api/v4/futures/usdt/orders").unwrap();
+        assert_eq!(request.query, "status=open");
+    }
+}
@@ -0,0 +1,608 @@
+//! Hyperliquid testnet client (perpetuals)
+//!
+//! Reads go to `/info`; orders, cancels and leverage changes are signed L1
+//! actions posted to `/exchange` (see `hyperliquid_signing`). Credentials are
+//! the account address (`api_key`) and the private key of the account or an
+//! approved API wallet (`api_secret`). Order IDs are Hyperliquid `oid`s.
+
+use std::error::Error;
+use std::collections::HashMap;
+use std::sync::Arc;
+use reqwest::Client;
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+use crate::strategy::types::{SimulatedOrder, OrderStatus, OrderSide, OrderStatusInfo, OrderBookDepth, PositionMargin, PriceLevel, TimeInForce, VenuePosition};
+use crate::strategy::testnet_config::ExchangeCredentials;
+use crate::strategy::execution_error::{http_error, ExecutionError};
+use super::{as_f64, format_decimal, open_order, round_to_step};
+use super::hyperliquid_signing::{keccak256, Action, CancelWire, HyperliquidSigner, LimitWire, OrderTypeWire, OrderWire};
+
+const HYPERLIQUID_TESTNET_URL: &str = "https://api.hyperliquid-testnet.xyz";
+
+/// Price offset for market orders, sent as IOC limits through the book
+const MARKET_SLIPPAGE: f64 = 0.05;
+
+/// Perp prices carry at most 5 significant figures and `6 - szDecimals` decimals
+const MAX_SIG_FIGS: i32 = 5;
+const MAX_PERP_DECIMALS: i32 = 6;
+
+/// Universe entry for a perp
+#[derive(Debug, Clone, Copy)]
+struct AssetSpec {
+    /// Asset index used in actions
+    index: u32,
+    sz_decimals: u32,
+}
+
+impl AssetSpec {
+    fn size_step(&self) -> f64 {
+        10f64.powi(-(self.sz_decimals as i32))
+    }
+}
+
+/// Hyperliquid coin for a normalized symbol (BTCUSDT -> BTC)
+pub fn hyperliquid_coin(symbol: &str) -> &str {
+    symbol.trim_end_matches("USDT")
+}
+
+/// Round a price to 5 significant figures and the asset's decimal limit
+fn round_price(price: f64, sz_decimals: u32) -> f64 {
+    if price <= 0.0 {
+        return price;
+    }
+    let magnitude = price.log10().floor() as i32;
+    let decimals = (MAX_SIG_FIGS - 1 - magnitude)
+        .min(MAX_PERP_DECIMALS - sz_decimals as i32)
+        .max(0);
+    let factor = 10f64.powi(decimals);
+    (price * factor).round() / factor
+}
+
+/// Deterministic 16-byte client order ID, so a resubmitted order is recognised
+fn cloid(id: &str) -> Option<String> {
+    (!id.is_empty()).then(|| format!("0x{}", hex::encode(&keccak256(id.as_bytes())[..16])))
+}
+
+/// Map a Hyperliquid rejection onto `ExecutionError`. The API has no error
+/// codes, only a fixed set of messages per failure.
+pub fn hyperliquid_error(message: &str) -> ExecutionError {
+    let text = message.to_string();
+    if message.starts_with("Order was never placed, already canceled, or filled") {
+        ExecutionError::OrderNotFound(text)
+    } else if message.starts_with("Insufficient margin") {
+        ExecutionError::InsufficientBalance(text)
+    } else if message.starts_with("Post only order would have immediately matched") {
+        ExecutionError::PostOnlyRejected(text)
+    } else if message.starts_with("Order has invalid size") || message.starts_with("Order has zero size")
+        || message.starts_with("Order must have minimum value") {
+        ExecutionError::InvalidQuantity(text)
+    } else if message.starts_with("User or API Wallet") {
+        // Signature recovered to an address that is not the account or one of its agents
+        ExecutionError::Auth(text)
+    } else {
+        ExecutionError::rejected("exchange", text)
+    }
+}
+
+pub struct HyperliquidDemoClient {
+    client: Client,
+    base_url: String,
+    /// Account whose state is queried (may differ from the signing API wallet)
+    account: String,
+    signer: HyperliquidSigner,
+    // Cache of the perp universe: coin -> AssetSpec
+    asset_cache: Arc<Mutex<HashMap<String, AssetSpec>>>,
+}
+
+impl HyperliquidDemoClient {
+    pub fn new(credentials: ExchangeCredentials) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let signer = HyperliquidSigner::new(&credentials.api_secret, false)?;
+        let account = if credentials.api_key.is_empty() {
+            signer.address()
+        } else {
+            credentials.api_key.to_lowercase()
+        };
+        Ok(Self {
+            client: Client::new(),
+            base_url: HYPERLIQUID_TESTNET_URL.to_string(),
+            account,
+            signer,
+            asset_cache: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    /// Point the client at another host (e.g. a local mock server)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    async fn info(&self, request: Value) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let response = self
+            .client
+            .post(format!("{}/info", self.base_url))
+            .json(&request)
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(http_error(status.as_u16(), None, &text).into());
+        }
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Sign and submit an action; returns `response.data`
+    async fn exchange(&self, action: &Action) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let nonce = chrono::Utc::now().timestamp_millis() as u64;
+        let signature = self.signer.sign_l1_action(action, None, nonce)?;
+        let body = json!({
+            "action": action,
+            "nonce": nonce,
+            "signature": signature,
+            "vaultAddress": Value::Null,
+        });
+
+        let response = self
+            .client
+            .post(format!("{}/exchange", self.base_url))
+            .json(&body)
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            return Err(http_error(status.as_u16(), None, &text).into());
+        }
+        let response_json: Value = serde_json::from_str(&text)?;
+
+        if response_json.get("status").and_then(|s| s.as_str()) != Some("ok") {
+            // Signature and nonce failures come back as `{"status": "err", "response": "<text>"}`
+            let msg = match response_json.get("response") {
+                Some(Value::String(msg)) => msg.clone(),
+                Some(other) => other.to_string(),
+                None => response_json.to_string(),
+            };
+            return Err(hyperliquid_error(&msg).into());
+        }
+        Ok(response_json
+            .get("response")
+            .and_then(|r| r.get("data"))
+            .cloned()
+            .unwrap_or(Value::Null))
+    }
+
+    /// Universe entry for a symbol (cached)
+    async fn asset_spec(&self, symbol: &str) -> Result<AssetSpec, Box<dyn Error + Send + Sync>> {
+        let coin = hyperliquid_coin(symbol);
+        {
+            let cache = self.asset_cache.lock().await;
+            if let Some(&spec) = cache.get(coin) {
+                return Ok(spec);
+            }
+        }
+
+        let meta = self.info(json!({"type": "meta"})).await?;
+        let universe = meta.get("universe").and_then(|u| u.as_array()).ok_or("Missing universe in meta response")?;
+
+        let mut cache = self.asset_cache.lock().await;
+        for (index, asset) in universe.iter().enumerate() {
+            if let Some(name) = asset.get("name").and_then(|n| n.as_str()) {
+                let sz_decimals = asset.get("szDecimals").and_then(|d| d.as_u64()).unwrap_or(0) as u32;
+                cache.insert(name.to_string(), AssetSpec { index: index as u32, sz_decimals });
+            }
+        }
+        cache.get(coin).copied().ok_or_else(|| format!("Hyperliquid asset {} not found", coin).into())
+    }
+
+    /// Quantity step for a symbol (`10^-szDecimals`)
+    pub async fn get_qty_step(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        Ok(self.asset_spec(symbol).await?.size_step())
+    }
+
+    /// Set isolated leverage for a symbol
+    pub async fn set_leverage(&self, symbol: &str, leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let spec = self.asset_spec(symbol).await?;
+        let leverage = u32::from(leverage.max(1));
+        self.exchange(&Action::UpdateLeverage { asset: spec.index, is_cross: false, leverage }).await?;
+        eprintln!("[HYPERLIQUID DEMO] Leverage set to {}x isolated for {}", leverage, symbol);
+        Ok(())
+    }
+
+    /// Isolated margin is chosen together with the leverage (`isCross: false`)
+    pub async fn set_margin_mode_isolated(&self, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(())
+    }
+
+    async fn submit(&self, order: SimulatedOrder, market: bool) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let spec = self.asset_spec(&order.symbol).await?;
+        let size = round_to_step(order.size, spec.size_step());
+        if size <= 0.0 {
+            return Err(ExecutionError::InvalidQuantity(format!("Order size {} below Hyperliquid step {} for {}", order.size, spec.size_step(), order.symbol)).into());
+        }
+        let is_buy = order.side == OrderSide::Long;
+
+        let limit_price = if market {
+            let reference = if is_buy { self.get_best_ask(&order.symbol).await? } else { self.get_best_bid(&order.symbol).await? };
+            if is_buy { reference * (1.0 + MARKET_SLIPPAGE) } else { reference * (1.0 - MARKET_SLIPPAGE) }
+        } else {
+            order.price
+        };
+        let price = round_price(limit_price, spec.sz_decimals);
+        // `Alo` (add liquidity only) is post-only; there is no FOK
+        let tif = match (market, order.time_in_force) {
+            (true, _) | (false, TimeInForce::Ioc) => "Ioc",
+            (false, TimeInForce::Gtc) => "Gtc",
+            (false, TimeInForce::PostOnly) => "Alo",
+            (false, TimeInForce::Fok) => return Err("Hyperliquid does not support FOK orders".into()),
+        };
+
+        let action = Action::Order {
+            orders: vec![OrderWire {
+                a: spec.index,
+                b: is_buy,
+                p: format_decimal(price),
+                s: format_decimal(size),
+                r: order.is_reduce_only(),
+                t: OrderTypeWire { limit: LimitWire { tif: tif.to_string() } },
+                c: cloid(&order.id),
+            }],
+            grouping: "na".to_string(),
+        };
+        let data = self.exchange(&action).await?;
+        let status = data
+            .get("statuses")
+            .and_then(|s| s.as_array())
+            .and_then(|s| s.first())
+            .ok_or("Missing order status in response")?;
+
+        if let Some(error) = status.get("error").and_then(|e| e.as_str()) {
+            return Err(hyperliquid_error(error).into());
+        }
+
+        let mut placed = order;
+        placed.size = size;
+        placed.created_at = chrono::Utc::now().timestamp_millis() as u64;
+        if !market {
+            placed.price = price;
+        }
+
+        if let Some(resting) = status.get("resting") {
+            placed.id = resting.get("oid").and_then(|o| o.as_u64()).ok_or("Missing oid in response")?.to_string();
+            placed.status = OrderStatus::Pending;
+        } else if let Some(filled) = status.get("filled") {
+            placed.id = filled.get("oid").and_then(|o| o.as_u64()).ok_or("Missing oid in response")?.to_string();
+            placed.fill_price = filled.get("avgPx").and_then(as_f64);
+            placed.filled_at = Some(placed.created_at);
+            placed.status = OrderStatus::Filled;
+        } else {
+            return Err(format!("Unexpected Hyperliquid order status: {}", status).into());
+        }
+        Ok(placed)
+    }
+
+    /// Place a limit order on Hyperliquid testnet
+    pub async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let placed = self.submit(order, false).await?;
+        eprintln!("[HYPERLIQUID DEMO] Order placed: {} | Symbol: {} | Side: {:?} | Size: {} | Price: {}",
+            placed.id, placed.symbol, placed.side, placed.size, placed.price);
+        Ok(placed)
+    }
+
+    /// Place a market order (IOC limit through the book) on Hyperliquid testnet
+    pub async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let placed = self.submit(order, true).await?;
+        eprintln!("[HYPERLIQUID DEMO] Market order placed: {} | Symbol: {} | Size: {}", placed.id, placed.symbol, placed.size);
+        Ok(placed)
+    }
+
+    /// Cancel an order on Hyperliquid testnet
+    pub async fn cancel_order(&self, order_id: &str, symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let spec = self.asset_spec(symbol).await?;
+        let oid: u64 = order_id.parse().map_err(|_| format!("Invalid Hyperliquid order id {}", order_id))?;
+        let data = self.exchange(&Action::Cancel { cancels: vec![CancelWire { a: spec.index, o: oid }] }).await?;
+
+        let status = data.get("statuses").and_then(|s| s.as_array()).and_then(|s| s.first());
+        if let Some(error) = status.and_then(|s| s.get("error")).and_then(|e| e.as_str()) {
+            return Err(hyperliquid_error(error).into());
+        }
+        eprintln!("[HYPERLIQUID DEMO] Order cancelled: {}", order_id);
+        Ok(())
+    }
+
+    /// Get order status from Hyperliquid testnet
+    pub async fn get_order_status(&self, order_id: &str, symbol: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+        Ok(self.get_order_status_detailed(order_id, symbol).await?.status)
+    }
+
+    /// Get order status with filled quantity from Hyperliquid testnet
+    pub async fn get_order_status_detailed(&self, order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+        let oid: u64 = order_id.parse().map_err(|_| format!("Invalid Hyperliquid order id {}", order_id))?;
+        let data = self.info(json!({"type": "orderStatus", "user": self.account, "oid": oid})).await?;
+        parse_order_status(&data)
+    }
+
+    /// Withdrawable USDC of the account
+    pub async fn get_balance(&self) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let data = self.info(json!({"type": "clearinghouseState", "user": self.account})).await?;
+        data.get("withdrawable")
+            .and_then(as_f64)
+            .ok_or_else(|| "Failed to extract withdrawable balance from response".into())
+    }
+
+    /// Open perp positions from `clearinghouseState`
+    pub async fn get_open_positions(&self) -> Result<Vec<VenuePosition>, Box<dyn Error + Send + Sync>> {
+        let data = self.info(json!({"type": "clearinghouseState", "user": self.account})).await?;
+        Ok(parse_positions(&data))
+    }
+
+    /// Resting orders of the account
+    pub async fn get_open_orders(&self) -> Result<Vec<SimulatedOrder>, Box<dyn Error + Send + Sync>> {
+        let data = self.info(json!({"type": "frontendOpenOrders", "user": self.account})).await?;
+        Ok(parse_open_orders(&data))
+    }
+
+    /// Get order book depth (top N levels)
+    pub async fn get_order_book_depth(&self, symbol: &str, levels: usize) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+        let data = self.info(json!({"type": "l2Book", "coin": hyperliquid_coin(symbol)})).await?;
+        parse_l2_book(&data, levels)
+    }
+
+    /// Best bid from the order book
+    pub async fn get_best_bid(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.bids.first().map(|l| l.price).ok_or_else(|| "No bids available in order book".into())
+    }
+
+    /// Best ask from the order book
+    pub async fn get_best_ask(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.asks.first().map(|l| l.price).ok_or_else(|| "No asks available in order book".into())
+    }
+
+    /// Check if a symbol is listed in the perp universe
+    pub async fn is_symbol_tradeable(&self, symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        match self.asset_spec(symbol).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                eprintln!("[HYPERLIQUID VALIDATION] {} not tradeable: {}", symbol, e);
+                Ok(false)
+            }
+        }
+    }
+}
+
+/// Parse an `orderStatus` info response. `sz` is the unfilled remainder.
+fn parse_order_status(data: &Value) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    if data.get("status").and_then(|s| s.as_str()) != Some("order") {
+        return Err(ExecutionError::OrderNotFound(format!("Hyperliquid order not found: {}", data)).into());
+    }
+    let wrapper = data.get("order").ok_or("Missing order in response")?;
+    let order = wrapper.get("order").ok_or("Missing order details in response")?;
+    let state = wrapper.get("status").and_then(|s| s.as_str()).ok_or("Missing order status in response")?;
+
+    let total = order.get("origSz").and_then(as_f64).unwrap_or(0.0);
+    let remaining = order.get("sz").and_then(as_f64).unwrap_or(0.0);
+    let filled = (total - remaining).max(0.0);
+
+    let status = match state {
+        "filled" => OrderStatus::Filled,
+        "open" | "triggered" => OrderStatus::Pending,
+        _ => OrderStatus::Cancelled,
+    };
+    Ok(OrderStatusInfo::new(status, filled, total))
+}
+
+/// Parse an `l2Book` info response; `levels` is `[bids, asks]` of `{px, sz, n}`
+fn parse_l2_book(data: &Value, levels: usize) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    let sides = data.get("levels").and_then(|l| l.as_array()).ok_or("Missing levels in response")?;
+    let side = |index: usize| -> Vec<PriceLevel> {
+        sides
+            .get(index)
+            .and_then(|s| s.as_array())
+            .map(|entries| {
+                entries
+                    .iter()
+                    .take(levels)
+                    .filter_map(|e| Some(PriceLevel { price: e.get("px").and_then(as_f64)?, quantity: e.get("sz").and_then(as_f64)? }))
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    Ok(OrderBookDepth {
+        bids: side(0),
+        asks: side(1),
+        timestamp: data.get("time").and_then(|t| t.as_u64()).unwrap_or(0),
+    })
+}
+
+/// Parse `assetPositions` of a `clearinghouseState` response; `szi` is signed
+fn parse_positions(data: &Value) -> Vec<VenuePosition> {
+    data.get("assetPositions")
+        .and_then(|v| v.as_array())
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|e| {
+                    let position = e.get("position")?;
+                    let size = position.get("szi").and_then(as_f64)?;
+                    (size != 0.0).then(|| VenuePosition {
+                        exchange: "hyperliquid".to_string(),
+                        symbol: format!("{}USDT", position.get("coin").and_then(|c| c.as_str()).unwrap_or_default()),
+                        side: if size > 0.0 { OrderSide::Long } else { OrderSide::Short },
+                        size: size.abs(),
+                        entry_price: position.get("entryPx").and_then(as_f64).unwrap_or(0.0),
+                        // No mark price per position: derive it from the position value
+                        margin: PositionMargin::new(
+                            position.get("positionValue").and_then(as_f64).map(|value| value / size.abs()),
+                            position.get("liquidationPx").and_then(as_f64),
+                            position.get("marginUsed").and_then(as_f64),
+                            position.get("leverage").and_then(|l| l.get("value")).and_then(as_f64),
+                        ),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parse a `frontendOpenOrders` response; side `B` is a bid, `sz` is the remainder
+fn parse_open_orders(data: &Value) -> Vec<SimulatedOrder> {
+    data.as_array()
+        .map(|entries| {
+            entries
+                .iter()
+                .filter_map(|o| {
+                    Some(open_order(
+                        "hyperliquid",
+                        o.get("oid")?.as_u64()?.to_string(),
+                        format!("{}USDT", o.get("coin")?.as_str()?),
+                        if o.get("side").and_then(|v| v.as_str()) == Some("B") { OrderSide::Long } else { OrderSide::Short },
+                        o.get("limitPx").and_then(as_f64).unwrap_or(0.0),
+                        o.get("sz").and_then(as_f64).unwrap_or(0.0),
+                        o.get("reduceOnly").and_then(|v| v.as_bool()).unwrap_or(false),
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::testnet::mock_server::MockServer;
+    use crate::strategy::types::OrderType;
+
+    const KEY: &str = "0x0123456789012345678901234567890123456789012345678901234567890123";
+
+    fn client(server: &MockServer) -> HyperliquidDemoClient {
+        HyperliquidDemoClient::new(ExchangeCredentials {
+            api_key: String::new(),
+            api_secret: KEY.to_string(),
+            passphrase: None,
+        })
+        .unwrap()
+        .with_base_url(&server.url)
+    }
+
+    #[test]
+    fn test_price_rounding_and_cloid() {
+        assert_eq!(round_price(1670.123, 4), 1670.1);
+        assert_eq!(round_price(123_456.7, 5), 123_457.0);
+        assert_eq!(round_price(0.0123456, 0), 0.012346);
+        assert_eq!(round_price(0.0123456, 2), 0.0123);
+        assert_eq!(cloid("arb-1").unwrap().len(), 34);
+        assert_eq!(cloid(""), None);
+    }
+
+    #[tokio::test]
+    async fn test_order_is_signed_over_the_posted_action() {
+        let server = MockServer::start().await;
+        server.route("POST", "/info", json!({"universe": [{"name": "BTC", "szDecimals": 5}, {"name": "ETH", "szDecimals": 4}]}));
+        server.route("POST", "/exchange", json!({
+            "status": "ok",
+            "response": {"type": "order", "data": {"statuses": [{"resting": {"oid": 77}}]}}
+        }));
+        let hl = client(&server);
+
+        let placed = hl.place_order(SimulatedOrder {
+            id: "arb-9".to_string(),
+            exchange: "hyperliquid".to_string(),
+            symbol: "ETHUSDT".to_string(),
+            side: OrderSide::Long,
+            order_type: OrderType::Limit,
+            price: 1670.123,
+            size: 0.01478,
+            time_in_force: TimeInForce::PostOnly,
+            reduce_only: true,
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(placed.id, "77");
+        assert_eq!(placed.size, 0.0147);
+        assert_eq!(placed.price, 1670.1);
+
+        let body = server.last_request("POST", "/exchange").unwrap().json();
+        assert_eq!(body["action"]["type"], "order");
+        assert_eq!(body["action"]["orders"][0]["a"], 1);
+        assert_eq!(body["action"]["orders"][0]["p"], "1670.1");
+        assert_eq!(body["action"]["orders"][0]["s"], "0.0147");
+        assert_eq!(body["action"]["orders"][0]["r"], true);
+        assert_eq!(body["action"]["orders"][0]["t"]["limit"]["tif"], "Alo");
+        assert_eq!(body["action"]["grouping"], "na");
+
+        // Re-sign the expected action with the posted nonce
+        let expected_action = Action::Order {
+            orders: vec![OrderWire {
+                a: 1,
+                b: true,
+                p: "1670.1".to_string(),
+                s: "0.0147".to_string(),
+                r: true,
+                t: OrderTypeWire { limit: LimitWire { tif: "Alo".to_string() } },
+                c: cloid("arb-9"),
+            }],
+            grouping: "na".to_string(),
+        };
+        let nonce = body["nonce"].as_u64().unwrap();
+        let expected = HyperliquidSigner::new(KEY, false).unwrap().sign_l1_action(&expected_action, None, nonce).unwrap();
+        assert_eq!(body["signature"]["r"], expected.r);
+        assert_eq!(body["signature"]["s"], expected.s);
+        assert_eq!(body["signature"]["v"], expected.v);
+    }
+
+    #[test]
+    fn test_parse_order_status_and_book() {
+        let status = json!({"status": "order", "order": {
+            "order": {"coin": "ETH", "oid": 77, "sz": "0.01", "origSz": "0.04"}, "status": "canceled"
+        }});
+        let info = parse_order_status(&status).unwrap();
+        assert_eq!(info.status, OrderStatus::Cancelled);
+        assert!((info.filled_quantity - 0.03).abs() < 1e-12);
+        let missing = ExecutionError::from(parse_order_status(&json!({"status": "unknownOid"})).unwrap_err());
+        assert!(matches!(missing, ExecutionError::OrderNotFound(_)));
+        assert!(matches!(
+            hyperliquid_error("Post only order would have immediately matched, bbo was 1670.0@1670.1. asset=4"),
+            ExecutionError::PostOnlyRejected(_),
+        ));
+
+        let book = json!({"coin": "ETH", "time": 1_700_000_000_000u64, "levels": [
+            [{"px": "1670.0", "sz": "2.5", "n": 3}],
+            [{"px": "1670.1", "sz": "1.0", "n": 1}, {"px": "1670.2", "sz": "4.0", "n": 2}]
+        ]});
+        let depth = parse_l2_book(&book, 1).unwrap();
+        assert_eq!(depth.bids[0].quantity, 2.5);
+        assert_eq!(depth.asks.len(), 1);
+        assert_eq!(depth.asks[0].price, 1670.1);
+        assert_eq!(depth.timestamp, 1_700_000_000_000);
+    }
+
+    #[test]
+    fn test_parse_positions_and_open_orders() {
+        let state = json!({"withdrawable": "100.0", "assetPositions": [
+            {"type": "oneWay", "position": {"coin": "ETH", "szi": "-0.25", "entryPx": "1670.5", "positionValue": "425.0",
+                "liquidationPx": "2125.0", "marginUsed": "85.0", "leverage": {"type": "isolated", "value": 5}}},
+            {"type": "oneWay", "position": {"coin": "BTC", "szi": "0.0", "entryPx": null}}
+        ]});
+        let positions = parse_positions(&state);
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].symbol, "ETHUSDT");
+        assert_eq!(positions[0].side, OrderSide::Short);
+        assert_eq!(positions[0].size, 0.25);
+        assert_eq!(positions[0].entry_price, 1670.5);
+        // Mark 1700 from the position value; a 25% rise liquidates the short
+        assert_eq!(positions[0].margin.mark_price, Some(1700.0));
+        assert_eq!(positions[0].margin.margin_usd, Some(85.0));
+        assert_eq!(positions[0].margin.leverage, Some(5.0));
+        assert_eq!(positions[0].margin.liquidation_distance(OrderSide::Short), Some(0.25));
+
+        let orders = parse_open_orders(&json!([
+            {"coin": "ETH", "side": "B", "limitPx": "1669.0", "sz": "0.25", "oid": 91, "reduceOnly": true}
+        ]));
+        assert_eq!(orders[0].id, "91");
+        assert_eq!(orders[0].symbol, "ETHUSDT");
+        assert_eq!(orders[0].side, OrderSide::Long);
+        assert!(orders[0].reduce_only);
+    }
+}
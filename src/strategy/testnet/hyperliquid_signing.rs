@@ -0,0 +1,262 @@
+//! Hyperliquid L1 action signing
+//!
+//! Exchange actions are msgpack-encoded and hashed together with the nonce
+//! and vault flag into a `connectionId`. That hash is then signed as an
+//! EIP-712 "phantom agent" (`Agent(string source,bytes32 connectionId)`)
+//! under the `Exchange` domain with chain id 1337. `source` is `a` on
+//! mainnet and `b` on testnet.
+
+use std::error::Error;
+use k256::ecdsa::SigningKey;
+use serde::Serialize;
+use sha3::{Digest, Keccak256};
+
+/// Chain id of the L1 action signing domain (not the Arbitrum chain id)
+const L1_CHAIN_ID: u64 = 1337;
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    Keccak256::digest(data).into()
+}
+
+/// `{"limit": {"tif": ...}}`
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderTypeWire {
+    pub limit: LimitWire,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LimitWire {
+    /// `Gtc`, `Ioc` or `Alo`
+    pub tif: String,
+}
+
+/// Order as sent on the wire; field order matters for the msgpack hash
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderWire {
+    /// Asset index in the perp universe
+    pub a: u32,
+    /// Buy side
+    pub b: bool,
+    /// Limit price
+    pub p: String,
+    /// Size in coins
+    pub s: String,
+    /// Reduce-only
+    pub r: bool,
+    pub t: OrderTypeWire,
+    /// Client order ID (`0x` + 16 bytes hex)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub c: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CancelWire {
+    pub a: u32,
+    pub o: u64,
+}
+
+/// Exchange actions used by the execution client
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum Action {
+    Order {
+        orders: Vec<OrderWire>,
+        grouping: String,
+    },
+    Cancel {
+        cancels: Vec<CancelWire>,
+    },
+    #[serde(rename_all = "camelCase")]
+    UpdateLeverage {
+        asset: u32,
+        is_cross: bool,
+        leverage: u32,
+    },
+}
+
+/// ECDSA signature in the `{r, s, v}` form the exchange endpoint expects
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ActionSignature {
+    pub r: String,
+    pub s: String,
+    pub v: u8,
+}
+
+/// keccak256(msgpack(action) || nonce (u64 BE) || vault flag [|| vault address])
+pub fn action_hash(action: &Action, vault_address: Option<&[u8; 20]>, nonce: u64) -> Result<[u8; 32], Box<dyn Error + Send + Sync>> {
+    let mut data = rmp_serde::to_vec_named(action)?;
+    data.extend_from_slice(&nonce.to_be_bytes());
+    match vault_address {
+        Some(address) => {
+            data.push(1);
+            data.extend_from_slice(address);
+        }
+        None => data.push(0),
+    }
+    Ok(keccak256(&data))
+}
+
+/// EIP-712 domain separator for `EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)`
+pub fn domain_separator(name: &str, version: &str, chain_id: u64, verifying_contract: &[u8; 20]) -> [u8; 32] {
+    let type_hash = keccak256(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)");
+    let mut encoded = Vec::with_capacity(32 * 5);
+    encoded.extend_from_slice(&type_hash);
+    encoded.extend_from_slice(&keccak256(name.as_bytes()));
+    encoded.extend_from_slice(&keccak256(version.as_bytes()));
+    encoded.extend_from_slice(&u256_word(chain_id));
+    encoded.extend_from_slice(&address_word(verifying_contract));
+    keccak256(&encoded)
+}
+
+/// keccak256(0x1901 || domainSeparator || hashStruct(message))
+pub fn typed_data_digest(domain_separator: &[u8; 32], struct_hash: &[u8; 32]) -> [u8; 32] {
+    let mut encoded = Vec::with_capacity(66);
+    encoded.extend_from_slice(&[0x19, 0x01]);
+    encoded.extend_from_slice(domain_separator);
+    encoded.extend_from_slice(struct_hash);
+    keccak256(&encoded)
+}
+
+/// hashStruct of the phantom agent
+fn agent_struct_hash(source: &str, connection_id: &[u8; 32]) -> [u8; 32] {
+    let type_hash = keccak256(b"Agent(string source,bytes32 connectionId)");
+    let mut encoded = Vec::with_capacity(96);
+    encoded.extend_from_slice(&type_hash);
+    encoded.extend_from_slice(&keccak256(source.as_bytes()));
+    encoded.extend_from_slice(connection_id);
+    keccak256(&encoded)
+}
+
+fn u256_word(value: u64) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&value.to_be_bytes());
+    word
+}
+
+fn address_word(address: &[u8; 20]) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[12..].copy_from_slice(address);
+    word
+}
+
+/// Parse a `0x`-prefixed or bare hex string into a fixed-size array
+pub fn parse_hex<const N: usize>(value: &str) -> Result<[u8; N], Box<dyn Error + Send + Sync>> {
+    let bytes = hex::decode(value.trim_start_matches("0x"))?;
+    bytes.try_into().map_err(|_| format!("Expected {} bytes of hex, got {}", N, value).into())
+}
+
+/// Sign a 32-byte digest, returning `v` as 27/28
+pub fn sign_digest(key: &SigningKey, digest: &[u8; 32]) -> Result<ActionSignature, Box<dyn Error + Send + Sync>> {
+    let (signature, recovery_id) = key.sign_prehash_recoverable(digest)?;
+    let (r, s) = signature.split_bytes();
+    Ok(ActionSignature {
+        r: format!("0x{}", hex::encode(r)),
+        s: format!("0x{}", hex::encode(s)),
+        v: 27 + recovery_id.to_byte(),
+    })
+}
+
+/// Ethereum address of a signing key
+pub fn address_of(key: &SigningKey) -> [u8; 20] {
+    let point = key.verifying_key().to_encoded_point(false);
+    let hash = keccak256(&point.as_bytes()[1..]);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..]);
+    address
+}
+
+/// Signs L1 actions with an account or API-wallet key
+pub struct HyperliquidSigner {
+    key: SigningKey,
+    is_mainnet: bool,
+}
+
+impl HyperliquidSigner {
+    /// `private_key` is 32 bytes of hex, with or without `0x`
+    pub fn new(private_key: &str, is_mainnet: bool) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let bytes: [u8; 32] = parse_hex(private_key)?;
+        Ok(Self {
+            key: SigningKey::from_slice(&bytes)?,
+            is_mainnet,
+        })
+    }
+
+    pub fn address(&self) -> String {
+        format!("0x{}", hex::encode(address_of(&self.key)))
+    }
+
+    /// EIP-712 digest of the phantom agent for an action hash
+    pub fn l1_digest(&self, connection_id: &[u8; 32]) -> [u8; 32] {
+        let source = if self.is_mainnet { "a" } else { "b" };
+        let domain = domain_separator("Exchange", "1", L1_CHAIN_ID, &[0u8; 20]);
+        typed_data_digest(&domain, &agent_struct_hash(source, connection_id))
+    }
+
+    pub fn sign_l1_action(&self, action: &Action, vault_address: Option<&[u8; 20]>, nonce: u64) -> Result<ActionSignature, Box<dyn Error + Send + Sync>> {
+        let connection_id = action_hash(action, vault_address, nonce)?;
+        sign_digest(&self.key, &self.l1_digest(&connection_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn eth_order(sz: &str, px: &str, asset: u32, tif: &str) -> Action {
+        Action::Order {
+            orders: vec![OrderWire {
+                a: asset,
+                b: true,
+                p: px.to_string(),
+                s: sz.to_string(),
+                r: false,
+                t: OrderTypeWire { limit: LimitWire { tif: tif.to_string() } },
+                c: None,
+            }],
+            grouping: "na".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_eip712_mail_vector() {
+        // Example from the EIP-712 specification
+        let contract: [u8; 20] = parse_hex("0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC").unwrap();
+        let domain = domain_separator("Ether Mail", "1", 1, &contract);
+        assert_eq!(hex::encode(domain), "f2cee375fa42b42143804025fc449deafd50cc031ca257e0b194a650a912090f");
+
+        let struct_hash: [u8; 32] = parse_hex("c52c0ee5d84264471806290a3f2c4cecfc5490626bf912d01f240d7a274b371e").unwrap();
+        let digest = typed_data_digest(&domain, &struct_hash);
+        assert_eq!(hex::encode(digest), "be609aee343fb3c4b28e1df9e632fca64fcfaede20f02e86244efddf30957bd2");
+
+        let key = SigningKey::from_slice(&keccak256(b"cow")).unwrap();
+        assert_eq!(hex::encode(address_of(&key)), "cd2a3d9f938e13cd947ec05abc7fe734df8dd826");
+        let signature = sign_digest(&key, &digest).unwrap();
+        assert_eq!(signature.v, 28);
+        assert_eq!(signature.r, "0x4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d");
+        assert_eq!(signature.s, "0x07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562");
+    }
+
+    #[test]
+    fn test_phantom_agent_connection_id_vector() {
+        // Recorded from a production order (Python SDK test vector)
+        let action = eth_order("0.0147", "1670.1", 4, "Ioc");
+        let hash = action_hash(&action, None, 1_677_777_606_040).unwrap();
+        assert_eq!(hex::encode(hash), "0fcbeda5ae3c4950a548021552a4fea2226858c4453571bf3f24ba017eac2908");
+    }
+
+    #[test]
+    fn test_l1_order_signature_vectors() {
+        let key = "0x0123456789012345678901234567890123456789012345678901234567890123";
+        let action = eth_order("100", "100", 1, "Gtc");
+
+        let mainnet = HyperliquidSigner::new(key, true).unwrap().sign_l1_action(&action, None, 0).unwrap();
+        assert_eq!(mainnet.r, "0xd65369825a9df5d80099e513cce430311d7d26ddf477f5b3a33d2806b100d78e");
+        assert_eq!(mainnet.s, "0x2b54116ff64054968aa237c20ca9ff68000f977c93289157748a3162b6ea940e");
+        assert_eq!(mainnet.v, 28);
+
+        let testnet = HyperliquidSigner::new(key, false).unwrap().sign_l1_action(&action, None, 0).unwrap();
+        assert_eq!(testnet.r, "0x82b2ba28e76b3d761093aaded1b1cdad4960b3af30212b343fb2e6cdfa4e3d54");
+        assert_eq!(testnet.s, "0x6b53878fc99d26047f4d7e8c90eb98955a109f44209163f52d8dc4278cbbd9f5");
+        assert_eq!(testnet.v, 27);
+    }
+}
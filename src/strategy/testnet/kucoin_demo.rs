@@ -0,0 +1,577 @@
+//! KuCoin Futures sandbox client (USDT-margined perpetuals)
+//!
+//! Contracts are named `{BASE}USDTM` (BTC is `XBT`) and sized in lots of
+//! `multiplier` coins. This client takes and reports quantities in coins and
+//! converts at the edge. Uses API key version 2, where the passphrase is sent
+//! HMAC-signed.
+
+use std::error::Error;
+use std::collections::HashMap;
+use std::sync::Arc;
+use reqwest::Client;
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+use crate::strategy::types::{SimulatedOrder, OrderStatus, OrderSide, OrderStatusInfo, OrderBookDepth, PositionMargin, PriceLevel, TimeInForce, VenuePosition};
+use crate::strategy::testnet_config::ExchangeCredentials;
+use crate::strategy::execution_error::ExecutionError;
+use super::{as_f64, format_decimal, open_order, round_to_step, round_to_tick};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use base64::{engine::general_purpose, Engine as _};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const KUCOIN_DEMO_URL: &str = "https://api-sandbox-futures.kucoin.com";
+
+/// Sizing rules of a futures contract
+#[derive(Debug, Clone, Copy)]
+struct ContractSpec {
+    /// Coins per lot (`multiplier`)
+    multiplier: f64,
+    /// Lot step (`lotSize`)
+    lot_size: f64,
+    /// Price tick (`tickSize`)
+    tick_size: f64,
+}
+
+impl ContractSpec {
+    /// Coin quantity -> lots, rounded down to the lot step
+    fn lots(&self, quantity: f64) -> f64 {
+        round_to_step(quantity / self.multiplier, self.lot_size)
+    }
+
+    fn coins(&self, lots: f64) -> f64 {
+        round_to_step(lots * self.multiplier, self.multiplier * self.lot_size)
+    }
+}
+
+/// KuCoin Futures contract for a normalized symbol (BTCUSDT -> XBTUSDTM)
+pub fn kucoin_contract(symbol: &str) -> String {
+    match symbol.trim_end_matches("USDT") {
+        "BTC" => "XBTUSDTM".to_string(),
+        base => format!("{}USDTM", base),
+    }
+}
+
+/// Normalized symbol for a USDT-margined contract (XBTUSDTM -> BTCUSDT)
+fn kucoin_symbol(contract: &str) -> Option<String> {
+    match contract.strip_suffix("USDTM")? {
+        "XBT" => Some("BTCUSDT".to_string()),
+        base => Some(format!("{}USDT", base)),
+    }
+}
+
+/// `clientOid` is mandatory and limited to 40 characters
+fn client_oid(id: &str) -> String {
+    if !id.is_empty() && id.len() <= 40 {
+        id.to_string()
+    } else {
+        uuid::Uuid::new_v4().simple().to_string()
+    }
+}
+
+/// Map a KuCoin Futures error `code` onto `ExecutionError`
+pub fn kucoin_error(code: &str, msg: &str) -> ExecutionError {
+    let text = msg.to_string();
+    match code {
+        // Order does not exist or is no longer cancellable
+        "100004" => ExecutionError::OrderNotFound(text),
+        "200004" | "300003" => ExecutionError::InsufficientBalance(text),
+        "429000" => ExecutionError::RateLimited { retry_after: None },
+        "400001" | "400002" | "400003" | "400004" | "400005" | "400006" | "400007" => ExecutionError::Auth(text),
+        _ => ExecutionError::rejected(code, text),
+    }
+}
+
+pub struct KucoinDemoClient {
+    client: Client,
+    base_url: String,
+    api_key: String,
+    api_secret: String,
+    passphrase: String,
+    // Cache of contract sizing rules: contract -> ContractSpec
+    contract_cache: Arc<Mutex<HashMap<String, ContractSpec>>>,
+    // Leverage sent with orders: contract -> leverage (1x when unset)
+    leverage: Arc<Mutex<HashMap<String, u8>>>,
+}
+
+impl KucoinDemoClient {
+    pub fn new(credentials: ExchangeCredentials) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: KUCOIN_DEMO_URL.to_string(),
+            api_key: credentials.api_key,
+            api_secret: credentials.api_secret,
+            passphrase: credentials.passphrase.unwrap_or_default(),
+            contract_cache: Arc::new(Mutex::new(HashMap::new())),
+            leverage: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Point the client at another host (e.g. a local mock server)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    fn timestamp() -> String {
+        chrono::Utc::now().timestamp_millis().to_string()
+    }
+
+    fn hmac_base64(&self, message: &str) -> String {
+        let mut mac = HmacSha256::new_from_slice(self.api_secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(message.as_bytes());
+        general_purpose::STANDARD.encode(mac.finalize().into_bytes())
+    }
+
+    /// Generate signature for KuCoin API
+    /// Format: base64(HMAC-SHA256(timestamp + method + endpoint[?query] + body))
+    fn generate_signature(&self, timestamp: &str, method: &str, endpoint: &str, body: &str) -> String {
+        self.hmac_base64(&format!("{}{}{}{}", timestamp, method, endpoint, body))
+    }
+
+    /// Fail on a non-`200000` response code, otherwise return `data`
+    fn response_data(response_json: Value) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let code = response_json.get("code").and_then(|v| v.as_str()).unwrap_or("");
+        if code != "200000" {
+            let msg = response_json
+                .get("msg")
+                .and_then(|v| v.as_str())
+                .unwrap_or("Unknown error");
+            return Err(kucoin_error(code, msg).into());
+        }
+        Ok(response_json.get("data").cloned().unwrap_or(Value::Null))
+    }
+
+    async fn signed_request(&self, method: &str, endpoint: &str, body: Option<&Value>) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let timestamp = Self::timestamp();
+        let body_str = body.map(|b| b.to_string()).unwrap_or_default();
+        let signature = self.generate_signature(&timestamp, method, endpoint, &body_str);
+
+        let url = format!("{}{}", self.base_url, endpoint);
+        let request = match method {
+            "POST" => self.client.post(&url).header("Content-Type", "application/json").body(body_str),
+            "DELETE" => self.client.delete(&url),
+            _ => self.client.get(&url),
+        };
+
+        let response = request
+            .header("KC-API-KEY", &self.api_key)
+            .header("KC-API-SIGN", signature)
+            .header("KC-API-TIMESTAMP", &timestamp)
+            .header("KC-API-PASSPHRASE", self.hmac_base64(&self.passphrase))
+            .header("KC-API-KEY-VERSION", "2")
+            .send()
+            .await?;
+
+        Self::response_data(serde_json::from_str(&response.text().await?)?)
+    }
+
+    async fn public_get(&self, endpoint: &str) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let response = self.client.get(format!("{}{}", self.base_url, endpoint)).send().await?;
+        Self::response_data(serde_json::from_str(&response.text().await?)?)
+    }
+
+    async fn contract(&self, contract: &str) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        self.public_get(&format!("/api/v1/contracts/{}", contract)).await
+    }
+
+    /// Sizing rules for a contract (cached)
+    async fn contract_spec(&self, symbol: &str) -> Result<ContractSpec, Box<dyn Error + Send + Sync>> {
+        let contract = kucoin_contract(symbol);
+        {
+            let cache = self.contract_cache.lock().await;
+            if let Some(&spec) = cache.get(&contract) {
+                return Ok(spec);
+            }
+        }
+
+        let data = self.contract(&contract).await?;
+        let spec = ContractSpec {
+            multiplier: data.get("multiplier").and_then(as_f64).ok_or("Failed to extract multiplier from response")?,
+            lot_size: data.get("lotSize").and_then(as_f64).unwrap_or(1.0),
+            tick_size: data.get("tickSize").and_then(as_f64).unwrap_or(0.0),
+        };
+
+        let mut cache = self.contract_cache.lock().await;
+        cache.insert(contract, spec);
+        Ok(spec)
+    }
+
+    /// Quantity step in coins (one lot)
+    pub async fn get_qty_step(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(symbol).await?;
+        Ok(spec.coins(spec.lot_size))
+    }
+
+    /// Isolated-margin leverage travels with each order (`leverage`), so it is
+    /// only remembered here
+    pub async fn set_leverage(&self, symbol: &str, leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let leverage = leverage.max(1);
+        self.leverage.lock().await.insert(kucoin_contract(symbol), leverage);
+        eprintln!("[KUCOIN DEMO] Leverage {}x will be sent per order for {}", leverage, symbol);
+        Ok(())
+    }
+
+    /// Switch a contract to isolated margin
+    pub async fn set_margin_mode_isolated(&self, symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let body = json!({
+            "symbol": kucoin_contract(symbol),
+            "marginMode": "ISOLATED",
+        });
+        self.signed_request("POST", "/api/v2/position/changeMarginMode", Some(&body)).await?;
+        Ok(())
+    }
+
+    /// Request body for an order, with size and price rounded to the contract rules.
+    /// Returns the body plus the coin size and price actually sent.
+    async fn order_body(&self, order: &SimulatedOrder, market: bool) -> Result<(Value, f64, f64), Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(&order.symbol).await?;
+        let lots = spec.lots(order.size);
+        if lots <= 0.0 {
+            return Err(ExecutionError::InvalidQuantity(format!("Order size {} below one KuCoin lot ({}) for {}",
+                order.size, spec.coins(spec.lot_size), order.symbol)).into());
+        }
+        let price = round_to_tick(order.price, spec.tick_size);
+        let leverage = self.leverage.lock().await.get(&kucoin_contract(&order.symbol)).copied().unwrap_or(1);
+
+        let mut body = json!({
+            // Reused client order ID lets KuCoin reject duplicate submissions
+            "clientOid": client_oid(&order.id),
+            "symbol": kucoin_contract(&order.symbol),
+            "side": match order.side {
+                OrderSide::Long => "buy",
+                OrderSide::Short => "sell",
+            },
+            "type": if market { "market" } else { "limit" },
+            "leverage": leverage,
+            "marginMode": "ISOLATED",
+            "size": lots as u64,
+        });
+        if !market {
+            body["price"] = json!(format_decimal(price));
+            // Post-only is a separate flag and only valid with GTC; there is no FOK
+            match order.time_in_force {
+                TimeInForce::Gtc => body["timeInForce"] = json!("GTC"),
+                TimeInForce::Ioc => body["timeInForce"] = json!("IOC"),
+                TimeInForce::PostOnly => {
+                    body["timeInForce"] = json!("GTC");
+                    body["postOnly"] = json!(true);
+                }
+                TimeInForce::Fok => return Err("KuCoin Futures does not support FOK orders".into()),
+            }
+        }
+        if order.close_position {
+            // Closes the whole position; side and size are ignored by the venue
+            body["closeOrder"] = json!(true);
+        } else if order.reduce_only {
+            body["reduceOnly"] = json!(true);
+        }
+        Ok((body, spec.coins(lots), price))
+    }
+
+    async fn submit(&self, order: SimulatedOrder, market: bool) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let (body, size, price) = self.order_body(&order, market).await?;
+        let data = self.signed_request("POST", "/api/v1/orders", Some(&body)).await?;
+
+        let order_id = data
+            .get("orderId")
+            .and_then(|v| v.as_str())
+            .ok_or("Failed to extract order ID from response")?
+            .to_string();
+
+        let mut placed = order;
+        placed.id = order_id;
+        placed.size = size;
+        if !market {
+            placed.price = price;
+        }
+        placed.status = OrderStatus::Pending;
+        placed.created_at = chrono::Utc::now().timestamp_millis() as u64;
+        Ok(placed)
+    }
+
+    /// Place a limit order on KuCoin Futures sandbox
+    pub async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let placed = self.submit(order, false).await?;
+        eprintln!("[KUCOIN DEMO] Order placed: {} | Symbol: {} | Side: {:?} | Size: {} | Price: {}",
+            placed.id, placed.symbol, placed.side, placed.size, placed.price);
+        Ok(placed)
+    }
+
+    /// Place a market order on KuCoin Futures sandbox
+    pub async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let placed = self.submit(order, true).await?;
+        eprintln!("[KUCOIN DEMO] Market order placed: {} | Symbol: {} | Size: {}", placed.id, placed.symbol, placed.size);
+        Ok(placed)
+    }
+
+    /// Cancel an order on KuCoin Futures sandbox
+    pub async fn cancel_order(&self, order_id: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.signed_request("DELETE", &format!("/api/v1/orders/{}", order_id), None)
+            .await?;
+        eprintln!("[KUCOIN DEMO] Order cancelled: {}", order_id);
+        Ok(())
+    }
+
+    /// Get order status from KuCoin Futures sandbox
+    pub async fn get_order_status(&self, order_id: &str, symbol: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+        Ok(self.get_order_status_detailed(order_id, symbol).await?.status)
+    }
+
+    /// Get order status with filled quantity (in coins) from KuCoin Futures sandbox
+    pub async fn get_order_status_detailed(&self, order_id: &str, symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(symbol).await?;
+        let data = self.signed_request("GET", &format!("/api/v1/orders/{}", order_id), None).await?;
+        parse_order(&data, spec.multiplier)
+    }
+
+    /// Open futures positions, converted from lots to coins
+    pub async fn get_open_positions(&self) -> Result<Vec<VenuePosition>, Box<dyn Error + Send + Sync>> {
+        let data = self.signed_request("GET", "/api/v1/positions", None).await?;
+        let mut positions = Vec::new();
+        for p in data.as_array().cloned().unwrap_or_default() {
+            let Some(symbol) = p.get("symbol").and_then(|v| v.as_str()).and_then(kucoin_symbol) else {
+                continue;
+            };
+            // `currentQty` is signed lots
+            let lots = p.get("currentQty").and_then(as_f64).unwrap_or(0.0);
+            if lots == 0.0 {
+                continue;
+            }
+            let spec = self.contract_spec(&symbol).await?;
+            positions.push(VenuePosition {
+                exchange: "kucoin".to_string(),
+                symbol,
+                side: if lots > 0.0 { OrderSide::Long } else { OrderSide::Short },
+                size: spec.coins(lots.abs()),
+                entry_price: p.get("avgEntryPrice").and_then(as_f64).unwrap_or(0.0),
+                margin: PositionMargin::new(
+                    p.get("markPrice").and_then(as_f64),
+                    p.get("liquidationPrice").and_then(as_f64),
+                    p.get("posMargin").and_then(as_f64),
+                    p.get("realLeverage").and_then(as_f64),
+                ),
+            });
+        }
+        Ok(positions)
+    }
+
+    /// Working futures orders, converted from lots to coins
+    pub async fn get_open_orders(&self) -> Result<Vec<SimulatedOrder>, Box<dyn Error + Send + Sync>> {
+        let data = self.signed_request("GET", "/api/v1/orders?status=active", None).await?;
+        let mut orders = Vec::new();
+        for o in data.get("items").and_then(|v| v.as_array()).cloned().unwrap_or_default() {
+            let Some(symbol) = o.get("symbol").and_then(|v| v.as_str()).and_then(kucoin_symbol) else {
+                continue;
+            };
+            let spec = self.contract_spec(&symbol).await?;
+            let remaining = o.get("size").and_then(as_f64).unwrap_or(0.0) - o.get("filledSize").and_then(as_f64).unwrap_or(0.0);
+            orders.push(open_order(
+                "kucoin",
+                o.get("id").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                symbol,
+                if o.get("side").and_then(|v| v.as_str()) == Some("buy") { OrderSide::Long } else { OrderSide::Short },
+                o.get("price").and_then(as_f64).unwrap_or(0.0),
+                spec.coins(remaining),
+                o.get("reduceOnly").and_then(|v| v.as_bool()).unwrap_or(false)
+                    || o.get("closeOrder").and_then(|v| v.as_bool()).unwrap_or(false),
+            ));
+        }
+        Ok(orders)
+    }
+
+    /// Get available USDT balance from KuCoin Futures sandbox
+    pub async fn get_balance(&self) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let data = self.signed_request("GET", "/api/v1/account-overview?currency=USDT", None).await?;
+        data.get("availableBalance")
+            .and_then(as_f64)
+            .ok_or_else(|| "Failed to extract USDT balance from response".into())
+    }
+
+    /// Get order book depth (top N levels), quantities in coins
+    pub async fn get_order_book_depth(&self, symbol: &str, levels: usize) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(symbol).await?;
+        // Partial snapshots come in 20 or 100 levels
+        let snapshot = if levels <= 20 { "depth20" } else { "depth100" };
+        let data = self.public_get(&format!("/api/v1/level2/{}?symbol={}", snapshot, kucoin_contract(symbol))).await?;
+        parse_depth(&data, levels, spec.multiplier)
+    }
+
+    /// Best bid from the order book
+    pub async fn get_best_bid(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.bids.first().map(|l| l.price).ok_or_else(|| "No bids available in order book".into())
+    }
+
+    /// Best ask from the order book
+    pub async fn get_best_ask(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.asks.first().map(|l| l.price).ok_or_else(|| "No asks available in order book".into())
+    }
+
+    /// Check if a symbol is an open contract on KuCoin Futures
+    pub async fn is_symbol_tradeable(&self, symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        let contract = kucoin_contract(symbol);
+        match self.contract(&contract).await {
+            Ok(data) => {
+                let status = data.get("status").and_then(|s| s.as_str()).unwrap_or("");
+                eprintln!("[KUCOIN VALIDATION] {} found: status={}", contract, status);
+                Ok(status == "Open")
+            }
+            Err(e) => {
+                eprintln!("[KUCOIN VALIDATION] {} not tradeable: {}", contract, e);
+                Ok(false)
+            }
+        }
+    }
+}
+
+/// Parse an order from `/api/v1/orders/{orderId}`, converting lots to coins.
+/// A `done` order that did not fill completely was cancelled.
+fn parse_order(data: &Value, multiplier: f64) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    let state = data.get("status").and_then(|v| v.as_str()).ok_or("Missing order status in response")?;
+    let total_lots = data.get("size").and_then(as_f64).unwrap_or(0.0);
+    let filled_lots = data.get("filledSize").and_then(as_f64).unwrap_or(0.0);
+
+    let status = match state {
+        "done" if total_lots > 0.0 && filled_lots >= total_lots => OrderStatus::Filled,
+        "done" => OrderStatus::Cancelled,
+        _ => OrderStatus::Pending,
+    };
+    Ok(OrderStatusInfo::new(status, filled_lots * multiplier, total_lots * multiplier))
+}
+
+/// Parse a level2 snapshot; levels are `[price, lots]`, `ts` in nanoseconds
+fn parse_depth(data: &Value, levels: usize, multiplier: f64) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    let side = |key: &str| -> Result<Vec<PriceLevel>, Box<dyn Error + Send + Sync>> {
+        Ok(data
+            .get(key)
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| format!("Missing {} in response", key))?
+            .iter()
+            .take(levels)
+            .filter_map(|level| {
+                let arr = level.as_array()?;
+                Some(PriceLevel { price: as_f64(arr.first()?)?, quantity: as_f64(arr.get(1)?)? * multiplier })
+            })
+            .collect())
+    };
+
+    Ok(OrderBookDepth {
+        bids: side("bids")?,
+        asks: side("asks")?,
+        timestamp: data.get("ts").and_then(as_f64).map(|t| (t / 1_000_000.0) as u64).unwrap_or(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::testnet::mock_server::MockServer;
+    use crate::strategy::types::OrderType;
+
+    fn client(server: &MockServer) -> KucoinDemoClient {
+        KucoinDemoClient::new(ExchangeCredentials {
+            api_key: "kc-key".to_string(),
+            api_secret: "kc-secret".to_string(),
+            passphrase: Some("kc-pass".to_string()),
+        })
+        .with_base_url(&server.url)
+    }
+
+    fn contract_route(server: &MockServer) {
+        server.route("GET", "/api/v1/contracts/XBTUSDTM", json!({
+            "code": "200000",
+            "data": {"symbol": "XBTUSDTM", "multiplier": 0.001, "lotSize": 1, "tickSize": 0.1, "status": "Open"}
+        }));
+    }
+
+    #[test]
+    fn test_contract_mapping() {
+        assert_eq!(kucoin_contract("BTCUSDT"), "XBTUSDTM");
+        assert_eq!(kucoin_contract("ETHUSDT"), "ETHUSDTM");
+        assert_eq!(kucoin_symbol("XBTUSDTM").as_deref(), Some("BTCUSDT"));
+        assert_eq!(kucoin_symbol("ETHUSDTM").as_deref(), Some("ETHUSDT"));
+        assert_eq!(kucoin_symbol("XBTUSDM"), None);
+        assert_eq!(client_oid("arb-1"), "arb-1");
+        assert_eq!(client_oid("").len(), 32);
+    }
+
+    #[tokio::test]
+    async fn test_place_order_signs_and_converts_to_lots() {
+        let server = MockServer::start().await;
+        contract_route(&server);
+        server.route("POST", "/api/v1/orders", json!({"code": "200000", "data": {"orderId": "kc-1"}}));
+        let kucoin = client(&server);
+
+        let placed = kucoin.place_order(SimulatedOrder {
+            id: "arb-2".to_string(),
+            exchange: "kucoin".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Long,
+            order_type: OrderType::Limit,
+            price: 50_000.06,
+            size: 0.0157,
+            time_in_force: TimeInForce::PostOnly,
+            ..Default::default()
+        }).await.unwrap();
+
+        assert_eq!(placed.id, "kc-1");
+        assert_eq!(placed.size, 0.015);
+        assert_eq!(placed.price, 50_000.1);
+
+        let request = server.last_request("POST", "/api/v1/orders").unwrap();
+        let body = request.json();
+        assert_eq!(body["symbol"], "XBTUSDTM");
+        assert_eq!(body["clientOid"], "arb-2");
+        assert_eq!(body["side"], "buy");
+        assert_eq!(body["size"], 15);
+        assert_eq!(body["marginMode"], "ISOLATED");
+        assert_eq!(body["timeInForce"], "GTC");
+        assert_eq!(body["postOnly"], true);
+        assert!(body.get("reduceOnly").is_none());
+
+        assert_eq!(request.header("KC-API-KEY-VERSION"), "2");
+        assert_eq!(request.header("KC-API-PASSPHRASE"), kucoin.hmac_base64("kc-pass"));
+        let expected = kucoin.generate_signature(request.header("KC-API-TIMESTAMP"), "POST", "/api/v1/orders", &request.body);
+        assert_eq!(request.header("KC-API-SIGN"), expected);
+    }
+
+    #[tokio::test]
+    async fn test_status_depth_balance_and_errors() {
+        let server = MockServer::start().await;
+        contract_route(&server);
+        server.route("GET", "/api/v1/orders/kc-1", json!({
+            "code": "200000", "data": {"id": "kc-1", "status": "done", "size": 20, "filledSize": 5}
+        }));
+        server.route("GET", "/api/v1/level2/depth20", json!({
+            "code": "200000",
+            "data": {"symbol": "XBTUSDTM", "asks": [[100.5, 300]], "bids": [[100.4, 100]], "ts": 1_700_000_000_000_000_000u64}
+        }));
+        server.route("GET", "/api/v1/account-overview", json!({"code": "200000", "data": {"availableBalance": 1234.5}}));
+        server.route("DELETE", "/api/v1/orders/kc-2", json!({"code": "100004", "msg": "order not exist"}));
+        let kucoin = client(&server);
+
+        let info = kucoin.get_order_status_detailed("kc-1", "BTCUSDT").await.unwrap();
+        assert_eq!(info.status, OrderStatus::Cancelled);
+        assert!((info.filled_quantity - 0.005).abs() < 1e-12);
+        assert!((info.total_quantity - 0.02).abs() < 1e-12);
+
+        let book = kucoin.get_order_book_depth("BTCUSDT", 5).await.unwrap();
+        assert!((book.asks[0].quantity - 0.3).abs() < 1e-12);
+        assert_eq!(book.timestamp, 1_700_000_000_000);
+        assert_eq!(kucoin.get_best_ask("BTCUSDT").await.unwrap(), 100.5);
+
+        assert_eq!(kucoin.get_balance().await.unwrap(), 1234.5);
+        let request = server.last_request("GET", "/api/v1/account-overview").unwrap();
+        let expected = kucoin.generate_signature(request.header("KC-API-TIMESTAMP"), "GET",
+            "/api/v1/account-overview?currency=USDT", "");
+        assert_eq!(request.header("KC-API-SIGN"), expected);
+
+        let err = ExecutionError::from(kucoin.cancel_order("kc-2", "BTCUSDT").await.unwrap_err());
+        assert_eq!(err, ExecutionError::OrderNotFound("order not exist".to_string()));
+        assert!(kucoin.is_symbol_tradeable("BTCUSDT").await.unwrap());
+    }
+}
@@ -0,0 +1,142 @@
+//! Minimal HTTP server for exercising the demo clients offline
+//!
+//! Serves canned JSON responses keyed by method and path, and records every
+//! request so tests can check signing headers and request bodies.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use serde_json::Value;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+
+#[derive(Debug, Clone)]
+pub struct RecordedRequest {
+    pub method: String,
+    /// Path without the query string
+    pub path: String,
+    pub query: String,
+    /// Header names lowercased
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl RecordedRequest {
+    pub fn header(&self, name: &str) -> &str {
+        self.headers.get(&name.to_lowercase()).map(|s| s.as_str()).unwrap_or("")
+    }
+
+    pub fn json(&self) -> Value {
+        serde_json::from_str(&self.body).unwrap_or(Value::Null)
+    }
+}
+
+/// One canned response: (method, path, status, body)
+type Route = (String, String, u16, Value);
+
+pub struct MockServer {
+    pub url: String,
+    routes: Arc<Mutex<Vec<Route>>>,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+}
+
+impl MockServer {
+    pub async fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind mock server");
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let routes: Arc<Mutex<Vec<Route>>> = Arc::new(Mutex::new(Vec::new()));
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let (routes_task, requests_task) = (routes.clone(), requests.clone());
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let (routes, requests) = (routes_task.clone(), requests_task.clone());
+                tokio::spawn(async move {
+                    let _ = serve(stream, routes, requests).await;
+                });
+            }
+        });
+
+        Self { url, routes, requests }
+    }
+
+    /// Respond to `method path` with `body` (HTTP 200)
+    pub fn route(&self, method: &str, path: &str, body: Value) {
+        self.route_with_status(method, path, 200, body);
+    }
+
+    pub fn route_with_status(&self, method: &str, path: &str, status: u16, body: Value) {
+        self.routes.lock().unwrap().push((method.to_string(), path.to_string(), status, body));
+    }
+
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Last request sent to `method path`
+    pub fn last_request(&self, method: &str, path: &str) -> Option<RecordedRequest> {
+        self.requests().into_iter().rev().find(|r| r.method == method && r.path == path)
+    }
+}
+
+async fn serve(
+    mut stream: TcpStream,
+    routes: Arc<Mutex<Vec<Route>>>,
+    requests: Arc<Mutex<Vec<RecordedRequest>>>,
+) -> std::io::Result<()> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    let header_end = loop {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            return Ok(());
+        }
+        buf.extend_from_slice(&chunk[..n]);
+        if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+            break pos + 4;
+        }
+    };
+
+    let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or("").split_whitespace();
+    let method = request_line.next().unwrap_or("").to_string();
+    let target = request_line.next().unwrap_or("").to_string();
+    let headers: HashMap<String, String> = lines
+        .filter_map(|line| line.split_once(':'))
+        .map(|(k, v)| (k.trim().to_lowercase(), v.trim().to_string()))
+        .collect();
+
+    let content_length: usize = headers.get("content-length").and_then(|v| v.parse().ok()).unwrap_or(0);
+    while buf.len() < header_end + content_length {
+        let n = stream.read(&mut chunk).await?;
+        if n == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+    let body = String::from_utf8_lossy(&buf[header_end..]).to_string();
+
+    let (path, query) = match target.split_once('?') {
+        Some((p, q)) => (p.to_string(), q.to_string()),
+        None => (target.clone(), String::new()),
+    };
+
+    let (status, response) = routes
+        .lock()
+        .unwrap()
+        .iter()
+        .find(|(m, p, _, _)| *m == method && *p == path)
+        .map(|(_, _, status, body)| (*status, body.to_string()))
+        .unwrap_or((404, r#"{"error":"no route"}"#.to_string()));
+
+    requests.lock().unwrap().push(RecordedRequest { method, path, query, headers, body });
+
+    let reply = format!(
+        "HTTP/1.1 {} MOCK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        response.len(),
+        response
+    );
+    stream.write_all(reply.as_bytes()).await?;
+    stream.shutdown().await
+}
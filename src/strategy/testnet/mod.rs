@@ -1,2 +1,67 @@
 pub mod bybit_testnet;
+pub mod bybit_private_ws;
 pub mod bitget_demo;
+pub mod binance_demo;
+pub mod okx_demo;
+pub mod kucoin_demo;
+pub mod gateio_demo;
+pub mod hyperliquid_demo;
+pub mod hyperliquid_signing;
+pub mod paradex_demo;
+pub mod paradex_signing;
+#[cfg(test)]
+mod mock_server;
+
+use serde_json::Value;
+use crate::strategy::types::{OrderSide, OrderStatus, OrderType, SimulatedOrder};
+
+/// Round a quantity down to the exchange step
+fn round_to_step(quantity: f64, step: f64) -> f64 {
+    if step <= 0.0 {
+        return quantity;
+    }
+    // Small epsilon keeps exact multiples from flooring one step down
+    let steps = (quantity / step + 1e-9).floor();
+    let decimals = (-step.log10()).ceil().max(0.0) as i32;
+    let factor = 10f64.powi(decimals);
+    (steps * step * factor).round() / factor
+}
+
+/// Round a price to the nearest tick
+fn round_to_tick(price: f64, tick: f64) -> f64 {
+    if tick <= 0.0 {
+        return price;
+    }
+    let decimals = (-tick.log10()).ceil().max(0.0) as i32;
+    let factor = 10f64.powi(decimals);
+    ((price / tick).round() * tick * factor).round() / factor
+}
+
+fn format_decimal(value: f64) -> String {
+    format!("{:.8}", value)
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_string()
+}
+
+/// Numbers arrive as strings or JSON numbers depending on the endpoint
+fn as_f64(v: &Value) -> Option<f64> {
+    v.as_str().and_then(|s| s.parse().ok()).or_else(|| v.as_f64())
+}
+
+/// A working order as listed by a venue's open-orders endpoint.
+/// `size` is what is still resting on the book, in coins.
+fn open_order(exchange: &str, id: String, symbol: String, side: OrderSide, price: f64, size: f64, reduce_only: bool) -> SimulatedOrder {
+    SimulatedOrder {
+        id,
+        exchange: exchange.to_string(),
+        symbol,
+        side,
+        order_type: OrderType::Limit,
+        price,
+        size,
+        status: OrderStatus::Pending,
+        reduce_only,
+        ..Default::default()
+    }
+}
@@ -0,0 +1,669 @@
+//! OKX v5 demo trading client (USDT-margined perpetual swaps)
+//!
+//! Requests go to the regular REST host with `x-simulated-trading: 1`.
+//! Swap sizes are in contracts of `ctVal` coins; this client takes and
+//! reports quantities in coins and converts at the edge.
+
+use std::error::Error;
+use std::collections::HashMap;
+use std::sync::Arc;
+use reqwest::Client;
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+use crate::strategy::types::{SimulatedOrder, OrderStatus, OrderSide, OrderStatusInfo, OrderBookDepth, PositionMargin, PriceLevel, TimeInForce, VenuePosition};
+use crate::strategy::testnet_config::ExchangeCredentials;
+use crate::strategy::execution_error::ExecutionError;
+use super::{as_f64, format_decimal, open_order, round_to_step, round_to_tick};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use base64::{engine::general_purpose, Engine as _};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const OKX_DEMO_URL: &str = "https://www.okx.com";
+
+/// Sizing rules of a swap instrument
+#[derive(Debug, Clone, Copy)]
+struct ContractSpec {
+    /// Coins per contract (`ctVal`)
+    ct_val: f64,
+    /// Contract step (`lotSz`)
+    lot_size: f64,
+    /// Price tick (`tickSz`)
+    tick_size: f64,
+}
+
+impl ContractSpec {
+    /// Coin quantity -> contracts, rounded down to the lot size
+    fn contracts(&self, quantity: f64) -> f64 {
+        round_to_step(quantity / self.ct_val, self.lot_size)
+    }
+
+    fn coins(&self, contracts: f64) -> f64 {
+        round_to_step(contracts * self.ct_val, self.ct_val * self.lot_size)
+    }
+}
+
+/// OKX instrument ID for a normalized symbol (BTCUSDT -> BTC-USDT-SWAP)
+pub fn okx_inst_id(symbol: &str) -> String {
+    format!("{}-USDT-SWAP", symbol.trim_end_matches("USDT"))
+}
+
+/// Normalized symbol for a USDT swap instrument ID (BTC-USDT-SWAP -> BTCUSDT)
+fn okx_symbol(inst_id: &str) -> Option<String> {
+    inst_id.strip_suffix("-USDT-SWAP").map(|base| format!("{}USDT", base))
+}
+
+/// `clOrdId` allows 1-32 alphanumerics only
+fn client_order_id(id: &str) -> Option<String> {
+    let cleaned: String = id.chars().filter(|c| c.is_ascii_alphanumeric()).collect();
+    Some(cleaned).filter(|c| !c.is_empty() && c.len() <= 32)
+}
+
+/// `ordType` of a limit order: OKX folds time in force into the order type.
+/// A crossing `post_only` order is cancelled by the venue.
+fn okx_limit_ord_type(tif: TimeInForce) -> &'static str {
+    match tif {
+        TimeInForce::Gtc => "limit",
+        TimeInForce::Ioc => "ioc",
+        TimeInForce::Fok => "fok",
+        TimeInForce::PostOnly => "post_only",
+    }
+}
+
+/// Map an OKX error code (`code` or per-item `sCode`) onto `ExecutionError`
+pub fn okx_error(code: &str, msg: &str) -> ExecutionError {
+    let text = msg.to_string();
+    match code {
+        // Order does not exist; cancel failed because the order is already done
+        "51603" | "51400" | "51401" => ExecutionError::OrderNotFound(text),
+        "51402" => ExecutionError::AlreadyFilled(text),
+        // Amend failed: order already filled, cancelled or unknown
+        "51503" => ExecutionError::OrderNotFound(text),
+        "51008" => ExecutionError::InsufficientBalance(text),
+        "50011" | "50061" => ExecutionError::RateLimited { retry_after: None },
+        "50111" | "50112" | "50113" | "50114" => ExecutionError::Auth(text),
+        // Below minimum size / not a multiple of the lot size
+        "51020" | "51121" => ExecutionError::InvalidQuantity(text),
+        _ => ExecutionError::rejected(code, text),
+    }
+}
+
+pub struct OkxDemoClient {
+    client: Client,
+    base_url: String,
+    api_key: String,
+    api_secret: String,
+    passphrase: String,
+    // Cache of instrument sizing rules: instId -> ContractSpec
+    contract_cache: Arc<Mutex<HashMap<String, ContractSpec>>>,
+}
+
+impl OkxDemoClient {
+    pub fn new(credentials: ExchangeCredentials) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: OKX_DEMO_URL.to_string(),
+            api_key: credentials.api_key,
+            api_secret: credentials.api_secret,
+            passphrase: credentials.passphrase.unwrap_or_default(),
+            contract_cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Point the client at another host (e.g. a local mock server)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// ISO-8601 timestamp with milliseconds, as OKX expects in `OK-ACCESS-TIMESTAMP`
+    fn timestamp() -> String {
+        chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+    }
+
+    /// Generate signature for OKX API
+    /// Format: base64(HMAC-SHA256(timestamp + method + requestPath[?query] + body))
+    fn generate_signature(&self, timestamp: &str, method: &str, request_path: &str, body: &str) -> String {
+        let mut mac = HmacSha256::new_from_slice(self.api_secret.as_bytes())
+            .expect("HMAC can take key of any size");
+        mac.update(format!("{}{}{}{}", timestamp, method, request_path, body).as_bytes());
+        general_purpose::STANDARD.encode(mac.finalize().into_bytes())
+    }
+
+    /// Fail on a non-`0` response code, otherwise return `data`
+    fn response_data(response_json: Value) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let code = response_json.get("code").and_then(|v| v.as_str()).unwrap_or("");
+        if code != "0" {
+            // Batch-style endpoints put the reason on the item
+            let item = response_json
+                .get("data")
+                .and_then(|d| d.as_array())
+                .and_then(|a| a.first())
+                .filter(|item| item.get("sCode").and_then(|v| v.as_str()).is_some_and(|c| c != "0"));
+            let (code, msg) = match item {
+                Some(item) => (
+                    item.get("sCode").and_then(|v| v.as_str()).unwrap_or(code),
+                    item.get("sMsg").and_then(|v| v.as_str()).unwrap_or("Unknown error"),
+                ),
+                None => (code, response_json.get("msg").and_then(|v| v.as_str()).unwrap_or("Unknown error")),
+            };
+            return Err(okx_error(code, msg).into());
+        }
+        Ok(response_json.get("data").cloned().unwrap_or(Value::Null))
+    }
+
+    /// First element of `data`
+    fn first_item(data: Value) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        data.as_array()
+            .and_then(|a| a.first())
+            .cloned()
+            .ok_or_else(|| "Empty data in OKX response".into())
+    }
+
+    async fn signed_request(&self, method: &str, request_path: &str, body: Option<&Value>) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let timestamp = Self::timestamp();
+        let body_str = body.map(|b| b.to_string()).unwrap_or_default();
+        let signature = self.generate_signature(&timestamp, method, request_path, &body_str);
+
+        let url = format!("{}{}", self.base_url, request_path);
+        let request = if method == "POST" {
+            self.client.post(&url).header("Content-Type", "application/json").body(body_str)
+        } else {
+            self.client.get(&url)
+        };
+
+        let response = request
+            .header("OK-ACCESS-KEY", &self.api_key)
+            .header("OK-ACCESS-SIGN", signature)
+            .header("OK-ACCESS-TIMESTAMP", &timestamp)
+            .header("OK-ACCESS-PASSPHRASE", &self.passphrase)
+            .header("x-simulated-trading", "1")
+            .send()
+            .await?;
+
+        Self::response_data(serde_json::from_str(&response.text().await?)?)
+    }
+
+    async fn public_get(&self, request_path: &str) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let response = self
+            .client
+            .get(format!("{}{}", self.base_url, request_path))
+            .header("x-simulated-trading", "1")
+            .send()
+            .await?;
+        Self::response_data(serde_json::from_str(&response.text().await?)?)
+    }
+
+    async fn instrument(&self, inst_id: &str) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let data = self.public_get(&format!("/api/v5/public/instruments?instType=SWAP&instId={}", inst_id)).await?;
+        Self::first_item(data).map_err(|_| format!("OKX instrument {} not found", inst_id).into())
+    }
+
+    /// Sizing rules for an instrument (cached)
+    async fn contract_spec(&self, symbol: &str) -> Result<ContractSpec, Box<dyn Error + Send + Sync>> {
+        let inst_id = okx_inst_id(symbol);
+        {
+            let cache = self.contract_cache.lock().await;
+            if let Some(&spec) = cache.get(&inst_id) {
+                return Ok(spec);
+            }
+        }
+
+        let instrument = self.instrument(&inst_id).await?;
+        let spec = ContractSpec {
+            ct_val: instrument.get("ctVal").and_then(as_f64).ok_or("Failed to extract ctVal from response")?,
+            lot_size: instrument.get("lotSz").and_then(as_f64).unwrap_or(1.0),
+            tick_size: instrument.get("tickSz").and_then(as_f64).unwrap_or(0.0),
+        };
+
+        let mut cache = self.contract_cache.lock().await;
+        cache.insert(inst_id, spec);
+        Ok(spec)
+    }
+
+    /// Quantity step in coins (one lot)
+    pub async fn get_qty_step(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(symbol).await?;
+        Ok(spec.coins(spec.lot_size))
+    }
+
+    /// Set isolated leverage for a symbol
+    pub async fn set_leverage(&self, symbol: &str, leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let body = json!({
+            "instId": okx_inst_id(symbol),
+            "lever": leverage.to_string(),
+            "mgnMode": "isolated",
+        });
+        self.signed_request("POST", "/api/v5/account/set-leverage", Some(&body)).await?;
+        eprintln!("[OKX DEMO] Leverage set to {}x for {}", leverage, symbol);
+        Ok(())
+    }
+
+    /// OKX picks the margin mode per order (`tdMode`), which is always isolated here
+    pub async fn set_margin_mode_isolated(&self, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(())
+    }
+
+    /// Request body for an order, with size and price rounded to the instrument rules.
+    /// Returns the body plus the coin size and price actually sent.
+    async fn order_body(&self, order: &SimulatedOrder, market: bool) -> Result<(Value, f64, f64), Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(&order.symbol).await?;
+        let contracts = spec.contracts(order.size);
+        if contracts <= 0.0 {
+            return Err(ExecutionError::InvalidQuantity(format!("Order size {} below one OKX lot ({} contracts of {}) for {}",
+                order.size, spec.lot_size, spec.ct_val, order.symbol)).into());
+        }
+        let price = round_to_tick(order.price, spec.tick_size);
+
+        let mut body = json!({
+            "instId": okx_inst_id(&order.symbol),
+            "tdMode": "isolated",
+            "side": match order.side {
+                OrderSide::Long => "buy",
+                OrderSide::Short => "sell",
+            },
+            "ordType": if market { "market" } else { okx_limit_ord_type(order.time_in_force) },
+            "sz": format_decimal(contracts),
+        });
+        if !market {
+            body["px"] = json!(format_decimal(price));
+        }
+        if order.is_reduce_only() {
+            body["reduceOnly"] = json!(true);
+        }
+        // Client order ID lets OKX reject duplicate submissions
+        if let Some(cl_ord_id) = client_order_id(&order.id) {
+            body["clOrdId"] = json!(cl_ord_id);
+        }
+        Ok((body, spec.coins(contracts), price))
+    }
+
+    async fn submit(&self, order: SimulatedOrder, market: bool) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let (body, size, price) = self.order_body(&order, market).await?;
+        let data = self.signed_request("POST", "/api/v5/trade/order", Some(&body)).await?;
+        let item = Self::first_item(data)?;
+
+        let order_id = item
+            .get("ordId")
+            .and_then(|v| v.as_str())
+            .filter(|id| !id.is_empty())
+            .ok_or("Failed to extract order ID from response")?
+            .to_string();
+
+        let mut placed = order;
+        placed.id = order_id;
+        placed.size = size;
+        if !market {
+            placed.price = price;
+        }
+        placed.status = OrderStatus::Pending;
+        placed.created_at = chrono::Utc::now().timestamp_millis() as u64;
+        Ok(placed)
+    }
+
+    /// Place a limit order on OKX demo
+    pub async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let placed = self.submit(order, false).await?;
+        eprintln!("[OKX DEMO] Order placed: {} | Symbol: {} | Side: {:?} | Size: {} | Price: {}",
+            placed.id, placed.symbol, placed.side, placed.size, placed.price);
+        Ok(placed)
+    }
+
+    /// Place a market order on OKX demo
+    pub async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let placed = self.submit(order, true).await?;
+        eprintln!("[OKX DEMO] Market order placed: {} | Symbol: {} | Size: {}", placed.id, placed.symbol, placed.size);
+        Ok(placed)
+    }
+
+    /// Cancel an order on OKX demo
+    pub async fn cancel_order(&self, order_id: &str, symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let body = json!({
+            "instId": okx_inst_id(symbol),
+            "ordId": order_id,
+        });
+        self.signed_request("POST", "/api/v5/trade/cancel-order", Some(&body))
+            .await?;
+        eprintln!("[OKX DEMO] Order cancelled: {}", order_id);
+        Ok(())
+    }
+
+    /// Amend a resting order's price (and coin size if given) in place on OKX demo
+    pub async fn amend_order(&self, order_id: &str, symbol: &str, price: f64, size: Option<f64>) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(symbol).await?;
+        let new_px = format_decimal(round_to_tick(price, spec.tick_size));
+        let mut body = json!({
+            "instId": okx_inst_id(symbol),
+            "ordId": order_id,
+            "newPx": new_px,
+        });
+        if let Some(size) = size {
+            let contracts = spec.contracts(size);
+            if contracts <= 0.0 {
+                return Err(ExecutionError::InvalidQuantity(format!("Amended size {} below one OKX lot for {}", size, symbol)).into());
+            }
+            body["newSz"] = json!(format_decimal(contracts));
+        }
+        self.signed_request("POST", "/api/v5/trade/amend-order", Some(&body))
+            .await?;
+        eprintln!("[OKX DEMO] Order amended: {} | Price: {}", order_id, new_px);
+        Ok(())
+    }
+
+    /// Get order status from OKX demo
+    pub async fn get_order_status(&self, order_id: &str, symbol: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+        Ok(self.get_order_status_detailed(order_id, symbol).await?.status)
+    }
+
+    /// Get order status with filled quantity (in coins) from OKX demo
+    pub async fn get_order_status_detailed(&self, order_id: &str, symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(symbol).await?;
+        let path = format!("/api/v5/trade/order?instId={}&ordId={}", okx_inst_id(symbol), order_id);
+        let data = self.signed_request("GET", &path, None).await?;
+        parse_order(&Self::first_item(data)?, spec.ct_val)
+    }
+
+    /// Open swap positions, converted from contracts to coins
+    pub async fn get_open_positions(&self) -> Result<Vec<VenuePosition>, Box<dyn Error + Send + Sync>> {
+        let data = self.signed_request("GET", "/api/v5/account/positions?instType=SWAP", None).await?;
+        let mut positions = Vec::new();
+        for p in data.as_array().cloned().unwrap_or_default() {
+            let Some(symbol) = p.get("instId").and_then(|v| v.as_str()).and_then(okx_symbol) else {
+                continue;
+            };
+            let contracts = p.get("pos").and_then(as_f64).unwrap_or(0.0);
+            if contracts == 0.0 {
+                continue;
+            }
+            // Net mode signs `pos`; long/short mode reports the side in `posSide`
+            let side = match p.get("posSide").and_then(|v| v.as_str()) {
+                Some("long") => OrderSide::Long,
+                Some("short") => OrderSide::Short,
+                _ if contracts > 0.0 => OrderSide::Long,
+                _ => OrderSide::Short,
+            };
+            let spec = self.contract_spec(&symbol).await?;
+            positions.push(VenuePosition {
+                exchange: "okx".to_string(),
+                symbol,
+                side,
+                size: contracts.abs() * spec.ct_val,
+                entry_price: p.get("avgPx").and_then(as_f64).unwrap_or(0.0),
+                // `margin` is only set for isolated positions, `imr` for cross
+                margin: PositionMargin::new(
+                    p.get("markPx").and_then(as_f64),
+                    p.get("liqPx").and_then(as_f64),
+                    p.get("margin").and_then(as_f64).or_else(|| p.get("imr").and_then(as_f64)),
+                    p.get("lever").and_then(as_f64),
+                ),
+            });
+        }
+        Ok(positions)
+    }
+
+    /// Working swap orders, converted from contracts to coins
+    pub async fn get_open_orders(&self) -> Result<Vec<SimulatedOrder>, Box<dyn Error + Send + Sync>> {
+        let data = self.signed_request("GET", "/api/v5/trade/orders-pending?instType=SWAP", None).await?;
+        let mut orders = Vec::new();
+        for o in data.as_array().cloned().unwrap_or_default() {
+            let Some(symbol) = o.get("instId").and_then(|v| v.as_str()).and_then(okx_symbol) else {
+                continue;
+            };
+            let spec = self.contract_spec(&symbol).await?;
+            let remaining = o.get("sz").and_then(as_f64).unwrap_or(0.0) - o.get("accFillSz").and_then(as_f64).unwrap_or(0.0);
+            orders.push(open_order(
+                "okx",
+                o.get("ordId").and_then(|v| v.as_str()).unwrap_or_default().to_string(),
+                symbol,
+                if o.get("side").and_then(|v| v.as_str()) == Some("buy") { OrderSide::Long } else { OrderSide::Short },
+                o.get("px").and_then(as_f64).unwrap_or(0.0),
+                remaining * spec.ct_val,
+                o.get("reduceOnly").and_then(|v| v.as_str()) == Some("true"),
+            ));
+        }
+        Ok(orders)
+    }
+
+    /// Get available USDT balance from OKX demo
+    pub async fn get_balance(&self) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let data = self.signed_request("GET", "/api/v5/account/balance?ccy=USDT", None).await?;
+        Self::first_item(data)?
+            .get("details")
+            .and_then(|d| d.as_array())
+            .and_then(|details| details.iter().find(|d| d.get("ccy").and_then(|c| c.as_str()) == Some("USDT")))
+            .and_then(|usdt| usdt.get("availBal"))
+            .and_then(as_f64)
+            .ok_or_else(|| "Failed to extract USDT balance from response".into())
+    }
+
+    /// Get order book depth (top N levels), quantities in coins
+    pub async fn get_order_book_depth(&self, symbol: &str, levels: usize) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+        let spec = self.contract_spec(symbol).await?;
+        let data = self.public_get(&format!("/api/v5/market/books?instId={}&sz={}", okx_inst_id(symbol), levels.clamp(1, 400))).await?;
+        parse_depth(&Self::first_item(data)?, levels, spec.ct_val)
+    }
+
+    /// Best bid from the order book
+    pub async fn get_best_bid(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.bids.first().map(|l| l.price).ok_or_else(|| "No bids available in order book".into())
+    }
+
+    /// Best ask from the order book
+    pub async fn get_best_ask(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.asks.first().map(|l| l.price).ok_or_else(|| "No asks available in order book".into())
+    }
+
+    /// Check if a symbol is a live USDT swap on OKX
+    pub async fn is_symbol_tradeable(&self, symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        let inst_id = okx_inst_id(symbol);
+        match self.instrument(&inst_id).await {
+            Ok(instrument) => {
+                let state = instrument.get("state").and_then(|s| s.as_str()).unwrap_or("");
+                eprintln!("[OKX VALIDATION] {} found: state={}", inst_id, state);
+                Ok(state == "live")
+            }
+            Err(e) => {
+                eprintln!("[OKX VALIDATION] {} not tradeable: {}", inst_id, e);
+                Ok(false)
+            }
+        }
+    }
+}
+
+/// Parse an order from `/api/v5/trade/order`, converting contracts to coins
+fn parse_order(data: &Value, ct_val: f64) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    let state = data.get("state").and_then(|v| v.as_str()).ok_or("Missing order state in response")?;
+    let total = data.get("sz").and_then(as_f64).unwrap_or(0.0) * ct_val;
+    let filled = data.get("accFillSz").and_then(as_f64).unwrap_or(0.0) * ct_val;
+
+    let status = match state {
+        "filled" => OrderStatus::Filled,
+        "canceled" | "mmp_canceled" => OrderStatus::Cancelled,
+        _ => OrderStatus::Pending,
+    };
+    Ok(OrderStatusInfo::new(status, filled, total))
+}
+
+/// Parse `/api/v5/market/books`; levels are `[price, contracts, _, orders]`
+fn parse_depth(data: &Value, levels: usize, ct_val: f64) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    let side = |key: &str| -> Result<Vec<PriceLevel>, Box<dyn Error + Send + Sync>> {
+        Ok(data
+            .get(key)
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| format!("Missing {} in response", key))?
+            .iter()
+            .take(levels)
+            .filter_map(|level| {
+                let arr = level.as_array()?;
+                Some(PriceLevel { price: as_f64(arr.first()?)?, quantity: as_f64(arr.get(1)?)? * ct_val })
+            })
+            .collect())
+    };
+
+    Ok(OrderBookDepth {
+        bids: side("bids")?,
+        asks: side("asks")?,
+        timestamp: data.get("ts").and_then(as_f64).map(|t| t as u64).unwrap_or(0),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::testnet::mock_server::MockServer;
+    use crate::strategy::types::OrderType;
+
+    fn client(server: &MockServer) -> OkxDemoClient {
+        OkxDemoClient::new(ExchangeCredentials {
+            api_key: "okx-key".to_string(),
+            api_secret: "okx-secret".to_string(),
+            passphrase: Some("okx-pass".to_string()),
+        })
+        .with_base_url(&server.url)
+    }
+
+    fn instrument_route(server: &MockServer) {
+        server.route("GET", "/api/v5/public/instruments", json!({
+            "code": "0", "msg": "",
+            "data": [{"instId": "BTC-USDT-SWAP", "ctVal": "0.01", "lotSz": "0.1", "tickSz": "0.1", "state": "live"}]
+        }));
+    }
+
+    #[test]
+    fn test_symbol_and_client_order_id_mapping() {
+        assert_eq!(okx_inst_id("BTCUSDT"), "BTC-USDT-SWAP");
+        assert_eq!(okx_symbol("BTC-USDT-SWAP").as_deref(), Some("BTCUSDT"));
+        assert_eq!(okx_symbol("BTC-USD-SWAP"), None);
+        assert_eq!(client_order_id("arb-18c2f-7").as_deref(), Some("arb18c2f7"));
+        assert_eq!(client_order_id(""), None);
+    }
+
+    #[tokio::test]
+    async fn test_place_order_signs_and_converts_to_contracts() {
+        let server = MockServer::start().await;
+        instrument_route(&server);
+        server.route("POST", "/api/v5/trade/order", json!({
+            "code": "0", "msg": "", "data": [{"ordId": "555", "clOrdId": "arb1", "sCode": "0", "sMsg": ""}]
+        }));
+        let okx = client(&server);
+
+        let placed = okx.place_order(SimulatedOrder {
+            id: "arb-1".to_string(),
+            exchange: "okx".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Short,
+            order_type: OrderType::Limit,
+            price: 50_000.04,
+            size: 0.0157,
+            time_in_force: TimeInForce::PostOnly,
+            reduce_only: true,
+            ..Default::default()
+        }).await.unwrap();
+
+        assert_eq!(placed.id, "555");
+        assert_eq!(placed.size, 0.015);
+        assert_eq!(placed.price, 50_000.0);
+
+        let request = server.last_request("POST", "/api/v5/trade/order").unwrap();
+        let body = request.json();
+        assert_eq!(body["instId"], "BTC-USDT-SWAP");
+        assert_eq!(body["tdMode"], "isolated");
+        assert_eq!(body["side"], "sell");
+        assert_eq!(body["sz"], "1.5");
+        assert_eq!(body["px"], "50000");
+        assert_eq!(body["clOrdId"], "arb1");
+        assert_eq!(body["ordType"], "post_only");
+        assert_eq!(body["reduceOnly"], true);
+
+        assert_eq!(request.header("OK-ACCESS-PASSPHRASE"), "okx-pass");
+        assert_eq!(request.header("x-simulated-trading"), "1");
+        let expected = okx.generate_signature(request.header("OK-ACCESS-TIMESTAMP"), "POST", "/api/v5/trade/order", &request.body);
+        assert_eq!(request.header("OK-ACCESS-SIGN"), expected);
+    }
+
+    #[tokio::test]
+    async fn test_amend_order_sends_new_price_and_contracts() {
+        let server = MockServer::start().await;
+        instrument_route(&server);
+        server.route("POST", "/api/v5/trade/amend-order", json!({
+            "code": "0", "msg": "", "data": [{"ordId": "555", "sCode": "0", "sMsg": ""}]
+        }));
+        let okx = client(&server);
+
+        okx.amend_order("555", "BTCUSDT", 50_100.04, Some(0.02)).await.unwrap();
+        let body = server.last_request("POST", "/api/v5/trade/amend-order").unwrap().json();
+        assert_eq!(body["instId"], "BTC-USDT-SWAP");
+        assert_eq!(body["ordId"], "555");
+        assert_eq!(body["newPx"], "50100");
+        assert_eq!(body["newSz"], "2");
+
+        okx.amend_order("555", "BTCUSDT", 50_000.0, None).await.unwrap();
+        let body = server.last_request("POST", "/api/v5/trade/amend-order").unwrap().json();
+        assert!(body.get("newSz").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_open_positions_and_orders_in_coins() {
+        let server = MockServer::start().await;
+        instrument_route(&server);
+        server.route("GET", "/api/v5/account/positions", json!({
+            "code": "0", "data": [
+                {"instId": "BTC-USDT-SWAP", "pos": "-3", "posSide": "net", "avgPx": "50000"},
+                {"instId": "BTC-USDT-SWAP", "pos": "0", "posSide": "net", "avgPx": ""}
+            ]
+        }));
+        server.route("GET", "/api/v5/trade/orders-pending", json!({
+            "code": "0", "data": [{"ordId": "777", "instId": "BTC-USDT-SWAP", "side": "buy", "px": "49000", "sz": "5", "accFillSz": "1", "reduceOnly": "true"}]
+        }));
+        let okx = client(&server);
+
+        let positions = okx.get_open_positions().await.unwrap();
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].symbol, "BTCUSDT");
+        assert_eq!(positions[0].side, OrderSide::Short);
+        assert!((positions[0].size - 0.03).abs() < 1e-12);
+
+        let orders = okx.get_open_orders().await.unwrap();
+        assert_eq!(orders[0].id, "777");
+        assert_eq!(orders[0].side, OrderSide::Long);
+        assert!((orders[0].size - 0.04).abs() < 1e-12);
+        assert!(orders[0].reduce_only);
+    }
+
+    #[tokio::test]
+    async fn test_status_depth_and_errors() {
+        let server = MockServer::start().await;
+        instrument_route(&server);
+        server.route("GET", "/api/v5/trade/order", json!({
+            "code": "0", "data": [{"ordId": "555", "state": "partially_filled", "sz": "2", "accFillSz": "0.5"}]
+        }));
+        server.route("GET", "/api/v5/market/books", json!({
+            "code": "0", "data": [{"asks": [["100.5", "30", "0", "2"]], "bids": [["100.4", "10", "0", "1"]], "ts": "1700000000000"}]
+        }));
+        server.route("POST", "/api/v5/trade/cancel-order", json!({
+            "code": "1", "msg": "", "data": [{"ordId": "555", "sCode": "51400", "sMsg": "Order cancellation failed"}]
+        }));
+        let okx = client(&server);
+
+        let info = okx.get_order_status_detailed("555", "BTCUSDT").await.unwrap();
+        assert_eq!(info.status, OrderStatus::Pending);
+        assert_eq!(info.filled_quantity, 0.005);
+        assert_eq!(info.total_quantity, 0.02);
+        let request = server.last_request("GET", "/api/v5/trade/order").unwrap();
+        assert_eq!(request.query, "instId=BTC-USDT-SWAP&ordId=555");
+        let expected = okx.generate_signature(request.header("OK-ACCESS-TIMESTAMP"), "GET",
+            "/api/v5/trade/order?instId=BTC-USDT-SWAP&ordId=555", "");
+        assert_eq!(request.header("OK-ACCESS-SIGN"), expected);
+
+        let book = okx.get_order_book_depth("BTCUSDT", 5).await.unwrap();
+        assert!((book.asks[0].quantity - 0.3).abs() < 1e-12);
+        assert_eq!(okx.get_best_bid("BTCUSDT").await.unwrap(), 100.4);
+        assert!((okx.get_qty_step("BTCUSDT").await.unwrap() - 0.001).abs() < 1e-12);
+
+        let err = ExecutionError::from(okx.cancel_order("555", "BTCUSDT").await.unwrap_err());
+        assert_eq!(err, ExecutionError::OrderNotFound("Order cancellation failed".to_string()));
+    }
+}
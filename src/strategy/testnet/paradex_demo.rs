@@ -0,0 +1,588 @@
+//! Paradex testnet client (perpetuals)
+//!
+//! Private endpoints take a short-lived JWT obtained by signing a `Request`
+//! typed-data message on `/auth`; each order additionally carries its own
+//! StarkNet signature (see `paradex_signing`). Credentials are the L2 account
+//! address (`api_key`) and its Stark private key (`api_secret`).
+
+use std::error::Error;
+use std::collections::HashMap;
+use std::sync::Arc;
+use reqwest::Client;
+use serde_json::{json, Value};
+use tokio::sync::Mutex;
+use crate::strategy::types::{SimulatedOrder, OrderStatus, OrderSide, OrderStatusInfo, OrderBookDepth, PositionMargin, PriceLevel, TimeInForce, VenuePosition};
+use crate::strategy::testnet_config::ExchangeCredentials;
+use crate::strategy::execution_error::{http_error, ExecutionError};
+use super::{as_f64, format_decimal, open_order, round_to_step, round_to_tick};
+use super::paradex_signing::{ParadexSigner, SignableOrder};
+
+const PARADEX_TESTNET_URL: &str = "https://api.testnet.paradex.trade/v1";
+
+/// Lifetime requested for the `/auth` signature
+const AUTH_SIGNATURE_TTL_SECS: u64 = 300;
+
+/// Refresh the JWT this long after issuing it (tokens live ~5 minutes)
+const JWT_REFRESH_SECS: u64 = 240;
+
+/// Sizing rules of a market
+#[derive(Debug, Clone, Copy)]
+struct MarketSpec {
+    /// `order_size_increment`
+    size_step: f64,
+    /// `price_tick_size`
+    tick_size: f64,
+}
+
+/// Paradex market for a normalized symbol (BTCUSDT -> BTC-USD-PERP)
+pub fn paradex_market(symbol: &str) -> String {
+    format!("{}-USD-PERP", symbol.trim_end_matches("USDT"))
+}
+
+/// Normalized symbol for a perp market (BTC-USD-PERP -> BTCUSDT)
+fn paradex_symbol(market: &str) -> Option<String> {
+    market.strip_suffix("-USD-PERP").map(|base| format!("{}USDT", base))
+}
+
+/// Map a Paradex error response (HTTP status and `error` code) onto `ExecutionError`
+pub fn paradex_error(status: u16, error: &str, message: &str) -> ExecutionError {
+    let text = message.to_string();
+    match error {
+        "ORDER_ID_NOT_FOUND" | "ORDER_IS_CLOSED" => ExecutionError::OrderNotFound(text),
+        "INSUFFICIENT_MARGIN" | "NOT_ENOUGH_MARGIN" => ExecutionError::InsufficientBalance(text),
+        "POST_ONLY_WOULD_CROSS" => ExecutionError::PostOnlyRejected(text),
+        "INVALID_ORDER_SIZE" | "ORDER_SIZE_BELOW_MIN" => ExecutionError::InvalidQuantity(text),
+        "INVALID_TOKEN" | "INVALID_SIGNATURE" => ExecutionError::Auth(text),
+        _ if status == 404 => ExecutionError::OrderNotFound(text),
+        "" => http_error(status, None, message),
+        _ if matches!(status, 401 | 403 | 429) || status >= 500 => http_error(status, None, message),
+        _ => ExecutionError::rejected(error, text),
+    }
+}
+
+fn now_secs() -> u64 {
+    chrono::Utc::now().timestamp() as u64
+}
+
+pub struct ParadexDemoClient {
+    client: Client,
+    base_url: String,
+    account: String,
+    private_key: String,
+    // Signer is built once the chain id is known from /system/config
+    signer: Mutex<Option<Arc<ParadexSigner>>>,
+    // Cached JWT and the time it was issued
+    jwt: Mutex<Option<(String, u64)>>,
+    // Cache of market sizing rules: market -> MarketSpec
+    market_cache: Arc<Mutex<HashMap<String, MarketSpec>>>,
+}
+
+impl ParadexDemoClient {
+    pub fn new(credentials: ExchangeCredentials) -> Self {
+        Self {
+            client: Client::new(),
+            base_url: PARADEX_TESTNET_URL.to_string(),
+            account: credentials.api_key,
+            private_key: credentials.api_secret,
+            signer: Mutex::new(None),
+            jwt: Mutex::new(None),
+            market_cache: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Point the client at another API root (e.g. a local mock server)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Paradex answers errors with a non-2xx status and `{error, message}`
+    async fn read_response(response: reqwest::Response) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let status = response.status();
+        let text = response.text().await?;
+        if !status.is_success() {
+            let detail: Value = serde_json::from_str(&text).unwrap_or(Value::Null);
+            let error = detail.get("error").and_then(|v| v.as_str()).unwrap_or("");
+            let message = detail.get("message").and_then(|v| v.as_str()).unwrap_or(&text);
+            return Err(paradex_error(status.as_u16(), error, message).into());
+        }
+        if text.is_empty() {
+            return Ok(Value::Null);
+        }
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    async fn public_get(&self, path: &str) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let response = self.client.get(format!("{}{}", self.base_url, path)).send().await?;
+        Self::read_response(response).await
+    }
+
+    async fn signer(&self) -> Result<Arc<ParadexSigner>, Box<dyn Error + Send + Sync>> {
+        let mut signer = self.signer.lock().await;
+        if let Some(signer) = signer.as_ref() {
+            return Ok(signer.clone());
+        }
+        let config = self.public_get("/system/config").await?;
+        let chain_id = config
+            .get("starknet_chain_id")
+            .and_then(|c| c.as_str())
+            .ok_or("Missing starknet_chain_id in system config")?;
+        let built = Arc::new(ParadexSigner::new(&self.account, &self.private_key, chain_id)?);
+        *signer = Some(built.clone());
+        Ok(built)
+    }
+
+    /// Current JWT, re-authenticating when the cached one is about to expire
+    async fn jwt(&self) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let mut jwt = self.jwt.lock().await;
+        if let Some((token, issued_at)) = jwt.as_ref() {
+            if now_secs() < issued_at + JWT_REFRESH_SECS {
+                return Ok(token.clone());
+            }
+        }
+
+        let signer = self.signer().await?;
+        let timestamp = now_secs();
+        let expiration = timestamp + AUTH_SIGNATURE_TTL_SECS;
+        let signature = signer.signature_string(&signer.auth_hash(timestamp, expiration)?)?;
+
+        let response = self
+            .client
+            .post(format!("{}/auth", self.base_url))
+            .header("PARADEX-STARKNET-ACCOUNT", &self.account)
+            .header("PARADEX-STARKNET-SIGNATURE", signature)
+            .header("PARADEX-TIMESTAMP", timestamp.to_string())
+            .header("PARADEX-SIGNATURE-EXPIRATION", expiration.to_string())
+            .send()
+            .await?;
+        let data = Self::read_response(response).await?;
+        let token = data
+            .get("jwt_token")
+            .and_then(|t| t.as_str())
+            .ok_or("Missing jwt_token in auth response")?
+            .to_string();
+
+        *jwt = Some((token.clone(), timestamp));
+        Ok(token)
+    }
+
+    async fn private_request(&self, method: &str, path: &str, body: Option<&Value>) -> Result<Value, Box<dyn Error + Send + Sync>> {
+        let token = self.jwt().await?;
+        let url = format!("{}{}", self.base_url, path);
+        let request = match method {
+            "POST" => self.client.post(&url).json(body.unwrap_or(&Value::Null)),
+            "DELETE" => self.client.delete(&url),
+            _ => self.client.get(&url),
+        };
+        let response = request.bearer_auth(token).send().await?;
+        Self::read_response(response).await
+    }
+
+    /// Sizing rules for a market (cached)
+    async fn market_spec(&self, symbol: &str) -> Result<MarketSpec, Box<dyn Error + Send + Sync>> {
+        let market = paradex_market(symbol);
+        {
+            let cache = self.market_cache.lock().await;
+            if let Some(&spec) = cache.get(&market) {
+                return Ok(spec);
+            }
+        }
+
+        let data = self.public_get(&format!("/markets?market={}", market)).await?;
+        let entry = data
+            .get("results")
+            .and_then(|r| r.as_array())
+            .and_then(|r| r.first())
+            .ok_or_else(|| format!("Paradex market {} not found", market))?;
+        let spec = MarketSpec {
+            size_step: entry.get("order_size_increment").and_then(as_f64).ok_or("Failed to extract order_size_increment from response")?,
+            tick_size: entry.get("price_tick_size").and_then(as_f64).unwrap_or(0.0),
+        };
+
+        let mut cache = self.market_cache.lock().await;
+        cache.insert(market, spec);
+        Ok(spec)
+    }
+
+    /// Quantity step for a symbol
+    pub async fn get_qty_step(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        Ok(self.market_spec(symbol).await?.size_step)
+    }
+
+    /// Set isolated margin at `leverage` for a market
+    pub async fn set_leverage(&self, symbol: &str, leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let market = paradex_market(symbol);
+        let leverage = leverage.max(1);
+        let body = json!({"leverage": leverage, "margin_type": "ISOLATED"});
+        self.private_request("POST", &format!("/account/margin/{}", market), Some(&body)).await?;
+        eprintln!("[PARADEX DEMO] Leverage set to {}x isolated for {}", leverage, market);
+        Ok(())
+    }
+
+    /// Margin type is set together with the leverage
+    pub async fn set_margin_mode_isolated(&self, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        Ok(())
+    }
+
+    async fn submit(&self, order: SimulatedOrder, market: bool) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let spec = self.market_spec(&order.symbol).await?;
+        let size = round_to_step(order.size, spec.size_step);
+        if size <= 0.0 {
+            return Err(ExecutionError::InvalidQuantity(format!("Order size {} below Paradex step {} for {}", order.size, spec.size_step, order.symbol)).into());
+        }
+        let price = if market { 0.0 } else { round_to_tick(order.price, spec.tick_size) };
+        let instruction = match (market, order.time_in_force) {
+            (true, _) | (false, TimeInForce::Ioc) => "IOC",
+            (false, TimeInForce::Gtc) => "GTC",
+            (false, TimeInForce::PostOnly) => "POST_ONLY",
+            (false, TimeInForce::Fok) => return Err("Paradex does not support FOK orders".into()),
+        };
+        let market_name = paradex_market(&order.symbol);
+        let order_type = if market { "MARKET" } else { "LIMIT" };
+        let is_buy = order.side == OrderSide::Long;
+        let signature_timestamp = chrono::Utc::now().timestamp_millis() as u64;
+
+        let signer = self.signer().await?;
+        let hash = signer.order_hash(&SignableOrder {
+            timestamp_ms: signature_timestamp,
+            market: &market_name,
+            is_buy,
+            order_type,
+            size,
+            price,
+        })?;
+
+        let mut body = json!({
+            "market": market_name,
+            "side": if is_buy { "BUY" } else { "SELL" },
+            "type": order_type,
+            "size": format_decimal(size),
+            "instruction": instruction,
+            "signature": signer.signature_string(&hash)?,
+            "signature_timestamp": signature_timestamp,
+        });
+        if !market {
+            body["price"] = json!(format_decimal(price));
+        }
+        // Flags are not part of the signed order hash
+        if order.is_reduce_only() {
+            body["flags"] = json!(["REDUCE_ONLY"]);
+        }
+        // Client ID lets Paradex reject duplicate submissions
+        if !order.id.is_empty() {
+            body["client_id"] = json!(order.id.clone());
+        }
+
+        let data = self.private_request("POST", "/orders", Some(&body)).await?;
+        let order_id = data
+            .get("id")
+            .and_then(|v| v.as_str())
+            .ok_or("Failed to extract order ID from response")?
+            .to_string();
+
+        let mut placed = order;
+        placed.id = order_id;
+        placed.size = size;
+        if !market {
+            placed.price = price;
+        }
+        placed.status = OrderStatus::Pending;
+        placed.created_at = signature_timestamp;
+        Ok(placed)
+    }
+
+    /// Place a limit order on Paradex testnet
+    pub async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let placed = self.submit(order, false).await?;
+        eprintln!("[PARADEX DEMO] Order placed: {} | Symbol: {} | Side: {:?} | Size: {} | Price: {}",
+            placed.id, placed.symbol, placed.side, placed.size, placed.price);
+        Ok(placed)
+    }
+
+    /// Place a market order on Paradex testnet
+    pub async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        let placed = self.submit(order, true).await?;
+        eprintln!("[PARADEX DEMO] Market order placed: {} | Symbol: {} | Size: {}", placed.id, placed.symbol, placed.size);
+        Ok(placed)
+    }
+
+    /// Cancel an order on Paradex testnet
+    pub async fn cancel_order(&self, order_id: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.private_request("DELETE", &format!("/orders/{}", order_id), None)
+            .await?;
+        eprintln!("[PARADEX DEMO] Order cancelled: {}", order_id);
+        Ok(())
+    }
+
+    /// Get order status from Paradex testnet
+    pub async fn get_order_status(&self, order_id: &str, symbol: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+        Ok(self.get_order_status_detailed(order_id, symbol).await?.status)
+    }
+
+    /// Get order status with filled quantity from Paradex testnet
+    pub async fn get_order_status_detailed(&self, order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+        let data = self.private_request("GET", &format!("/orders/{}", order_id), None).await?;
+        parse_order(&data)
+    }
+
+    /// Free collateral of the account
+    pub async fn get_balance(&self) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let data = self.private_request("GET", "/account", None).await?;
+        data.get("free_collateral")
+            .and_then(as_f64)
+            .ok_or_else(|| "Failed to extract free_collateral from response".into())
+    }
+
+    /// Open perp positions of the account
+    pub async fn get_open_positions(&self) -> Result<Vec<VenuePosition>, Box<dyn Error + Send + Sync>> {
+        let data = self.private_request("GET", "/positions", None).await?;
+        Ok(parse_positions(&data))
+    }
+
+    /// Working orders of the account
+    pub async fn get_open_orders(&self) -> Result<Vec<SimulatedOrder>, Box<dyn Error + Send + Sync>> {
+        let data = self.private_request("GET", "/orders", None).await?;
+        Ok(parse_open_orders(&data))
+    }
+
+    /// Get order book depth (top N levels)
+    pub async fn get_order_book_depth(&self, symbol: &str, levels: usize) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+        let data = self.public_get(&format!("/orderbook/{}?depth={}", paradex_market(symbol), levels.max(1))).await?;
+        parse_depth(&data, levels)
+    }
+
+    /// Best bid from the order book
+    pub async fn get_best_bid(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.bids.first().map(|l| l.price).ok_or_else(|| "No bids available in order book".into())
+    }
+
+    /// Best ask from the order book
+    pub async fn get_best_ask(&self, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        let depth = self.get_order_book_depth(symbol, 1).await?;
+        depth.asks.first().map(|l| l.price).ok_or_else(|| "No asks available in order book".into())
+    }
+
+    /// Check if a symbol is a listed Paradex perp
+    pub async fn is_symbol_tradeable(&self, symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        match self.market_spec(symbol).await {
+            Ok(_) => Ok(true),
+            Err(e) => {
+                eprintln!("[PARADEX VALIDATION] {} not tradeable: {}", symbol, e);
+                Ok(false)
+            }
+        }
+    }
+}
+
+/// Parse an order. A `CLOSED` order with size left over was cancelled.
+fn parse_order(data: &Value) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    let state = data.get("status").and_then(|v| v.as_str()).ok_or("Missing order status in response")?;
+    let total = data.get("size").and_then(as_f64).unwrap_or(0.0);
+    let remaining = data.get("remaining_size").and_then(as_f64).unwrap_or(0.0);
+    let filled = (total - remaining).max(0.0);
+
+    let status = match state {
+        "CLOSED" if total > 0.0 && remaining <= 0.0 => OrderStatus::Filled,
+        "CLOSED" => OrderStatus::Cancelled,
+        _ => OrderStatus::Pending,
+    };
+    Ok(OrderStatusInfo::new(status, filled, total))
+}
+
+fn parse_depth(data: &Value, levels: usize) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    let side = |key: &str| -> Result<Vec<PriceLevel>, Box<dyn Error + Send + Sync>> {
+        Ok(data
+            .get(key)
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| format!("Missing {} in response", key))?
+            .iter()
+            .take(levels)
+            .filter_map(|level| {
+                let arr = level.as_array()?;
+                Some(PriceLevel { price: as_f64(arr.first()?)?, quantity: as_f64(arr.get(1)?)? })
+            })
+            .collect())
+    };
+
+    Ok(OrderBookDepth {
+        bids: side("bids")?,
+        asks: side("asks")?,
+        timestamp: data.get("last_updated_at").and_then(as_f64).map(|t| t as u64).unwrap_or(0),
+    })
+}
+
+/// Parse `/positions` results, skipping closed and empty positions
+fn parse_positions(data: &Value) -> Vec<VenuePosition> {
+    data.get("results")
+        .and_then(|v| v.as_array())
+        .map(|results| {
+            results
+                .iter()
+                .filter(|p| p.get("status").and_then(|v| v.as_str()) == Some("OPEN"))
+                .filter_map(|p| {
+                    let size = p.get("size").and_then(as_f64)?.abs();
+                    if size == 0.0 {
+                        return None;
+                    }
+                    Some(VenuePosition {
+                        exchange: "paradex".to_string(),
+                        symbol: paradex_symbol(p.get("market")?.as_str()?)?,
+                        side: if p.get("side").and_then(|v| v.as_str()) == Some("SHORT") { OrderSide::Short } else { OrderSide::Long },
+                        size,
+                        entry_price: p.get("average_entry_price").and_then(as_f64).unwrap_or(0.0),
+                        // Positions carry no mark price or margin; the account is margined as a whole
+                        margin: PositionMargin::new(None, p.get("liquidation_price").and_then(as_f64), None, p.get("leverage").and_then(as_f64)),
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Parse `/orders` results; reduce-only is carried in `flags`
+fn parse_open_orders(data: &Value) -> Vec<SimulatedOrder> {
+    data.get("results")
+        .and_then(|v| v.as_array())
+        .map(|results| {
+            results
+                .iter()
+                .filter_map(|o| {
+                    let reduce_only = o
+                        .get("flags")
+                        .and_then(|f| f.as_array())
+                        .is_some_and(|flags| flags.iter().any(|f| f.as_str() == Some("REDUCE_ONLY")));
+                    Some(open_order(
+                        "paradex",
+                        o.get("id")?.as_str()?.to_string(),
+                        paradex_symbol(o.get("market")?.as_str()?)?,
+                        if o.get("side").and_then(|v| v.as_str()) == Some("BUY") { OrderSide::Long } else { OrderSide::Short },
+                        o.get("price").and_then(as_f64).unwrap_or(0.0),
+                        o.get("remaining_size").and_then(as_f64).unwrap_or(0.0),
+                        reduce_only,
+                    ))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::testnet::mock_server::MockServer;
+    use crate::strategy::types::OrderType;
+    use starknet_crypto::FieldElement;
+
+    const ACCOUNT: &str = "0x129f3dc1b8962d8a87abc692424c78fda963ade0e1cd17bf3d1c26f8d41ee7a";
+    const PRIVATE_KEY: &str = "0x3c1e9550e66958296d11b60f8e8e7a7ad990d07fa65d5f7652c4a6c87d4e3cc";
+    const CHAIN_ID: &str = "PRIVATE_SN_POTC_SEPOLIA";
+
+    fn parse_signature(value: &str) -> (FieldElement, FieldElement) {
+        let parts: Vec<String> = serde_json::from_str(value).unwrap();
+        (FieldElement::from_dec_str(&parts[0]).unwrap(), FieldElement::from_dec_str(&parts[1]).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_auth_then_signed_order() {
+        let server = MockServer::start().await;
+        server.route("GET", "/system/config", json!({"starknet_chain_id": CHAIN_ID}));
+        server.route("POST", "/auth", json!({"jwt_token": "jwt-1"}));
+        server.route("GET", "/markets", json!({"results": [
+            {"symbol": "BTC-USD-PERP", "order_size_increment": "0.001", "price_tick_size": "0.1"}
+        ]}));
+        server.route("POST", "/orders", json!({"id": "px-1", "status": "NEW"}));
+        let paradex = ParadexDemoClient::new(ExchangeCredentials {
+            api_key: ACCOUNT.to_string(),
+            api_secret: PRIVATE_KEY.to_string(),
+            passphrase: None,
+        })
+        .with_base_url(&server.url);
+
+        let placed = paradex.place_order(SimulatedOrder {
+            id: "arb-5".to_string(),
+            exchange: "paradex".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side: OrderSide::Short,
+            order_type: OrderType::Limit,
+            price: 35_000.04,
+            size: 0.0157,
+            time_in_force: TimeInForce::PostOnly,
+            reduce_only: true,
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(placed.id, "px-1");
+        assert_eq!(placed.size, 0.015);
+        assert_eq!(placed.price, 35_000.0);
+
+        let signer = ParadexSigner::new(ACCOUNT, PRIVATE_KEY, CHAIN_ID).unwrap();
+
+        // Auth headers carry a valid signature over the Request message
+        let auth = server.last_request("POST", "/auth").unwrap();
+        assert_eq!(auth.header("PARADEX-STARKNET-ACCOUNT"), ACCOUNT);
+        let timestamp: u64 = auth.header("PARADEX-TIMESTAMP").parse().unwrap();
+        let expiration: u64 = auth.header("PARADEX-SIGNATURE-EXPIRATION").parse().unwrap();
+        let (r, s) = parse_signature(auth.header("PARADEX-STARKNET-SIGNATURE"));
+        let auth_hash = signer.auth_hash(timestamp, expiration).unwrap();
+        assert!(starknet_crypto::verify(&signer.public_key(), &auth_hash, &r, &s).unwrap());
+
+        // Order uses the JWT and signs the rounded size and price
+        let request = server.last_request("POST", "/orders").unwrap();
+        assert_eq!(request.header("Authorization"), "Bearer jwt-1");
+        let body = request.json();
+        assert_eq!(body["side"], "SELL");
+        assert_eq!(body["size"], "0.015");
+        assert_eq!(body["price"], "35000");
+        assert_eq!(body["client_id"], "arb-5");
+        assert_eq!(body["instruction"], "POST_ONLY");
+        assert_eq!(body["flags"], json!(["REDUCE_ONLY"]));
+        let order_hash = signer.order_hash(&SignableOrder {
+            timestamp_ms: body["signature_timestamp"].as_u64().unwrap(),
+            market: "BTC-USD-PERP",
+            is_buy: false,
+            order_type: "LIMIT",
+            size: 0.015,
+            price: 35_000.0,
+        }).unwrap();
+        let (r, s) = parse_signature(body["signature"].as_str().unwrap());
+        assert!(starknet_crypto::verify(&signer.public_key(), &order_hash, &r, &s).unwrap());
+    }
+
+    #[test]
+    fn test_parse_order_and_depth() {
+        let cancelled = json!({"id": "px-1", "status": "CLOSED", "size": "0.2", "remaining_size": "0.05", "cancel_reason": "USER_CANCELED"});
+        let info = parse_order(&cancelled).unwrap();
+        assert_eq!(info.status, OrderStatus::Cancelled);
+        assert!((info.filled_quantity - 0.15).abs() < 1e-12);
+
+        let filled = json!({"status": "CLOSED", "size": "0.2", "remaining_size": "0"});
+        assert!(parse_order(&filled).unwrap().is_fully_filled());
+
+        let depth = json!({"market": "BTC-USD-PERP", "asks": [["35000.1", "0.5"]], "bids": [["35000", "1.2"]], "last_updated_at": 1_700_000_000_000u64});
+        let book = parse_depth(&depth, 5).unwrap();
+        assert_eq!(book.asks[0].price, 35_000.1);
+        assert_eq!(book.bids[0].quantity, 1.2);
+        assert_eq!(book.timestamp, 1_700_000_000_000);
+    }
+
+    #[test]
+    fn test_parse_positions_and_open_orders() {
+        assert_eq!(paradex_symbol("ETH-USD-PERP").as_deref(), Some("ETHUSDT"));
+        let positions = parse_positions(&json!({"results": [
+            {"market": "BTC-USD-PERP", "side": "SHORT", "size": "-0.2", "average_entry_price": "35000.5", "status": "OPEN"},
+            {"market": "ETH-USD-PERP", "side": "LONG", "size": "0", "status": "CLOSED"}
+        ]}));
+        assert_eq!(positions.len(), 1);
+        assert_eq!(positions[0].symbol, "BTCUSDT");
+        assert_eq!(positions[0].side, OrderSide::Short);
+        assert_eq!(positions[0].size, 0.2);
+
+        let orders = parse_open_orders(&json!({"results": [
+            {"id": "px-9", "market": "BTC-USD-PERP", "side": "BUY", "price": "34990", "size": "0.2", "remaining_size": "0.1", "flags": ["REDUCE_ONLY"]}
+        ]}));
+        assert_eq!(orders[0].id, "px-9");
+        assert_eq!(orders[0].side, OrderSide::Long);
+        assert_eq!(orders[0].size, 0.1);
+        assert!(orders[0].reduce_only);
+    }
+}
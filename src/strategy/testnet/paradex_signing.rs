@@ -0,0 +1,238 @@
+//! Paradex StarkNet signing
+//!
+//! Paradex authenticates with StarkNet typed data (the pre-SNIP-12 Pedersen
+//! scheme): each struct is hashed as `h(type_hash, fields...)` with the
+//! Pedersen chain hash, and the final message hash binds the
+//! `StarkNetDomain`, the account address and the struct. Two messages are
+//! used: `Request` for the JWT handshake on `/auth` and `Order` for every
+//! order submission. Signatures are Stark-curve ECDSA with RFC 6979 nonces.
+
+use std::error::Error;
+use sha3::{Digest, Keccak256};
+use starknet_crypto::{pedersen_hash, rfc6979_generate_k, sign, FieldElement};
+
+/// Order sizes and prices are signed as fixed-point integers with 8 decimals
+const CHAIN_DECIMALS: i32 = 8;
+
+const DOMAIN_TYPE: &str = "StarkNetDomain(name:felt,chainId:felt,version:felt)";
+const ORDER_TYPE: &str = "Order(timestamp:felt,market:felt,side:felt,orderType:felt,size:felt,price:felt)";
+const REQUEST_TYPE: &str = "Request(method:felt,path:felt,body:felt,timestamp:felt,expiration:felt)";
+
+/// Encode an ASCII string of at most 31 characters as a felt
+pub fn short_string(value: &str) -> Result<FieldElement, Box<dyn Error + Send + Sync>> {
+    if value.len() > 31 || !value.is_ascii() {
+        return Err(format!("'{}' is not a valid short string", value).into());
+    }
+    FieldElement::from_byte_slice_be(value.as_bytes())
+        .map_err(|_| format!("'{}' does not fit in a felt", value).into())
+}
+
+/// keccak256 truncated to 250 bits, as used for StarkNet selectors and type hashes
+pub fn starknet_keccak(data: &[u8]) -> FieldElement {
+    let mut hash: [u8; 32] = Keccak256::digest(data).into();
+    hash[0] &= 0x03;
+    FieldElement::from_bytes_be(&hash).expect("250-bit value fits in a felt")
+}
+
+/// Pedersen chain hash `h(h(h(0, a), b), ..., n)` over the elements and their count
+pub fn compute_hash_on_elements(elements: &[FieldElement]) -> FieldElement {
+    let chained = elements.iter().fold(FieldElement::ZERO, |acc, e| pedersen_hash(&acc, e));
+    pedersen_hash(&chained, &FieldElement::from(elements.len() as u64))
+}
+
+fn struct_hash(type_string: &str, fields: &[FieldElement]) -> FieldElement {
+    let mut elements = Vec::with_capacity(fields.len() + 1);
+    elements.push(starknet_keccak(type_string.as_bytes()));
+    elements.extend_from_slice(fields);
+    compute_hash_on_elements(&elements)
+}
+
+/// Final typed-data hash binding the domain and the signing account to a struct
+fn typed_data_hash(domain_hash: FieldElement, account: FieldElement, struct_hash: FieldElement) -> Result<FieldElement, Box<dyn Error + Send + Sync>> {
+    Ok(compute_hash_on_elements(&[
+        short_string("StarkNet Message")?,
+        domain_hash,
+        account,
+        struct_hash,
+    ]))
+}
+
+/// Decimal quantity as the fixed-point integer Paradex signs (e.g. 0.1 -> 10000000)
+pub fn chain_amount(value: f64) -> FieldElement {
+    FieldElement::from((value * 10f64.powi(CHAIN_DECIMALS)).round() as u64)
+}
+
+fn felt_hex(value: &str) -> Result<FieldElement, Box<dyn Error + Send + Sync>> {
+    FieldElement::from_hex_be(value).map_err(|e| format!("Invalid felt {}: {}", value, e).into())
+}
+
+/// Order fields that go into the signature
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignableOrder<'a> {
+    /// Signature timestamp in milliseconds
+    pub timestamp_ms: u64,
+    /// Market symbol, e.g. `BTC-USD-PERP`
+    pub market: &'a str,
+    pub is_buy: bool,
+    /// `LIMIT` or `MARKET`
+    pub order_type: &'a str,
+    pub size: f64,
+    /// Zero for market orders
+    pub price: f64,
+}
+
+pub struct ParadexSigner {
+    account: FieldElement,
+    private_key: FieldElement,
+    domain_hash: FieldElement,
+}
+
+impl ParadexSigner {
+    /// `account` and `private_key` are hex felts; `chain_id` is the short-string
+    /// chain name from `/system/config` (e.g. `PRIVATE_SN_POTC_SEPOLIA`)
+    pub fn new(account: &str, private_key: &str, chain_id: &str) -> Result<Self, Box<dyn Error + Send + Sync>> {
+        let domain_hash = struct_hash(DOMAIN_TYPE, &[
+            short_string("Paradex")?,
+            short_string(chain_id)?,
+            FieldElement::ONE,
+        ]);
+        Ok(Self {
+            account: felt_hex(account)?,
+            private_key: felt_hex(private_key)?,
+            domain_hash,
+        })
+    }
+
+    pub fn public_key(&self) -> FieldElement {
+        starknet_crypto::get_public_key(&self.private_key)
+    }
+
+    /// Final typed-data hash for a struct hash
+    fn message_hash(&self, struct_hash: FieldElement) -> Result<FieldElement, Box<dyn Error + Send + Sync>> {
+        typed_data_hash(self.domain_hash, self.account, struct_hash)
+    }
+
+    pub fn order_hash(&self, order: &SignableOrder) -> Result<FieldElement, Box<dyn Error + Send + Sync>> {
+        let side = FieldElement::from(if order.is_buy { 1u64 } else { 2u64 });
+        let hash = struct_hash(ORDER_TYPE, &[
+            FieldElement::from(order.timestamp_ms),
+            short_string(order.market)?,
+            side,
+            short_string(order.order_type)?,
+            chain_amount(order.size),
+            chain_amount(order.price),
+        ]);
+        self.message_hash(hash)
+    }
+
+    /// Hash of the `POST /auth` request used to obtain a JWT
+    pub fn auth_hash(&self, timestamp_secs: u64, expiration_secs: u64) -> Result<FieldElement, Box<dyn Error + Send + Sync>> {
+        let hash = struct_hash(REQUEST_TYPE, &[
+            short_string("POST")?,
+            short_string("/v1/auth")?,
+            short_string("")?,
+            FieldElement::from(timestamp_secs),
+            FieldElement::from(expiration_secs),
+        ]);
+        self.message_hash(hash)
+    }
+
+    /// Sign a message hash; returns `(r, s)`
+    pub fn sign_hash(&self, hash: &FieldElement) -> Result<(FieldElement, FieldElement), Box<dyn Error + Send + Sync>> {
+        let k = rfc6979_generate_k(hash, &self.private_key, None);
+        let signature = sign(&self.private_key, hash, &k)?;
+        Ok((signature.r, signature.s))
+    }
+
+    /// Signature in the `["r","s"]` decimal form Paradex expects
+    pub fn signature_string(&self, hash: &FieldElement) -> Result<String, Box<dyn Error + Send + Sync>> {
+        let (r, s) = self.sign_hash(hash)?;
+        Ok(format!("[\"{}\",\"{}\"]", r, s))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn felt(hex: &str) -> FieldElement {
+        FieldElement::from_hex_be(hex).unwrap()
+    }
+
+    #[test]
+    fn test_hash_primitives_match_starknet_vectors() {
+        // ERC-20 `transfer` selector
+        assert_eq!(starknet_keccak(b"transfer"), felt("0x83afd3f4caedc6eebf44246fe54e38c95e3179a5ec9ea81740eca5b482d12e"));
+        // Hash of the empty list is pedersen(0, 0)
+        assert_eq!(compute_hash_on_elements(&[]), felt("0x49ee3eba8c1600700ee1b87eb599f16716b0b1022947733551fde4050ca6804"));
+        // StarkWare crypto-cpp pedersen vector
+        assert_eq!(
+            pedersen_hash(
+                &felt("0x03d937c035c878245caf64531a5756109c53068da139362728feb561405371cb"),
+                &felt("0x0208a0a10250e382e1e4bbe2880906c2791bf6275695e02fbbc6aeff9cd8b31a"),
+            ),
+            felt("0x030e480bed5fe53fa909cc0f8c4d99b8f9f2c016be4c41e13a4848797979c662"),
+        );
+        assert_eq!(short_string("Paradex").unwrap(), felt("0x50617261646578"));
+        assert_eq!(chain_amount(0.1), FieldElement::from(10_000_000u64));
+    }
+
+    #[test]
+    fn test_typed_data_matches_starknet_js_mail_vector() {
+        // starknet.js `typedData` example (pre-SNIP-12): nested structs, short
+        // strings and the account binding of the final message hash
+        let domain_type = "StarkNetDomain(name:felt,version:felt,chainId:felt)";
+        assert_eq!(starknet_keccak(domain_type.as_bytes()), felt("0x1bfc207425a47a5dfa1a50a4f5241203f50624ca5fdf5e18755765416b8e288"));
+        let domain = struct_hash(domain_type, &[short_string("StarkNet Mail").unwrap(), FieldElement::ONE, FieldElement::ONE]);
+        assert_eq!(domain, felt("0x54833b121883a3e3aebff48ec08a962f5742e5f7b973469c1f8f4f55d470b07"));
+
+        let person = |name: &str, wallet: &str| struct_hash("Person(name:felt,wallet:felt)", &[short_string(name).unwrap(), felt(wallet)]);
+        let mail = struct_hash("Mail(from:Person,to:Person,contents:felt)Person(name:felt,wallet:felt)", &[
+            person("Cow", "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"),
+            person("Bob", "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"),
+            short_string("Hello, Bob!").unwrap(),
+        ]);
+        let hash = typed_data_hash(domain, felt("0xcd2a3d9f938e13cd947ec05abc7fe734df8dd826"), mail).unwrap();
+        assert_eq!(hash, felt("0x6fcff244f63e38b9d88b9e3378d44757710d1b244282b435cb472053c8d78d0"));
+    }
+
+    #[test]
+    fn test_rfc6979_signature_vector() {
+        // starknet-rs signer vector (RFC 6979 nonce)
+        let signer = ParadexSigner::new(
+            "0x1",
+            "0x0139fe4d6f02e666e86a6f58e65060f115cd3c185bd9e98bd829636931458f79",
+            "SN_SEPOLIA",
+        ).unwrap();
+        let (r, s) = signer.sign_hash(&felt("0x06fea80189363a786037ed3e7ba546dad0ef7de49fccae0e31eb658b7dd4ea76")).unwrap();
+        assert_eq!(r, felt("0x061ec782f76a66f6984efc3a1b6d152a124c701c00abdd2bf76641b4135c770f"));
+        assert_eq!(s, felt("0x04e44e759cea02c23568bb4d8a09929bbca8768ab68270d50c18d214166ccd9a"));
+    }
+
+    #[test]
+    fn test_order_hash_binds_every_field_and_verifies() {
+        let signer = ParadexSigner::new(
+            "0x129f3dc1b8962d8a87abc692424c78fda963ade0e1cd17bf3d1c26f8d41ee7a",
+            "0x3c1e9550e66958296d11b60f8e8e7a7ad990d07fa65d5f7652c4a6c87d4e3cc",
+            "PRIVATE_SN_POTC_SEPOLIA",
+        ).unwrap();
+        assert_eq!(signer.public_key(), felt("0x077a3b314db07c45076d11f62b6f9e748a39790441823307743cf00d6597ea43"));
+
+        let order = SignableOrder {
+            timestamp_ms: 1_700_000_000_000,
+            market: "BTC-USD-PERP",
+            is_buy: true,
+            order_type: "LIMIT",
+            size: 0.1,
+            price: 35_000.5,
+        };
+        let hash = signer.order_hash(&order).unwrap();
+        assert_ne!(hash, signer.order_hash(&SignableOrder { is_buy: false, ..order.clone() }).unwrap());
+        assert_ne!(hash, signer.order_hash(&SignableOrder { size: 0.2, ..order.clone() }).unwrap());
+        assert_ne!(hash, signer.auth_hash(1_700_000_000, 1_700_000_300).unwrap());
+
+        let (r, s) = signer.sign_hash(&hash).unwrap();
+        assert!(starknet_crypto::verify(&signer.public_key(), &hash, &r, &s).unwrap());
+        assert!(signer.signature_string(&hash).unwrap().starts_with("[\""));
+    }
+}
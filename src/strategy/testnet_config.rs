@@ -3,9 +3,13 @@ use std::error::Error;
 #[derive(Debug, Clone)]
 pub struct TestnetConfig {
     pub bybit: Option<ExchangeCredentials>,
+    pub binance: Option<ExchangeCredentials>,
     pub okx: Option<ExchangeCredentials>,
     pub kucoin: Option<ExchangeCredentials>,
     pub bitget: Option<ExchangeCredentials>,
+    pub gateio: Option<ExchangeCredentials>,
+    pub hyperliquid: Option<ExchangeCredentials>,
+    pub paradex: Option<ExchangeCredentials>,
     pub single_exchange_mode: bool,
     pub primary_exchange: String,
 }
@@ -35,9 +39,13 @@ impl TestnetConfig {
 
         Ok(Self {
             bybit: Self::load_exchange_creds("BYBIT_DEMO")?,
+            binance: Self::load_exchange_creds("BINANCE_DEMO")?,
             okx: Self::load_exchange_creds_with_passphrase("OKX_DEMO")?,
             kucoin: Self::load_exchange_creds_with_passphrase("KUCOIN_DEMO")?,
             bitget: Self::load_exchange_creds_with_passphrase("BITGET_DEMO")?,
+            gateio: Self::load_exchange_creds("GATEIO_DEMO")?,
+            hyperliquid: Self::load_exchange_creds("HYPERLIQUID_DEMO")?,
+            paradex: Self::load_exchange_creds("PARADEX_DEMO")?,
             single_exchange_mode,
             primary_exchange,
         })
@@ -80,8 +88,12 @@ impl TestnetConfig {
 
     pub fn has_any_configured(&self) -> bool {
         self.bybit.is_some()
+            || self.binance.is_some()
             || self.okx.is_some()
             || self.kucoin.is_some()
             || self.bitget.is_some()
+            || self.gateio.is_some()
+            || self.hyperliquid.is_some()
+            || self.paradex.is_some()
     }
 }
@@ -0,0 +1,656 @@
+//! Crash-safe write-ahead trade journal
+//!
+//! Every trade and order state transition is appended to a local JSON-lines
+//! file and fsynced before the call returns, so after a crash the journal
+//! still says how far an entry got: intent, order sent, ack, fills, hedge,
+//! exit. `replay` folds the records back into active trades, closed trades
+//! and portfolio state, and lists entries that never reached an outcome.
+//!
+//! # Attribution
+//!
+//! Order events come from the `OrderManager`, which does not know about
+//! trades. The runner wraps an entry or exit in `in_scope(trade_id, phase, ..)`;
+//! orders registered inside the scope carry that trade ID for their whole
+//! life. Market orders sent during an entry are journaled as hedges.
+//!
+//! # Durability
+//!
+//! Records are written by a dedicated writer thread, which batches whatever
+//! is queued into one write and `sync_data` so callers on the runtime never
+//! block on disk. `record` only queues; `flush` (and `append`) wait until
+//! everything queued so far is on disk. The `OrderManager` flushes a `sent`
+//! record before the request leaves. Only the last line can be torn by a
+//! crash; `open` cuts it off before appending again.
+
+use crate::strategy::types::{OpportunityKind, OrderType, PaperTrade, PortfolioState, SimulatedOrder, TradeStatus};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::future::Future;
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tokio::sync::oneshot;
+
+/// Journal file used when `TRADE_JOURNAL_PATH` is not set
+pub const DEFAULT_JOURNAL_PATH: &str = "data/trade_journal.jsonl";
+
+/// Part of a trade's life an order belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JournalPhase {
+    Entry,
+    Exit,
+}
+
+/// Why an order was sent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderPurpose {
+    /// Opening leg of an entry
+    Entry,
+    /// Market order during an entry (hedging or unwinding a leg)
+    Hedge,
+    Exit,
+    /// Sent outside any trade scope
+    Other,
+}
+
+impl OrderPurpose {
+    fn classify(phase: Option<JournalPhase>, order_type: OrderType) -> Self {
+        match (phase, order_type) {
+            (Some(JournalPhase::Entry), OrderType::Market) => OrderPurpose::Hedge,
+            (Some(JournalPhase::Entry), _) => OrderPurpose::Entry,
+            (Some(JournalPhase::Exit), _) => OrderPurpose::Exit,
+            (None, _) => OrderPurpose::Other,
+        }
+    }
+}
+
+/// One state transition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JournalEvent {
+    /// About to enter; precedes every order of the entry
+    Intent {
+        trade_id: String,
+        symbol: String,
+        long_exchange: String,
+        short_exchange: String,
+        size_usd: f64,
+        kind: OpportunityKind,
+    },
+    /// Order about to be sent; `order.id` is the client order ID
+    OrderSent { trade_id: Option<String>, purpose: OrderPurpose, order: SimulatedOrder },
+    OrderAcked { trade_id: Option<String>, client_order_id: String, exchange_order_id: String },
+    /// Submission failed without a definitive answer; the order may still exist
+    OrderFailed { trade_id: Option<String>, client_order_id: String, error: String },
+    Fill { trade_id: Option<String>, client_order_id: String, quantity: f64, price: f64 },
+    OrderCancelled { trade_id: Option<String>, client_order_id: String },
+    OrderRejected { trade_id: Option<String>, client_order_id: String, reason: String },
+    /// Entry completed; `intent_id` is the `Intent` it resolves
+    Opened { intent_id: String, trade: PaperTrade },
+    /// Entry gave up; whatever its orders filled was unwound or is reported by reconciliation
+    Aborted { trade_id: String, reason: String },
+    /// Snapshot of an open trade after its exit orders or status changed
+    Updated { trade: PaperTrade },
+    Closed { trade_id: String, profit_usd: f64, exit_reason: String, exit_time: u64 },
+    /// Open trade dropped without an outcome (e.g. not found on the venues at startup); no PnL
+    Abandoned { trade_id: String, reason: String },
+}
+
+/// A journal line
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalRecord {
+    pub seq: u64,
+    pub ts_ms: u64,
+    pub event: JournalEvent,
+}
+
+tokio::task_local! {
+    static SCOPE: (String, JournalPhase);
+}
+
+/// Run `future` with its orders attributed to `trade_id`.
+pub async fn in_scope<F: Future>(trade_id: &str, phase: JournalPhase, future: F) -> F::Output {
+    SCOPE.scope((trade_id.to_string(), phase), future).await
+}
+
+/// Trade and phase of the enclosing `in_scope`, if any.
+pub fn current_scope() -> Option<(String, JournalPhase)> {
+    SCOPE.try_with(|scope| scope.clone()).ok()
+}
+
+/// Purpose of an order registered in the current scope.
+pub fn current_purpose(order_type: OrderType) -> OrderPurpose {
+    OrderPurpose::classify(current_scope().map(|(_, phase)| phase), order_type)
+}
+
+fn now_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis() as u64
+}
+
+/// Work for the writer thread
+enum WriterCommand {
+    Write(Vec<u8>),
+    /// Answered once every earlier write is on disk
+    Flush(oneshot::Sender<io::Result<()>>),
+}
+
+/// Append-only, fsynced journal file
+#[derive(Debug)]
+pub struct TradeJournal {
+    path: PathBuf,
+    /// Next sequence number and the writer queue; locked together so lines land in `seq` order
+    queue: Mutex<(u64, mpsc::Sender<WriterCommand>)>,
+    writer: Option<thread::JoinHandle<()>>,
+}
+
+/// Writer thread: write each batch of queued lines, `sync_data` once, then answer flushes.
+fn run_writer(path: PathBuf, mut file: File, commands: mpsc::Receiver<WriterCommand>) {
+    while let Ok(first) = commands.recv() {
+        let mut waiters = Vec::new();
+        let mut result = Ok(());
+        let mut dirty = false;
+        for command in std::iter::once(first).chain(commands.try_iter()) {
+            match command {
+                WriterCommand::Write(line) => {
+                    if result.is_ok() {
+                        result = file.write_all(&line);
+                        dirty = true;
+                    }
+                }
+                WriterCommand::Flush(waiter) => waiters.push(waiter),
+            }
+        }
+        if dirty && result.is_ok() {
+            result = file.sync_data();
+        }
+        if let Err(e) = &result {
+            eprintln!("[JOURNAL] ❌ Failed to write {}: {}", path.display(), e);
+        }
+        for waiter in waiters {
+            let _ = waiter.send(result.as_ref().map(|_| ()).map_err(|e| io::Error::new(e.kind(), e.to_string())));
+        }
+    }
+}
+
+impl TradeJournal {
+    /// Journal path from `TRADE_JOURNAL_PATH`, or `DEFAULT_JOURNAL_PATH`.
+    pub fn path_from_env() -> PathBuf {
+        std::env::var("TRADE_JOURNAL_PATH")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from(DEFAULT_JOURNAL_PATH))
+    }
+
+    /// Open (or create) the journal at `path`, dropping a torn last line.
+    pub fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let (records, valid_len) = if path.exists() { read_records(&path)? } else { (Vec::new(), 0) };
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        if file.metadata()?.len() > valid_len {
+            eprintln!("[JOURNAL] Dropping torn record at the end of {}", path.display());
+            file.set_len(valid_len)?;
+            file.sync_data()?;
+        }
+
+        let (sender, commands) = mpsc::channel();
+        let writer_path = path.clone();
+        let writer = thread::Builder::new()
+            .name("trade-journal".to_string())
+            .spawn(move || run_writer(writer_path, file, commands))?;
+
+        Ok(Self {
+            path,
+            queue: Mutex::new((records.last().map(|r| r.seq + 1).unwrap_or(0), sender)),
+            writer: Some(writer),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Queue `event` for the writer thread; returns its sequence number.
+    fn enqueue(&self, event: JournalEvent) -> io::Result<u64> {
+        let mut queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+        let seq = queue.0;
+        let mut line = serde_json::to_vec(&JournalRecord { seq, ts_ms: now_ms(), event })?;
+        line.push(b'\n');
+        queue.1.send(WriterCommand::Write(line)).map_err(|_| writer_gone())?;
+        queue.0 = seq + 1;
+        Ok(seq)
+    }
+
+    /// Wait until every record queued so far is fsynced.
+    pub async fn flush(&self) -> io::Result<()> {
+        let (done, waiter) = oneshot::channel();
+        {
+            let queue = self.queue.lock().unwrap_or_else(|e| e.into_inner());
+            queue.1.send(WriterCommand::Flush(done)).map_err(|_| writer_gone())?;
+        }
+        waiter.await.map_err(|_| writer_gone())?
+    }
+
+    /// Append `event` and wait until it is fsynced; returns its sequence number.
+    pub async fn append(&self, event: JournalEvent) -> io::Result<u64> {
+        let seq = self.enqueue(event)?;
+        self.flush().await?;
+        Ok(seq)
+    }
+
+    /// Queue `event` without waiting, logging instead of failing (state hooks that cannot abort).
+    pub fn record(&self, event: JournalEvent) {
+        if let Err(e) = self.enqueue(event) {
+            eprintln!("[JOURNAL] ❌ Failed to write {}: {}", self.path.display(), e);
+        }
+    }
+
+    /// Replay this journal's file, including everything queued so far.
+    pub async fn replay(&self) -> io::Result<JournalReplay> {
+        self.flush().await?;
+        replay(&self.path)
+    }
+}
+
+impl Drop for TradeJournal {
+    /// Close the queue and let the writer drain it.
+    fn drop(&mut self) {
+        // Swapping out the sender closes the queue
+        self.queue.get_mut().unwrap_or_else(|e| e.into_inner()).1 = mpsc::channel().0;
+        if let Some(writer) = self.writer.take() {
+            let _ = writer.join();
+        }
+    }
+}
+
+fn writer_gone() -> io::Error {
+    io::Error::new(io::ErrorKind::BrokenPipe, "journal writer thread stopped")
+}
+
+/// Parse every complete record; also returns the byte length they span.
+///
+/// An unparsable last line is a torn write and is ignored; an unparsable line
+/// followed by valid ones means the file is corrupt.
+fn read_records(path: &Path) -> io::Result<(Vec<JournalRecord>, u64)> {
+    let mut reader = BufReader::new(File::open(path)?);
+    let mut records = Vec::new();
+    let mut valid_len = 0u64;
+    let mut torn_at: Option<u64> = None;
+    let mut line = String::new();
+
+    loop {
+        line.clear();
+        let read = reader.read_line(&mut line)?;
+        if read == 0 {
+            break;
+        }
+        let complete = line.ends_with('\n');
+        match serde_json::from_str::<JournalRecord>(line.trim_end()) {
+            Ok(record) if complete => {
+                if let Some(offset) = torn_at {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("{}: corrupt record at byte {}", path.display(), offset),
+                    ));
+                }
+                records.push(record);
+                valid_len += read as u64;
+            }
+            _ if line.trim().is_empty() && complete && torn_at.is_none() => valid_len += read as u64,
+            _ => {
+                torn_at.get_or_insert(valid_len);
+            }
+        }
+    }
+    Ok((records, valid_len))
+}
+
+/// Latest known state of a journaled order
+#[derive(Debug, Clone)]
+pub struct JournaledOrder {
+    pub order: SimulatedOrder,
+    pub purpose: OrderPurpose,
+    pub exchange_order_id: Option<String>,
+    pub filled_quantity: f64,
+    /// Cancelled or rejected
+    pub closed: bool,
+}
+
+/// An `Intent` that never reached `Opened` or `Aborted`
+#[derive(Debug, Clone)]
+pub struct PendingEntry {
+    pub trade_id: String,
+    pub symbol: String,
+    pub long_exchange: String,
+    pub short_exchange: String,
+    pub size_usd: f64,
+    /// Orders the entry sent, in sending order
+    pub orders: Vec<JournaledOrder>,
+}
+
+impl PendingEntry {
+    /// Orders of the entry with a fill, i.e. exposure the crash may have left behind
+    pub fn filled_orders(&self) -> impl Iterator<Item = &JournaledOrder> {
+        self.orders.iter().filter(|o| o.filled_quantity > 0.0)
+    }
+}
+
+/// State rebuilt from a journal
+#[derive(Debug, Clone, Default)]
+pub struct JournalReplay {
+    pub active_trades: Vec<PaperTrade>,
+    pub closed_trades: Vec<PaperTrade>,
+    /// Trades dropped without an outcome; not part of PnL or win/loss counts
+    pub abandoned_trades: Vec<PaperTrade>,
+    pub pending_entries: Vec<PendingEntry>,
+    pub records: usize,
+}
+
+impl JournalReplay {
+    /// Portfolio state implied by the journaled trades.
+    pub fn portfolio_state(&self, starting_capital: f64) -> PortfolioState {
+        let mut state = PortfolioState::new(starting_capital);
+        for trade in &self.closed_trades {
+            state.cumulative_pnl += trade.actual_profit_usd;
+            state.cumulative_funding_pnl += trade.funding_pnl_usd();
+            if trade.actual_profit_usd > 0.0 {
+                state.increment_wins();
+            } else {
+                state.increment_losses();
+            }
+            if trade.leg_out_event.is_some() && trade.actual_profit_usd < 0.0 {
+                state.leg_out_total_loss += trade.actual_profit_usd.abs();
+                state.increment_leg_outs();
+            }
+        }
+        state.total_open_positions = self.active_trades.iter().map(|t| t.position_size_usd).sum();
+        state.available_capital = starting_capital + state.cumulative_pnl - state.total_open_positions;
+        state.active_trades = self.active_trades.clone();
+        state.closed_trades = self.closed_trades.clone();
+        state
+    }
+}
+
+/// Rebuild trades and interrupted entries from the journal at `path`.
+pub fn replay(path: impl AsRef<Path>) -> io::Result<JournalReplay> {
+    let path = path.as_ref();
+    if !path.exists() {
+        return Ok(JournalReplay::default());
+    }
+    let (records, _) = read_records(path)?;
+
+    let mut active: Vec<PaperTrade> = Vec::new();
+    let mut closed = Vec::new();
+    let mut abandoned = Vec::new();
+    let mut pending: Vec<PendingEntry> = Vec::new();
+    let mut order_trade: HashMap<String, String> = HashMap::new();
+
+    fn order_mut<'a>(pending: &'a mut [PendingEntry], trade_id: &Option<String>, client_order_id: &str) -> Option<&'a mut JournaledOrder> {
+        let entry = pending.iter_mut().find(|p| Some(&p.trade_id) == trade_id.as_ref())?;
+        entry.orders.iter_mut().find(|o| o.order.id == client_order_id)
+    }
+
+    for record in &records {
+        match &record.event {
+            JournalEvent::Intent { trade_id, symbol, long_exchange, short_exchange, size_usd, .. } => {
+                pending.push(PendingEntry {
+                    trade_id: trade_id.clone(),
+                    symbol: symbol.clone(),
+                    long_exchange: long_exchange.clone(),
+                    short_exchange: short_exchange.clone(),
+                    size_usd: *size_usd,
+                    orders: Vec::new(),
+                });
+            }
+            JournalEvent::OrderSent { trade_id, purpose, order } => {
+                if let Some(trade_id) = trade_id {
+                    order_trade.insert(order.id.clone(), trade_id.clone());
+                }
+                if let Some(entry) = pending.iter_mut().find(|p| Some(&p.trade_id) == trade_id.as_ref()) {
+                    if !entry.orders.iter().any(|o| o.order.id == order.id) {
+                        entry.orders.push(JournaledOrder {
+                            order: order.clone(),
+                            purpose: *purpose,
+                            exchange_order_id: None,
+                            filled_quantity: 0.0,
+                            closed: false,
+                        });
+                    }
+                }
+            }
+            JournalEvent::OrderAcked { trade_id, client_order_id, exchange_order_id } => {
+                if let Some(order) = order_mut(&mut pending, trade_id, client_order_id) {
+                    order.exchange_order_id = Some(exchange_order_id.clone());
+                }
+            }
+            JournalEvent::Fill { trade_id, client_order_id, quantity, .. } => {
+                if let Some(order) = order_mut(&mut pending, trade_id, client_order_id) {
+                    order.filled_quantity += quantity;
+                }
+            }
+            JournalEvent::OrderCancelled { trade_id, client_order_id }
+            | JournalEvent::OrderRejected { trade_id, client_order_id, .. } => {
+                if let Some(order) = order_mut(&mut pending, trade_id, client_order_id) {
+                    order.closed = true;
+                }
+            }
+            JournalEvent::OrderFailed { .. } => {}
+            JournalEvent::Opened { intent_id, trade } => {
+                pending.retain(|p| &p.trade_id != intent_id);
+                active.retain(|t| t.id != trade.id);
+                active.push(trade.clone());
+            }
+            JournalEvent::Aborted { trade_id, .. } => pending.retain(|p| &p.trade_id != trade_id),
+            JournalEvent::Updated { trade } => match active.iter_mut().find(|t| t.id == trade.id) {
+                Some(existing) => *existing = trade.clone(),
+                None => active.push(trade.clone()),
+            },
+            JournalEvent::Closed { trade_id, profit_usd, exit_reason, exit_time } => {
+                if let Some(pos) = active.iter().position(|t| &t.id == trade_id) {
+                    let mut trade = active.remove(pos);
+                    trade.status = TradeStatus::Closed;
+                    trade.actual_profit_usd = *profit_usd;
+                    trade.exit_reason = Some(exit_reason.clone());
+                    trade.exit_time = Some(*exit_time);
+                    closed.push(trade);
+                }
+            }
+            JournalEvent::Abandoned { trade_id, reason } => {
+                if let Some(pos) = active.iter().position(|t| &t.id == trade_id) {
+                    let mut trade = active.remove(pos);
+                    trade.exit_reason = Some(reason.clone());
+                    abandoned.push(trade);
+                }
+            }
+        }
+    }
+
+    Ok(JournalReplay {
+        active_trades: active,
+        closed_trades: closed,
+        abandoned_trades: abandoned,
+        pending_entries: pending,
+        records: records.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::types::{OrderSide, OrderStatus};
+
+    fn temp_path() -> PathBuf {
+        std::env::temp_dir().join(format!("trade_journal_{}.jsonl", uuid::Uuid::new_v4().simple()))
+    }
+
+    fn order(id: &str, exchange: &str, side: OrderSide, order_type: OrderType) -> SimulatedOrder {
+        SimulatedOrder {
+            id: id.to_string(),
+            exchange: exchange.to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side,
+            order_type,
+            price: 50_000.0,
+            size: 0.02,
+            status: OrderStatus::Pending,
+            ..Default::default()
+        }
+    }
+
+    fn trade(id: &str) -> PaperTrade {
+        PaperTrade {
+            entry_long_price: 50_000.0,
+            entry_short_price: 50_010.0,
+            entry_spread_bps: 2.0,
+            long_order: order("l", "bybit", OrderSide::Long, OrderType::Limit),
+            short_order: order("s", "okx", OrderSide::Short, OrderType::Limit),
+            ..PaperTrade::test_trade(id, "bybit", "okx")
+        }
+    }
+
+    fn intent(trade_id: &str) -> JournalEvent {
+        JournalEvent::Intent {
+            trade_id: trade_id.to_string(),
+            symbol: "BTCUSDT".to_string(),
+            long_exchange: "bybit".to_string(),
+            short_exchange: "okx".to_string(),
+            size_usd: 1_000.0,
+            kind: OpportunityKind::SpreadArbitrage,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_rebuilds_trades_and_portfolio() {
+        let path = temp_path();
+        let journal = TradeJournal::open(&path).unwrap();
+        journal.append(intent("i1")).await.unwrap();
+        journal.append(JournalEvent::Opened { intent_id: "i1".to_string(), trade: trade("t1") }).await.unwrap();
+        journal.append(intent("i2")).await.unwrap();
+        journal.append(JournalEvent::Opened { intent_id: "i2".to_string(), trade: trade("t2") }).await.unwrap();
+        let mut exiting = trade("t1");
+        exiting.status = TradeStatus::Exiting;
+        journal.append(JournalEvent::Updated { trade: exiting }).await.unwrap();
+        journal.append(JournalEvent::Closed {
+            trade_id: "t1".to_string(), profit_usd: 12.5, exit_reason: "spread_closed".to_string(), exit_time: 7,
+        }).await.unwrap();
+        journal.append(intent("i3")).await.unwrap();
+        journal.append(JournalEvent::Aborted { trade_id: "i3".to_string(), reason: "post-only rejected".to_string() }).await.unwrap();
+
+        let replay = journal.replay().await.unwrap();
+        assert_eq!(replay.records, 8);
+        assert!(replay.pending_entries.is_empty());
+        assert_eq!(replay.active_trades.iter().map(|t| t.id.as_str()).collect::<Vec<_>>(), vec!["t2"]);
+        assert_eq!(replay.closed_trades[0].status, TradeStatus::Closed);
+        assert_eq!(replay.closed_trades[0].exit_reason.as_deref(), Some("spread_closed"));
+
+        let state = replay.portfolio_state(10_000.0);
+        assert_eq!(state.cumulative_pnl, 12.5);
+        assert_eq!(state.total_open_positions, 1_000.0);
+        assert_eq!(state.available_capital, 9_012.5);
+        assert_eq!(state.get_win_count(), 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_abandoned_trade_is_left_out_of_pnl() {
+        let path = temp_path();
+        let journal = TradeJournal::open(&path).unwrap();
+        journal.record(intent("i1"));
+        journal.record(JournalEvent::Opened { intent_id: "i1".to_string(), trade: trade("t1") });
+        journal.record(JournalEvent::Abandoned { trade_id: "t1".to_string(), reason: "unreconciled".to_string() });
+
+        // Queued records are on disk once flushed
+        journal.flush().await.unwrap();
+        let replay = replay(&path).unwrap();
+        assert!(replay.active_trades.is_empty());
+        assert!(replay.closed_trades.is_empty());
+        assert_eq!(replay.abandoned_trades[0].exit_reason.as_deref(), Some("unreconciled"));
+
+        let state = replay.portfolio_state(10_000.0);
+        assert_eq!(state.cumulative_pnl, 0.0);
+        assert_eq!(state.get_win_count() + state.get_loss_count(), 0);
+        assert_eq!(state.available_capital, 10_000.0);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_interrupted_entry_keeps_its_orders() {
+        let path = temp_path();
+        let journal = TradeJournal::open(&path).unwrap();
+        let id = Some("i1".to_string());
+        journal.append(intent("i1")).await.unwrap();
+        for (client_id, exchange, side, order_type) in [
+            ("arb-1", "bybit", OrderSide::Long, OrderType::Limit),
+            ("arb-2", "okx", OrderSide::Short, OrderType::Limit),
+            ("arb-3", "okx", OrderSide::Short, OrderType::Market),
+        ] {
+            journal.append(JournalEvent::OrderSent {
+                trade_id: id.clone(),
+                purpose: OrderPurpose::classify(Some(JournalPhase::Entry), order_type),
+                order: order(client_id, exchange, side, order_type),
+            }).await.unwrap();
+        }
+        journal.append(JournalEvent::OrderAcked { trade_id: id.clone(), client_order_id: "arb-1".to_string(), exchange_order_id: "77".to_string() }).await.unwrap();
+        journal.append(JournalEvent::Fill { trade_id: id.clone(), client_order_id: "arb-1".to_string(), quantity: 0.02, price: 50_000.0 }).await.unwrap();
+        journal.append(JournalEvent::OrderCancelled { trade_id: id.clone(), client_order_id: "arb-2".to_string() }).await.unwrap();
+
+        let replay = replay(&path).unwrap();
+        let entry = &replay.pending_entries[0];
+        assert_eq!(entry.trade_id, "i1");
+        assert_eq!(entry.orders.len(), 3);
+        assert_eq!(entry.orders[2].purpose, OrderPurpose::Hedge);
+        let filled: Vec<_> = entry.filled_orders().collect();
+        assert_eq!(filled.len(), 1);
+        assert_eq!(filled[0].exchange_order_id.as_deref(), Some("77"));
+        assert!(entry.orders[1].closed);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_torn_tail_is_dropped_and_appending_continues() {
+        let path = temp_path();
+        {
+            let journal = TradeJournal::open(&path).unwrap();
+            journal.append(intent("i1")).await.unwrap();
+            journal.append(intent("i2")).await.unwrap();
+        }
+        // Crash in the middle of a write
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(br#"{"seq":2,"ts_ms":1,"event":{"type":"abo"#).unwrap();
+        drop(file);
+        assert_eq!(replay(&path).unwrap().records, 2);
+
+        let journal = TradeJournal::open(&path).unwrap();
+        assert_eq!(journal.append(JournalEvent::Aborted { trade_id: "i1".to_string(), reason: "x".to_string() }).await.unwrap(), 2);
+        let replay = journal.replay().await.unwrap();
+        assert_eq!(replay.records, 3);
+        assert_eq!(replay.pending_entries.len(), 1);
+
+        // A bad record followed by good ones is corruption, not a torn write
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(b"garbage\n").unwrap();
+        drop(file);
+        journal.append(intent("i4")).await.unwrap();
+        assert_eq!(journal.replay().await.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_scope_attributes_orders() {
+        assert_eq!(current_scope(), None);
+        let (scope, purpose) = in_scope("i1", JournalPhase::Entry, async {
+            (current_scope(), current_purpose(OrderType::Market))
+        }).await;
+        assert_eq!(scope, Some(("i1".to_string(), JournalPhase::Entry)));
+        assert_eq!(purpose, OrderPurpose::Hedge);
+        assert_eq!(current_purpose(OrderType::Limit), OrderPurpose::Other);
+    }
+}
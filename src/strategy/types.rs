@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use crate::strategy::funding_carry::FundingCarryPosition;
 use zerocopy::{AsBytes, FromBytes, FromZeroes};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -176,6 +177,9 @@ pub struct Cancelled {
 /// let price = filled_order.get_fill_price(); // Only works on filled orders
 /// ```
 /// 
+/// Live order lifecycle tracking (partial fills, rejects, exchange IDs) is
+/// handled at runtime by `order_manager::OrderManager`.
+///
 /// Requirements: 10.1, 10.2, 10.3, 10.4
 #[derive(Debug, Clone)]
 pub struct Order<S> {
@@ -433,8 +437,8 @@ pub struct OrderRequest {
     /// Order type (0 = Market, 1 = Limit)
     pub order_type: u8,
     
-    /// Padding for alignment
-    _pad1: u8,
+    /// Time in force (0 = GTC, 1 = IOC, 2 = FOK, 3 = PostOnly)
+    pub time_in_force: u8,
     
     /// Limit price (for limit orders)
     pub price: f64,
@@ -445,11 +449,17 @@ pub struct OrderRequest {
     /// Timestamp (microseconds since epoch)
     pub timestamp_us: u64,
     
+    /// Order flags (bit 0 = reduce-only, bit 1 = close-position)
+    pub flags: u8,
+    
     /// Padding to 64 bytes (cache line)
-    _padding: [u8; 24],
+    _padding: [u8; 23],
 }
 
 impl OrderRequest {
+    pub const FLAG_REDUCE_ONLY: u8 = 1;
+    pub const FLAG_CLOSE_POSITION: u8 = 1 << 1;
+    
     /// Create a new market order request.
     ///
     /// # Arguments
@@ -480,11 +490,12 @@ impl OrderRequest {
             exchange_id,
             side,
             order_type: 0, // Market
-            _pad1: 0,
+            time_in_force: 0, // GTC
             price: 0.0,
             size,
             timestamp_us,
-            _padding: [0; 24],
+            flags: 0,
+            _padding: [0; 23],
         }
     }
     
@@ -520,14 +531,36 @@ impl OrderRequest {
             exchange_id,
             side,
             order_type: 1, // Limit
-            _pad1: 0,
+            time_in_force: 0, // GTC
             price,
             size,
             timestamp_us,
-            _padding: [0; 24],
+            flags: 0,
+            _padding: [0; 23],
         }
     }
     
+    /// Set the time in force.
+    #[inline(always)]
+    pub fn with_time_in_force(mut self, tif: TimeInForce) -> Self {
+        self.time_in_force = tif as u8;
+        self
+    }
+    
+    /// Mark the order reduce-only.
+    #[inline(always)]
+    pub fn with_reduce_only(mut self) -> Self {
+        self.flags |= Self::FLAG_REDUCE_ONLY;
+        self
+    }
+    
+    /// Mark the order as closing the whole position.
+    #[inline(always)]
+    pub fn with_close_position(mut self) -> Self {
+        self.flags |= Self::FLAG_CLOSE_POSITION;
+        self
+    }
+    
     /// Check if this is a market order.
     #[inline(always)]
     pub fn is_market(&self) -> bool {
@@ -551,9 +584,32 @@ impl OrderRequest {
     pub fn is_sell(&self) -> bool {
         self.side == 1
     }
+    
+    /// Decoded time in force (unknown values fall back to GTC).
+    #[inline(always)]
+    pub fn tif(&self) -> TimeInForce {
+        match self.time_in_force {
+            1 => TimeInForce::Ioc,
+            2 => TimeInForce::Fok,
+            3 => TimeInForce::PostOnly,
+            _ => TimeInForce::Gtc,
+        }
+    }
+    
+    /// Check if this order may only reduce a position.
+    #[inline(always)]
+    pub fn is_reduce_only(&self) -> bool {
+        self.flags & (Self::FLAG_REDUCE_ONLY | Self::FLAG_CLOSE_POSITION) != 0
+    }
+    
+    /// Check if this order closes the whole position.
+    #[inline(always)]
+    pub fn is_close_position(&self) -> bool {
+        self.flags & Self::FLAG_CLOSE_POSITION != 0
+    }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
 pub enum OrderSide {
     #[default]
     Long,
@@ -567,6 +623,20 @@ pub enum OrderType {
     Market,
 }
 
+/// How long an order stays working on the book
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum TimeInForce {
+    /// Good till cancelled
+    #[default]
+    Gtc = 0,
+    /// Immediate or cancel: fill what crosses, cancel the rest
+    Ioc = 1,
+    /// Fill or kill: fill the whole size immediately or nothing
+    Fok = 2,
+    /// Maker only: rejected (or cancelled) if it would take liquidity
+    PostOnly = 3,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Default)]
 pub enum OrderStatus {
     #[default]
@@ -575,6 +645,118 @@ pub enum OrderStatus {
     Cancelled,
 }
 
+/// A perpetual position held on a venue, as reported by its API
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VenuePosition {
+    pub exchange: String,
+    pub symbol: String,      // Normalized symbol (BTCUSDT)
+    pub side: OrderSide,
+    pub size: f64,           // In coins, always positive
+    pub entry_price: f64,
+    #[serde(default)]
+    pub margin: PositionMargin,
+}
+
+/// Margin state of a position, as far as the venue reports it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct PositionMargin {
+    pub mark_price: Option<f64>,
+    pub liquidation_price: Option<f64>,
+    /// Margin allocated to the position, in USDT
+    pub margin_usd: Option<f64>,
+    pub leverage: Option<f64>,
+}
+
+impl PositionMargin {
+    /// Venues report missing values as zero or empty; keep positive ones only
+    pub fn new(mark_price: Option<f64>, liquidation_price: Option<f64>, margin_usd: Option<f64>, leverage: Option<f64>) -> Self {
+        let positive = |v: Option<f64>| v.filter(|v| v.is_finite() && *v > 0.0);
+        Self {
+            mark_price: positive(mark_price),
+            liquidation_price: positive(liquidation_price),
+            margin_usd: positive(margin_usd),
+            leverage: positive(leverage),
+        }
+    }
+
+    /// Adverse move from mark to liquidation for a position on `side`, as a
+    /// fraction of mark (0.25 = liquidated after a 25% move)
+    pub fn liquidation_distance(&self, side: OrderSide) -> Option<f64> {
+        let mark = self.mark_price?;
+        let liquidation = self.liquidation_price?;
+        Some(match side {
+            OrderSide::Long => (mark - liquidation) / mark,
+            OrderSide::Short => (liquidation - mark) / mark,
+        })
+    }
+}
+
+/// A funding payment settled on one trade leg
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FundingPayment {
+    pub exchange: String,
+    pub symbol: String,
+    /// Side of the leg that paid or received it
+    pub side: OrderSide,
+    /// Funding rate of the interval; 0 when the venue does not report it
+    pub rate: f64,
+    /// Positive when received, negative when paid (USDT)
+    pub amount_usd: f64,
+    /// Funding timestamp (unix seconds)
+    pub funding_time: u64,
+}
+
+/// Whether a fill added liquidity to the book or took it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Liquidity {
+    Maker,
+    #[default]
+    Taker,
+}
+
+/// Maker and taker fee of a venue or symbol in basis points; a negative maker
+/// fee is a rebate
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FeeRates {
+    pub maker_bps: f64,
+    pub taker_bps: f64,
+}
+
+impl FeeRates {
+    pub fn bps(&self, liquidity: Liquidity) -> f64 {
+        match liquidity {
+            Liquidity::Maker => self.maker_bps,
+            Liquidity::Taker => self.taker_bps,
+        }
+    }
+}
+
+/// One execution of an order, as reported by the venue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fill {
+    /// Venue execution (trade) id
+    pub id: String,
+    pub order_id: String,
+    pub exchange: String,
+    pub symbol: String,
+    pub side: OrderSide,
+    pub price: f64,
+    /// Executed size in coins
+    pub quantity: f64,
+    /// Fee charged in `fee_asset`; negative for rebates
+    pub fee: f64,
+    pub fee_asset: String,
+    pub liquidity: Liquidity,
+    /// Execution time (unix seconds)
+    pub time: u64,
+}
+
+impl Fill {
+    pub fn notional(&self) -> f64 {
+        self.price * self.quantity
+    }
+}
+
 /// Order status information including filled quantity
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct OrderStatusInfo {
@@ -623,6 +805,46 @@ pub struct SimulatedOrder {
     pub filled_at: Option<u64>,
     pub fill_price: Option<f64>,
     pub status: OrderStatus,
+    /// Ignored for market orders
+    #[serde(default)]
+    pub time_in_force: TimeInForce,
+    /// Never increase the position (exits)
+    #[serde(default)]
+    pub reduce_only: bool,
+    /// Close the whole position regardless of `size` (venues that support it)
+    #[serde(default)]
+    pub close_position: bool,
+}
+
+impl SimulatedOrder {
+    pub fn is_post_only(&self) -> bool {
+        self.order_type == OrderType::Limit && self.time_in_force == TimeInForce::PostOnly
+    }
+
+    /// Reduce-only, or implied by close-position
+    pub fn is_reduce_only(&self) -> bool {
+        self.reduce_only || self.close_position
+    }
+}
+
+/// Which edge an opportunity or trade is built on
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum OpportunityKind {
+    /// Cross-venue price spread expected to converge
+    #[default]
+    SpreadArbitrage,
+    /// Funding differential collected while the position is held
+    FundingCarry,
+}
+
+/// Instrument type of a trade leg
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum InstrumentKind {
+    /// Perpetual swap (margined, funded, can be shorted)
+    #[default]
+    Perp,
+    /// Spot (fully paid, no funding, long-only without borrowing)
+    Spot,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -641,6 +863,25 @@ pub struct ArbitrageOpportunity {
     pub order_book_depth_long: f64,
     pub order_book_depth_short: f64,
     pub timestamp: Option<u64>,  // Unix timestamp in seconds when opportunity was detected
+    #[serde(default)]
+    pub kind: OpportunityKind,
+}
+
+impl ArbitrageOpportunity {
+    /// Instrument bought on the long venue
+    pub fn long_instrument(&self) -> InstrumentKind {
+        crate::strategy::instruments::instrument_kind(&self.long_exchange)
+    }
+
+    /// Instrument sold on the short venue
+    pub fn short_instrument(&self) -> InstrumentKind {
+        crate::strategy::instruments::instrument_kind(&self.short_exchange)
+    }
+
+    /// True when one leg is spot and the other a perpetual (cash-and-carry)
+    pub fn is_spot_perp(&self) -> bool {
+        self.long_instrument() != self.short_instrument()
+    }
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
@@ -688,6 +929,77 @@ pub struct PaperTrade {
     pub stop_loss_long_price: f64,   // Price threshold for long stop
     pub stop_loss_short_price: f64,  // Price threshold for short stop
     pub leg_out_event: Option<LegOutEvent>,
+    #[serde(default)]
+    pub kind: OpportunityKind,
+    /// Funding settled on either leg while the trade was open
+    #[serde(default)]
+    pub funding_payments: Vec<FundingPayment>,
+    /// Venue executions of both legs, entry and exit
+    #[serde(default)]
+    pub fills: Vec<Fill>,
+    /// Holding-policy state of a funding-carry trade
+    #[serde(default)]
+    pub carry: Option<FundingCarryPosition>,
+}
+
+#[cfg(test)]
+impl PaperTrade {
+    /// Active BTCUSDT trade long on `long_exchange` and short on `short_exchange`;
+    /// tests set what they care about with struct update syntax.
+    pub fn test_trade(id: &str, long_exchange: &str, short_exchange: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            symbol: "BTCUSDT".to_string(),
+            long_exchange: long_exchange.to_string(),
+            short_exchange: short_exchange.to_string(),
+            entry_time: 0,
+            entry_long_price: 100.0,
+            entry_short_price: 100.5,
+            entry_spread_bps: 50.0,
+            position_size_usd: 1_000.0,
+            funding_delta_entry: 0.0,
+            projected_profit_usd: 0.0,
+            actual_profit_usd: 0.0,
+            status: TradeStatus::Active,
+            exit_reason: None,
+            exit_spread_bps: None,
+            exit_time: None,
+            long_order: SimulatedOrder::default(),
+            short_order: SimulatedOrder::default(),
+            long_exit_order: None,
+            short_exit_order: None,
+            stop_loss_triggered: false,
+            stop_loss_long_price: 0.0,
+            stop_loss_short_price: 0.0,
+            leg_out_event: None,
+            kind: OpportunityKind::default(),
+            funding_payments: Vec::new(),
+            fills: Vec::new(),
+            carry: None,
+        }
+    }
+}
+
+impl PaperTrade {
+    /// Instrument held on the long venue
+    pub fn long_instrument(&self) -> InstrumentKind {
+        crate::strategy::instruments::instrument_kind(&self.long_exchange)
+    }
+
+    /// Instrument held on the short venue
+    pub fn short_instrument(&self) -> InstrumentKind {
+        crate::strategy::instruments::instrument_kind(&self.short_exchange)
+    }
+
+    /// True when one leg is spot and the other a perpetual (cash-and-carry)
+    pub fn is_spot_perp(&self) -> bool {
+        self.long_instrument() != self.short_instrument()
+    }
+
+    /// Net funding received (positive) or paid (negative) so far, in USDT
+    pub fn funding_pnl_usd(&self) -> f64 {
+        self.funding_payments.iter().map(|p| p.amount_usd).sum()
+    }
 }
 
 #[derive(Debug, Serialize)]
@@ -699,6 +1011,7 @@ pub struct PortfolioState {
     pub active_trades: Vec<PaperTrade>,
     pub closed_trades: Vec<PaperTrade>,
     pub cumulative_pnl: f64,
+    pub cumulative_funding_pnl: f64,  // Funding part of cumulative_pnl
     
     // Atomic counters for lock-free updates
     #[serde(skip)]
@@ -729,6 +1042,7 @@ impl PortfolioState {
             active_trades: Vec::new(),
             closed_trades: Vec::new(),
             cumulative_pnl: 0.0,
+            cumulative_funding_pnl: 0.0,
             win_count: AtomicU64::new(0),
             _pad1: [0; 56],
             loss_count: AtomicU64::new(0),
@@ -780,6 +1094,7 @@ impl PortfolioState {
             active_trades: self.active_trades.clone(),
             closed_trades: self.closed_trades.clone(),
             cumulative_pnl: self.cumulative_pnl,
+            cumulative_funding_pnl: self.cumulative_funding_pnl,
             win_count: self.get_win_count(),
             loss_count: self.get_loss_count(),
             leg_out_count: self.get_leg_out_count(),
@@ -800,6 +1115,7 @@ impl Clone for PortfolioState {
             active_trades: self.active_trades.clone(),
             closed_trades: self.closed_trades.clone(),
             cumulative_pnl: self.cumulative_pnl,
+            cumulative_funding_pnl: self.cumulative_funding_pnl,
             // Clone atomic values by loading and creating new atomics
             win_count: AtomicU64::new(self.win_count.load(Ordering::Relaxed)),
             _pad1: [0; 56],
@@ -822,6 +1138,8 @@ pub struct SerializablePortfolioState {
     pub active_trades: Vec<PaperTrade>,
     pub closed_trades: Vec<PaperTrade>,
     pub cumulative_pnl: f64,
+    #[serde(default)]
+    pub cumulative_funding_pnl: f64,
     pub win_count: u64,
     pub loss_count: u64,
     pub leg_out_count: u64,
@@ -853,6 +1171,8 @@ pub struct PortfolioMetrics {
     pub total_trades: u64,
     pub win_rate: f64,
     pub cumulative_pnl: f64,
+    /// Funding part of `cumulative_pnl`
+    pub funding_pnl: f64,
     pub pnl_percentage: f64,
     pub available_capital: f64,
     pub utilization_pct: f64,
@@ -0,0 +1,69 @@
+//! Private User-Data Stream Events
+//!
+//! Venue-neutral events pushed by authenticated exchange websockets (order
+//! updates, executions, positions). Venue clients such as
+//! `testnet::bybit_private_ws` translate their wire format into these events
+//! and hand them to the `OrderManager`, which becomes the source of truth for
+//! order state while the stream is connected. REST status polling remains a
+//! reconciliation fallback.
+
+use serde::Serialize;
+
+/// Order status as reported by a private order stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum StreamOrderStatus {
+    New,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+    Rejected,
+}
+
+/// Order lifecycle update
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct OrderUpdate {
+    pub venue: String,
+    pub symbol: String,
+    pub exchange_order_id: String,
+    /// Client order ID echoed back by the venue, if any
+    pub client_order_id: Option<String>,
+    pub status: StreamOrderStatus,
+    /// Cumulative filled quantity
+    pub filled_quantity: f64,
+    /// Average fill price so far (0 when nothing filled)
+    pub avg_price: f64,
+    pub reason: Option<String>,
+}
+
+/// Single execution (trade) against one of our orders
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ExecutionUpdate {
+    pub venue: String,
+    pub symbol: String,
+    pub exchange_order_id: String,
+    pub client_order_id: Option<String>,
+    /// Venue execution ID, used to drop duplicate deliveries
+    pub execution_id: String,
+    pub quantity: f64,
+    pub price: f64,
+    pub fee: f64,
+    /// Execution time in milliseconds
+    pub timestamp_ms: u64,
+}
+
+/// Position snapshot for one symbol
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PositionUpdate {
+    pub venue: String,
+    pub symbol: String,
+    /// Signed size: positive long, negative short
+    pub size: f64,
+    pub entry_price: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub enum UserStreamEvent {
+    Order(OrderUpdate),
+    Execution(ExecutionUpdate),
+    Position(PositionUpdate),
+}
@@ -0,0 +1,146 @@
+use arbitrage2::strategy::execution_backend::{BackendCapabilities, ExecutionBackend};
+use arbitrage2::strategy::execution_error::ExecutionError;
+use arbitrage2::strategy::order_manager::{ManagedBackend, OrderManager, OrderState};
+use arbitrage2::strategy::types::{OrderBookDepth, OrderSide, OrderStatus, OrderStatusInfo, OrderType, SimulatedOrder};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Venue that keys live orders by client order ID, like Binance with
+/// `newClientOrderId`: a repeated ID resolves to the order it already holds.
+/// The first `lost_answers` placements are accepted but answer with a timeout.
+struct MockClientIdVenue {
+    orders: Mutex<HashMap<String, SimulatedOrder>>,
+    submissions: Mutex<Vec<String>>,
+    lost_answers: AtomicUsize,
+}
+
+impl MockClientIdVenue {
+    fn new(lost_answers: usize) -> Self {
+        Self {
+            orders: Mutex::new(HashMap::new()),
+            submissions: Mutex::new(Vec::new()),
+            lost_answers: AtomicUsize::new(lost_answers),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ExecutionBackend for MockClientIdVenue {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        self.submissions.lock().unwrap().push(order.id.clone());
+        let mut orders = self.orders.lock().unwrap();
+        if let Some(existing) = orders.get(&order.id) {
+            return Ok(existing.clone());
+        }
+        let placed = SimulatedOrder { id: format!("venue_{}", orders.len() + 1), status: OrderStatus::Pending, ..order.clone() };
+        orders.insert(order.id, placed.clone());
+        if self.lost_answers.load(Ordering::SeqCst) > 0 {
+            self.lost_answers.fetch_sub(1, Ordering::SeqCst);
+            return Err(ExecutionError::Network("operation timed out".to_string()));
+        }
+        Ok(placed)
+    }
+
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        self.place_order(order).await
+    }
+
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
+        Ok(OrderStatus::Pending)
+    }
+
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
+        Ok(OrderStatusInfo::new(OrderStatus::Pending, 0.0, 1.0))
+    }
+
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
+        Ok(1000.0)
+    }
+
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
+        Ok(HashMap::new())
+    }
+
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
+        Ok(true)
+    }
+
+    async fn get_order_book_depth(&self, _exchange: &str, _symbol: &str, _levels: usize) -> Result<OrderBookDepth, ExecutionError> {
+        Err("Not implemented in mock".into())
+    }
+
+    async fn get_best_bid(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
+        Err("Not implemented in mock".into())
+    }
+
+    async fn get_best_ask(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
+        Err("Not implemented in mock".into())
+    }
+
+    fn backend_name(&self) -> &str {
+        "mock"
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
+
+    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
+        Ok(0.001)
+    }
+}
+
+fn limit_order(id: &str) -> SimulatedOrder {
+    SimulatedOrder {
+        id: id.to_string(),
+        exchange: "binance".to_string(),
+        symbol: "BTCUSDT".to_string(),
+        side: OrderSide::Long,
+        order_type: OrderType::Limit,
+        price: 100.0,
+        size: 1.0,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_retry_after_ambiguous_error_does_not_place_a_second_order() {
+    let venue = Arc::new(MockClientIdVenue::new(1));
+    let oms = Arc::new(OrderManager::new());
+    let backend = ManagedBackend::new(venue.clone(), oms.clone());
+
+    // The venue took the order but the answer was lost
+    let lost = backend.place_order(limit_order("entry_long_1")).await;
+    assert!(matches!(lost, Err(ExecutionError::Network(_))));
+    assert_eq!(oms.get("entry_long_1").unwrap().state, OrderState::New);
+
+    // The retry carries the same client order ID and resolves to the accepted order
+    let retried = backend.place_order(limit_order("entry_long_1")).await.unwrap();
+    assert_eq!(retried.id, "venue_1");
+
+    let submissions = venue.submissions.lock().unwrap().clone();
+    assert_eq!(submissions.len(), 2);
+    assert_eq!(submissions[0], submissions[1]);
+    assert_eq!(venue.orders.lock().unwrap().len(), 1);
+
+    let managed = oms.get("entry_long_1").unwrap();
+    assert_eq!(managed.state, OrderState::Acked);
+    assert_eq!(managed.exchange_order_id.as_deref(), Some("venue_1"));
+
+    // Once accepted, further retries don't reach the venue at all
+    backend.place_order(limit_order("entry_long_1")).await.unwrap();
+    assert_eq!(venue.submissions.lock().unwrap().len(), 2);
+}
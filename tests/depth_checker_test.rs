@@ -5,7 +5,8 @@ use tokio::sync::Mutex;
 
 // Import the modules we're testing
 use arbitrage2::strategy::depth_checker::{DepthChecker, DepthCheckResult};
-use arbitrage2::strategy::execution_backend::ExecutionBackend;
+use arbitrage2::strategy::execution_backend::{BackendCapabilities, ExecutionBackend};
+use arbitrage2::strategy::execution_error::ExecutionError;
 use arbitrage2::strategy::types::{OrderBookDepth, PriceLevel, SimulatedOrder, OrderStatus, OrderStatusInfo};
 
 /// Mock ExecutionBackend for testing
@@ -36,43 +37,43 @@ impl MockExecutionBackend {
 
 #[async_trait::async_trait]
 impl ExecutionBackend for MockExecutionBackend {
-    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
         Ok(OrderStatus::Pending)
     }
     
-    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
         Ok(OrderStatusInfo::new(OrderStatus::Pending, 0.0, 0.0))
     }
     
-    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
         Ok(10000.0)
     }
     
-    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
         Ok(HashMap::new())
     }
     
-    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
         Ok(true)
     }
     
@@ -81,7 +82,7 @@ impl ExecutionBackend for MockExecutionBackend {
         exchange: &str,
         symbol: &str,
         _levels: usize,
-    ) -> Result<OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    ) -> Result<OrderBookDepth, ExecutionError> {
         let should_fail = *self.should_fail.lock().await;
         if should_fail {
             return Err("Mock API error".into());
@@ -95,14 +96,14 @@ impl ExecutionBackend for MockExecutionBackend {
             .ok_or_else(|| format!("Order book not found for {}", key).into())
     }
     
-    async fn get_best_bid(&self, exchange: &str, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_best_bid(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError> {
         let depth = self.get_order_book_depth(exchange, symbol, 1).await?;
         depth.bids.first()
             .map(|level| level.price)
             .ok_or_else(|| "No bids available".into())
     }
     
-    async fn get_best_ask(&self, exchange: &str, symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_best_ask(&self, exchange: &str, symbol: &str) -> Result<f64, ExecutionError> {
         let depth = self.get_order_book_depth(exchange, symbol, 1).await?;
         depth.asks.first()
             .map(|level| level.price)
@@ -112,6 +113,10 @@ impl ExecutionBackend for MockExecutionBackend {
     fn backend_name(&self) -> &str {
         "MockBackend"
     }
+    
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
 }
 
 // Helper function to create a mock order book
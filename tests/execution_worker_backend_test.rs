@@ -0,0 +1,147 @@
+use arbitrage2::strategy::execution_backend::{BackendCapabilities, ExecutionBackend};
+use arbitrage2::strategy::execution_error::ExecutionError;
+use arbitrage2::strategy::execution_worker::{ExecutionReport, ExecutionWorker, ExecutionWorkerConfig, WorkerBackend};
+use arbitrage2::strategy::fee_schedule::FeeSchedule;
+use arbitrage2::strategy::symbol_map::SymbolMap;
+use arbitrage2::strategy::trade_journal::{current_scope, in_scope, JournalPhase};
+use arbitrage2::strategy::types::{SimulatedOrder, OrderSide, OrderStatus, OrderType, OrderStatusInfo};
+use std::sync::{Arc, Mutex};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Venue where limit orders rest unfilled; records the journal scope of each placement
+struct MockRestingVenue {
+    placed: Mutex<Vec<(String, Option<(String, JournalPhase)>)>>,
+}
+
+#[async_trait::async_trait]
+impl ExecutionBackend for MockRestingVenue {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    async fn place_order(&self, mut order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        self.placed.lock().unwrap().push((order.id.clone(), current_scope()));
+        order.id = format!("venue_{}", order.id);
+        Ok(order)
+    }
+
+    async fn place_market_order(&self, _order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        Err(ExecutionError::InsufficientBalance("no margin".to_string()))
+    }
+
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
+        Ok(OrderStatus::Pending)
+    }
+
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
+        Ok(OrderStatusInfo::new(OrderStatus::Pending, 0.0, 1.0))
+    }
+
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
+        Ok(1000.0)
+    }
+
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
+        Ok(HashMap::new())
+    }
+
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
+        Ok(true)
+    }
+
+    async fn get_order_book_depth(
+        &self,
+        _exchange: &str,
+        _symbol: &str,
+        _levels: usize,
+    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
+        Err("Not implemented in mock".into())
+    }
+
+    async fn get_best_bid(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
+        Err("Not implemented in mock".into())
+    }
+
+    async fn get_best_ask(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
+        Err("Not implemented in mock".into())
+    }
+
+    fn backend_name(&self) -> &str {
+        "mock"
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
+
+    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
+        Ok(0.001)
+    }
+}
+
+fn order(id: &str, order_type: OrderType) -> SimulatedOrder {
+    SimulatedOrder {
+        id: id.to_string(),
+        exchange: "bybit".to_string(),
+        symbol: "BTCUSDT".to_string(),
+        side: OrderSide::Long,
+        order_type,
+        price: 100.0,
+        size: 1.0,
+        ..Default::default()
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_worker_backend_places_through_the_worker_and_hands_over_resting_orders() {
+    let venue = Arc::new(MockRestingVenue { placed: Mutex::new(Vec::new()) });
+    let config = ExecutionWorkerConfig {
+        fill_poll_interval: Duration::from_millis(10),
+        fill_timeout: Duration::from_millis(100),
+        ..ExecutionWorkerConfig::default()
+    };
+    let symbol_map = Arc::new(SymbolMap::new());
+    let worker = ExecutionWorker::start(config, venue.clone(), symbol_map.clone(), Arc::new(FeeSchedule::default()), tokio::runtime::Handle::current());
+    let handle = worker.handle();
+    let backend = WorkerBackend::new(venue.clone(), handle.clone(), symbol_map);
+
+    // The caller gets the venue's answer, with its client ID and journal scope intact
+    let placed = in_scope("trade-1", JournalPhase::Entry, backend.place_order(order("cid-1", OrderType::Limit))).await.unwrap();
+    assert_eq!(placed.id, "venue_cid-1");
+    assert_eq!(
+        *venue.placed.lock().unwrap(),
+        vec![("cid-1".to_string(), Some(("trade-1".to_string(), JournalPhase::Entry)))]
+    );
+
+    let rejected = backend.place_market_order(order("cid-2", OrderType::Market)).await;
+    assert!(matches!(rejected, Err(ExecutionError::InsufficientBalance(_))));
+
+    let mut reports = Vec::new();
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while reports.iter().filter(|r: &&ExecutionReport| r.is_final()).count() < 2 && Instant::now() < deadline {
+        match handle.poll_report() {
+            Some(report) => reports.push(report),
+            None => tokio::time::sleep(Duration::from_millis(5)).await,
+        }
+    }
+    worker.shutdown();
+
+    // The unfilled limit order is handed back once the fill timeout passes
+    assert!(reports.iter().any(|r| matches!(r, ExecutionReport::Ack { exchange_order_id, .. } if exchange_order_id == "venue_cid-1")));
+    assert!(reports.iter().any(|r| matches!(r, ExecutionReport::Handover { filled_size, .. } if *filled_size == 0.0)));
+    assert!(reports.iter().any(|r| matches!(r, ExecutionReport::Rejected { error: ExecutionError::InsufficientBalance(_), .. })));
+
+    // After shutdown orders are placed inline
+    let inline = backend.place_order(order("cid-3", OrderType::Limit)).await.unwrap();
+    assert_eq!(inline.id, "venue_cid-3");
+    assert_eq!(handle.metrics().dispatched, 2);
+}
@@ -1,5 +1,6 @@
 use arbitrage2::strategy::atomic_execution::{MarketOrderPlacer, HedgeTimingMetrics};
-use arbitrage2::strategy::execution_backend::ExecutionBackend;
+use arbitrage2::strategy::execution_backend::{BackendCapabilities, ExecutionBackend};
+use arbitrage2::strategy::execution_error::ExecutionError;
 use arbitrage2::strategy::types::{SimulatedOrder, OrderSide, OrderStatus, OrderType, OrderStatusInfo};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
@@ -11,43 +12,43 @@ struct MockBackendSuccessFirstAttempt;
 
 #[async_trait::async_trait]
 impl ExecutionBackend for MockBackendSuccessFirstAttempt {
-    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
         Ok(OrderStatus::Filled)
     }
     
-    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
         Ok(OrderStatusInfo::new(OrderStatus::Filled, 1.5, 1.5))
     }
     
-    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
         Ok(1000.0)
     }
     
-    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
         Ok(HashMap::new())
     }
     
-    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
         Ok(true)
     }
     
@@ -56,7 +57,7 @@ impl ExecutionBackend for MockBackendSuccessFirstAttempt {
         _exchange: &str,
         _symbol: &str,
         _levels: usize,
-    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
         Err("Not implemented in mock".into())
     }
 
@@ -64,7 +65,7 @@ impl ExecutionBackend for MockBackendSuccessFirstAttempt {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
@@ -72,7 +73,7 @@ impl ExecutionBackend for MockBackendSuccessFirstAttempt {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
@@ -80,7 +81,11 @@ impl ExecutionBackend for MockBackendSuccessFirstAttempt {
         "mock"
     }
     
-    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
+    
+    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
         Ok(0.001)
     }
 }
@@ -103,6 +108,7 @@ async fn test_place_with_retry_success_first_attempt() {
         filled_at: None,
         fill_price: None,
         status: OrderStatus::Pending,
+        ..Default::default()
     };
     
     let mut metrics = HedgeTimingMetrics::new();
@@ -119,19 +125,19 @@ struct MockBackendSuccessAfterRetries {
 
 #[async_trait::async_trait]
 impl ExecutionBackend for MockBackendSuccessAfterRetries {
-    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         let count = self.attempt_count.fetch_add(1, Ordering::SeqCst);
         if count < 2 {
             // Fail first 2 attempts
@@ -142,27 +148,27 @@ impl ExecutionBackend for MockBackendSuccessAfterRetries {
         }
     }
     
-    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
         Ok(OrderStatus::Filled)
     }
     
-    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
         Ok(OrderStatusInfo::new(OrderStatus::Filled, 1.0, 1.0))
     }
     
-    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
         Ok(1000.0)
     }
     
-    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
         Ok(HashMap::new())
     }
     
-    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
         Ok(true)
     }
     
@@ -171,7 +177,7 @@ impl ExecutionBackend for MockBackendSuccessAfterRetries {
         _exchange: &str,
         _symbol: &str,
         _levels: usize,
-    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
         Err("Not implemented in mock".into())
     }
 
@@ -179,7 +185,7 @@ impl ExecutionBackend for MockBackendSuccessAfterRetries {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
@@ -187,7 +193,7 @@ impl ExecutionBackend for MockBackendSuccessAfterRetries {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
@@ -195,7 +201,11 @@ impl ExecutionBackend for MockBackendSuccessAfterRetries {
         "mock"
     }
     
-    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
+    
+    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
         Ok(0.001)
     }
 }
@@ -220,6 +230,7 @@ async fn test_place_with_retry_success_after_retries() {
         filled_at: None,
         fill_price: None,
         status: OrderStatus::Pending,
+        ..Default::default()
     };
     
     let mut metrics = HedgeTimingMetrics::new();
@@ -232,43 +243,43 @@ struct MockBackendAllFail;
 
 #[async_trait::async_trait]
 impl ExecutionBackend for MockBackendAllFail {
-    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn place_market_order(&self, _order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_market_order(&self, _order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Err("Insufficient balance".into())
     }
     
-    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
         Ok(OrderStatus::Filled)
     }
     
-    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
         Ok(OrderStatusInfo::new(OrderStatus::Pending, 0.0, 1.0))
     }
     
-    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
         Ok(1000.0)
     }
     
-    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
         Ok(HashMap::new())
     }
     
-    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
         Ok(true)
     }
     
@@ -277,7 +288,7 @@ impl ExecutionBackend for MockBackendAllFail {
         _exchange: &str,
         _symbol: &str,
         _levels: usize,
-    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
         Err("Not implemented in mock".into())
     }
 
@@ -285,7 +296,7 @@ impl ExecutionBackend for MockBackendAllFail {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
@@ -293,7 +304,7 @@ impl ExecutionBackend for MockBackendAllFail {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
@@ -301,7 +312,11 @@ impl ExecutionBackend for MockBackendAllFail {
         "mock"
     }
     
-    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
+    
+    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
         Ok(0.001)
     }
 }
@@ -324,13 +339,14 @@ async fn test_place_with_retry_all_attempts_fail() {
         filled_at: None,
         fill_price: None,
         status: OrderStatus::Pending,
+        ..Default::default()
     };
     
     let mut metrics = HedgeTimingMetrics::new();
     let result = placer.place_with_retry(order.clone(), 1.0, 3, &mut metrics).await;
     
     assert!(result.is_err());
-    assert!(result.unwrap_err().contains("Failed to place market order after 3 attempts"));
+    assert!(result.unwrap_err().to_string().contains("Failed to place market order after 3 attempts"));
 }
 
 #[tokio::test]
@@ -353,6 +369,7 @@ async fn test_place_with_retry_exponential_backoff_timing() {
         filled_at: None,
         fill_price: None,
         status: OrderStatus::Pending,
+        ..Default::default()
     };
     
     let mut metrics = HedgeTimingMetrics::new();
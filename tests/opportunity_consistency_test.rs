@@ -15,7 +15,7 @@
 // - Order preserved across consumers
 
 use arbitrage2::strategy::opportunity_queue::OpportunityQueue;
-use arbitrage2::strategy::types::{ArbitrageOpportunity, ConfluenceMetrics, HardConstraints};
+use arbitrage2::strategy::types::{ArbitrageOpportunity, ConfluenceMetrics, HardConstraints, OpportunityKind};
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
@@ -56,6 +56,7 @@ fn create_test_opportunity(id: u64, spread_bps: f64) -> ArbitrageOpportunity {
                 .unwrap()
                 .as_secs()
         ),
+        kind: OpportunityKind::SpreadArbitrage,
     }
 }
 
@@ -1,5 +1,5 @@
 use arbitrage2::strategy::opportunity_queue::OpportunityQueue;
-use arbitrage2::strategy::types::{ArbitrageOpportunity, ConfluenceMetrics, HardConstraints};
+use arbitrage2::strategy::types::{ArbitrageOpportunity, ConfluenceMetrics, HardConstraints, OpportunityKind};
 use std::time::Instant;
 
 fn create_test_opportunity(symbol: &str, spread_bps: f64) -> ArbitrageOpportunity {
@@ -33,6 +33,7 @@ fn create_test_opportunity(symbol: &str, spread_bps: f64) -> ArbitrageOpportunit
         order_book_depth_long: 10000.0,
         order_book_depth_short: 10000.0,
         timestamp: Some(1234567890),
+        kind: OpportunityKind::SpreadArbitrage,
     }
 }
 
@@ -1,5 +1,6 @@
 use arbitrage2::strategy::atomic_execution::OrderStatusChecker;
-use arbitrage2::strategy::execution_backend::ExecutionBackend;
+use arbitrage2::strategy::execution_backend::{BackendCapabilities, ExecutionBackend};
+use arbitrage2::strategy::execution_error::ExecutionError;
 use arbitrage2::strategy::types::{SimulatedOrder, OrderStatus, OrderStatusInfo};
 use std::error::Error;
 use std::sync::Arc;
@@ -15,31 +16,31 @@ struct MockBackend {
 
 #[async_trait::async_trait]
 impl ExecutionBackend for MockBackend {
-    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
         Ok(OrderStatus::Filled)
     }
     
-    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
         self.call_count.fetch_add(1, Ordering::SeqCst);
         
         if self.should_fail {
@@ -49,15 +50,15 @@ impl ExecutionBackend for MockBackend {
         }
     }
     
-    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
         Ok(1000.0)
     }
     
-    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
         Ok(HashMap::new())
     }
     
-    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
         Ok(true)
     }
     
@@ -66,7 +67,7 @@ impl ExecutionBackend for MockBackend {
         _exchange: &str,
         _symbol: &str,
         _levels: usize,
-    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
         Err("Not implemented in mock".into())
     }
 
@@ -74,7 +75,7 @@ impl ExecutionBackend for MockBackend {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
@@ -82,7 +83,7 @@ impl ExecutionBackend for MockBackend {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
@@ -90,7 +91,11 @@ impl ExecutionBackend for MockBackend {
         "mock"
     }
     
-    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
+    
+    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
         Ok(0.001)
     }
 }
@@ -182,43 +187,43 @@ struct MockCancelSuccessBackend;
 
 #[async_trait::async_trait]
 impl ExecutionBackend for MockCancelSuccessBackend {
-    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
         Ok(OrderStatus::Filled)
     }
     
-    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
         Ok(OrderStatusInfo::new(OrderStatus::Filled, 1.5, 1.5))
     }
     
-    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
         Ok(1000.0)
     }
     
-    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
         Ok(HashMap::new())
     }
     
-    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
         Ok(true)
     }
     
@@ -227,7 +232,7 @@ impl ExecutionBackend for MockCancelSuccessBackend {
         _exchange: &str,
         _symbol: &str,
         _levels: usize,
-    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
         Err("Not implemented in mock".into())
     }
 
@@ -235,13 +240,17 @@ impl ExecutionBackend for MockCancelSuccessBackend {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
     fn backend_name(&self) -> &str {
         "mock_cancel_success"
     }
+    
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
 }
 
 // Mock backend that simulates Binance "already filled" error
@@ -249,43 +258,43 @@ struct MockBinanceAlreadyFilledBackend;
 
 #[async_trait::async_trait]
 impl ExecutionBackend for MockBinanceAlreadyFilledBackend {
-    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
         Err("Failed to cancel order: UNKNOWN_ORDER".into())
     }
     
-    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
         Ok(OrderStatus::Filled)
     }
     
-    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
         Ok(OrderStatusInfo::new(OrderStatus::Filled, 2.5, 2.5))
     }
     
-    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
         Ok(1000.0)
     }
     
-    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
         Ok(HashMap::new())
     }
     
-    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
         Ok(true)
     }
     
@@ -294,7 +303,7 @@ impl ExecutionBackend for MockBinanceAlreadyFilledBackend {
         _exchange: &str,
         _symbol: &str,
         _levels: usize,
-    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
         Err("Not implemented in mock".into())
     }
 
@@ -302,13 +311,17 @@ impl ExecutionBackend for MockBinanceAlreadyFilledBackend {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
     fn backend_name(&self) -> &str {
         "mock_binance_already_filled"
     }
+    
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
 }
 
 // Mock backend that simulates Bybit "already filled" error
@@ -316,43 +329,43 @@ struct MockBybitAlreadyFilledBackend;
 
 #[async_trait::async_trait]
 impl ExecutionBackend for MockBybitAlreadyFilledBackend {
-    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
         Err("Failed to cancel order: 110017".into())
     }
     
-    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
         Ok(OrderStatus::Filled)
     }
     
-    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
         Ok(OrderStatusInfo::new(OrderStatus::Filled, 3.5, 3.5))
     }
     
-    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
         Ok(1000.0)
     }
     
-    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
         Ok(HashMap::new())
     }
     
-    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
         Ok(true)
     }
     
@@ -361,7 +374,7 @@ impl ExecutionBackend for MockBybitAlreadyFilledBackend {
         _exchange: &str,
         _symbol: &str,
         _levels: usize,
-    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
         Err("Not implemented in mock".into())
     }
 
@@ -369,13 +382,17 @@ impl ExecutionBackend for MockBybitAlreadyFilledBackend {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
     fn backend_name(&self) -> &str {
         "mock_bybit_already_filled"
     }
+    
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
 }
 
 // Mock backend that simulates generic cancellation failure
@@ -383,43 +400,43 @@ struct MockCancelFailureBackend;
 
 #[async_trait::async_trait]
 impl ExecutionBackend for MockCancelFailureBackend {
-    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
         Ok(())
     }
     
-    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
         Ok(order)
     }
     
-    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
         Err("Network timeout during cancellation".into())
     }
     
-    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
         Ok(OrderStatus::Pending)
     }
     
-    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
         Ok(OrderStatusInfo::new(OrderStatus::Pending, 0.0, 1.5))
     }
     
-    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
         Ok(1000.0)
     }
     
-    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
         Ok(HashMap::new())
     }
     
-    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
         Ok(true)
     }
     
@@ -428,7 +445,7 @@ impl ExecutionBackend for MockCancelFailureBackend {
         _exchange: &str,
         _symbol: &str,
         _levels: usize,
-    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, Box<dyn Error + Send + Sync>> {
+    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
         Err("Not implemented in mock".into())
     }
 
@@ -436,13 +453,17 @@ impl ExecutionBackend for MockCancelFailureBackend {
         &self,
         _exchange: &str,
         _symbol: &str,
-    ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+    ) -> Result<f64, ExecutionError> {
         Err("Not implemented in mock".into())
     }
     
     fn backend_name(&self) -> &str {
         "mock_cancel_failure"
     }
+    
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities::simulated()
+    }
 }
 
 #[tokio::test]
@@ -518,43 +539,43 @@ async fn test_verify_cancellation_binance_error_codes() {
     
     #[async_trait::async_trait]
     impl ExecutionBackend for MockBinanceErrorBackend {
-        async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+        async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
             Ok(())
         }
         
-        async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
             Ok(())
         }
         
-        async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
             Ok(order)
         }
         
-        async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
             Ok(order)
         }
         
-        async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
             Err(self.error_msg.clone().into())
         }
         
-        async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+        async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
             Ok(OrderStatus::Filled)
         }
         
-        async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+        async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
             Ok(OrderStatusInfo::new(OrderStatus::Filled, 1.0, 1.0))
         }
         
-        async fn get_available_balance(&self, _exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
             Ok(1000.0)
         }
         
-        async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+        async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
             Ok(HashMap::new())
         }
         
-        async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
             Ok(true)
         }
         
@@ -563,7 +584,7 @@ async fn test_verify_cancellation_binance_error_codes() {
             _exchange: &str,
             _symbol: &str,
             _levels: usize,
-        ) -> Result<arbitrage2::strategy::types::OrderBookDepth, Box<dyn Error + Send + Sync>> {
+        ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
             Err("Not implemented in mock".into())
         }
 
@@ -571,13 +592,20 @@ async fn test_verify_cancellation_binance_error_codes() {
             &self,
             _exchange: &str,
             _symbol: &str,
-        ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        ) -> Result<f64, ExecutionError> {
             Err("Not implemented in mock".into())
         }
         
         fn backend_name(&self) -> &str {
             "mock_binance_error"
         }
+
+        
+        fn capabilities(&self) -> BackendCapabilities {
+        
+            BackendCapabilities::simulated()
+        
+        }
     }
     
     // Test "Unknown order sent" error
@@ -606,43 +634,43 @@ async fn test_verify_cancellation_bybit_error_codes() {
     
     #[async_trait::async_trait]
     impl ExecutionBackend for MockBybitErrorBackend {
-        async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), Box<dyn Error + Send + Sync>> {
+        async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
             Ok(())
         }
         
-        async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
             Ok(())
         }
         
-        async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        async fn place_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
             Ok(order)
         }
         
-        async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, Box<dyn Error + Send + Sync>> {
+        async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
             Ok(order)
         }
         
-        async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+        async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
             Err(self.error_msg.clone().into())
         }
         
-        async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, Box<dyn Error + Send + Sync>> {
+        async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
             Ok(OrderStatus::Filled)
         }
         
-        async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, Box<dyn Error + Send + Sync>> {
+        async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
             Ok(OrderStatusInfo::new(OrderStatus::Filled, 1.0, 1.0))
         }
         
-        async fn get_available_balance(&self, _exchange: &str) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
             Ok(1000.0)
         }
         
-        async fn get_all_balances(&self) -> Result<HashMap<String, f64>, Box<dyn Error + Send + Sync>> {
+        async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
             Ok(HashMap::new())
         }
         
-        async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
             Ok(true)
         }
         
@@ -651,7 +679,7 @@ async fn test_verify_cancellation_bybit_error_codes() {
             _exchange: &str,
             _symbol: &str,
             _levels: usize,
-        ) -> Result<arbitrage2::strategy::types::OrderBookDepth, Box<dyn Error + Send + Sync>> {
+        ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
             Err("Not implemented in mock".into())
         }
 
@@ -659,13 +687,20 @@ async fn test_verify_cancellation_bybit_error_codes() {
             &self,
             _exchange: &str,
             _symbol: &str,
-        ) -> Result<f64, Box<dyn Error + Send + Sync>> {
+        ) -> Result<f64, ExecutionError> {
             Err("Not implemented in mock".into())
         }
         
         fn backend_name(&self) -> &str {
             "mock_bybit_error"
         }
+
+        
+        fn capabilities(&self) -> BackendCapabilities {
+        
+            BackendCapabilities::simulated()
+        
+        }
     }
     
     // Test error code 110001 (order not found)
@@ -0,0 +1,144 @@
+use arbitrage2::strategy::execution_backend::{BackendCapabilities, ExecutionBackend};
+use arbitrage2::strategy::execution_error::ExecutionError;
+use arbitrage2::strategy::price_chaser::{PriceChaser, RepricingConfig};
+use arbitrage2::strategy::types::{SimulatedOrder, OrderSide, OrderStatus, OrderType, OrderStatusInfo, TimeInForce};
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::collections::HashMap;
+
+/// Book at 99.9 / 100.1 that rejects post-only orders crossing it
+struct MockCrossingBook {
+    placed_prices: Mutex<Vec<f64>>,
+}
+
+impl MockCrossingBook {
+    const BID: f64 = 99.9;
+    const ASK: f64 = 100.1;
+
+    fn new() -> Self {
+        Self { placed_prices: Mutex::new(Vec::new()) }
+    }
+}
+
+#[async_trait::async_trait]
+impl ExecutionBackend for MockCrossingBook {
+    async fn set_leverage(&self, _exchange: &str, _symbol: &str, _leverage: u8) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    async fn set_margin_type_isolated(&self, _exchange: &str, _symbol: &str) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    async fn place_order(&self, mut order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        self.placed_prices.lock().unwrap().push(order.price);
+        let crosses = match order.side {
+            OrderSide::Long => order.price >= Self::ASK,
+            OrderSide::Short => order.price <= Self::BID,
+        };
+        if crosses && order.is_post_only() {
+            return Err(ExecutionError::PostOnlyRejected("would take liquidity".to_string()));
+        }
+        order.id = format!("resting_{}", self.placed_prices.lock().unwrap().len());
+        Ok(order)
+    }
+
+    async fn place_market_order(&self, order: SimulatedOrder) -> Result<SimulatedOrder, ExecutionError> {
+        Ok(order)
+    }
+
+    async fn cancel_order(&self, _exchange: &str, _order_id: &str) -> Result<(), ExecutionError> {
+        Ok(())
+    }
+
+    async fn get_order_status(&self, _exchange: &str, _order_id: &str) -> Result<OrderStatus, ExecutionError> {
+        Ok(OrderStatus::Pending)
+    }
+
+    async fn get_order_status_detailed(&self, _exchange: &str, _order_id: &str, _symbol: &str) -> Result<OrderStatusInfo, ExecutionError> {
+        Ok(OrderStatusInfo::new(OrderStatus::Pending, 0.0, 1.0))
+    }
+
+    async fn get_available_balance(&self, _exchange: &str) -> Result<f64, ExecutionError> {
+        Ok(1000.0)
+    }
+
+    async fn get_all_balances(&self) -> Result<HashMap<String, f64>, ExecutionError> {
+        Ok(HashMap::new())
+    }
+
+    async fn is_symbol_tradeable(&self, _exchange: &str, _symbol: &str) -> Result<bool, ExecutionError> {
+        Ok(true)
+    }
+
+    async fn get_order_book_depth(
+        &self,
+        _exchange: &str,
+        _symbol: &str,
+        _levels: usize,
+    ) -> Result<arbitrage2::strategy::types::OrderBookDepth, ExecutionError> {
+        Err("Not implemented in mock".into())
+    }
+
+    async fn get_best_bid(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
+        Ok(Self::BID)
+    }
+
+    async fn get_best_ask(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
+        Ok(Self::ASK)
+    }
+
+    fn backend_name(&self) -> &str {
+        "mock"
+    }
+
+    fn capabilities(&self) -> BackendCapabilities {
+        BackendCapabilities { supports_post_only: true, ..BackendCapabilities::simulated() }
+    }
+
+    async fn get_quantity_step(&self, _exchange: &str, _symbol: &str) -> Result<f64, ExecutionError> {
+        Ok(0.001)
+    }
+}
+
+fn resting(side: OrderSide, price: f64, time_in_force: TimeInForce) -> SimulatedOrder {
+    SimulatedOrder {
+        exchange: "binance".to_string(),
+        symbol: "BTCUSDT".to_string(),
+        side,
+        order_type: OrderType::Limit,
+        price,
+        size: 1.0,
+        time_in_force,
+        ..Default::default()
+    }
+}
+
+#[tokio::test]
+async fn test_crossing_post_only_is_reposted_at_the_passive_touch() {
+    let backend = Arc::new(MockCrossingBook::new());
+    let chaser = PriceChaser::new(backend.clone(), RepricingConfig::balanced());
+
+    let buy = chaser.place_resting(resting(OrderSide::Long, 100.1, TimeInForce::PostOnly)).await.unwrap();
+    assert_eq!(buy.price, MockCrossingBook::BID);
+
+    let sell = chaser.place_resting(resting(OrderSide::Short, 99.9, TimeInForce::PostOnly)).await.unwrap();
+    assert_eq!(sell.price, MockCrossingBook::ASK);
+
+    assert_eq!(*backend.placed_prices.lock().unwrap(), vec![100.1, 99.9, 99.9, 100.1]);
+}
+
+#[tokio::test]
+async fn test_passive_post_only_and_gtc_are_placed_as_given() {
+    let backend = Arc::new(MockCrossingBook::new());
+    let chaser = PriceChaser::new(backend.clone(), RepricingConfig::balanced());
+
+    let passive = chaser.place_resting(resting(OrderSide::Long, 99.8, TimeInForce::PostOnly)).await.unwrap();
+    assert_eq!(passive.price, 99.8);
+
+    // Only post-only orders are rejected for crossing
+    let crossing_gtc = chaser.place_resting(resting(OrderSide::Long, 100.2, TimeInForce::Gtc)).await.unwrap();
+    assert_eq!(crossing_gtc.price, 100.2);
+
+    assert_eq!(backend.placed_prices.lock().unwrap().len(), 2);
+}
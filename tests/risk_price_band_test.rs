@@ -0,0 +1,57 @@
+use arbitrage2::strategy::execution_backend::ExecutionBackend;
+use arbitrage2::strategy::execution_error::ExecutionError;
+use arbitrage2::strategy::order_manager::{ManagedBackend, OrderManager, OrderState};
+use arbitrage2::strategy::paper_trading_backend::PaperTradingBackend;
+use arbitrage2::strategy::risk_engine::{RiskEngine, RiskLimits};
+use arbitrage2::strategy::symbol_map::SymbolMap;
+use arbitrage2::strategy::types::{MarketUpdate, OrderSide, OrderType, SimulatedOrder};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn now_us() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_micros() as u64
+}
+
+fn limit_order(id: &str, price: f64) -> SimulatedOrder {
+    SimulatedOrder {
+        id: id.to_string(),
+        exchange: "bybit".to_string(),
+        symbol: "BTCUSDT".to_string(),
+        side: OrderSide::Long,
+        order_type: OrderType::Limit,
+        price,
+        size: 0.1,
+        ..Default::default()
+    }
+}
+
+/// Wired like `main`: quotes are written to the risk engine's store under the
+/// shared symbol map, and every order goes through the OMS and its risk check.
+#[tokio::test]
+async fn test_order_priced_off_an_old_quote_is_refused_by_the_price_band() {
+    let symbol_map = Arc::new(SymbolMap::new());
+    let limits = RiskLimits { price_band_bps: 100.0, ..Default::default() };
+    let risk = Arc::new(RiskEngine::new(limits, symbol_map.clone()));
+    let oms = Arc::new(OrderManager::new().with_risk_engine(risk.clone()));
+    let mut balances = HashMap::new();
+    balances.insert("bybit".to_string(), 100_000.0);
+    let backend = ManagedBackend::new(Arc::new(PaperTradingBackend::new(balances)), oms.clone());
+
+    let market_data = risk.market_data();
+    let quote = |bid: f64, ask: f64| {
+        let update = MarketUpdate::new(symbol_map.get_or_insert("bybit", "BTCUSDT"), bid, ask, now_us());
+        market_data.write().unwrap().update_from_market_update(&update);
+    };
+
+    quote(99.9, 100.1);
+    backend.place_order(limit_order("entry_1", 100.2)).await.unwrap();
+
+    // The market moves away; an order still priced off the earlier quote is out of band
+    quote(109.9, 110.1);
+    let refused = backend.place_order(limit_order("entry_2", 100.2)).await.unwrap_err();
+    assert!(matches!(refused, ExecutionError::RiskRejected(ref reason) if reason.contains("vs mid 110")));
+    assert_eq!(oms.get("entry_2").unwrap().state, OrderState::Rejected);
+
+    backend.place_order(limit_order("entry_3", 110.2)).await.unwrap();
+}
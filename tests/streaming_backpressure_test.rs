@@ -17,7 +17,7 @@
 
 use arbitrage2::strategy::pipeline::MarketPipeline;
 use arbitrage2::strategy::opportunity_queue::OpportunityQueue;
-use arbitrage2::strategy::types::{MarketUpdate, ArbitrageOpportunity, ConfluenceMetrics, HardConstraints};
+use arbitrage2::strategy::types::{MarketUpdate, ArbitrageOpportunity, ConfluenceMetrics, HardConstraints, OpportunityKind};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::Arc;
 use std::thread;
@@ -65,6 +65,7 @@ fn create_test_opportunity(id: u64, spread_bps: f64) -> ArbitrageOpportunity {
                 .unwrap()
                 .as_secs()
         ),
+        kind: OpportunityKind::SpreadArbitrage,
     }
 }
 
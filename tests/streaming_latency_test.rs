@@ -13,7 +13,7 @@
 
 use arbitrage2::strategy::pipeline::MarketPipeline;
 use arbitrage2::strategy::opportunity_queue::OpportunityQueue;
-use arbitrage2::strategy::types::{MarketUpdate, ArbitrageOpportunity};
+use arbitrage2::strategy::types::{MarketUpdate, ArbitrageOpportunity, OpportunityKind};
 use std::sync::Arc;
 use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
@@ -343,6 +343,7 @@ fn create_test_opportunity(id: u64) -> ArbitrageOpportunity {
                 .unwrap()
                 .as_secs()
         ),
+        kind: OpportunityKind::SpreadArbitrage,
     }
 }
 
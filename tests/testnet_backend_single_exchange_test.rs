@@ -19,6 +19,9 @@ async fn test_single_exchange_mode_routes_to_primary() {
         okx: None,
         kucoin: None,
         bitget: None,
+        gateio: None,
+        hyperliquid: None,
+        paradex: None,
         single_exchange_mode: true,
         primary_exchange: "bybit".to_string(),
     };
@@ -69,6 +72,9 @@ async fn test_normal_mode_routes_to_specified_exchange() {
         okx: None,
         kucoin: None,
         bitget: None,
+        gateio: None,
+        hyperliquid: None,
+        paradex: None,
         single_exchange_mode: false,
         primary_exchange: "bybit".to_string(),
     };